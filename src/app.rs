@@ -1,13 +1,25 @@
 use crate::cache::Cache;
 use std::cell::RefCell;
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
 
 const UNASSIGNED_TEAM_NAME: &str = "Unassigned";
 const UNASSIGNED_TEAM_EMAIL: &str = "__unassigned__";
 const NO_EPIC_KEY: &str = "NO-EPIC";
 const NO_EPIC_SUMMARY: &str = "No Epic";
 
-pub const ISSUE_TYPES: &[&str] = &["Task", "Bug", "Story"];
+pub const ISSUE_TYPES: &[&str] = &["Task", "Bug", "Story", EPIC_ISSUE_TYPE];
+/// Issue type for epics; jira-cli creates these through `jira epic create`.
+pub const EPIC_ISSUE_TYPE: &str = "Epic";
+/// Issue type used when creating a sub-task under the ticket in detail view.
+pub const SUBTASK_ISSUE_TYPE: &str = "Sub-task";
+/// Priorities offered by the create form; index 0 in the picker means "project default".
+pub const PRIORITIES: &[&str] = &[
+    "Blocker - P1",
+    "Critical - P2",
+    "Major - P3",
+    "Minor - P4",
+    "Trivial - P5",
+];
 
 /// An item in the visible selection list — either a group header or a ticket.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -36,28 +48,287 @@ pub struct EditFieldsState {
     pub labels: String, // comma-separated
 }
 
+#[derive(Debug, Clone)]
+pub struct WorklogState {
+    pub ticket_key: String,
+    pub focused_field: usize, // 0=duration, 1=date, 2=comment
+    pub duration: String,
+    pub date: String, // YYYY-MM-DD
+    pub comment: String,
+}
+
+/// A running work timer for a ticket that is In Progress.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct WorkTimer {
+    pub ticket_key: String,
+    pub started_at_unix_secs: u64,
+}
+
+impl WorkTimer {
+    pub fn elapsed_secs(&self, now_unix_secs: u64) -> u64 {
+        now_unix_secs.saturating_sub(self.started_at_unix_secs)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct CreateTicketState {
-    pub focused_field: usize, // 0=type, 1=summary, 2=assignee, 3=epic
+    /// Set when the form was opened from a ticket's detail view to derive new work.
+    pub derived_from: Option<DerivedFrom>,
+    pub focused_field: usize, // see the CreateTicketState::* field constants
+    pub template_idx: usize,  // 0 = "None", then 1..N = config templates
     pub issue_type_idx: usize,
     pub summary: String,
+    pub description: String,
+    pub labels: String,      // comma-separated
+    pub priority_idx: usize, // 0 = "Default", then 1..N = PRIORITIES
+    pub components: String,  // comma-separated
+    pub story_points: String,
     pub assignee_idx: usize, // 0 = "None", then 1..N = team members
     pub epic_idx: usize,     // 0 = "None", then 1..N = cached epics
 }
 
+impl CreateTicketState {
+    pub const TEMPLATE: usize = 0;
+    pub const TYPE: usize = 1;
+    pub const SUMMARY: usize = 2;
+    pub const DESCRIPTION: usize = 3;
+    pub const LABELS: usize = 4;
+    pub const PRIORITY: usize = 5;
+    pub const COMPONENTS: usize = 6;
+    pub const STORY_POINTS: usize = 7;
+    pub const ASSIGNEE: usize = 8;
+    pub const EPIC: usize = 9;
+    pub const FIELD_COUNT: usize = 10;
+
+    pub fn new() -> Self {
+        Self {
+            derived_from: None,
+            focused_field: Self::TEMPLATE,
+            template_idx: 0,
+            issue_type_idx: 0,
+            summary: String::new(),
+            description: String::new(),
+            labels: String::new(),
+            priority_idx: 0,
+            components: String::new(),
+            story_points: String::new(),
+            assignee_idx: 0,
+            epic_idx: 0,
+        }
+    }
+
+    /// A blank form with the issue type preset to Epic and the summary focused.
+    pub fn new_epic() -> Self {
+        let mut state = Self::new();
+        state.select_issue_type(EPIC_ISSUE_TYPE);
+        state.focused_field = Self::SUMMARY;
+        state
+    }
+
+    pub fn is_epic(&self) -> bool {
+        !self.is_subtask() && ISSUE_TYPES.get(self.issue_type_idx) == Some(&EPIC_ISSUE_TYPE)
+    }
+
+    /// Sub-task forms always create a Sub-task, so their type can't be changed.
+    pub fn is_subtask(&self) -> bool {
+        self.derived_from
+            .as_ref()
+            .is_some_and(|d| d.kind == DeriveKind::SubTask)
+    }
+
+    fn select_issue_type(&mut self, issue_type: &str) {
+        if let Some(idx) = ISSUE_TYPES
+            .iter()
+            .position(|t| t.eq_ignore_ascii_case(issue_type))
+        {
+            self.issue_type_idx = idx;
+        }
+    }
+
+    pub fn is_text_field(&self) -> bool {
+        matches!(
+            self.focused_field,
+            Self::SUMMARY
+                | Self::DESCRIPTION
+                | Self::LABELS
+                | Self::COMPONENTS
+                | Self::STORY_POINTS
+        )
+    }
+
+    pub fn focused_text_mut(&mut self) -> Option<&mut String> {
+        match self.focused_field {
+            Self::SUMMARY => Some(&mut self.summary),
+            Self::DESCRIPTION => Some(&mut self.description),
+            Self::LABELS => Some(&mut self.labels),
+            Self::COMPONENTS => Some(&mut self.components),
+            Self::STORY_POINTS => Some(&mut self.story_points),
+            _ => None,
+        }
+    }
+
+    /// Switch the form from the `previous` template to `next`. A field is only filled when
+    /// it still holds what `previous` put there (blank for "None"), so text the user typed
+    /// or copied from a source ticket is kept. Summary, assignee and epic are never touched,
+    /// nor the type of a sub-task.
+    pub fn apply_template(
+        &mut self,
+        previous: Option<&crate::config::TicketTemplate>,
+        next: Option<&crate::config::TicketTemplate>,
+    ) {
+        fn refill<T: PartialEq>(field: &mut T, previous: T, next: T) {
+            if *field == previous {
+                *field = next;
+            }
+        }
+        let (old, new) = (TemplateValues::of(previous), TemplateValues::of(next));
+        if let Some(idx) = new.issue_type_idx.filter(|_| !self.is_subtask()) {
+            if old
+                .issue_type_idx
+                .is_none_or(|old| old == self.issue_type_idx)
+            {
+                self.issue_type_idx = idx;
+            }
+        }
+        refill(&mut self.description, old.description, new.description);
+        refill(&mut self.labels, old.labels, new.labels);
+        refill(&mut self.priority_idx, old.priority_idx, new.priority_idx);
+        refill(&mut self.components, old.components, new.components);
+        refill(&mut self.story_points, old.story_points, new.story_points);
+    }
+}
+
+/// What a ticket template puts into each create form field; `None` gives the blank form.
+struct TemplateValues {
+    issue_type_idx: Option<usize>,
+    description: String,
+    labels: String,
+    priority_idx: usize,
+    components: String,
+    story_points: String,
+}
+
+impl TemplateValues {
+    fn of(template: Option<&crate::config::TicketTemplate>) -> Self {
+        let Some(template) = template else {
+            return Self {
+                issue_type_idx: None,
+                description: String::new(),
+                labels: String::new(),
+                priority_idx: 0,
+                components: String::new(),
+                story_points: String::new(),
+            };
+        };
+        Self {
+            issue_type_idx: template.issue_type.as_deref().and_then(|issue_type| {
+                ISSUE_TYPES
+                    .iter()
+                    .position(|t| t.eq_ignore_ascii_case(issue_type))
+            }),
+            description: template.description.clone().unwrap_or_default(),
+            labels: template.labels.join(", "),
+            priority_idx: template
+                .priority
+                .as_deref()
+                .and_then(|p| PRIORITIES.iter().position(|x| x.eq_ignore_ascii_case(p)))
+                .map(|idx| idx + 1)
+                .unwrap_or(0),
+            components: template.components.join(", "),
+            story_points: template
+                .story_points
+                .map(|p| p.to_string())
+                .unwrap_or_default(),
+        }
+    }
+}
+
+impl Default for CreateTicketState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Ways to derive a new ticket from the one open in detail view.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeriveKind {
+    Clone,
+    SubTask,
+    FollowUp,
+}
+
+impl DeriveKind {
+    pub fn all() -> &'static [DeriveKind] {
+        &[DeriveKind::Clone, DeriveKind::SubTask, DeriveKind::FollowUp]
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            DeriveKind::Clone => "Clone",
+            DeriveKind::SubTask => "Sub-task",
+            DeriveKind::FollowUp => "Follow-up (relates to)",
+        }
+    }
+
+    pub fn shortcut(&self) -> char {
+        match self {
+            DeriveKind::Clone => 'c',
+            DeriveKind::SubTask => 's',
+            DeriveKind::FollowUp => 'f',
+        }
+    }
+
+    pub fn from_shortcut(c: char) -> Option<Self> {
+        Self::all().iter().copied().find(|k| k.shortcut() == c)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DerivedFrom {
+    pub kind: DeriveKind,
+    pub source_key: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub struct BulkUploadRow {
     pub row_number: usize,
+    /// Existing ticket to update instead of creating a new one.
+    pub key: Option<String>,
     pub issue_type: String,
     pub summary: String,
     pub assignee_email: Option<String>,
     pub epic_key: Option<String>,
+    pub parent_key: Option<String>,
     pub labels: Vec<String>,
     pub description: Option<String>,
+    pub priority: Option<String>,
+    pub components: Vec<String>,
+    /// Validated as a number; kept as text so rows stay `Eq`.
+    pub story_points: Option<String>,
+    pub due_date: Option<String>,
+    /// `field:<name>` columns: jira-cli custom field name -> value.
+    pub custom_fields: BTreeMap<String, String>,
+    /// Changes an update row makes to the cached ticket.
+    pub diffs: Vec<FieldDiff>,
     pub errors: Vec<String>,
     pub warnings: Vec<String>,
 }
 
+impl BulkUploadRow {
+    /// Update rows whose values all match the cached ticket are not sent.
+    pub fn is_noop_update(&self) -> bool {
+        self.key.is_some() && self.diffs.is_empty()
+    }
+}
+
+/// One field an upsert row changes; `current` is `?` when it is not cached.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldDiff {
+    pub field: String,
+    pub current: String,
+    pub new: String,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct BulkUploadPreview {
     pub source_path: String,
@@ -82,6 +353,7 @@ pub struct BulkUploadSummary {
     pub succeeded: usize,
     pub failed: usize,
     pub created_keys: Vec<String>,
+    pub updated_keys: Vec<String>,
     pub failed_details: Vec<(usize, String, String)>,
 }
 
@@ -98,19 +370,23 @@ pub enum BulkUploadState {
     Running {
         preview: BulkUploadPreview,
     },
+    /// `preview` is kept so failed rows can be retried.
     Result {
         summary: BulkUploadSummary,
+        preview: BulkUploadPreview,
     },
 }
 
 /// Which tab is currently active.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub enum Tab {
     MyWork,
     Team,
     Epics,
     Unassigned,
+    Watching,
     Filters,
+    Dashboard,
 }
 
 impl Tab {
@@ -119,8 +395,10 @@ impl Tab {
             Tab::MyWork => Tab::Team,
             Tab::Team => Tab::Epics,
             Tab::Epics => Tab::Unassigned,
-            Tab::Unassigned => Tab::Filters,
-            Tab::Filters => Tab::MyWork,
+            Tab::Unassigned => Tab::Watching,
+            Tab::Watching => Tab::Filters,
+            Tab::Filters => Tab::Dashboard,
+            Tab::Dashboard => Tab::MyWork,
         }
     }
 
@@ -130,7 +408,9 @@ impl Tab {
             Tab::Team => "Team",
             Tab::Epics => "Epics",
             Tab::Unassigned => "Unassigned",
+            Tab::Watching => "Watching",
             Tab::Filters => "Filters",
+            Tab::Dashboard => "Dashboard",
         }
     }
 
@@ -140,11 +420,24 @@ impl Tab {
             Tab::Team,
             Tab::Epics,
             Tab::Unassigned,
+            Tab::Watching,
             Tab::Filters,
+            Tab::Dashboard,
         ]
     }
 }
 
+/// Where ticket and epic detail is shown.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DetailLayout {
+    /// Over the list, opened with Enter.
+    #[default]
+    Overlay,
+    /// In a right-hand pane that previews the selected row.
+    Split,
+}
+
 /// Which pane is focused in the Filters tab.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum FilterFocus {
@@ -179,6 +472,8 @@ pub enum DetailMode {
     },
     /// Showing the activity/history timeline with scroll offset.
     History { scroll: u16 },
+    /// Choosing how to derive a new ticket (clone, sub-task, follow-up).
+    DerivePicker { selected: usize },
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -198,6 +493,45 @@ pub enum GroupSelectionState {
 pub enum BulkAction {
     Move,
     Assign,
+    Epic,
+    AddLabels,
+    RemoveLabels,
+    Priority,
+    Comment,
+    CustomField,
+    /// Restore a summary; only produced by undo.
+    Summary,
+    /// Replay of a journal entry's inverse operations.
+    Undo,
+}
+
+impl BulkAction {
+    /// Actions in the order shown by the bulk action menu.
+    pub const MENU: &'static [BulkAction] = &[
+        BulkAction::Move,
+        BulkAction::Assign,
+        BulkAction::Epic,
+        BulkAction::AddLabels,
+        BulkAction::RemoveLabels,
+        BulkAction::Priority,
+        BulkAction::Comment,
+        BulkAction::CustomField,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            BulkAction::Move => "Move tickets",
+            BulkAction::Assign => "Assign tickets",
+            BulkAction::Epic => "Change epic",
+            BulkAction::AddLabels => "Add labels",
+            BulkAction::RemoveLabels => "Remove labels",
+            BulkAction::Priority => "Set priority",
+            BulkAction::Comment => "Add comment",
+            BulkAction::CustomField => "Set custom field",
+            BulkAction::Summary => "Set summary",
+            BulkAction::Undo => "Undo",
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -205,13 +539,260 @@ pub enum BulkTarget {
     Move {
         status: crate::cache::Status,
         resolution: Option<String>,
+        /// Clear the resolution after the transition; undoes a move that set one.
+        clear_resolution: bool,
     },
     Assign {
         member_email: String,
         member_name: String,
     },
+    /// Link tickets to an epic; `None` removes them from their current epic.
+    Epic {
+        epic_key: Option<String>,
+        epic_name: Option<String>,
+    },
+    AddLabels {
+        labels: Vec<String>,
+    },
+    RemoveLabels {
+        labels: Vec<String>,
+    },
+    Priority {
+        priority: String,
+    },
+    /// `body` may contain `{key}`-style placeholders, filled in per ticket.
+    Comment {
+        body: String,
+    },
+    /// `name` is the jira-cli custom field name; `field_id` is used to read current values.
+    CustomField {
+        name: String,
+        field_id: String,
+        value: String,
+    },
+    Summary {
+        summary: String,
+    },
+    /// Summary-level target for an undo run; each ticket runs its own recorded inverse.
+    Undo {
+        label: String,
+    },
+}
+
+impl BulkTarget {
+    pub fn action(&self) -> BulkAction {
+        match self {
+            BulkTarget::Move { .. } => BulkAction::Move,
+            BulkTarget::Assign { .. } => BulkAction::Assign,
+            BulkTarget::Epic { .. } => BulkAction::Epic,
+            BulkTarget::AddLabels { .. } => BulkAction::AddLabels,
+            BulkTarget::RemoveLabels { .. } => BulkAction::RemoveLabels,
+            BulkTarget::Priority { .. } => BulkAction::Priority,
+            BulkTarget::Comment { .. } => BulkAction::Comment,
+            BulkTarget::CustomField { .. } => BulkAction::CustomField,
+            BulkTarget::Summary { .. } => BulkAction::Summary,
+            BulkTarget::Undo { .. } => BulkAction::Undo,
+        }
+    }
+
+    pub fn describe(&self) -> String {
+        match self {
+            BulkTarget::Move {
+                status,
+                resolution,
+                clear_resolution,
+            } => match resolution {
+                Some(resolution) => {
+                    format!("Move to {} (resolution: {})", status.as_str(), resolution)
+                }
+                None if *clear_resolution => {
+                    format!("Move to {} (clear resolution)", status.as_str())
+                }
+                None => format!("Move to {}", status.as_str()),
+            },
+            BulkTarget::Assign { member_email, .. } if member_email.is_empty() => {
+                "Unassign".to_string()
+            }
+            BulkTarget::Assign {
+                member_name,
+                member_email,
+            } => format!("Assign to {} ({})", member_name, member_email),
+            BulkTarget::Epic {
+                epic_key: Some(epic_key),
+                epic_name,
+            } => match epic_name {
+                Some(name) => format!("Add to epic {} ({})", epic_key, name),
+                None => format!("Add to epic {}", epic_key),
+            },
+            BulkTarget::Epic { epic_key: None, .. } => "Remove from epic".to_string(),
+            BulkTarget::AddLabels { labels } => format!("Add labels: {}", labels.join(", ")),
+            BulkTarget::RemoveLabels { labels } => {
+                format!("Remove labels: {}", labels.join(", "))
+            }
+            BulkTarget::Priority { priority } => format!("Set priority to {}", priority),
+            BulkTarget::Comment { body } => {
+                format!("Comment: {}", body.lines().next().unwrap_or_default())
+            }
+            BulkTarget::CustomField { name, value, .. } => format!("Set {} to {}", name, value),
+            BulkTarget::Summary { summary } => format!("Set summary to \"{}\"", summary),
+            BulkTarget::Undo { label } => format!("Undo: {}", label),
+        }
+    }
+
+    /// The ticket's cached value for the field this target changes, for dry-run previews.
+    pub fn current_value(&self, ticket: &crate::cache::Ticket) -> String {
+        fn or_dash(value: Option<&str>) -> String {
+            value.unwrap_or("-").to_string()
+        }
+        match self {
+            BulkTarget::Move { .. } => ticket.status.as_str().to_string(),
+            BulkTarget::Assign { .. } => ticket
+                .assignee
+                .clone()
+                .unwrap_or_else(|| "Unassigned".to_string()),
+            BulkTarget::Epic { .. } => or_dash(ticket.epic_key.as_deref()),
+            BulkTarget::AddLabels { .. } | BulkTarget::RemoveLabels { .. } => {
+                if !ticket.detail_loaded {
+                    "(detail not loaded)".to_string()
+                } else if ticket.labels.is_empty() {
+                    "-".to_string()
+                } else {
+                    ticket.labels.join(", ")
+                }
+            }
+            BulkTarget::Priority { .. } => or_dash(ticket.priority.as_deref()),
+            BulkTarget::Comment { .. } => {
+                let comments = ticket
+                    .activity
+                    .iter()
+                    .filter(|e| matches!(e.kind, crate::cache::ActivityKind::Comment { .. }))
+                    .count();
+                format!("{} comments", comments)
+            }
+            BulkTarget::CustomField { field_id, .. } => {
+                or_dash(ticket.custom_fields.get(field_id).map(|s| s.as_str()))
+            }
+            BulkTarget::Summary { .. } => ticket.summary.clone(),
+            BulkTarget::Undo { .. } => "-".to_string(),
+        }
+    }
+
+    /// The operation that restores `ticket` to its cached state before this target ran.
+    /// `None` when the change cannot be reverted or the prior value is unknown.
+    pub fn inverse_for(&self, ticket: &crate::cache::Ticket) -> Option<BulkTarget> {
+        match self {
+            BulkTarget::Move { resolution, .. } => Some(BulkTarget::Move {
+                status: ticket.status.clone(),
+                resolution: ticket.resolution.clone(),
+                clear_resolution: resolution.is_some() && ticket.resolution.is_none(),
+            }),
+            BulkTarget::Assign { .. } => Some(BulkTarget::Assign {
+                member_email: ticket.assignee_email.clone().unwrap_or_default(),
+                member_name: ticket.assignee.clone().unwrap_or_default(),
+            }),
+            BulkTarget::Epic { .. } => Some(BulkTarget::Epic {
+                epic_key: ticket.epic_key.clone(),
+                epic_name: ticket.epic_name.clone(),
+            }),
+            BulkTarget::AddLabels { labels } => {
+                let added: Vec<String> = labels
+                    .iter()
+                    .filter(|l| !ticket.labels.contains(l))
+                    .cloned()
+                    .collect();
+                (!added.is_empty()).then_some(BulkTarget::RemoveLabels { labels: added })
+            }
+            BulkTarget::RemoveLabels { labels } => {
+                let removed: Vec<String> = labels
+                    .iter()
+                    .filter(|l| ticket.labels.contains(l))
+                    .cloned()
+                    .collect();
+                (!removed.is_empty()).then_some(BulkTarget::AddLabels { labels: removed })
+            }
+            BulkTarget::Priority { .. } => ticket
+                .priority
+                .clone()
+                .map(|priority| BulkTarget::Priority { priority }),
+            BulkTarget::CustomField { name, field_id, .. } => ticket
+                .custom_fields
+                .get(field_id)
+                .map(|value| BulkTarget::CustomField {
+                    name: name.clone(),
+                    field_id: field_id.clone(),
+                    value: value.clone(),
+                }),
+            BulkTarget::Summary { summary } => {
+                (ticket.summary != *summary).then(|| BulkTarget::Summary {
+                    summary: ticket.summary.clone(),
+                })
+            }
+            BulkTarget::Comment { .. } | BulkTarget::Undo { .. } => None,
+        }
+    }
+}
+
+/// One reversible change: a ticket and the operation that restores its prior state.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JournalChange {
+    pub key: String,
+    pub inverse: BulkTarget,
+}
+
+/// An undoable action in the journal, e.g. a single move or a whole bulk run.
+#[derive(Debug, Clone)]
+pub struct JournalEntry {
+    pub label: String,
+    pub recorded_at: u64,
+    pub changes: Vec<JournalChange>,
+}
+
+/// The standup report overlay: who it covers, how it renders and the rendered text.
+#[derive(Debug, Clone)]
+pub struct StandupState {
+    pub scope: crate::standup::StandupScope,
+    pub format: crate::standup::StandupFormat,
+    pub window_hours: u32,
+    pub text: String,
+    pub scroll: u16,
+}
+
+/// Go-to-key prompt: a typed key, or a pick from the recently viewed list.
+#[derive(Debug, Clone, Default)]
+pub struct GotoKeyState {
+    pub input: String,
+    /// Index into `recently_viewed` when a recent entry is highlighted.
+    pub selected: Option<usize>,
+}
+
+/// The create-branch prompt for a ticket.
+#[derive(Debug, Clone)]
+pub struct GitBranchState {
+    pub ticket_key: String,
+    pub name: String,
 }
 
+/// What a mouse click at a screen position refers to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClickTarget {
+    Tab(Tab),
+    /// A selectable row of the active tab's list, by item index.
+    Item(usize),
+    /// An entry of the Filters sidebar.
+    Filter(usize),
+}
+
+/// Detail views kept for back/forward.
+const NAV_HISTORY_LIMIT: usize = 100;
+/// Entries kept in the persisted recently-viewed list.
+const RECENTLY_VIEWED_LIMIT: usize = 20;
+
+/// Two clicks on the same target within this window open it.
+pub const DOUBLE_CLICK: std::time::Duration = std::time::Duration::from_millis(400);
+
+/// Number of journal entries kept for undo; older entries are dropped.
+pub const JOURNAL_LIMIT: usize = 50;
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct BulkSummary {
     pub action: BulkAction,
@@ -222,9 +803,19 @@ pub struct BulkSummary {
     pub skipped: usize,
     pub failed: usize,
     pub successful_keys: Vec<String>,
+    pub skipped_keys: Vec<String>,
     pub failed_details: Vec<(String, String)>,
 }
 
+/// One ticket in a bulk dry-run: its current value, the planned change and any skip reason.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BulkPreviewRow {
+    pub key: String,
+    pub current: String,
+    pub planned: String,
+    pub skip: Option<String>,
+}
+
 #[derive(Debug, Clone)]
 pub enum BulkState {
     ActionPicker {
@@ -244,10 +835,51 @@ pub enum BulkState {
         targets: Vec<String>,
         selected: usize,
     },
+    /// 0 = remove from epic, then 1..N = cached epics.
+    EpicPicker {
+        targets: Vec<String>,
+        selected: usize,
+    },
+    /// Comma- or space-separated labels to add (or remove when `remove` is set).
+    LabelsInput {
+        targets: Vec<String>,
+        remove: bool,
+        input: String,
+    },
+    PriorityPicker {
+        targets: Vec<String>,
+        selected: usize,
+    },
+    /// 0 = blank comment, then 1..N = config comment templates.
+    CommentTemplatePicker {
+        targets: Vec<String>,
+        selected: usize,
+    },
+    CommentInput {
+        targets: Vec<String>,
+        body: String,
+    },
+    CustomFieldPicker {
+        targets: Vec<String>,
+        selected: usize,
+    },
+    CustomFieldInput {
+        targets: Vec<String>,
+        name: String,
+        field_id: String,
+        value: String,
+    },
     Confirm {
         targets: Vec<String>,
         target: BulkTarget,
     },
+    /// Per-ticket preview of a confirmed action; nothing has run yet.
+    DryRun {
+        targets: Vec<String>,
+        target: BulkTarget,
+        rows: Vec<BulkPreviewRow>,
+        selected: usize,
+    },
     Running {
         targets: Vec<String>,
         target: BulkTarget,
@@ -302,8 +934,13 @@ pub struct App {
     pub ticket_sync_stage: Option<TicketSyncStage>,
     /// Age of the cache snapshot loaded at startup, in seconds.
     pub cache_stale_age_secs: Option<u64>,
+    pub detail_layout: DetailLayout,
     /// Whether the keybindings overlay is visible.
     pub show_keybindings: bool,
+    /// Scroll offset of the keybindings overlay.
+    pub keybindings_scroll: u16,
+    /// Active key bindings, from the `[keys]` config.
+    pub keymap: crate::keymap::Keymap,
     /// Ticket keys currently being fetched for rich detail.
     detail_fetching: HashSet<String>,
     /// Monotonic generation used to invalidate derived visibility caches.
@@ -325,6 +962,10 @@ pub struct App {
     pub assign_state: Option<AssignState>,
     /// State for the edit fields modal overlay.
     pub edit_state: Option<EditFieldsState>,
+    /// State for the log work modal overlay.
+    pub worklog_state: Option<WorklogState>,
+    /// Running work timer, if any. Only one ticket is timed at a time.
+    pub work_timer: Option<WorkTimer>,
     /// Which pane is focused in the Filters tab.
     pub filter_focus: FilterFocus,
     /// Index of the selected filter in the sidebar.
@@ -340,6 +981,48 @@ pub struct App {
     pub collapsed_team: HashSet<String>,
     pub collapsed_epics: HashSet<String>,
     pub collapsed_unassigned: HashSet<String>,
+    pub collapsed_watching: HashSet<String>,
+    /// Tickets opened directly by key that are not part of any cached list.
+    pub adhoc_tickets: Vec<crate::cache::Ticket>,
+    /// Ticket key whose detail should open once its fetch completes.
+    pub pending_detail_open: Option<String>,
+    /// Undoable actions, oldest first.
+    pub journal: Vec<JournalEntry>,
+    /// Label and inverses for the bulk run in flight; recorded for the keys that succeed.
+    pub pending_bulk_journal: Option<(String, Vec<JournalChange>)>,
+    /// Journal entry currently being undone.
+    pub undo_in_flight: Option<JournalEntry>,
+    /// Selected row when the undo history list is open.
+    pub undo_history: Option<usize>,
+    /// Selected format when the export picker is open.
+    pub export_picker: Option<usize>,
+    /// Standup report overlay, when open.
+    pub standup: Option<StandupState>,
+    /// Ticket key named by the checked-out git branch.
+    pub branch_ticket_key: Option<String>,
+    /// Create-branch prompt, when open.
+    pub git_branch: Option<GitBranchState>,
+    /// Command palette, when open.
+    pub command_palette: Option<crate::palette::PaletteState>,
+    /// Go-to-key prompt, when open.
+    pub goto_key: Option<GotoKeyState>,
+    /// Detail views visited this session, for back/forward; `nav_pos` is the current one.
+    nav_history: Vec<crate::cache::Viewed>,
+    nav_pos: usize,
+    /// Tickets whose watcher names were requested this session, so each is fetched once.
+    watcher_names_requested: HashSet<String>,
+    /// Most recently viewed first, persisted across sessions.
+    pub recently_viewed: Vec<crate::cache::Viewed>,
+    /// Ticket keys with a remote branch at the last scan; None before the first scan.
+    pub pushed_branch_keys: Option<HashSet<String>>,
+    /// My tickets whose branch was pushed, waiting for a y/n on moving them to In Review.
+    pub review_prompts: Vec<String>,
+    /// Local commits mentioning each ticket key, newest first, from the last git scan.
+    pub ticket_commits: HashMap<String, Vec<crate::git::CommitRef>>,
+    /// Clickable areas from the last draw, rebuilt on every frame.
+    click_targets: RefCell<Vec<(ratatui::layout::Rect, ClickTarget)>>,
+    /// Last click, for double-click detection.
+    last_click: Option<(std::time::Instant, ClickTarget)>,
     /// Optional epic focus order used by the Epics tab; empty means show all epics.
     epics_i_care_about_rank: HashMap<String, usize>,
 }
@@ -362,7 +1045,10 @@ impl App {
             epics_refreshing: false,
             ticket_sync_stage: None,
             cache_stale_age_secs: None,
+            detail_layout: DetailLayout::Overlay,
             show_keybindings: false,
+            keybindings_scroll: 0,
+            keymap: crate::keymap::Keymap::default(),
             detail_fetching: HashSet::new(),
             view_generation: 0,
             visible_keys_cache: RefCell::new(VisibleKeysCache::default()),
@@ -374,6 +1060,8 @@ impl App {
             comment_state: None,
             assign_state: None,
             edit_state: None,
+            worklog_state: None,
+            work_timer: None,
             filter_focus: FilterFocus::Sidebar,
             filter_sidebar_idx: 0,
             filter_results: Vec::new(),
@@ -383,6 +1071,28 @@ impl App {
             collapsed_team: HashSet::new(),
             collapsed_epics: HashSet::new(),
             collapsed_unassigned: HashSet::new(),
+            collapsed_watching: HashSet::new(),
+            adhoc_tickets: Vec::new(),
+            pending_detail_open: None,
+            journal: Vec::new(),
+            pending_bulk_journal: None,
+            undo_in_flight: None,
+            undo_history: None,
+            export_picker: None,
+            standup: None,
+            branch_ticket_key: None,
+            git_branch: None,
+            command_palette: None,
+            goto_key: None,
+            nav_history: Vec::new(),
+            nav_pos: 0,
+            watcher_names_requested: HashSet::new(),
+            recently_viewed: Vec::new(),
+            pushed_branch_keys: None,
+            review_prompts: Vec::new(),
+            ticket_commits: HashMap::new(),
+            click_targets: RefCell::new(Vec::new()),
+            last_click: None,
             epics_i_care_about_rank: HashMap::new(),
         }
     }
@@ -405,6 +1115,45 @@ impl App {
         }
     }
 
+    /// Snapshot of the view state saved on quit.
+    pub fn ui_state(&self) -> crate::ui_state::UiState {
+        crate::ui_state::UiState {
+            active_tab: Some(self.active_tab),
+            show_done: self.show_done,
+            status_focus: self.status_focus.clone(),
+            filter_sidebar_idx: self.filter_sidebar_idx,
+            search: self.search.clone().filter(|s| !s.is_empty()),
+            collapsed_my_work: self.collapsed_my_work.iter().cloned().collect(),
+            collapsed_team: self.collapsed_team.iter().cloned().collect(),
+            collapsed_epics: self.collapsed_epics.iter().cloned().collect(),
+            collapsed_unassigned: self.collapsed_unassigned.iter().cloned().collect(),
+            collapsed_watching: self.collapsed_watching.iter().cloned().collect(),
+            detail_layout: self.detail_layout,
+            work_timer: self.work_timer.clone(),
+        }
+    }
+
+    /// Restore the view state from the last session. The filter index is clamped to the
+    /// filters that still exist.
+    pub fn restore_ui_state(&mut self, state: crate::ui_state::UiState, filter_count: usize) {
+        if let Some(tab) = state.active_tab {
+            self.active_tab = tab;
+        }
+        self.show_done = state.show_done;
+        self.status_focus = state.status_focus;
+        self.filter_sidebar_idx = state.filter_sidebar_idx.min(filter_count.saturating_sub(1));
+        self.search = state.search.filter(|s| !s.is_empty());
+        self.collapsed_my_work = state.collapsed_my_work.into_iter().collect();
+        self.collapsed_team = state.collapsed_team.into_iter().collect();
+        self.collapsed_epics = state.collapsed_epics.into_iter().collect();
+        self.collapsed_unassigned = state.collapsed_unassigned.into_iter().collect();
+        self.collapsed_watching = state.collapsed_watching.into_iter().collect();
+        self.detail_layout = state.detail_layout;
+        self.work_timer = state.work_timer;
+        self.selected_index = 0;
+        self.mark_cache_changed();
+    }
+
     pub fn replace_cache(&mut self, cache: Cache) {
         self.cache = cache;
         self.mark_cache_changed();
@@ -425,6 +1174,7 @@ impl App {
     }
 
     pub fn open_detail(&mut self, key: String) {
+        self.record_visit(crate::cache::Viewed::Ticket(key.clone()));
         self.detail_ticket_key = Some(key);
         self.detail_epic_key = None;
         self.detail_mode = DetailMode::View;
@@ -432,12 +1182,46 @@ impl App {
     }
 
     pub fn open_epic_detail(&mut self, key: String) {
+        self.record_visit(crate::cache::Viewed::Epic(key.clone()));
         self.detail_epic_key = Some(key);
         self.detail_ticket_key = None;
         self.detail_mode = DetailMode::View;
         self.detail_scroll = 0;
     }
 
+    /// Move `viewed` to the front of the recent list, and make it the current history entry
+    /// unless it already is (so reopening the entry back/forward landed on changes nothing).
+    fn record_visit(&mut self, viewed: crate::cache::Viewed) {
+        self.recently_viewed.retain(|v| *v != viewed);
+        self.recently_viewed.insert(0, viewed.clone());
+        self.recently_viewed.truncate(RECENTLY_VIEWED_LIMIT);
+
+        if self.nav_history.get(self.nav_pos) == Some(&viewed) {
+            return;
+        }
+        self.nav_history.truncate(self.nav_pos + 1);
+        self.nav_history.push(viewed);
+        if self.nav_history.len() > NAV_HISTORY_LIMIT {
+            self.nav_history.remove(0);
+        }
+        self.nav_pos = self.nav_history.len() - 1;
+    }
+
+    /// Step back through the detail history. With the detail closed this reopens the
+    /// current entry instead of stepping past it.
+    pub fn nav_back(&mut self) -> Option<crate::cache::Viewed> {
+        if self.is_detail_open() {
+            self.nav_pos = self.nav_pos.checked_sub(1)?;
+        }
+        self.nav_history.get(self.nav_pos).cloned()
+    }
+
+    pub fn nav_forward(&mut self) -> Option<crate::cache::Viewed> {
+        let next = self.nav_history.get(self.nav_pos + 1)?.clone();
+        self.nav_pos += 1;
+        Some(next)
+    }
+
     pub fn close_detail(&mut self) {
         self.detail_ticket_key = None;
         self.detail_epic_key = None;
@@ -445,6 +1229,81 @@ impl App {
         self.detail_scroll = 0;
     }
 
+    pub fn set_tab(&mut self, tab: Tab) {
+        if self.active_tab != tab {
+            self.active_tab = tab;
+            self.selected_index = 0;
+            self.clamp_selection();
+        }
+    }
+
+    pub fn toggle_detail_layout(&mut self) {
+        self.detail_layout = match self.detail_layout {
+            DetailLayout::Overlay => DetailLayout::Split,
+            DetailLayout::Split => DetailLayout::Overlay,
+        };
+    }
+
+    /// Whether the current tab is drawn as list plus preview pane.
+    pub fn is_split_view(&self) -> bool {
+        self.detail_layout == DetailLayout::Split && self.active_tab != Tab::Dashboard
+    }
+
+    /// Ticket shown in the preview pane whose detail has not been fetched yet.
+    pub fn preview_fetch_key(&self) -> Option<String> {
+        if !self.is_split_view() || self.is_detail_open() {
+            return None;
+        }
+        let key = self.selected_ticket_key()?;
+        let ticket = self.find_ticket(&key)?;
+        (!ticket.detail_loaded && !self.detail_fetching.contains(&key)).then_some(key)
+    }
+
+    pub fn clear_click_targets(&self) {
+        self.click_targets.borrow_mut().clear();
+    }
+
+    pub fn add_click_target(&self, area: ratatui::layout::Rect, target: ClickTarget) {
+        self.click_targets.borrow_mut().push((area, target));
+    }
+
+    pub fn click_target_at(&self, column: u16, row: u16) -> Option<ClickTarget> {
+        let position = ratatui::layout::Position::new(column, row);
+        self.click_targets
+            .borrow()
+            .iter()
+            .find(|(area, _)| area.contains(position))
+            .map(|(_, target)| *target)
+    }
+
+    /// Record a click and report whether it completes a double-click on the same target.
+    pub fn register_click(&mut self, target: ClickTarget, now: std::time::Instant) -> bool {
+        let double = self.last_click.is_some_and(|(at, previous)| {
+            previous == target && now.saturating_duration_since(at) <= DOUBLE_CLICK
+        });
+        self.last_click = if double { None } else { Some((now, target)) };
+        double
+    }
+
+    /// A modal other than the detail view or keybindings help has the keyboard.
+    pub fn is_modal_open(&self) -> bool {
+        self.is_filter_edit_open()
+            || self.is_bulk_upload_open()
+            || self.is_create_ticket_open()
+            || self.is_comment_open()
+            || self.is_assign_open()
+            || self.is_edit_open()
+            || self.is_worklog_open()
+            || self.is_bulk_open()
+            || self.is_export_picker_open()
+            || self.is_undo_history_open()
+            || self.is_standup_open()
+            || self.is_git_branch_open()
+            || self.is_command_palette_open()
+            || self.is_goto_key_open()
+            || self.is_review_prompt_open()
+    }
+
     pub fn is_detail_open(&self) -> bool {
         self.detail_ticket_key.is_some() || self.detail_epic_key.is_some()
     }
@@ -499,11 +1358,172 @@ impl App {
             Tab::Team => self.team_visible_items(),
             Tab::Epics => self.epics_visible_items(),
             Tab::Unassigned => self.unassigned_visible_items(),
+            Tab::Watching => self.watching_visible_items(),
             Tab::Filters => self
                 .filter_results
                 .iter()
                 .map(|t| VisibleItem::Ticket(t.key.clone()))
                 .collect(),
+            Tab::Dashboard => Vec::new(),
+        }
+    }
+
+    /// Tickets visible in the active tab, in display order, with the label of their group.
+    pub fn visible_tickets_for_export(&self) -> Vec<(String, &crate::cache::Ticket)> {
+        let mut group = String::new();
+        let mut rows = Vec::new();
+        for item in self.compute_visible_items_for_tab(self.active_tab) {
+            match item {
+                VisibleItem::GroupHeader(id) => group = self.group_label(&id),
+                VisibleItem::Ticket(key) => {
+                    if let Some(ticket) = self.find_ticket(&key) {
+                        rows.push((group.clone(), ticket));
+                    }
+                }
+            }
+        }
+        rows
+    }
+
+    /// Readable name for a group header id: member names on the Team tab, epic
+    /// summaries on the Epics tab, otherwise the id itself (a status).
+    fn group_label(&self, id: &str) -> String {
+        match self.active_tab {
+            Tab::Team => self
+                .cache
+                .team_members
+                .iter()
+                .find(|m| m.email == id)
+                .map(|m| m.name.clone())
+                .unwrap_or_else(|| id.to_string()),
+            Tab::Epics => self
+                .cache
+                .epics
+                .iter()
+                .find(|e| e.key == id)
+                .map(|e| format!("{} {}", e.key, e.summary))
+                .unwrap_or_else(|| id.to_string()),
+            _ => id.to_string(),
+        }
+    }
+
+    pub fn is_export_picker_open(&self) -> bool {
+        self.export_picker.is_some()
+    }
+
+    pub fn is_standup_open(&self) -> bool {
+        self.standup.is_some()
+    }
+
+    pub fn is_git_branch_open(&self) -> bool {
+        self.git_branch.is_some()
+    }
+
+    pub fn is_command_palette_open(&self) -> bool {
+        self.command_palette.is_some()
+    }
+
+    pub fn is_goto_key_open(&self) -> bool {
+        self.goto_key.is_some()
+    }
+
+    pub fn is_review_prompt_open(&self) -> bool {
+        !self.review_prompts.is_empty()
+    }
+
+    /// Queue the In Review question for newly pushed tickets not already waiting.
+    pub fn queue_review_prompts(&mut self, keys: Vec<String>) {
+        for key in keys {
+            if !self.review_prompts.contains(&key) {
+                self.review_prompts.push(key);
+            }
+        }
+    }
+
+    /// Record the remote branches from a scan and return my tickets whose branch is newly
+    /// pushed and that are not in review yet. The first scan only sets the baseline.
+    pub fn newly_pushed_review_keys(&mut self, pushed: HashSet<String>) -> Vec<String> {
+        let Some(previous) = self.pushed_branch_keys.replace(pushed) else {
+            return Vec::new();
+        };
+        let current = self.pushed_branch_keys.as_ref().expect("just set");
+        let mut keys: Vec<String> = self
+            .cache
+            .my_tickets
+            .iter()
+            .filter(|t| current.contains(&t.key) && !previous.contains(&t.key))
+            .filter(|t| {
+                matches!(
+                    t.status,
+                    crate::cache::Status::ToDo
+                        | crate::cache::Status::ReadyForWork
+                        | crate::cache::Status::InProgress
+                )
+            })
+            .map(|t| t.key.clone())
+            .collect();
+        keys.sort();
+        keys
+    }
+
+    pub fn commits_for(&self, key: &str) -> &[crate::git::CommitRef] {
+        self.ticket_commits.get(key).map_or(&[], Vec::as_slice)
+    }
+
+    /// Work has been committed for the ticket but it still sits in To Do.
+    pub fn has_commits_but_not_started(&self, ticket: &crate::cache::Ticket) -> bool {
+        ticket.status == crate::cache::Status::ToDo && !self.commits_for(&ticket.key).is_empty()
+    }
+
+    /// The current user's email, taken from the assignee of My Work tickets.
+    pub fn my_email(&self) -> Option<&str> {
+        self.cache
+            .my_tickets
+            .iter()
+            .find_map(|t| t.assignee_email.as_deref())
+    }
+
+    pub fn open_standup(&mut self, window_hours: u32) {
+        self.standup = Some(StandupState {
+            scope: crate::standup::StandupScope::Me,
+            format: crate::standup::StandupFormat::Markdown,
+            window_hours,
+            text: String::new(),
+            scroll: 0,
+        });
+        self.refresh_standup();
+    }
+
+    /// Re-render the standup text for the overlay's current scope, format and window.
+    pub fn refresh_standup(&mut self) {
+        let Some(state) = &self.standup else {
+            return;
+        };
+        let members: Vec<crate::cache::TeamMember> = match state.scope {
+            crate::standup::StandupScope::Team => self.cache.team_members.clone(),
+            crate::standup::StandupScope::Me => {
+                let email = self.my_email().unwrap_or_default();
+                let member = self
+                    .cache
+                    .team_members
+                    .iter()
+                    .find(|m| m.email.eq_ignore_ascii_case(email))
+                    .cloned()
+                    .unwrap_or_else(|| crate::cache::TeamMember {
+                        name: "Me".to_string(),
+                        email: email.to_string(),
+                    });
+                vec![member]
+            }
+        };
+        let since = crate::jira_client::now_unix_secs()
+            .saturating_sub(u64::from(state.window_hours) * 3600);
+        let tickets = self.all_cached_tickets();
+        let sections = crate::standup::build_sections(&members, &tickets, since);
+        let text = crate::standup::render(&sections, state.format, state.window_hours);
+        if let Some(state) = &mut self.standup {
+            state.text = text;
+            state.scroll = 0;
         }
     }
 
@@ -608,7 +1628,7 @@ impl App {
         }
     }
 
-    fn sort_epic_children<'a>(tickets: &mut Vec<&'a crate::cache::Ticket>) {
+    fn sort_epic_children(tickets: &mut [&crate::cache::Ticket]) {
         tickets.sort_by(|a, b| {
             Self::epic_status_rank(&a.status)
                 .cmp(&Self::epic_status_rank(&b.status))
@@ -632,9 +1652,9 @@ impl App {
     }
 
     /// Epics and visible child rows in the exact order used by the Epics tab.
-    pub(crate) fn epics_visible_epics<'a>(
-        &'a self,
-    ) -> Vec<(&'a crate::cache::Epic, Vec<&'a crate::cache::Ticket>)> {
+    pub(crate) fn epics_visible_epics(
+        &self,
+    ) -> Vec<(&crate::cache::Epic, Vec<&crate::cache::Ticket>)> {
         let search = self.normalized_search();
         let mut visible = Vec::new();
         let mut epics: Vec<_> = self
@@ -700,9 +1720,9 @@ impl App {
     }
 
     /// Unassigned tickets grouped by epic.
-    pub(crate) fn unassigned_visible_by_epic<'a>(
-        &'a self,
-    ) -> Vec<(String, String, Vec<&'a crate::cache::Ticket>)> {
+    pub(crate) fn unassigned_visible_by_epic(
+        &self,
+    ) -> Vec<(String, String, Vec<&crate::cache::Ticket>)> {
         let search = self.normalized_search();
         let mut grouped: HashMap<(String, String), Vec<&crate::cache::Ticket>> = HashMap::new();
 
@@ -780,8 +1800,33 @@ impl App {
     }
 
     /// Status groups and visible tickets in the exact order used by the My Work tab.
-    pub(crate) fn my_work_visible_by_status<'a>(
-        &'a self,
+    pub(crate) fn my_work_visible_by_status(
+        &self,
+    ) -> Vec<(&'static crate::cache::Status, Vec<&crate::cache::Ticket>)> {
+        self.visible_by_status(&self.cache.my_tickets)
+    }
+
+    /// Status groups and visible tickets in the exact order used by the Watching tab.
+    /// Tickets unwatched this session stay cached (so an open detail keeps working)
+    /// but drop out of the tab.
+    pub(crate) fn watching_visible_by_status(
+        &self,
+    ) -> Vec<(&'static crate::cache::Status, Vec<&crate::cache::Ticket>)> {
+        self.visible_by_status(&self.cache.watched_tickets)
+            .into_iter()
+            .filter_map(|(status, tickets)| {
+                let tickets: Vec<_> = tickets
+                    .into_iter()
+                    .filter(|t| t.watchers.is_watching)
+                    .collect();
+                (!tickets.is_empty()).then_some((status, tickets))
+            })
+            .collect()
+    }
+
+    fn visible_by_status<'a>(
+        &self,
+        source: &'a [crate::cache::Ticket],
     ) -> Vec<(&'static crate::cache::Status, Vec<&'a crate::cache::Ticket>)> {
         let search = self.normalized_search();
 
@@ -798,9 +1843,7 @@ impl App {
                     }
                 }
 
-                let tickets: Vec<_> = self
-                    .cache
-                    .my_tickets
+                let tickets: Vec<_> = source
                     .iter()
                     .filter(|t| &t.status == status)
                     .filter(|t| {
@@ -833,14 +1876,27 @@ impl App {
         items
     }
 
+    fn watching_visible_items(&self) -> Vec<VisibleItem> {
+        let mut items = Vec::new();
+        for (status, tickets) in self.watching_visible_by_status() {
+            items.push(VisibleItem::GroupHeader(status.as_str().to_string()));
+            if !self.collapsed_watching.contains(status.as_str()) {
+                for ticket in tickets {
+                    items.push(VisibleItem::Ticket(ticket.key.clone()));
+                }
+            }
+        }
+        items
+    }
+
     /// Team members and visible tickets in the exact order used by the Team tab.
     /// Returns active tickets first, then Done tickets as a secondary group.
-    pub(crate) fn team_visible_tickets_by_member<'a>(
-        &'a self,
+    pub(crate) fn team_visible_tickets_by_member(
+        &self,
     ) -> Vec<(
-        &'a crate::cache::TeamMember,
-        Vec<&'a crate::cache::Ticket>,
-        Vec<&'a crate::cache::Ticket>,
+        &crate::cache::TeamMember,
+        Vec<&crate::cache::Ticket>,
+        Vec<&crate::cache::Ticket>,
     )> {
         let search = self.normalized_search();
         let mut visible = Vec::new();
@@ -860,7 +1916,7 @@ impl App {
                 .map(Vec::as_slice)
                 .unwrap_or(&[]);
 
-            let member_match = search.as_ref().map_or(false, |s| {
+            let member_match = search.as_ref().is_some_and(|s| {
                 Self::contains_case_insensitive(&member.name, s)
                     || Self::contains_case_insensitive(&member.email, s)
             });
@@ -920,6 +1976,7 @@ impl App {
 
     pub fn toggle_keybindings(&mut self) {
         self.show_keybindings = !self.show_keybindings;
+        self.keybindings_scroll = 0;
     }
 
     pub fn close_keybindings(&mut self) {
@@ -934,6 +1991,39 @@ impl App {
         self.bulk_state.is_some()
     }
 
+    pub fn is_undo_history_open(&self) -> bool {
+        self.undo_history.is_some()
+    }
+
+    /// Inverse operations for applying `target` to `keys`, from the cached ticket state.
+    pub fn journal_changes_for(&self, keys: &[String], target: &BulkTarget) -> Vec<JournalChange> {
+        keys.iter()
+            .filter_map(|key| {
+                let inverse = target.inverse_for(self.find_ticket(key)?)?;
+                Some(JournalChange {
+                    key: key.clone(),
+                    inverse,
+                })
+            })
+            .collect()
+    }
+
+    /// Add an undoable action to the journal. Entries without changes are ignored.
+    pub fn record_journal(&mut self, label: String, changes: Vec<JournalChange>) {
+        if changes.is_empty() {
+            return;
+        }
+        self.journal.push(JournalEntry {
+            label,
+            recorded_at: crate::jira_client::now_unix_secs(),
+            changes,
+        });
+        if self.journal.len() > JOURNAL_LIMIT {
+            let overflow = self.journal.len() - JOURNAL_LIMIT;
+            self.journal.drain(..overflow);
+        }
+    }
+
     pub fn is_bulk_upload_open(&self) -> bool {
         self.bulk_upload_state.is_some()
     }
@@ -954,13 +2044,52 @@ impl App {
         self.filter_edit.is_some()
     }
 
+    pub fn is_worklog_open(&self) -> bool {
+        self.worklog_state.is_some()
+    }
+
+    pub fn open_worklog(&mut self, ticket_key: String, duration: String, date: String) {
+        self.worklog_state = Some(WorklogState {
+            ticket_key,
+            focused_field: 0,
+            duration,
+            date,
+            comment: String::new(),
+        });
+    }
+
+    pub fn is_timing(&self, key: &str) -> bool {
+        self.work_timer
+            .as_ref()
+            .map(|t| t.ticket_key == key)
+            .unwrap_or(false)
+    }
+
+    pub fn start_work_timer(&mut self, key: &str, now_unix_secs: u64) {
+        self.work_timer = Some(WorkTimer {
+            ticket_key: key.to_string(),
+            started_at_unix_secs: now_unix_secs,
+        });
+    }
+
+    /// Stop the timer if it is running for `key`, returning elapsed seconds.
+    pub fn stop_work_timer(&mut self, key: &str, now_unix_secs: u64) -> Option<u64> {
+        if !self.is_timing(key) {
+            return None;
+        }
+        self.work_timer
+            .take()
+            .map(|timer| timer.elapsed_secs(now_unix_secs))
+    }
+
     pub fn is_collapsed(&self, tab: Tab, group_id: &str) -> bool {
         match tab {
             Tab::MyWork => self.collapsed_my_work.contains(group_id),
             Tab::Team => self.collapsed_team.contains(group_id),
             Tab::Epics => self.collapsed_epics.contains(group_id),
             Tab::Unassigned => self.collapsed_unassigned.contains(group_id),
-            Tab::Filters => false,
+            Tab::Watching => self.collapsed_watching.contains(group_id),
+            Tab::Filters | Tab::Dashboard => false,
         }
     }
 
@@ -997,11 +2126,10 @@ impl App {
 
     pub fn toggle_selection_at_cursor(&mut self) {
         match self.selected_item() {
-            Some(VisibleItem::Ticket(key)) => {
-                if !self.selected_ticket_keys.remove(&key) {
-                    self.selected_ticket_keys.insert(key);
-                }
+            Some(VisibleItem::Ticket(key)) if !self.selected_ticket_keys.remove(&key) => {
+                self.selected_ticket_keys.insert(key);
             }
+            Some(VisibleItem::Ticket(_)) => {}
             Some(VisibleItem::GroupHeader(group_id)) => self.toggle_group_selection(&group_id),
             None => {}
         }
@@ -1086,7 +2214,8 @@ impl App {
             Tab::Team => &mut self.collapsed_team,
             Tab::Epics => &mut self.collapsed_epics,
             Tab::Unassigned => &mut self.collapsed_unassigned,
-            Tab::Filters => return,
+            Tab::Watching => &mut self.collapsed_watching,
+            Tab::Filters | Tab::Dashboard => return,
         };
         let collapsing = !set.remove(group_id);
         if collapsing {
@@ -1141,7 +2270,15 @@ impl App {
                     .collect();
                 (&mut self.collapsed_unassigned, ids)
             }
-            Tab::Filters => return,
+            Tab::Watching => {
+                let ids: Vec<String> = self
+                    .watching_visible_by_status()
+                    .iter()
+                    .map(|(s, _)| s.as_str().to_string())
+                    .collect();
+                (&mut self.collapsed_watching, ids)
+            }
+            Tab::Filters | Tab::Dashboard => return,
         };
         if set.is_empty() {
             // Collapse all except the current group
@@ -1177,11 +2314,28 @@ impl App {
                 keys.insert(ticket.key.clone());
             }
         }
+        for ticket in &self.cache.watched_tickets {
+            if !ticket.detail_loaded && !self.detail_fetching.contains(&ticket.key) {
+                keys.insert(ticket.key.clone());
+            }
+        }
         let mut keys: Vec<String> = keys.into_iter().collect();
         keys.sort();
         keys
     }
 
+    /// Children of `epic_key` whose history is not loaded yet, for the burndown chart.
+    pub fn missing_epic_child_detail_keys(&self, epic_key: &str) -> Vec<String> {
+        self.cache
+            .epics
+            .iter()
+            .filter(|e| e.key == epic_key)
+            .flat_map(|e| e.children.iter())
+            .filter(|t| !t.detail_loaded && !self.detail_fetching.contains(&t.key))
+            .map(|t| t.key.clone())
+            .collect()
+    }
+
     pub fn toggle_status_focus(&mut self, status: crate::cache::Status) {
         if self.status_focus.as_ref() == Some(&status) {
             self.status_focus = None;
@@ -1276,6 +2430,7 @@ impl App {
             .iter()
             .find(|t| t.key == key)
             .or_else(|| self.cache.team_tickets.iter().find(|t| t.key == key))
+            .or_else(|| self.cache.watched_tickets.iter().find(|t| t.key == key))
             .or_else(|| {
                 self.cache
                     .epics
@@ -1284,6 +2439,78 @@ impl App {
                     .find(|t| t.key == key)
             })
             .or_else(|| self.filter_results.iter().find(|t| t.key == key))
+            .or_else(|| self.adhoc_tickets.iter().find(|t| t.key == key))
+    }
+
+    /// Every cached ticket once, in `find_ticket` lookup order.
+    pub fn all_cached_tickets(&self) -> Vec<&crate::cache::Ticket> {
+        let mut seen = HashSet::new();
+        self.cache
+            .my_tickets
+            .iter()
+            .chain(&self.cache.team_tickets)
+            .chain(&self.cache.watched_tickets)
+            .chain(self.cache.epics.iter().flat_map(|e| e.children.iter()))
+            .chain(&self.filter_results)
+            .chain(&self.adhoc_tickets)
+            .filter(|t| seen.insert(t.key.as_str()))
+            .collect()
+    }
+
+    /// Keep a fetched ticket that is not in any list so its detail can be shown.
+    pub fn insert_adhoc_ticket(&mut self, ticket: crate::cache::Ticket) {
+        if self.find_ticket(&ticket.key).is_some() {
+            return;
+        }
+        self.adhoc_tickets.push(ticket);
+    }
+
+    /// Open the create form pre-filled from the ticket `source_key`.
+    pub fn open_derived_ticket_form(&mut self, kind: DeriveKind, source_key: &str) {
+        let Some(source) = self.find_ticket(source_key) else {
+            return;
+        };
+
+        let mut state = CreateTicketState::new();
+        state.focused_field = CreateTicketState::SUMMARY;
+        state.labels = source.labels.join(", ");
+        let epic_idx = source
+            .epic_key
+            .as_ref()
+            .and_then(|ek| self.cache.epics.iter().position(|e| &e.key == ek))
+            .map(|idx| idx + 1)
+            .unwrap_or(0);
+
+        match kind {
+            DeriveKind::Clone => {
+                state.summary = format!("CLONE - {}", source.summary);
+                state.description = source.description.clone().unwrap_or_default();
+                state.epic_idx = epic_idx;
+                state.assignee_idx = source
+                    .assignee_email
+                    .as_ref()
+                    .and_then(|email| {
+                        self.cache
+                            .team_members
+                            .iter()
+                            .position(|m| &m.email == email)
+                    })
+                    .map(|idx| idx + 1)
+                    .unwrap_or(0);
+            }
+            DeriveKind::SubTask => {}
+            DeriveKind::FollowUp => {
+                state.summary = format!("Follow-up: {}", source.summary);
+                state.description = format!("Follow-up to {}.", source.key);
+                state.epic_idx = epic_idx;
+            }
+        }
+
+        state.derived_from = Some(DerivedFrom {
+            kind,
+            source_key: source.key.clone(),
+        });
+        self.create_ticket = Some(state);
     }
 
     /// Enrich a cached ticket with full detail from JSON (description, accurate status/assignee).
@@ -1311,126 +2538,361 @@ impl App {
             if !detail.activity.is_empty() {
                 ticket.activity = detail.activity.clone();
             }
+            ticket.time_tracking = detail.time_tracking.clone();
+            // A refetched detail has no names; keep the fetched ones while the count holds.
+            let names = std::mem::take(&mut ticket.watchers.names);
+            ticket.watchers = detail.watchers.clone();
+            if ticket.watchers.names.is_empty() && ticket.watchers.count == names.len() {
+                ticket.watchers.names = names;
+            }
+            ticket.resolution = detail.resolution.clone();
+            ticket.priority = detail.priority.clone();
+            ticket.custom_fields = detail.custom_fields.clone();
             ticket.detail_loaded = true;
         };
         for ticket in &mut self.cache.my_tickets {
             if ticket.key == key {
                 update(ticket);
-                changed = true;
+                changed = true;
+            }
+        }
+        for ticket in &mut self.cache.team_tickets {
+            if ticket.key == key {
+                update(ticket);
+                changed = true;
+            }
+        }
+        for ticket in &mut self.cache.watched_tickets {
+            if ticket.key == key {
+                update(ticket);
+                changed = true;
+            }
+        }
+        for epic in &mut self.cache.epics {
+            for ticket in &mut epic.children {
+                if ticket.key == key {
+                    update(ticket);
+                    changed = true;
+                }
+            }
+        }
+        for ticket in &mut self.adhoc_tickets {
+            if ticket.key == key {
+                update(ticket);
+                changed = true;
+            }
+        }
+        if changed {
+            self.mark_cache_changed();
+        }
+    }
+
+    /// Update a ticket's status in the cache (optimistic update).
+    pub fn update_ticket_status(&mut self, key: &str, new_status: crate::cache::Status) {
+        let mut changed = false;
+        for ticket in &mut self.cache.my_tickets {
+            if ticket.key == key {
+                ticket.status = new_status.clone();
+                changed = true;
+            }
+        }
+        for ticket in &mut self.cache.team_tickets {
+            if ticket.key == key {
+                ticket.status = new_status.clone();
+                changed = true;
+            }
+        }
+        for ticket in &mut self.cache.watched_tickets {
+            if ticket.key == key {
+                ticket.status = new_status.clone();
+                changed = true;
+            }
+        }
+        for epic in &mut self.cache.epics {
+            for ticket in &mut epic.children {
+                if ticket.key == key {
+                    ticket.status = new_status.clone();
+                    changed = true;
+                }
+            }
+        }
+        for ticket in &mut self.filter_results {
+            if ticket.key == key {
+                ticket.status = new_status.clone();
+                changed = true;
+            }
+        }
+        for ticket in &mut self.adhoc_tickets {
+            if ticket.key == key {
+                ticket.status = new_status.clone();
+                changed = true;
+            }
+        }
+        if changed {
+            self.mark_cache_changed();
+        }
+    }
+
+    /// Update a ticket's assignee in the cache.
+    pub fn update_ticket_assignee(&mut self, key: &str, name: &str, email: &str) {
+        let mut changed = false;
+        for ticket in &mut self.cache.my_tickets {
+            if ticket.key == key {
+                ticket.assignee = Some(name.to_string());
+                ticket.assignee_email = Some(email.to_string());
+                changed = true;
+            }
+        }
+        for ticket in &mut self.cache.team_tickets {
+            if ticket.key == key {
+                ticket.assignee = Some(name.to_string());
+                ticket.assignee_email = Some(email.to_string());
+                changed = true;
+            }
+        }
+        for ticket in &mut self.cache.watched_tickets {
+            if ticket.key == key {
+                ticket.assignee = Some(name.to_string());
+                ticket.assignee_email = Some(email.to_string());
+                changed = true;
+            }
+        }
+        for epic in &mut self.cache.epics {
+            for ticket in &mut epic.children {
+                if ticket.key == key {
+                    ticket.assignee = Some(name.to_string());
+                    ticket.assignee_email = Some(email.to_string());
+                    changed = true;
+                }
+            }
+        }
+        for ticket in &mut self.filter_results {
+            if ticket.key == key {
+                ticket.assignee = Some(name.to_string());
+                ticket.assignee_email = Some(email.to_string());
+                changed = true;
+            }
+        }
+        if changed {
+            self.mark_cache_changed();
+        }
+    }
+
+    /// Apply an in-place edit to every cached copy of a ticket.
+    pub fn edit_cached_ticket(
+        &mut self,
+        key: &str,
+        mut edit: impl FnMut(&mut crate::cache::Ticket),
+    ) {
+        let mut changed = false;
+        let epic_children = self
+            .cache
+            .epics
+            .iter_mut()
+            .flat_map(|e| e.children.iter_mut());
+        for ticket in self
+            .cache
+            .my_tickets
+            .iter_mut()
+            .chain(self.cache.team_tickets.iter_mut())
+            .chain(self.cache.watched_tickets.iter_mut())
+            .chain(epic_children)
+            .chain(self.filter_results.iter_mut())
+            .chain(self.adhoc_tickets.iter_mut())
+            .filter(|t| t.key == key)
+        {
+            edit(ticket);
+            changed = true;
+        }
+        if changed {
+            self.mark_cache_changed();
+        }
+    }
+
+    /// Update a ticket's epic link in the cache, moving it between epics on the Epics tab.
+    pub fn update_ticket_epic(
+        &mut self,
+        key: &str,
+        epic_key: Option<&str>,
+        epic_name: Option<&str>,
+    ) {
+        let update = |ticket: &mut crate::cache::Ticket| {
+            ticket.epic_key = epic_key.map(str::to_string);
+            ticket.epic_name = epic_name.map(str::to_string);
+        };
+        let mut moved: Option<crate::cache::Ticket> = None;
+        for list in [
+            &mut self.cache.my_tickets,
+            &mut self.cache.team_tickets,
+            &mut self.cache.watched_tickets,
+            &mut self.filter_results,
+            &mut self.adhoc_tickets,
+        ] {
+            for ticket in list.iter_mut().filter(|t| t.key == key) {
+                update(ticket);
+                moved.get_or_insert_with(|| ticket.clone());
+            }
+        }
+        for epic in &mut self.cache.epics {
+            if Some(epic.key.as_str()) == epic_key {
+                continue;
+            }
+            if let Some(pos) = epic.children.iter().position(|t| t.key == key) {
+                let mut ticket = epic.children.remove(pos);
+                update(&mut ticket);
+                moved.get_or_insert(ticket);
+            }
+        }
+        if let (Some(epic_key), Some(ticket)) = (epic_key, moved.as_ref()) {
+            if let Some(epic) = self.cache.epics.iter_mut().find(|e| e.key == epic_key) {
+                if !epic.children.iter().any(|t| t.key == key) {
+                    epic.children.push(ticket.clone());
+                }
+            }
+        }
+        if moved.is_some() {
+            self.mark_cache_changed();
+        }
+    }
+
+    /// Number of an epic's children that are not yet Closed, or `None` if the epic is not cached.
+    pub fn epic_open_children(&self, epic_key: &str) -> Option<usize> {
+        self.cache
+            .epics
+            .iter()
+            .find(|e| e.key == epic_key)
+            .map(|e| e.total() - e.done_count())
+    }
+
+    /// Drop a closed epic from the Epics tab, closing its detail view if open.
+    pub fn remove_epic(&mut self, epic_key: &str) {
+        let Some(pos) = self.cache.epics.iter().position(|e| e.key == epic_key) else {
+            return;
+        };
+        self.cache.epics.remove(pos);
+        self.collapsed_epics.remove(epic_key);
+        if self.detail_epic_key.as_deref() == Some(epic_key) {
+            self.close_detail();
+        }
+        self.mark_cache_changed();
+    }
+
+    /// The open ticket when its detail has watchers but no names yet; each is asked once.
+    pub fn watcher_names_fetch_key(&mut self) -> Option<String> {
+        let key = self.detail_ticket_key.clone()?;
+        let ticket = self.find_ticket(&key)?;
+        if !ticket.detail_loaded || ticket.watchers.count == 0 || !ticket.watchers.names.is_empty()
+        {
+            return None;
+        }
+        self.watcher_names_requested
+            .insert(key.clone())
+            .then_some(key)
+    }
+
+    pub fn set_watcher_names(&mut self, key: &str, names: Vec<String>) {
+        let update = |ticket: &mut crate::cache::Ticket| {
+            ticket.watchers.count = ticket.watchers.count.max(names.len());
+            ticket.watchers.names = names.clone();
+        };
+        for ticket in &mut self.cache.my_tickets {
+            if ticket.key == key {
+                update(ticket);
             }
         }
         for ticket in &mut self.cache.team_tickets {
             if ticket.key == key {
                 update(ticket);
-                changed = true;
+            }
+        }
+        for ticket in &mut self.cache.watched_tickets {
+            if ticket.key == key {
+                update(ticket);
             }
         }
         for epic in &mut self.cache.epics {
             for ticket in &mut epic.children {
                 if ticket.key == key {
                     update(ticket);
-                    changed = true;
                 }
             }
         }
-        if changed {
-            self.mark_cache_changed();
-        }
-    }
-
-    /// Update a ticket's status in the cache (optimistic update).
-    pub fn update_ticket_status(&mut self, key: &str, new_status: crate::cache::Status) {
-        let mut changed = false;
-        for ticket in &mut self.cache.my_tickets {
+        for ticket in &mut self.adhoc_tickets {
             if ticket.key == key {
-                ticket.status = new_status.clone();
-                changed = true;
+                update(ticket);
             }
         }
-        for ticket in &mut self.cache.team_tickets {
+        for ticket in &mut self.filter_results {
             if ticket.key == key {
-                ticket.status = new_status.clone();
-                changed = true;
+                update(ticket);
             }
         }
-        for epic in &mut self.cache.epics {
-            for ticket in &mut epic.children {
-                if ticket.key == key {
-                    ticket.status = new_status.clone();
-                    changed = true;
-                }
+    }
+
+    /// Update whether the current user watches a ticket (optimistic update).
+    /// Newly watched tickets are added to the Watching tab.
+    pub fn set_watching(&mut self, key: &str, watching: bool) {
+        let update = |ticket: &mut crate::cache::Ticket| {
+            if ticket.watchers.is_watching != watching {
+                ticket.watchers.is_watching = watching;
+                ticket.watchers.count = if watching {
+                    ticket.watchers.count + 1
+                } else {
+                    ticket.watchers.count.saturating_sub(1)
+                };
             }
-        }
-        for ticket in &mut self.filter_results {
-            if ticket.key == key {
-                ticket.status = new_status.clone();
-                changed = true;
+        };
+
+        if watching && !self.cache.watched_tickets.iter().any(|t| t.key == key) {
+            if let Some(ticket) = self.find_ticket(key).cloned() {
+                self.cache.watched_tickets.push(ticket);
+                self.cache.watched_tickets.sort_by(|a, b| a.key.cmp(&b.key));
             }
         }
-        if changed {
-            self.mark_cache_changed();
-        }
-    }
 
-    /// Update a ticket's assignee in the cache.
-    pub fn update_ticket_assignee(&mut self, key: &str, name: &str, email: &str) {
-        let mut changed = false;
         for ticket in &mut self.cache.my_tickets {
             if ticket.key == key {
-                ticket.assignee = Some(name.to_string());
-                ticket.assignee_email = Some(email.to_string());
-                changed = true;
+                update(ticket);
             }
         }
         for ticket in &mut self.cache.team_tickets {
             if ticket.key == key {
-                ticket.assignee = Some(name.to_string());
-                ticket.assignee_email = Some(email.to_string());
-                changed = true;
+                update(ticket);
+            }
+        }
+        for ticket in &mut self.cache.watched_tickets {
+            if ticket.key == key {
+                update(ticket);
             }
         }
         for epic in &mut self.cache.epics {
             for ticket in &mut epic.children {
                 if ticket.key == key {
-                    ticket.assignee = Some(name.to_string());
-                    ticket.assignee_email = Some(email.to_string());
-                    changed = true;
+                    update(ticket);
                 }
             }
         }
         for ticket in &mut self.filter_results {
             if ticket.key == key {
-                ticket.assignee = Some(name.to_string());
-                ticket.assignee_email = Some(email.to_string());
-                changed = true;
+                update(ticket);
             }
         }
-        if changed {
-            self.mark_cache_changed();
-        }
+        self.mark_cache_changed();
+        self.clamp_selection();
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{App, GroupSelectionState, Tab};
+    use super::{
+        App, BulkTarget, DetailLayout, GroupSelectionState, JournalChange, Tab, JOURNAL_LIMIT,
+    };
     use crate::cache::{Epic, Status, Ticket};
 
     fn ticket(key: &str, summary: &str) -> Ticket {
         Ticket {
-            key: key.to_string(),
-            summary: summary.to_string(),
-            status: Status::ToDo,
-            assignee: None,
-            assignee_email: None,
-            reporter: None,
-            description: None,
-            labels: Vec::new(),
-            epic_key: None,
-            epic_name: None,
-            detail_loaded: false,
             url: format!("https://jira.mongodb.org/browse/{}", key),
-            activity: Vec::new(),
+            ..Ticket::for_test(key, summary, Status::ToDo)
         }
     }
 
@@ -1461,6 +2923,198 @@ mod tests {
         assert_eq!(app.item_count(), 5);
     }
 
+    #[test]
+    fn dashboard_tab_follows_filters_and_has_no_rows() {
+        let mut app = App::new();
+        app.cache.my_tickets = vec![ticket("AMP-1", "Session")];
+        app.active_tab = Tab::Filters;
+        app.next_tab();
+
+        assert_eq!(app.active_tab, Tab::Dashboard);
+        assert_eq!(app.item_count(), 0);
+        assert!(app.visible_tickets_for_export().is_empty());
+        app.next_tab();
+        assert_eq!(app.active_tab, Tab::MyWork);
+    }
+
+    #[test]
+    fn missing_epic_child_detail_keys_skips_loaded_and_in_flight() {
+        let mut loaded = ticket("AMP-2", "Password");
+        loaded.detail_loaded = true;
+        let mut app = epics_app(vec![Epic {
+            key: "AMP-100".to_string(),
+            summary: "Auth".to_string(),
+            children: vec![ticket("AMP-1", "Session"), loaded, ticket("AMP-3", "Cache")],
+        }]);
+        assert!(app.begin_detail_fetch("AMP-3"));
+
+        assert_eq!(app.missing_epic_child_detail_keys("AMP-100"), vec!["AMP-1"]);
+        assert!(app.missing_epic_child_detail_keys("AMP-200").is_empty());
+    }
+
+    #[test]
+    fn split_view_previews_the_selected_ticket_until_its_detail_loads() {
+        let mut app = App::new();
+        app.loading = false;
+        let mut loaded = ticket("AMP-2", "Loaded");
+        loaded.detail_loaded = true;
+        app.cache.my_tickets = vec![ticket("AMP-1", "Bare"), loaded];
+        app.selected_index = 1;
+        assert_eq!(app.preview_fetch_key(), None);
+
+        app.toggle_detail_layout();
+        assert!(app.is_split_view());
+        assert_eq!(app.preview_fetch_key().as_deref(), Some("AMP-1"));
+        assert!(app.begin_detail_fetch("AMP-1"));
+        assert_eq!(app.preview_fetch_key(), None);
+
+        app.move_selection_down();
+        assert_eq!(app.selected_ticket_key().as_deref(), Some("AMP-2"));
+        assert_eq!(app.preview_fetch_key(), None);
+
+        app.set_tab(Tab::Dashboard);
+        assert!(!app.is_split_view());
+        assert_eq!(app.ui_state().detail_layout, DetailLayout::Split);
+    }
+
+    #[test]
+    fn detail_history_steps_back_and_forward_and_tracks_recent_views() {
+        use crate::cache::Viewed;
+
+        let mut app = App::new();
+        assert_eq!(app.nav_back(), None);
+        app.open_detail("AMP-1".to_string());
+        app.open_epic_detail("AMP-10".to_string());
+        app.open_detail("AMP-2".to_string());
+
+        assert_eq!(app.nav_back(), Some(Viewed::Epic("AMP-10".to_string())));
+        app.open_epic_detail("AMP-10".to_string());
+        assert_eq!(app.nav_back(), Some(Viewed::Ticket("AMP-1".to_string())));
+        app.open_detail("AMP-1".to_string());
+        assert_eq!(app.nav_back(), None);
+        assert_eq!(app.nav_forward(), Some(Viewed::Epic("AMP-10".to_string())));
+        app.open_epic_detail("AMP-10".to_string());
+
+        // Opening something new drops the forward entries.
+        app.open_detail("AMP-3".to_string());
+        assert_eq!(app.nav_forward(), None);
+
+        // From a list, back reopens the last view.
+        app.close_detail();
+        assert_eq!(app.nav_back(), Some(Viewed::Ticket("AMP-3".to_string())));
+
+        let recent: Vec<&str> = app.recently_viewed.iter().map(|v| v.key()).collect();
+        assert_eq!(recent, vec!["AMP-3", "AMP-10", "AMP-1", "AMP-2"]);
+    }
+
+    #[test]
+    fn clicks_hit_registered_targets_and_detect_double_clicks() {
+        use super::ClickTarget;
+        use ratatui::layout::Rect;
+
+        let mut app = App::new();
+        app.add_click_target(Rect::new(1, 1, 10, 1), ClickTarget::Tab(Tab::Team));
+        app.add_click_target(Rect::new(1, 5, 40, 1), ClickTarget::Item(3));
+        assert_eq!(app.click_target_at(4, 1), Some(ClickTarget::Tab(Tab::Team)));
+        assert_eq!(app.click_target_at(40, 5), Some(ClickTarget::Item(3)));
+        assert_eq!(app.click_target_at(41, 5), None);
+
+        let start = std::time::Instant::now();
+        let item = ClickTarget::Item(3);
+        assert!(!app.register_click(item, start));
+        assert!(app.register_click(item, start + std::time::Duration::from_millis(200)));
+        // A third click starts over instead of opening again.
+        assert!(!app.register_click(item, start + std::time::Duration::from_millis(300)));
+        assert!(!app.register_click(
+            ClickTarget::Item(4),
+            start + std::time::Duration::from_millis(350)
+        ));
+        assert!(!app.register_click(item, start + std::time::Duration::from_secs(2)));
+
+        app.clear_click_targets();
+        assert_eq!(app.click_target_at(4, 1), None);
+    }
+
+    #[test]
+    fn ui_state_round_trips_through_restore() {
+        let mut app = App::new();
+        app.active_tab = Tab::Team;
+        app.show_done = true;
+        app.status_focus = Some(Status::InProgress);
+        app.filter_sidebar_idx = 4;
+        app.search = Some("auth".to_string());
+        app.collapsed_team.insert("alice@example.com".to_string());
+        app.collapsed_epics.insert("AMP-100".to_string());
+        app.start_work_timer("AMP-7", 1_000);
+        let state = app.ui_state();
+
+        let mut restored = App::new();
+        restored.restore_ui_state(state.clone(), 2);
+        assert_eq!(restored.active_tab, Tab::Team);
+        assert!(restored.show_done);
+        assert_eq!(restored.status_focus, Some(Status::InProgress));
+        assert_eq!(restored.filter_sidebar_idx, 1);
+        assert_eq!(restored.search.as_deref(), Some("auth"));
+        assert!(restored.is_collapsed(Tab::Team, "alice@example.com"));
+        assert!(restored.is_collapsed(Tab::Epics, "AMP-100"));
+        assert!(!restored.is_collapsed(Tab::MyWork, "In Progress"));
+        assert_eq!(restored.work_timer, app.work_timer);
+
+        let mut fresh = App::new();
+        fresh.restore_ui_state(crate::ui_state::UiState::default(), 0);
+        assert_eq!(fresh.active_tab, Tab::MyWork);
+        assert!(fresh.show_done);
+        assert_eq!(fresh.search, None);
+    }
+
+    #[test]
+    fn tickets_with_commits_in_to_do_are_flagged() {
+        let mut app = App::new();
+        let todo = ticket("AMP-1", "Todo");
+        let mut started = ticket("AMP-2", "Started");
+        started.status = Status::InProgress;
+        let commit = crate::git::CommitRef {
+            hash: "abc1234".to_string(),
+            author: "Ada".to_string(),
+            date: "2026-03-02".to_string(),
+            subject: "AMP-1 AMP-2 groundwork".to_string(),
+        };
+        app.ticket_commits = [
+            ("AMP-1".to_string(), vec![commit.clone()]),
+            ("AMP-2".to_string(), vec![commit]),
+        ]
+        .into();
+
+        assert!(app.has_commits_but_not_started(&todo));
+        assert!(!app.has_commits_but_not_started(&started));
+        assert!(!app.has_commits_but_not_started(&ticket("AMP-3", "Fresh")));
+        assert_eq!(app.commits_for("AMP-3").len(), 0);
+    }
+
+    #[test]
+    fn newly_pushed_review_keys_ignores_baseline_and_reviewed_tickets() {
+        let mut app = App::new();
+        let mut reviewed = ticket("AMP-3", "Reviewed");
+        reviewed.status = crate::cache::Status::InReview;
+        let mut started = ticket("AMP-2", "Started");
+        started.status = crate::cache::Status::InProgress;
+        app.cache.my_tickets = vec![ticket("AMP-1", "Old"), started, reviewed];
+        let keys = |list: &[&str]| {
+            list.iter()
+                .map(|k| k.to_string())
+                .collect::<std::collections::HashSet<_>>()
+        };
+
+        assert!(app.newly_pushed_review_keys(keys(&["AMP-1"])).is_empty());
+        assert_eq!(
+            app.newly_pushed_review_keys(keys(&["AMP-1", "AMP-2", "AMP-3", "AMP-9"])),
+            vec!["AMP-2"]
+        );
+        assert!(app
+            .newly_pushed_review_keys(keys(&["AMP-1", "AMP-2"]))
+            .is_empty());
+    }
+
     #[test]
     fn epics_selected_ticket_key_uses_cross_epic_row_order() {
         let mut app = epics_app(vec![
@@ -1851,4 +3505,308 @@ mod tests {
         assert!(app.is_ticket_selected("AMP-31"));
         assert!(!app.is_ticket_selected("AMP-32"));
     }
+
+    #[test]
+    fn watching_tab_groups_by_status_and_hides_unwatched() {
+        let mut app = App::new();
+        app.active_tab = Tab::Watching;
+        app.loading = false;
+
+        let mut a = ticket("AMP-1", "Watched in progress");
+        a.status = Status::InProgress;
+        a.watchers.is_watching = true;
+        let mut b = ticket("AMP-2", "Watched todo");
+        b.watchers.is_watching = true;
+        app.cache.watched_tickets = vec![a, b];
+
+        // H(In Progress) + T(AMP-1) + H(To Do) + T(AMP-2)
+        assert_eq!(app.item_count(), 4);
+
+        app.set_watching("AMP-2", false);
+        assert_eq!(app.item_count(), 2);
+        // Still findable so an open detail keeps rendering.
+        assert!(app.find_ticket("AMP-2").is_some());
+    }
+
+    #[test]
+    fn watcher_names_are_requested_once_for_the_open_detail() {
+        let mut app = App::new();
+        let mut watched = ticket("AMP-1", "Watched");
+        watched.detail_loaded = true;
+        watched.watchers.count = 2;
+        app.cache.my_tickets = vec![watched.clone()];
+        assert_eq!(app.watcher_names_fetch_key(), None);
+
+        app.open_detail("AMP-1".to_string());
+        assert_eq!(app.watcher_names_fetch_key().as_deref(), Some("AMP-1"));
+        assert_eq!(app.watcher_names_fetch_key(), None);
+
+        app.set_watcher_names("AMP-1", vec!["Ann".to_string(), "Bo".to_string()]);
+        app.enrich_ticket("AMP-1", &watched);
+        assert_eq!(
+            app.find_ticket("AMP-1").unwrap().watchers.names,
+            ["Ann", "Bo"]
+        );
+    }
+
+    #[test]
+    fn set_watching_adds_ticket_to_watching_tab() {
+        let mut app = App::new();
+        app.active_tab = Tab::Watching;
+        app.loading = false;
+        app.cache.team_tickets = vec![ticket("AMP-7", "Teammate work")];
+
+        app.set_watching("AMP-7", true);
+
+        assert_eq!(app.item_count(), 2);
+        let watched = app.find_ticket("AMP-7").expect("ticket");
+        assert!(watched.watchers.is_watching);
+        assert_eq!(watched.watchers.count, 1);
+    }
+
+    #[test]
+    fn clone_form_copies_summary_labels_epic_and_description() {
+        let mut app = App::new();
+        app.cache.epics = vec![Epic {
+            key: "AMP-100".to_string(),
+            summary: "Auth".to_string(),
+            children: vec![],
+        }];
+        let mut source = ticket("AMP-1", "Session resume");
+        source.labels = vec!["backend".to_string()];
+        source.epic_key = Some("AMP-100".to_string());
+        source.description = Some("Details".to_string());
+        app.cache.my_tickets = vec![source];
+
+        app.open_derived_ticket_form(super::DeriveKind::Clone, "AMP-1");
+
+        let state = app.create_ticket.expect("form open");
+        assert_eq!(state.summary, "CLONE - Session resume");
+        assert_eq!(state.labels, "backend");
+        assert_eq!(state.epic_idx, 1);
+        assert_eq!(state.description, "Details");
+    }
+
+    #[test]
+    fn templates_only_replace_fields_they_filled() {
+        let bug = crate::config::TicketTemplate {
+            name: "Bug".to_string(),
+            description: Some("h3. Steps".to_string()),
+            labels: vec!["triage".to_string()],
+            ..Default::default()
+        };
+        let spike = crate::config::TicketTemplate {
+            name: "Spike".to_string(),
+            description: Some("h3. Question".to_string()),
+            labels: vec!["spike".to_string()],
+            ..Default::default()
+        };
+        let mut state = super::CreateTicketState::new();
+        state.description = "Already written".to_string();
+
+        state.apply_template(None, Some(&bug));
+        assert_eq!(state.description, "Already written");
+        assert_eq!(state.labels, "triage");
+
+        state.apply_template(Some(&bug), Some(&spike));
+        assert_eq!(state.labels, "spike");
+
+        state.labels = "spike, backend".to_string();
+        state.apply_template(Some(&spike), None);
+        assert_eq!(state.description, "Already written");
+        assert_eq!(state.labels, "spike, backend");
+    }
+
+    #[test]
+    fn adhoc_tickets_are_findable_and_not_duplicated() {
+        let mut app = App::new();
+        app.cache.my_tickets = vec![ticket("AMP-1", "Cached")];
+
+        app.insert_adhoc_ticket(ticket("AMP-1", "Cached"));
+        app.insert_adhoc_ticket(ticket("AMP-900", "Opened by key"));
+
+        assert_eq!(app.adhoc_tickets.len(), 1);
+        assert_eq!(
+            app.find_ticket("AMP-900").map(|t| t.summary.as_str()),
+            Some("Opened by key")
+        );
+    }
+
+    #[test]
+    fn update_ticket_epic_moves_child_between_epics() {
+        let mut app = App::new();
+        let mut child = ticket("AMP-1", "Tokenizer");
+        child.epic_key = Some("AMP-100".to_string());
+        app.cache.my_tickets = vec![child.clone()];
+        app.cache.epics = vec![
+            Epic {
+                key: "AMP-100".to_string(),
+                summary: "Parser".to_string(),
+                children: vec![child],
+            },
+            Epic {
+                key: "AMP-200".to_string(),
+                summary: "Runner".to_string(),
+                children: vec![],
+            },
+        ];
+
+        app.update_ticket_epic("AMP-1", Some("AMP-200"), Some("Runner"));
+
+        assert_eq!(app.cache.my_tickets[0].epic_key.as_deref(), Some("AMP-200"));
+        assert_eq!(app.cache.my_tickets[0].epic_name.as_deref(), Some("Runner"));
+        assert!(app.cache.epics[0].children.is_empty());
+        assert_eq!(app.cache.epics[1].children[0].key, "AMP-1");
+
+        app.update_ticket_epic("AMP-1", None, None);
+        assert_eq!(app.cache.my_tickets[0].epic_key, None);
+        assert!(app.cache.epics.iter().all(|e| e.children.is_empty()));
+    }
+
+    #[test]
+    fn epic_open_children_counts_unfinished_work() {
+        let mut app = App::new();
+        let mut done = ticket("AMP-2", "Done");
+        done.status = Status::Closed;
+        app.cache.epics = vec![Epic {
+            key: "AMP-100".to_string(),
+            summary: "Parser".to_string(),
+            children: vec![ticket("AMP-1", "Open"), done],
+        }];
+
+        assert_eq!(app.epic_open_children("AMP-100"), Some(1));
+        assert_eq!(app.epic_open_children("AMP-999"), None);
+
+        app.open_epic_detail("AMP-100".to_string());
+        app.remove_epic("AMP-100");
+        assert!(app.cache.epics.is_empty());
+        assert!(!app.is_detail_open());
+    }
+
+    #[test]
+    fn inverse_for_restores_prior_state() {
+        let mut t = ticket("AMP-1", "Old summary");
+        t.labels = vec!["keep".to_string()];
+        t.assignee = Some("Alice".to_string());
+        t.assignee_email = Some("alice@example.com".to_string());
+
+        let labels = BulkTarget::AddLabels {
+            labels: vec!["keep".to_string(), "new".to_string()],
+        };
+        assert_eq!(
+            labels.inverse_for(&t),
+            Some(BulkTarget::RemoveLabels {
+                labels: vec!["new".to_string()]
+            })
+        );
+        let assign = BulkTarget::Assign {
+            member_email: "bob@example.com".to_string(),
+            member_name: "Bob".to_string(),
+        };
+        assert_eq!(
+            assign.inverse_for(&t),
+            Some(BulkTarget::Assign {
+                member_email: "alice@example.com".to_string(),
+                member_name: "Alice".to_string(),
+            })
+        );
+        let same_summary = BulkTarget::Summary {
+            summary: "Old summary".to_string(),
+        };
+        assert_eq!(same_summary.inverse_for(&t), None);
+        let comment = BulkTarget::Comment {
+            body: "hi".to_string(),
+        };
+        assert_eq!(comment.inverse_for(&t), None);
+    }
+
+    #[test]
+    fn undoing_a_resolving_move_restores_the_resolution() {
+        let mut open = ticket("AMP-1", "Open");
+        open.status = crate::cache::Status::InProgress;
+        let close = BulkTarget::Move {
+            status: crate::cache::Status::Closed,
+            resolution: Some("Fixed".to_string()),
+            clear_resolution: false,
+        };
+        assert_eq!(
+            close.inverse_for(&open),
+            Some(BulkTarget::Move {
+                status: crate::cache::Status::InProgress,
+                resolution: None,
+                clear_resolution: true,
+            })
+        );
+
+        let mut closed = ticket("AMP-2", "Closed");
+        closed.status = crate::cache::Status::Closed;
+        closed.resolution = Some("Won't Fix".to_string());
+        let reopen = BulkTarget::Move {
+            status: crate::cache::Status::InProgress,
+            resolution: None,
+            clear_resolution: false,
+        };
+        assert_eq!(
+            reopen.inverse_for(&closed),
+            Some(BulkTarget::Move {
+                status: crate::cache::Status::Closed,
+                resolution: Some("Won't Fix".to_string()),
+                clear_resolution: false,
+            })
+        );
+    }
+
+    #[test]
+    fn record_journal_skips_empty_and_caps_length() {
+        let mut app = App::new();
+        app.record_journal("nothing".to_string(), Vec::new());
+        assert!(app.journal.is_empty());
+
+        for i in 0..JOURNAL_LIMIT + 5 {
+            app.record_journal(
+                format!("entry {}", i),
+                vec![JournalChange {
+                    key: "AMP-1".to_string(),
+                    inverse: BulkTarget::Summary {
+                        summary: "x".to_string(),
+                    },
+                }],
+            );
+        }
+        assert_eq!(app.journal.len(), JOURNAL_LIMIT);
+        assert_eq!(app.journal[0].label, "entry 5");
+    }
+
+    #[test]
+    fn export_rows_follow_search_and_group_labels() {
+        let mut app = epics_app(vec![
+            Epic {
+                key: "AMP-100".to_string(),
+                summary: "Auth Platform".to_string(),
+                children: vec![
+                    ticket("AMP-1", "Session resume"),
+                    ticket("AMP-2", "Passwords"),
+                ],
+            },
+            Epic {
+                key: "AMP-200".to_string(),
+                summary: "Performance".to_string(),
+                children: vec![ticket("AMP-3", "Session cache")],
+            },
+        ]);
+        app.search = Some("session".to_string());
+
+        let rows: Vec<(String, String)> = app
+            .visible_tickets_for_export()
+            .into_iter()
+            .map(|(group, t)| (group, t.key.clone()))
+            .collect();
+        assert_eq!(
+            rows,
+            vec![
+                ("AMP-100 Auth Platform".to_string(), "AMP-1".to_string()),
+                ("AMP-200 Performance".to_string(), "AMP-3".to_string()),
+            ]
+        );
+    }
 }
@@ -0,0 +1,152 @@
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+
+use crate::app::BulkSummary;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportFormat {
+    Csv,
+    Json,
+}
+
+impl ReportFormat {
+    pub fn extension(self) -> &'static str {
+        match self {
+            ReportFormat::Csv => "csv",
+            ReportFormat::Json => "json",
+        }
+    }
+}
+
+/// Per-ticket outcome of a bulk run.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ReportRow {
+    pub key: String,
+    pub outcome: &'static str,
+    pub error: String,
+}
+
+#[derive(Debug, Serialize)]
+struct JsonReport<'a> {
+    action: &'a str,
+    target: String,
+    total: usize,
+    attempted: usize,
+    succeeded: usize,
+    skipped: usize,
+    failed: usize,
+    items: Vec<ReportRow>,
+}
+
+/// Successes, then skips, then failures with their error text.
+pub fn report_rows(summary: &BulkSummary) -> Vec<ReportRow> {
+    let outcome = |outcome, keys: &[String]| {
+        keys.iter()
+            .map(|key| ReportRow {
+                key: key.clone(),
+                outcome,
+                error: String::new(),
+            })
+            .collect::<Vec<_>>()
+    };
+    let mut rows = outcome("succeeded", &summary.successful_keys);
+    rows.extend(outcome("skipped", &summary.skipped_keys));
+    rows.extend(summary.failed_details.iter().map(|(key, err)| ReportRow {
+        key: key.clone(),
+        outcome: "failed",
+        error: err.clone(),
+    }));
+    rows
+}
+
+/// File name like `lazyjira-bulk-move-tickets-1760000000.csv`.
+pub fn default_file_name(summary: &BulkSummary, format: ReportFormat, now: u64) -> String {
+    let action = summary
+        .action
+        .label()
+        .to_ascii_lowercase()
+        .replace(|c: char| !c.is_ascii_alphanumeric(), "-");
+    format!("lazyjira-bulk-{}-{}.{}", action, now, format.extension())
+}
+
+pub fn render_report(summary: &BulkSummary, format: ReportFormat) -> Result<String> {
+    let rows = report_rows(summary);
+    match format {
+        ReportFormat::Csv => {
+            let mut writer = csv::Writer::from_writer(Vec::new());
+            for row in &rows {
+                writer.serialize(row)?;
+            }
+            let bytes = writer.into_inner().context("Failed to flush CSV report")?;
+            Ok(String::from_utf8(bytes)?)
+        }
+        ReportFormat::Json => {
+            let report = JsonReport {
+                action: summary.action.label(),
+                target: summary.target.describe(),
+                total: summary.total,
+                attempted: summary.attempted,
+                succeeded: summary.succeeded,
+                skipped: summary.skipped,
+                failed: summary.failed,
+                items: rows,
+            };
+            Ok(serde_json::to_string_pretty(&report)?)
+        }
+    }
+}
+
+pub fn write_report(summary: &BulkSummary, path: &Path, format: ReportFormat) -> Result<()> {
+    let contents = render_report(summary, format)?;
+    std::fs::write(path, contents)
+        .with_context(|| format!("Failed to write report: {}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::{BulkAction, BulkTarget};
+
+    fn summary() -> BulkSummary {
+        BulkSummary {
+            action: BulkAction::Move,
+            target: BulkTarget::Move {
+                status: crate::cache::Status::InProgress,
+                resolution: None,
+                clear_resolution: false,
+            },
+            total: 3,
+            attempted: 2,
+            succeeded: 1,
+            skipped: 1,
+            failed: 1,
+            successful_keys: vec!["AMP-1".to_string()],
+            skipped_keys: vec!["AMP-2".to_string()],
+            failed_details: vec![("AMP-3".to_string(), "no transition".to_string())],
+        }
+    }
+
+    #[test]
+    fn csv_report_lists_every_outcome() {
+        let csv = render_report(&summary(), ReportFormat::Csv).unwrap();
+        assert_eq!(
+            csv,
+            "key,outcome,error\nAMP-1,succeeded,\nAMP-2,skipped,\nAMP-3,failed,no transition\n"
+        );
+    }
+
+    #[test]
+    fn json_report_includes_totals_and_items() {
+        let json = render_report(&summary(), ReportFormat::Json).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["failed"], 1);
+        assert_eq!(value["target"], "Move to In Progress");
+        assert_eq!(value["items"][2]["error"], "no transition");
+        assert_eq!(
+            default_file_name(&summary(), ReportFormat::Json, 42),
+            "lazyjira-bulk-move-tickets-42.json"
+        );
+    }
+}
@@ -1,16 +1,28 @@
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
 
 use anyhow::{bail, Context, Result};
 
-use crate::app::{BulkUploadPreview, BulkUploadRow, ISSUE_TYPES};
+use crate::app::{
+    BulkUploadPreview, BulkUploadRow, FieldDiff, ISSUE_TYPES, PRIORITIES, SUBTASK_ISSUE_TYPE,
+};
+use crate::cache::Ticket;
 
 pub const MAX_BULK_UPLOAD_ROWS: usize = 500;
 
+/// Header prefix for custom field columns, e.g. `field:team`.
+pub const CUSTOM_FIELD_PREFIX: &str = "field:";
+
 #[derive(Debug, Clone)]
 pub struct BulkUploadContext {
     pub known_epic_keys: HashSet<String>,
     pub existing_summaries: HashSet<String>,
     pub issue_types: Vec<String>,
+    /// Cached tickets by key, used to diff `key` (update) rows.
+    pub existing_tickets: HashMap<String, Ticket>,
+    /// jira-cli custom field name -> Jira field id, from `[custom_fields]`.
+    pub custom_fields: BTreeMap<String, String>,
+    /// Lower-case name, first name or email user -> email, for Markdown `@assignee`.
+    pub assignees: HashMap<String, String>,
 }
 
 impl BulkUploadContext {
@@ -19,6 +31,9 @@ impl BulkUploadContext {
             known_epic_keys,
             existing_summaries,
             issue_types: ISSUE_TYPES.iter().map(|s| s.to_string()).collect(),
+            existing_tickets: HashMap::new(),
+            custom_fields: BTreeMap::new(),
+            assignees: HashMap::new(),
         }
     }
 }
@@ -27,41 +42,75 @@ pub fn normalize_summary(summary: &str) -> String {
     summary.trim().to_ascii_lowercase()
 }
 
-pub fn parse_csv_preview(path: &str, context: &BulkUploadContext) -> Result<BulkUploadPreview> {
+/// Parse a bulk upload file into a preview, picking the format from its extension:
+/// `.json`, `.yaml`/`.yml`, `.md`/`.markdown`, otherwise CSV.
+pub fn parse_preview(path: &str, context: &BulkUploadContext) -> Result<BulkUploadPreview> {
+    let extension = std::path::Path::new(path)
+        .extension()
+        .and_then(|e| e.to_str())
+        .unwrap_or_default()
+        .to_ascii_lowercase();
+    let records = match extension.as_str() {
+        "json" | "yaml" | "yml" => {
+            let content = read_file(path)?;
+            let value: serde_json::Value = if extension == "json" {
+                serde_json::from_str(&content).context("Failed to parse JSON")?
+            } else {
+                serde_yaml::from_str(&content).context("Failed to parse YAML")?
+            };
+            structured_records(value)?
+        }
+        "md" | "markdown" => markdown_records(&read_file(path)?, context),
+        _ => csv_records(path)?,
+    };
+    build_preview(path, records, context)
+}
+
+/// One input row before validation: lower-case field name -> raw value. Lists use `|`
+/// separators and custom fields use `field:<name>` keys, as in CSV.
+struct RawRecord {
+    row_number: usize,
+    values: HashMap<String, String>,
+}
+
+impl RawRecord {
+    fn get(&self, name: &str) -> Option<String> {
+        let value = self.values.get(name)?.trim();
+        if value.is_empty() {
+            None
+        } else {
+            Some(value.to_string())
+        }
+    }
+}
+
+fn read_file(path: &str) -> Result<String> {
+    std::fs::read_to_string(path).with_context(|| format!("Failed to open file: {}", path))
+}
+
+fn csv_records(path: &str) -> Result<Vec<RawRecord>> {
     let mut reader = csv::ReaderBuilder::new()
         .flexible(true)
         .from_path(path)
         .with_context(|| format!("Failed to open CSV file: {}", path))?;
 
-    let raw_headers = reader
+    let headers = reader
         .headers()
         .context("Failed to read CSV headers")?
         .iter()
         .map(|s| s.trim().to_ascii_lowercase())
         .collect::<Vec<_>>();
 
-    if raw_headers.is_empty() {
+    if headers.is_empty() {
         bail!("CSV file is empty");
     }
 
-    let mut headers = HashMap::new();
-    for (idx, header) in raw_headers.iter().enumerate() {
-        headers.entry(header.clone()).or_insert(idx);
-    }
-
-    if !headers.contains_key("summary") {
+    // Update-only files may omit summary when every row has a key.
+    if !headers.iter().any(|h| h == "summary" || h == "key") {
         bail!("Missing required 'summary' header");
     }
 
-    let allowed_types: HashMap<String, String> = context
-        .issue_types
-        .iter()
-        .map(|t| (t.to_ascii_lowercase(), t.clone()))
-        .collect();
-
-    let mut rows = Vec::new();
-    let mut summary_seen: HashMap<String, usize> = HashMap::new();
-
+    let mut records = Vec::new();
     for (idx, record_result) in reader.records().enumerate() {
         if idx >= MAX_BULK_UPLOAD_ROWS {
             bail!(
@@ -78,19 +127,229 @@ pub fn parse_csv_preview(path: &str, context: &BulkUploadContext) -> Result<Bulk
             )
         })?;
 
+        let mut values = HashMap::new();
+        for (header, value) in headers.iter().zip(record.iter()) {
+            // The first column wins when a header repeats.
+            values
+                .entry(header.clone())
+                .or_insert_with(|| value.to_string());
+        }
+        records.push(RawRecord { row_number, values });
+    }
+    Ok(records)
+}
+
+/// JSON or YAML: an array of objects, or an object with a `tickets` array. Array values
+/// become `|` lists and a nested `fields` object becomes `field:<name>` values.
+fn structured_records(value: serde_json::Value) -> Result<Vec<RawRecord>> {
+    let items = match value {
+        serde_json::Value::Array(items) => items,
+        serde_json::Value::Object(mut map) => match map.remove("tickets") {
+            Some(serde_json::Value::Array(items)) => items,
+            _ => bail!("Expected a list of tickets or a 'tickets' list"),
+        },
+        _ => bail!("Expected a list of tickets or a 'tickets' list"),
+    };
+
+    let mut records = Vec::new();
+    for (idx, item) in items.into_iter().enumerate() {
+        let row_number = idx + 1;
+        let serde_json::Value::Object(map) = item else {
+            bail!("Item {} is not an object", row_number);
+        };
+        let mut values = HashMap::new();
+        for (name, value) in map {
+            let name = name.trim().to_ascii_lowercase();
+            match value {
+                serde_json::Value::Object(fields) if name == "fields" => {
+                    for (field, value) in fields {
+                        values.insert(
+                            format!(
+                                "{}{}",
+                                CUSTOM_FIELD_PREFIX,
+                                field.trim().to_ascii_lowercase()
+                            ),
+                            scalar_text(&value),
+                        );
+                    }
+                }
+                value => {
+                    values.insert(name, scalar_text(&value));
+                }
+            }
+        }
+        records.push(RawRecord { row_number, values });
+    }
+    Ok(records)
+}
+
+fn scalar_text(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::Null => String::new(),
+        serde_json::Value::String(s) => s.clone(),
+        serde_json::Value::Array(items) => {
+            items.iter().map(scalar_text).collect::<Vec<_>>().join("|")
+        }
+        other => other.to_string(),
+    }
+}
+
+/// Markdown task lists: each open `- [ ] Summary #label @assignee` item is a row. Lines right
+/// below an item may add labels: `Labels: backend, urgent`, or a line of `#tag` tokens.
+/// Checked items and other lines are ignored; row numbers are line numbers.
+fn markdown_records(content: &str, context: &BulkUploadContext) -> Vec<RawRecord> {
+    let mut records = Vec::new();
+    let mut labels: Vec<Vec<String>> = Vec::new();
+    let mut in_item = false;
+    for (idx, line) in content.lines().enumerate() {
+        let trimmed = line.trim_start();
+        if in_item {
+            if let (Some(found), Some(item_labels)) = (markdown_labels(trimmed), labels.last_mut())
+            {
+                item_labels.extend(found);
+                continue;
+            }
+        }
+        let Some(item) = ["- [ ]", "* [ ]", "+ [ ]"]
+            .iter()
+            .find_map(|marker| trimmed.strip_prefix(marker))
+        else {
+            in_item = false;
+            continue;
+        };
+
+        let mut summary = Vec::new();
+        let mut assignee = None;
+        let mut item_labels = Vec::new();
+        for word in item.split_whitespace() {
+            if let Some(tag) = markdown_tag(word) {
+                item_labels.push(tag.to_string());
+            } else if let Some(handle) = word.strip_prefix('@').filter(|h| !h.is_empty()) {
+                assignee = Some(
+                    context
+                        .assignees
+                        .get(&handle.to_ascii_lowercase())
+                        .cloned()
+                        .unwrap_or_else(|| handle.to_string()),
+                );
+            } else {
+                summary.push(word);
+            }
+        }
+
+        let mut values = HashMap::new();
+        values.insert("summary".to_string(), summary.join(" "));
+        if let Some(assignee) = assignee {
+            values.insert("assignee_email".to_string(), assignee);
+        }
+        records.push(RawRecord {
+            row_number: idx + 1,
+            values,
+        });
+        labels.push(item_labels);
+        in_item = true;
+    }
+    for (record, item_labels) in records.iter_mut().zip(labels) {
+        record
+            .values
+            .insert("labels".to_string(), item_labels.join("|"));
+    }
+    records
+}
+
+/// A `#tag` word whose tag starts with a letter, so `#123` and `C#` stay plain text.
+fn markdown_tag(word: &str) -> Option<&str> {
+    word.strip_prefix('#')
+        .filter(|tag| tag.starts_with(|c: char| c.is_alphabetic()))
+}
+
+/// Labels on a line below a Markdown task item: `Labels: a, b`, or only `#tag` tokens.
+fn markdown_labels(line: &str) -> Option<Vec<String>> {
+    let line = line.trim();
+    if let Some((name, rest)) = line.split_once(':') {
+        if name.trim().eq_ignore_ascii_case("labels") {
+            return Some(
+                rest.split(|c: char| c == ',' || c.is_whitespace())
+                    .filter(|label| !label.is_empty())
+                    .map(str::to_string)
+                    .collect(),
+            );
+        }
+    }
+    let tags = line
+        .split_whitespace()
+        .map(markdown_tag)
+        .collect::<Option<Vec<_>>>()?;
+    (!tags.is_empty()).then(|| tags.into_iter().map(str::to_string).collect())
+}
+
+/// Validate raw records with the shared row rules.
+fn build_preview(
+    path: &str,
+    records: Vec<RawRecord>,
+    context: &BulkUploadContext,
+) -> Result<BulkUploadPreview> {
+    if records.len() > MAX_BULK_UPLOAD_ROWS {
+        bail!(
+            "Row limit exceeded. Maximum supported rows per upload is {}",
+            MAX_BULK_UPLOAD_ROWS
+        );
+    }
+
+    let allowed_types: HashMap<String, String> = context
+        .issue_types
+        .iter()
+        .map(|t| (t.to_ascii_lowercase(), t.clone()))
+        .collect();
+
+    let mut rows = Vec::new();
+    let mut summary_seen: HashMap<String, usize> = HashMap::new();
+
+    for record in records {
+        let row_number = record.row_number;
         let mut errors = Vec::new();
         let mut warnings = Vec::new();
 
-        let summary = field_value(&record, &headers, "summary").unwrap_or_default();
-        if summary.is_empty() {
+        let key = record.get("key").map(|k| k.to_ascii_uppercase());
+        if let Some(ref key) = key {
+            if !is_valid_jira_key(key) {
+                errors.push(format!("invalid key '{}'", key));
+            }
+        }
+        let updating = key.is_some();
+
+        let summary = record.get("summary").unwrap_or_default();
+        if summary.is_empty() && !updating {
             errors.push("summary is required".to_string());
         }
 
-        let issue_type_input = field_value(&record, &headers, "type");
+        let parent_key = record.get("parent");
+        if let Some(ref parent) = parent_key {
+            if updating {
+                errors.push("parent cannot be changed when updating".to_string());
+            } else if !is_valid_jira_key(parent) {
+                errors.push(format!("invalid parent '{}'", parent));
+            }
+        }
+
+        let issue_type_input = record.get("type");
         let issue_type = match issue_type_input {
+            Some(ref t) if updating => {
+                warnings.push(format!("type '{}' is ignored when updating", t));
+                t.clone()
+            }
+            Some(ref t) if t.eq_ignore_ascii_case(SUBTASK_ISSUE_TYPE) => {
+                if parent_key.is_none() {
+                    errors.push("type Sub-task requires a parent".to_string());
+                }
+                SUBTASK_ISSUE_TYPE.to_string()
+            }
             Some(ref t) if !t.is_empty() => {
                 let normalized = t.to_ascii_lowercase();
                 if let Some(found) = allowed_types.get(&normalized) {
+                    if parent_key.is_some() {
+                        errors.push(format!("parent requires type {}", SUBTASK_ISSUE_TYPE));
+                    }
                     found.clone()
                 } else {
                     errors.push(format!(
@@ -101,17 +360,18 @@ pub fn parse_csv_preview(path: &str, context: &BulkUploadContext) -> Result<Bulk
                     t.clone()
                 }
             }
+            _ if parent_key.is_some() => SUBTASK_ISSUE_TYPE.to_string(),
             _ => "Task".to_string(),
         };
 
-        let assignee_email = field_value(&record, &headers, "assignee_email");
+        let assignee_email = record.get("assignee_email");
         if let Some(ref email) = assignee_email {
             if !is_valid_email(email) {
                 errors.push(format!("invalid assignee_email '{}'", email));
             }
         }
 
-        let epic_key = field_value(&record, &headers, "epic_key");
+        let epic_key = record.get("epic_key");
         if let Some(ref key) = epic_key {
             if !is_valid_jira_key(key) {
                 errors.push(format!("invalid epic_key '{}'", key));
@@ -120,17 +380,68 @@ pub fn parse_csv_preview(path: &str, context: &BulkUploadContext) -> Result<Bulk
             }
         }
 
-        let labels = field_value(&record, &headers, "labels")
-            .unwrap_or_default()
-            .split('|')
-            .map(|s| s.trim())
-            .filter(|s| !s.is_empty())
-            .map(|s| s.to_string())
-            .collect::<Vec<_>>();
+        let labels = split_list(record.get("labels"));
+        let components = split_list(record.get("components"));
+
+        let description = record.get("description");
+
+        let priority = record.get("priority").map(|p| {
+            match PRIORITIES.iter().find(|known| {
+                known.eq_ignore_ascii_case(&p)
+                    || known
+                        .split(" - ")
+                        .next()
+                        .is_some_and(|name| name.eq_ignore_ascii_case(&p))
+            }) {
+                Some(known) => known.to_string(),
+                None => {
+                    errors.push(format!(
+                        "invalid priority '{}'; expected one of {}",
+                        p,
+                        PRIORITIES.join(", ")
+                    ));
+                    p
+                }
+            }
+        });
+
+        let story_points = record.get("story_points");
+        if let Some(ref points) = story_points {
+            if points.parse::<f64>().is_err() {
+                errors.push(format!("invalid story_points '{}'", points));
+            }
+        }
+
+        let due_date = record.get("due_date");
+        if let Some(ref due) = due_date {
+            if chrono::NaiveDate::parse_from_str(due, "%Y-%m-%d").is_err() {
+                errors.push(format!("invalid due_date '{}'; expected YYYY-MM-DD", due));
+            }
+        }
 
-        let description = field_value(&record, &headers, "description");
+        let mut custom_fields = BTreeMap::new();
+        let mut custom_columns: Vec<(&str, &str)> = record
+            .values
+            .iter()
+            .filter_map(|(header, value)| {
+                let name = header.strip_prefix(CUSTOM_FIELD_PREFIX)?.trim();
+                let value = value.trim();
+                (!value.is_empty()).then_some((name, value))
+            })
+            .collect();
+        custom_columns.sort();
+        for (name, value) in custom_columns {
+            if name.is_empty() {
+                errors.push("custom field column 'field:' has no name".to_string());
+                continue;
+            }
+            if !context.custom_fields.is_empty() && !context.custom_fields.contains_key(name) {
+                warnings.push(format!("field '{}' is not in [custom_fields]", name));
+            }
+            custom_fields.insert(name.to_string(), value.to_string());
+        }
 
-        if !summary.is_empty() {
+        if !summary.is_empty() && !updating {
             let normalized_summary = normalize_summary(&summary);
 
             if context.existing_summaries.contains(&normalized_summary) {
@@ -144,17 +455,38 @@ pub fn parse_csv_preview(path: &str, context: &BulkUploadContext) -> Result<Bulk
             *seen += 1;
         }
 
-        rows.push(BulkUploadRow {
+        let mut row = BulkUploadRow {
             row_number,
+            key,
             issue_type,
             summary,
             assignee_email,
             epic_key,
+            parent_key,
             labels,
             description,
+            priority,
+            components,
+            story_points,
+            due_date,
+            custom_fields,
+            diffs: Vec::new(),
             errors,
             warnings,
-        });
+        };
+        if let Some(key) = row.key.clone() {
+            let ticket = context.existing_tickets.get(&key);
+            if ticket.is_none() {
+                row.warnings
+                    .push("not in local cache; current values unknown".to_string());
+            }
+            row.diffs = update_diffs(&row, ticket, &context.custom_fields);
+            if row.diffs.is_empty() {
+                row.warnings
+                    .push("no changes; row will be skipped".to_string());
+            }
+        }
+        rows.push(row);
     }
 
     let total_rows = rows.len();
@@ -172,18 +504,98 @@ pub fn parse_csv_preview(path: &str, context: &BulkUploadContext) -> Result<Bulk
     })
 }
 
-fn field_value(
-    record: &csv::StringRecord,
-    headers: &HashMap<String, usize>,
-    name: &str,
-) -> Option<String> {
-    let idx = *headers.get(name)?;
-    let value = record.get(idx)?.trim();
-    if value.is_empty() {
-        None
-    } else {
-        Some(value.to_string())
+/// Field-level changes an update row makes to `ticket`. Fields the cache does not
+/// track are always listed, with `?` as the current value.
+fn update_diffs(
+    row: &BulkUploadRow,
+    ticket: Option<&Ticket>,
+    custom_field_ids: &BTreeMap<String, String>,
+) -> Vec<FieldDiff> {
+    const UNKNOWN: &str = "?";
+    let mut diffs = Vec::new();
+    let mut push = |field: &str, current: Option<String>, new: &str| {
+        let current = current.unwrap_or_else(|| UNKNOWN.to_string());
+        if current != new {
+            diffs.push(FieldDiff {
+                field: field.to_string(),
+                current,
+                new: new.to_string(),
+            });
+        }
+    };
+    let none = || Some("-".to_string());
+
+    if !row.summary.is_empty() {
+        push("summary", ticket.map(|t| t.summary.clone()), &row.summary);
     }
+    if let Some(email) = row.assignee_email.as_deref() {
+        push(
+            "assignee",
+            ticket.map(|t| t.assignee_email.clone().unwrap_or_default()),
+            email,
+        );
+    }
+    if let Some(epic) = row.epic_key.as_deref() {
+        push(
+            "epic",
+            ticket.and_then(|t| t.epic_key.clone().or_else(none)),
+            epic,
+        );
+    }
+    if !row.labels.is_empty() {
+        // Labels are appended, so only new ones count as a change.
+        let current = ticket.filter(|t| t.detail_loaded).map(|t| t.labels.clone());
+        match current {
+            Some(current) => {
+                let mut merged = current.clone();
+                merged.extend(row.labels.iter().filter(|l| !current.contains(l)).cloned());
+                push("labels", Some(current.join(", ")), &merged.join(", "));
+            }
+            None => push("labels", None, &format!("+ {}", row.labels.join(", "))),
+        }
+    }
+    if let Some(description) = row.description.as_deref() {
+        push(
+            "description",
+            ticket
+                .filter(|t| t.detail_loaded)
+                .map(|t| t.description.clone().unwrap_or_default()),
+            description,
+        );
+    }
+    if let Some(priority) = row.priority.as_deref() {
+        push(
+            "priority",
+            ticket.and_then(|t| t.priority.clone()),
+            priority,
+        );
+    }
+    if !row.components.is_empty() {
+        push("components", None, &row.components.join(", "));
+    }
+    if let Some(points) = row.story_points.as_deref() {
+        push("story_points", None, points);
+    }
+    if let Some(due) = row.due_date.as_deref() {
+        push("due_date", None, due);
+    }
+    for (name, value) in &row.custom_fields {
+        let current = custom_field_ids
+            .get(name)
+            .and_then(|id| ticket.map(|t| t.custom_fields.get(id).cloned().unwrap_or_default()));
+        push(&format!("field:{}", name), current, value);
+    }
+    diffs
+}
+
+fn split_list(value: Option<String>) -> Vec<String> {
+    value
+        .unwrap_or_default()
+        .split('|')
+        .map(|s| s.trim())
+        .filter(|s| !s.is_empty())
+        .map(|s| s.to_string())
+        .collect()
 }
 
 fn is_valid_email(email: &str) -> bool {
@@ -211,12 +623,17 @@ mod tests {
     use std::time::{SystemTime, UNIX_EPOCH};
 
     fn write_temp_csv(content: &str) -> String {
+        write_temp_file(content, "csv")
+    }
+
+    fn write_temp_file(content: &str, extension: &str) -> String {
         let stamp = SystemTime::now()
             .duration_since(UNIX_EPOCH)
             .expect("time")
             .as_nanos();
-        let path = std::env::temp_dir().join(format!("lazyjira_bulk_upload_{}.csv", stamp));
-        fs::write(&path, content).expect("write temp csv");
+        let path =
+            std::env::temp_dir().join(format!("lazyjira_bulk_upload_{}.{}", stamp, extension));
+        fs::write(&path, content).expect("write temp file");
         path.to_string_lossy().to_string()
     }
 
@@ -228,10 +645,14 @@ mod tests {
         BulkUploadContext::new(epics, summaries)
     }
 
+    fn cached_ticket(key: &str, summary: &str) -> Ticket {
+        Ticket::for_test(key, summary, crate::cache::Status::ToDo)
+    }
+
     #[test]
     fn parse_success_with_minimal_summary_only() {
         let path = write_temp_csv("summary\nFirst task\nSecond task\n");
-        let preview = parse_csv_preview(&path, &sample_context()).expect("parse");
+        let preview = parse_preview(&path, &sample_context()).expect("parse");
         assert_eq!(preview.total_rows, 2);
         assert_eq!(preview.valid_rows, 2);
         assert_eq!(preview.invalid_rows, 0);
@@ -243,7 +664,7 @@ mod tests {
         let csv = "summary,type,assignee_email,epic_key,labels,description\n\
                    \"CSV task\",Bug,dev@example.com,AMP-1,\"frontend|urgent\",\"first line\nsecond line, with comma\"\n";
         let path = write_temp_csv(csv);
-        let preview = parse_csv_preview(&path, &sample_context()).expect("parse");
+        let preview = parse_preview(&path, &sample_context()).expect("parse");
         let row = &preview.rows[0];
         assert_eq!(row.issue_type, "Bug");
         assert_eq!(row.assignee_email.as_deref(), Some("dev@example.com"));
@@ -263,7 +684,7 @@ mod tests {
     #[test]
     fn parse_fails_when_summary_header_missing() {
         let path = write_temp_csv("type,assignee_email\nTask,dev@example.com\n");
-        let err = parse_csv_preview(&path, &sample_context()).expect_err("must fail");
+        let err = parse_preview(&path, &sample_context()).expect_err("must fail");
         assert!(err
             .to_string()
             .contains("Missing required 'summary' header"));
@@ -274,7 +695,7 @@ mod tests {
         let csv = "summary,type,assignee_email,epic_key\n\
                    ,Feature,bad-email,AMP-999\n";
         let path = write_temp_csv(csv);
-        let preview = parse_csv_preview(&path, &sample_context()).expect("parse");
+        let preview = parse_preview(&path, &sample_context()).expect("parse");
         let row = &preview.rows[0];
         assert!(row.errors.iter().any(|e| e.contains("summary is required")));
         assert!(row.errors.iter().any(|e| e.contains("invalid type")));
@@ -291,7 +712,7 @@ mod tests {
     fn duplicate_warnings_include_existing_and_in_csv() {
         let csv = "summary\nExisting Summary\nExisting Summary\n";
         let path = write_temp_csv(csv);
-        let preview = parse_csv_preview(&path, &sample_context()).expect("parse");
+        let preview = parse_preview(&path, &sample_context()).expect("parse");
         assert_eq!(preview.warning_count, 3);
         assert_eq!(preview.rows[0].warnings.len(), 1);
         assert_eq!(preview.rows[1].warnings.len(), 2);
@@ -304,7 +725,152 @@ mod tests {
             csv.push_str(format!("task {}\n", i).as_str());
         }
         let path = write_temp_csv(&csv);
-        let err = parse_csv_preview(&path, &sample_context()).expect_err("must fail");
+        let err = parse_preview(&path, &sample_context()).expect_err("must fail");
         assert!(err.to_string().contains("Row limit exceeded"));
     }
+
+    #[test]
+    fn parse_extended_columns() {
+        let csv = "summary,priority,components,story_points,due_date,parent,field:team\n\
+                   Child,major,Editor|Parser,3,2026-11-01,AMP-42,Parsers\n\
+                   Bad,Urgent,,lots,tomorrow,,\n";
+        let path = write_temp_csv(csv);
+        let mut context = sample_context();
+        context
+            .custom_fields
+            .insert("team".to_string(), "customfield_1".to_string());
+        let preview = parse_preview(&path, &context).expect("parse");
+
+        let row = &preview.rows[0];
+        assert!(row.errors.is_empty(), "{:?}", row.errors);
+        assert_eq!(row.issue_type, SUBTASK_ISSUE_TYPE);
+        assert_eq!(row.parent_key.as_deref(), Some("AMP-42"));
+        assert_eq!(row.priority.as_deref(), Some("Major - P3"));
+        assert_eq!(row.components, vec!["Editor", "Parser"]);
+        assert_eq!(row.story_points.as_deref(), Some("3"));
+        assert_eq!(row.due_date.as_deref(), Some("2026-11-01"));
+        assert_eq!(
+            row.custom_fields.get("team").map(String::as_str),
+            Some("Parsers")
+        );
+
+        let bad = &preview.rows[1];
+        assert!(bad.errors.iter().any(|e| e.contains("invalid priority")));
+        assert!(bad
+            .errors
+            .iter()
+            .any(|e| e.contains("invalid story_points")));
+        assert!(bad.errors.iter().any(|e| e.contains("invalid due_date")));
+    }
+
+    #[test]
+    fn key_rows_update_with_field_diffs() {
+        let csv = "key,summary,priority,labels,field:team\n\
+                   AMP-7,Renamed,Major - P3,triage|new,Parsers\n\
+                   AMP-8,Same summary,,,\n\
+                   AMP-9,,Minor - P4,,\n";
+        let path = write_temp_csv(csv);
+        let mut context = sample_context();
+        context
+            .custom_fields
+            .insert("team".to_string(), "customfield_1".to_string());
+        let mut existing = cached_ticket("AMP-7", "Old name");
+        existing.priority = Some("Major - P3".to_string());
+        existing.labels = vec!["triage".to_string()];
+        existing.detail_loaded = true;
+        existing
+            .custom_fields
+            .insert("customfield_1".to_string(), "Runtime".to_string());
+        context
+            .existing_tickets
+            .insert("AMP-7".to_string(), existing);
+        context
+            .existing_tickets
+            .insert("AMP-8".to_string(), cached_ticket("AMP-8", "Same summary"));
+        let preview = parse_preview(&path, &context).expect("parse");
+
+        let row = &preview.rows[0];
+        assert!(row.errors.is_empty());
+        let fields: Vec<&str> = row.diffs.iter().map(|d| d.field.as_str()).collect();
+        assert_eq!(fields, vec!["summary", "labels", "field:team"]);
+        assert_eq!(row.diffs[1].new, "triage, new");
+        assert_eq!(row.diffs[2].current, "Runtime");
+
+        assert!(preview.rows[1].is_noop_update());
+        assert!(preview.rows[2].errors.is_empty());
+        assert_eq!(preview.rows[2].diffs[0].current, "?");
+        assert!(preview.rows[2]
+            .warnings
+            .iter()
+            .any(|w| w.contains("not in local cache")));
+    }
+
+    #[test]
+    fn parse_json_list_with_arrays_and_fields() {
+        let json = r#"[
+            {"summary": "JSON task", "type": "Bug", "labels": ["api", "urgent"],
+             "story_points": 5, "fields": {"Team": "Parsers"}},
+            {"type": "Story"}
+        ]"#;
+        let path = write_temp_file(json, "json");
+        let preview = parse_preview(&path, &sample_context()).expect("parse");
+        let row = &preview.rows[0];
+        assert_eq!(row.row_number, 1);
+        assert_eq!(row.issue_type, "Bug");
+        assert_eq!(row.labels, vec!["api", "urgent"]);
+        assert_eq!(row.story_points.as_deref(), Some("5"));
+        assert_eq!(
+            row.custom_fields.get("team").map(String::as_str),
+            Some("Parsers")
+        );
+        assert!(preview.rows[1]
+            .errors
+            .iter()
+            .any(|e| e.contains("summary is required")));
+    }
+
+    #[test]
+    fn parse_yaml_tickets_document() {
+        let yaml = "tickets:\n  - summary: YAML task\n    epic_key: AMP-1\n    components: [Editor]\n  - summary: Other\n    assignee_email: nope\n";
+        let path = write_temp_file(yaml, "yaml");
+        let preview = parse_preview(&path, &sample_context()).expect("parse");
+        assert_eq!(preview.total_rows, 2);
+        assert_eq!(preview.rows[0].epic_key.as_deref(), Some("AMP-1"));
+        assert_eq!(preview.rows[0].components, vec!["Editor"]);
+        assert!(preview.rows[1]
+            .errors
+            .iter()
+            .any(|e| e.contains("invalid assignee_email")));
+    }
+
+    #[test]
+    fn parse_markdown_task_list() {
+        let md = "# Plan\n\n- [ ] Write parser #backend #urgent @alice\n- [ ] Port the C# client #123 #sdk\n  #api\n- [x] Already done\n  #skipped\n  * [ ] Nested item @bob@example.com\n    Labels: api, docs\nNot a task\n#orphan\n- [ ] Ask @carol\n";
+        let path = write_temp_file(md, "md");
+        let mut context = sample_context();
+        context
+            .assignees
+            .insert("alice".to_string(), "alice@example.com".to_string());
+        let preview = parse_preview(&path, &context).expect("parse");
+
+        assert_eq!(preview.total_rows, 4);
+        let first = &preview.rows[0];
+        assert_eq!(first.row_number, 3);
+        assert_eq!(first.summary, "Write parser");
+        assert_eq!(first.labels, vec!["backend", "urgent"]);
+        assert_eq!(first.assignee_email.as_deref(), Some("alice@example.com"));
+        assert!(first.errors.is_empty());
+        assert_eq!(preview.rows[1].summary, "Port the C# client #123");
+        assert_eq!(preview.rows[1].labels, vec!["sdk", "api"]);
+        assert_eq!(
+            preview.rows[2].assignee_email.as_deref(),
+            Some("bob@example.com")
+        );
+        assert_eq!(preview.rows[2].labels, vec!["api", "docs"]);
+        assert!(preview.rows[3].labels.is_empty());
+        assert!(preview.rows[3]
+            .errors
+            .iter()
+            .any(|e| e.contains("invalid assignee_email 'carol'")));
+    }
 }
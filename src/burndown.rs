@@ -0,0 +1,158 @@
+use crate::cache::{Epic, Status};
+use crate::dashboard::closed_at;
+
+const DAY_SECS: u64 = 24 * 3600;
+/// Longest history drawn in the burndown chart.
+pub const MAX_HISTORY_DAYS: u64 = 90;
+/// Recent window used for the throughput behind the forecast.
+pub const THROUGHPUT_WINDOW_DAYS: u64 = 28;
+
+/// Done and remaining children at the end of one day; `day` is days before today (<= 0).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BurnPoint {
+    pub day: i64,
+    pub done: usize,
+    pub remaining: usize,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Forecast {
+    /// Children closed per week over the throughput window.
+    pub per_week: f64,
+    pub remaining: usize,
+    /// Days until done at `per_week`; None when nothing closed recently.
+    pub days_left: Option<u64>,
+}
+
+impl Forecast {
+    /// Projected completion date as `YYYY-MM-DD`.
+    pub fn eta(&self, now: u64) -> Option<String> {
+        let days = self.days_left?;
+        let secs = i64::try_from(now + days * DAY_SECS).ok()?;
+        chrono::DateTime::from_timestamp(secs, 0).map(|dt| dt.format("%Y-%m-%d").to_string())
+    }
+}
+
+/// Daily burnup/burndown series from the children's status-change history, oldest day first.
+/// Closed children without a recorded close count as done from the start.
+pub fn burn_series(epic: &Epic, now: u64) -> Vec<BurnPoint> {
+    let total = epic.total();
+    let closed: Vec<Option<u64>> = epic
+        .children
+        .iter()
+        .filter(|t| t.status == Status::Closed)
+        .map(closed_at)
+        .collect();
+    let earliest = closed.iter().flatten().min().copied().unwrap_or(now);
+    let history_days = (now.saturating_sub(earliest) / DAY_SECS + 1).min(MAX_HISTORY_DAYS);
+
+    (0..=history_days)
+        .rev()
+        .map(|days_ago| {
+            let day_end = now.saturating_sub(days_ago * DAY_SECS);
+            let done = closed
+                .iter()
+                .filter(|ts| ts.is_none_or(|ts| ts <= day_end))
+                .count();
+            BurnPoint {
+                day: -(days_ago as i64),
+                done,
+                remaining: total - done,
+            }
+        })
+        .collect()
+}
+
+pub fn forecast(epic: &Epic, now: u64) -> Forecast {
+    let since = now.saturating_sub(THROUGHPUT_WINDOW_DAYS * DAY_SECS);
+    let recent = epic
+        .children
+        .iter()
+        .filter(|t| t.status == Status::Closed)
+        .filter_map(closed_at)
+        .filter(|ts| *ts > since && *ts <= now)
+        .count();
+    let per_week = recent as f64 * 7.0 / THROUGHPUT_WINDOW_DAYS as f64;
+    let remaining = epic.total() - epic.done_count();
+    let days_left = if remaining == 0 {
+        Some(0)
+    } else if recent == 0 {
+        None
+    } else {
+        Some((remaining as f64 * 7.0 / per_week).ceil() as u64)
+    };
+    Forecast {
+        per_week,
+        remaining,
+        days_left,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cache::{ActivityEntry, ActivityKind, Ticket};
+    use crate::standup::parse_timestamp;
+
+    fn child(key: &str, status: Status, closed: Option<&str>) -> Ticket {
+        Ticket {
+            epic_key: Some("AMP-100".to_string()),
+            detail_loaded: true,
+            activity: closed
+                .map(|ts| ActivityEntry {
+                    timestamp: ts.to_string(),
+                    author: "Dev".to_string(),
+                    author_email: None,
+                    kind: ActivityKind::StatusChange {
+                        from: "In Review".to_string(),
+                        to: "Done".to_string(),
+                    },
+                })
+                .into_iter()
+                .collect(),
+            ..Ticket::for_test(key, key, status)
+        }
+    }
+
+    fn epic() -> Epic {
+        Epic {
+            key: "AMP-100".to_string(),
+            summary: "Auth".to_string(),
+            children: vec![
+                child("AMP-1", Status::Closed, Some("2026-02-27T12:00:00")),
+                child("AMP-2", Status::Closed, Some("2026-02-20T12:00:00")),
+                child("AMP-3", Status::Closed, None),
+                child("AMP-4", Status::InProgress, None),
+                child("AMP-5", Status::ToDo, None),
+            ],
+        }
+    }
+
+    #[test]
+    fn burn_series_counts_closes_by_day() {
+        let now = parse_timestamp("2026-03-01T00:00:00").unwrap();
+        let series = burn_series(&epic(), now);
+        let first = series.first().unwrap();
+        let last = series.last().unwrap();
+
+        assert_eq!(first.day, -9);
+        assert_eq!((first.done, first.remaining), (1, 4));
+        assert_eq!((last.day, last.done, last.remaining), (0, 3, 2));
+        let day_minus_5 = series.iter().find(|p| p.day == -5).unwrap();
+        assert_eq!(day_minus_5.done, 2);
+    }
+
+    #[test]
+    fn forecast_projects_from_recent_throughput() {
+        let now = parse_timestamp("2026-03-01T00:00:00").unwrap();
+        let forecast = forecast(&epic(), now);
+
+        assert_eq!(forecast.per_week, 0.5);
+        assert_eq!(forecast.remaining, 2);
+        assert_eq!(forecast.days_left, Some(28));
+        assert_eq!(forecast.eta(now).as_deref(), Some("2026-03-29"));
+
+        let idle = super::forecast(&epic(), now + 60 * DAY_SECS);
+        assert_eq!(idle.days_left, None);
+    }
+}
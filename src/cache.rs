@@ -1,5 +1,5 @@
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 /// Represents a Jira ticket status.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -116,6 +116,43 @@ pub enum ActivityKind {
     },
 }
 
+/// A single worklog entry recorded against a ticket.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Worklog {
+    pub author: String,
+    pub started: String,
+    pub time_spent: String,
+    pub time_spent_seconds: u64,
+    pub comment: Option<String>,
+}
+
+/// Estimates and logged time for a ticket, populated from full detail.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TimeTracking {
+    pub original_estimate: Option<String>,
+    pub remaining_estimate: Option<String>,
+    pub time_spent: Option<String>,
+    pub worklogs: Vec<Worklog>,
+}
+
+impl TimeTracking {
+    pub fn is_empty(&self) -> bool {
+        self.original_estimate.is_none()
+            && self.remaining_estimate.is_none()
+            && self.time_spent.is_none()
+            && self.worklogs.is_empty()
+    }
+}
+
+/// Who is watching a ticket, populated from full detail.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Watchers {
+    pub count: usize,
+    pub is_watching: bool,
+    /// Display names; may be empty when the watcher list could not be fetched.
+    pub names: Vec<String>,
+}
+
 /// A single Jira ticket.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Ticket {
@@ -135,6 +172,60 @@ pub struct Ticket {
     pub url: String,
     #[serde(default)]
     pub activity: Vec<ActivityEntry>,
+    #[serde(default)]
+    pub time_tracking: TimeTracking,
+    #[serde(default)]
+    pub watchers: Watchers,
+    /// Resolution name (e.g. "Fixed"), from full detail; None while unresolved.
+    #[serde(default)]
+    pub resolution: Option<String>,
+    #[serde(default)]
+    pub priority: Option<String>,
+    /// Scalar custom field values keyed by field id (e.g. `customfield_10555`), from full detail.
+    #[serde(default)]
+    pub custom_fields: BTreeMap<String, String>,
+}
+
+#[cfg(test)]
+impl Ticket {
+    /// A ticket with only key, summary and status set; tests fill in what they need.
+    pub fn for_test(key: &str, summary: &str, status: Status) -> Self {
+        Self {
+            key: key.to_string(),
+            summary: summary.to_string(),
+            status,
+            assignee: None,
+            assignee_email: None,
+            reporter: None,
+            description: None,
+            labels: Vec::new(),
+            epic_key: None,
+            epic_name: None,
+            detail_loaded: false,
+            url: String::new(),
+            activity: Vec::new(),
+            time_tracking: Default::default(),
+            watchers: Default::default(),
+            resolution: None,
+            priority: None,
+            custom_fields: Default::default(),
+        }
+    }
+}
+
+/// A ticket or epic shown in the detail view, for back/forward and the recent list.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Viewed {
+    Ticket(String),
+    Epic(String),
+}
+
+impl Viewed {
+    pub fn key(&self) -> &str {
+        match self {
+            Viewed::Ticket(key) | Viewed::Epic(key) => key,
+        }
+    }
 }
 
 /// An epic with aggregated child ticket info.
@@ -185,8 +276,14 @@ pub struct TeamMember {
 pub struct Cache {
     pub my_tickets: Vec<Ticket>,
     pub team_tickets: Vec<Ticket>,
+    /// Tickets the current user watches, for the Watching tab.
+    #[serde(default)]
+    pub watched_tickets: Vec<Ticket>,
     pub epics: Vec<Epic>,
     pub team_members: Vec<TeamMember>,
+    /// Set when the watched query failed and `watched_tickets` is the last snapshot's list.
+    #[serde(skip)]
+    pub watched_error: Option<String>,
 }
 
 impl Cache {
@@ -194,8 +291,10 @@ impl Cache {
         Self {
             my_tickets: Vec::new(),
             team_tickets: Vec::new(),
+            watched_tickets: Vec::new(),
             epics: Vec::new(),
             team_members: Vec::new(),
+            watched_error: None,
         }
     }
 }
@@ -0,0 +1,404 @@
+use std::io::Read;
+
+use anyhow::{Context, Result};
+
+use crate::app::{App, Tab};
+use crate::cache::{Status, Ticket};
+use crate::config::AppConfig;
+use crate::jira_client;
+use crate::view_export::{self, ExportFormat};
+
+pub const USAGE: &str = "\
+Subcommands (non-interactive):
+  list [--tab my-work|team|epics|unassigned|watching] [--format table|json|csv|markdown] [--refresh]
+  show KEY [--format table|json]
+  move KEY STATUS [--resolution NAME]
+  comment KEY [-m MESSAGE]      (reads the body from stdin without -m)
+  filter NAME [--format table|json|csv|markdown]
+  upload FILE [--yes]           (previews only unless --yes is given)
+  trailer                       (prints `Refs: KEY` for the current git branch)";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    Table,
+    Export(ExportFormat),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    List {
+        tab: Tab,
+        format: OutputFormat,
+        refresh: bool,
+    },
+    Show {
+        key: String,
+        json: bool,
+    },
+    Move {
+        key: String,
+        status: String,
+        resolution: Option<String>,
+    },
+    Comment {
+        key: String,
+        message: Option<String>,
+    },
+    Filter {
+        name: String,
+        format: OutputFormat,
+    },
+    Upload {
+        path: String,
+        yes: bool,
+    },
+    Trailer,
+}
+
+fn parse_tab(value: &str) -> Result<Tab> {
+    Ok(match value {
+        "my-work" | "mine" => Tab::MyWork,
+        "team" => Tab::Team,
+        "epics" => Tab::Epics,
+        "unassigned" => Tab::Unassigned,
+        "watching" => Tab::Watching,
+        other => anyhow::bail!("Unknown tab '{}'", other),
+    })
+}
+
+fn parse_format(value: &str) -> Result<OutputFormat> {
+    Ok(match value {
+        "table" => OutputFormat::Table,
+        "json" => OutputFormat::Export(ExportFormat::Json),
+        "csv" => OutputFormat::Export(ExportFormat::Csv),
+        "markdown" | "md" => OutputFormat::Export(ExportFormat::Markdown),
+        other => anyhow::bail!("Unknown format '{}'", other),
+    })
+}
+
+/// Parse `lazyjira <subcommand> ...`; `None` when the arguments are for the TUI.
+pub fn parse(args: &[String]) -> Result<Option<Command>> {
+    let Some((name, rest)) = args.split_first() else {
+        return Ok(None);
+    };
+    if !matches!(
+        name.as_str(),
+        "list" | "show" | "move" | "comment" | "filter" | "upload" | "trailer"
+    ) {
+        return Ok(None);
+    }
+
+    let mut positional = Vec::new();
+    let mut tab = Tab::MyWork;
+    let mut format = OutputFormat::Table;
+    let mut refresh = false;
+    let mut resolution = None;
+    let mut message = None;
+    let mut yes = false;
+    let mut iter = rest.iter();
+    while let Some(arg) = iter.next() {
+        let mut value = |flag: &str| {
+            iter.next()
+                .cloned()
+                .with_context(|| format!("{} needs a value", flag))
+        };
+        match arg.as_str() {
+            "--tab" => tab = parse_tab(&value("--tab")?)?,
+            "--format" => format = parse_format(&value("--format")?)?,
+            "--resolution" => resolution = Some(value("--resolution")?),
+            "-m" | "--message" => message = Some(value("--message")?),
+            "--refresh" => refresh = true,
+            "--yes" | "-y" => yes = true,
+            flag if flag.starts_with("--") => anyhow::bail!("Unknown option '{}'", flag),
+            _ => positional.push(arg.clone()),
+        }
+    }
+
+    let expected = match name.as_str() {
+        "list" | "trailer" => 0,
+        "move" => 2,
+        _ => 1,
+    };
+    if positional.len() != expected {
+        anyhow::bail!("Wrong number of arguments for '{}'\n\n{}", name, USAGE);
+    }
+    let mut positional = positional.into_iter();
+    let mut next = || positional.next().unwrap_or_default();
+
+    Ok(Some(match name.as_str() {
+        "list" => Command::List {
+            tab,
+            format,
+            refresh,
+        },
+        "show" => Command::Show {
+            key: next().to_ascii_uppercase(),
+            json: format == OutputFormat::Export(ExportFormat::Json),
+        },
+        "move" => Command::Move {
+            key: next().to_ascii_uppercase(),
+            status: next(),
+            resolution,
+        },
+        "comment" => Command::Comment {
+            key: next().to_ascii_uppercase(),
+            message,
+        },
+        "filter" => Command::Filter {
+            name: next(),
+            format,
+        },
+        "upload" => Command::Upload { path: next(), yes },
+        _ => Command::Trailer,
+    }))
+}
+
+/// Cached data for list/upload: the startup snapshot, or a fresh fetch without one.
+async fn load_app(config: &AppConfig, refresh: bool) -> Result<App> {
+    let cache = match jira_client::load_startup_cache_snapshot(&config.jira.project) {
+        Some(snapshot) if !refresh => snapshot.cache,
+        _ => jira_client::fetch_active_only(config).await?,
+    };
+    if let Some(e) = &cache.watched_error {
+        eprintln!("Warning: watched tickets not refreshed: {}", e);
+    }
+    let mut app = App::new();
+    app.set_epics_i_care_about(config.epics_i_care_about_ordered());
+    app.replace_cache(cache);
+    app.loading = false;
+    Ok(app)
+}
+
+/// Tab-separated key, status, assignee, summary and group.
+fn render_table(rows: &[(String, &Ticket)]) -> String {
+    rows.iter()
+        .map(|(group, t)| {
+            format!(
+                "{}\t{}\t{}\t{}\t{}\n",
+                t.key,
+                t.status.as_str(),
+                t.assignee.as_deref().unwrap_or("Unassigned"),
+                t.summary,
+                group
+            )
+        })
+        .collect()
+}
+
+fn render_rows(rows: &[(String, &Ticket)], format: OutputFormat) -> Result<String> {
+    match format {
+        OutputFormat::Table => Ok(render_table(rows)),
+        OutputFormat::Export(format) => view_export::render_export(rows, format),
+    }
+}
+
+fn render_ticket(ticket: &Ticket) -> String {
+    let field =
+        |label: &str, value: Option<&str>| format!("{:<10}{}\n", label, value.unwrap_or("-"));
+    let mut out = format!("{} {}\n", ticket.key, ticket.summary);
+    out.push_str(&field("Status", Some(ticket.status.as_str())));
+    out.push_str(&field("Assignee", ticket.assignee.as_deref()));
+    out.push_str(&field("Reporter", ticket.reporter.as_deref()));
+    out.push_str(&field("Priority", ticket.priority.as_deref()));
+    out.push_str(&field(
+        "Epic",
+        ticket.epic_name.as_deref().or(ticket.epic_key.as_deref()),
+    ));
+    let labels = ticket.labels.join(", ");
+    out.push_str(&field(
+        "Labels",
+        Some(labels.as_str()).filter(|l| !l.is_empty()),
+    ));
+    out.push_str(&field("URL", Some(ticket.url.as_str())));
+    if let Some(description) = ticket.description.as_deref() {
+        out.push('\n');
+        out.push_str(description.trim_end());
+        out.push('\n');
+    }
+    out
+}
+
+pub async fn run(command: Command, config: &AppConfig) -> Result<()> {
+    match command {
+        Command::List {
+            tab,
+            format,
+            refresh,
+        } => {
+            let mut app = load_app(config, refresh).await?;
+            app.active_tab = tab;
+            print!(
+                "{}",
+                render_rows(&app.visible_tickets_for_export(), format)?
+            );
+        }
+        Command::Show { key, json } => {
+            let ticket = jira_client::fetch_ticket_detail(&key).await?;
+            if json {
+                println!("{}", serde_json::to_string_pretty(&ticket)?);
+            } else {
+                print!("{}", render_ticket(&ticket));
+            }
+        }
+        Command::Move {
+            key,
+            status,
+            resolution,
+        } => {
+            let target = Status::from_str(&status);
+            // Closing needs a resolution; default to the first configured one.
+            let resolution = resolution.or_else(|| {
+                (target == Status::Closed)
+                    .then(|| config.resolutions.first().cloned())
+                    .flatten()
+            });
+            jira_client::move_ticket(&key, target.as_str(), resolution.as_deref()).await?;
+            println!("Moved {} to {}", key, target.as_str());
+        }
+        Command::Comment { key, message } => {
+            let body = match message {
+                Some(message) => message,
+                None => {
+                    let mut body = String::new();
+                    std::io::stdin()
+                        .read_to_string(&mut body)
+                        .context("Failed to read comment from stdin")?;
+                    body
+                }
+            };
+            let body = body.trim();
+            if body.is_empty() {
+                anyhow::bail!("Comment body is empty");
+            }
+            jira_client::add_comment(&key, body).await?;
+            println!("Commented on {}", key);
+        }
+        Command::Filter { name, format } => {
+            let filter = config
+                .filters
+                .iter()
+                .find(|f| f.name.eq_ignore_ascii_case(&name))
+                .with_context(|| format!("No saved filter named '{}'", name))?;
+            let tickets = jira_client::fetch_jql_query(config, &filter.jql).await?;
+            let rows: Vec<(String, &Ticket)> =
+                tickets.iter().map(|t| (filter.name.clone(), t)).collect();
+            print!("{}", render_rows(&rows, format)?);
+        }
+        Command::Upload { path, yes } => {
+            let app = load_app(config, false).await?;
+            let context = crate::build_bulk_upload_context(&app, config);
+            let preview = crate::bulk_upload::parse_preview(&path, &context)?;
+            for row in &preview.rows {
+                let action = match (&row.key, row.is_noop_update()) {
+                    (Some(_), true) => "unchanged",
+                    (Some(_), false) => "update",
+                    (None, _) => "create",
+                };
+                println!(
+                    "row {}: {} {}",
+                    row.row_number,
+                    action,
+                    row.key.as_deref().unwrap_or(&row.summary)
+                );
+                for error in &row.errors {
+                    println!("  error: {}", error);
+                }
+                for warning in &row.warnings {
+                    println!("  warning: {}", warning);
+                }
+            }
+            if !preview.can_submit() {
+                anyhow::bail!(
+                    "{} of {} rows have errors; nothing was uploaded",
+                    preview.invalid_rows,
+                    preview.total_rows
+                );
+            }
+            if !yes {
+                println!(
+                    "Preview only: {} valid rows. Re-run with --yes to upload.",
+                    preview.valid_rows
+                );
+                return Ok(());
+            }
+            let summary = crate::execute_bulk_upload(preview, &config.jira.project, &[]).await;
+            for key in &summary.created_keys {
+                println!("created {}", key);
+            }
+            for key in &summary.updated_keys {
+                println!("updated {}", key);
+            }
+            for (row, label, err) in &summary.failed_details {
+                eprintln!("row {} ({}) failed: {}", row, label, err);
+            }
+            if summary.failed > 0 {
+                anyhow::bail!("{} of {} rows failed", summary.failed, summary.attempted);
+            }
+        }
+        Command::Trailer => {
+            let branch = crate::git::current_branch().context("Not on a git branch")?;
+            let key = crate::git::find_ticket_key(&branch, &config.jira.project)
+                .with_context(|| format!("Branch {} has no ticket key", branch))?;
+            println!("Refs: {}", key);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn non_subcommand_args_are_left_for_the_tui() {
+        assert_eq!(parse(&args(&[])).unwrap(), None);
+        assert_eq!(parse(&args(&["--dev"])).unwrap(), None);
+        assert_eq!(parse(&args(&["trailer"])).unwrap(), Some(Command::Trailer));
+    }
+
+    #[test]
+    fn parses_subcommands_and_flags() {
+        assert_eq!(
+            parse(&args(&["list", "--tab", "team", "--format", "csv"])).unwrap(),
+            Some(Command::List {
+                tab: Tab::Team,
+                format: OutputFormat::Export(ExportFormat::Csv),
+                refresh: false,
+            })
+        );
+        assert_eq!(
+            parse(&args(&["move", "amp-1", "In Review"])).unwrap(),
+            Some(Command::Move {
+                key: "AMP-1".to_string(),
+                status: "In Review".to_string(),
+                resolution: None,
+            })
+        );
+        assert_eq!(
+            parse(&args(&["upload", "tickets.csv", "--yes"])).unwrap(),
+            Some(Command::Upload {
+                path: "tickets.csv".to_string(),
+                yes: true,
+            })
+        );
+        assert_eq!(
+            parse(&args(&["comment", "AMP-2", "-m", "done"])).unwrap(),
+            Some(Command::Comment {
+                key: "AMP-2".to_string(),
+                message: Some("done".to_string()),
+            })
+        );
+    }
+
+    #[test]
+    fn rejects_bad_arguments() {
+        assert!(parse(&args(&["move", "AMP-1"])).is_err());
+        assert!(parse(&args(&["list", "--tab", "nope"])).is_err());
+        assert!(parse(&args(&["show", "AMP-1", "--bogus"])).is_err());
+        assert!(parse(&args(&["filter"])).is_err());
+    }
+}
@@ -16,6 +16,23 @@ pub struct AppConfig {
     pub resolutions: Vec<String>,
     #[serde(default)]
     pub filters: Vec<SavedFilter>,
+    #[serde(default)]
+    pub templates: Vec<TicketTemplate>,
+    /// Custom field names as mapped in jira-cli's config, to their Jira field ids.
+    #[serde(default)]
+    pub custom_fields: BTreeMap<String, String>,
+    #[serde(default)]
+    pub comment_templates: Vec<CommentTemplate>,
+    #[serde(default)]
+    pub standup: StandupConfig,
+    #[serde(default)]
+    pub dashboard: DashboardConfig,
+    #[serde(default)]
+    pub git: GitConfig,
+    #[serde(default)]
+    pub keys: KeysConfig,
+    #[serde(default)]
+    pub theme: ThemeConfig,
 }
 
 /// Jira project and team settings.
@@ -89,6 +106,182 @@ pub struct SavedFilter {
     pub jql: String,
 }
 
+/// A named preset for the create-ticket form.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TicketTemplate {
+    pub name: String,
+    #[serde(default)]
+    pub issue_type: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub labels: Vec<String>,
+    #[serde(default)]
+    pub priority: Option<String>,
+    #[serde(default)]
+    pub components: Vec<String>,
+    #[serde(default)]
+    pub story_points: Option<f64>,
+}
+
+/// A reusable comment body for bulk comments. Supports `{key}`, `{summary}`,
+/// `{status}` and `{assignee}` placeholders.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CommentTemplate {
+    pub name: String,
+    pub body: String,
+}
+
+/// Standup report settings.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StandupConfig {
+    /// How far back the report looks for activity.
+    #[serde(default = "default_standup_window_hours")]
+    pub window_hours: u32,
+}
+
+fn default_standup_window_hours() -> u32 {
+    24
+}
+
+impl Default for StandupConfig {
+    fn default() -> Self {
+        Self {
+            window_hours: default_standup_window_hours(),
+        }
+    }
+}
+
+/// Dashboard tab settings.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DashboardConfig {
+    /// In Progress + In Review tickets allowed per member; unset means no limit.
+    #[serde(default)]
+    pub wip_limit: Option<usize>,
+    /// Per-member overrides of `wip_limit`, keyed by the name used in `[team]`.
+    #[serde(default)]
+    pub wip_limits: BTreeMap<String, usize>,
+    /// Weeks shown in the closed-per-week sparkline.
+    #[serde(default = "default_dashboard_weeks")]
+    pub weeks: usize,
+}
+
+fn default_dashboard_weeks() -> usize {
+    12
+}
+
+impl Default for DashboardConfig {
+    fn default() -> Self {
+        Self {
+            wip_limit: None,
+            wip_limits: BTreeMap::new(),
+            weeks: default_dashboard_weeks(),
+        }
+    }
+}
+
+impl DashboardConfig {
+    pub fn wip_limit_for(&self, member_name: &str) -> Option<usize> {
+        self.wip_limits.get(member_name).copied().or(self.wip_limit)
+    }
+}
+
+/// Git integration for the repository lazyjira is started in.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitConfig {
+    /// Branch name for new branches; `{key}` and `{slug}` (from the summary) are filled in.
+    #[serde(default = "default_branch_template")]
+    pub branch_template: String,
+    /// Open the ticket named by the current branch on launch.
+    #[serde(default)]
+    pub open_branch_ticket: bool,
+    /// Offer to move my tickets to In Review once a branch with their key shows up on a remote.
+    #[serde(default)]
+    pub review_on_push: bool,
+}
+
+fn default_branch_template() -> String {
+    "{key}-{slug}".to_string()
+}
+
+impl Default for GitConfig {
+    fn default() -> Self {
+        Self {
+            branch_template: default_branch_template(),
+            open_branch_ticket: false,
+            review_on_push: false,
+        }
+    }
+}
+
+/// Base keymap that `[keys]` overrides are applied on top of.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum KeyPreset {
+    #[default]
+    Default,
+    Vim,
+    Emacs,
+}
+
+/// One key or several for an action; an empty list unbinds it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum KeyList {
+    One(String),
+    Many(Vec<String>),
+}
+
+impl KeyList {
+    pub fn keys(&self) -> Vec<&str> {
+        match self {
+            KeyList::One(key) => vec![key.as_str()],
+            KeyList::Many(keys) => keys.iter().map(String::as_str).collect(),
+        }
+    }
+}
+
+/// `[keys]`: a preset plus per-action overrides, e.g. `refresh = ["r", "F5"]`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct KeysConfig {
+    #[serde(default)]
+    pub preset: KeyPreset,
+    #[serde(flatten)]
+    pub bindings: BTreeMap<String, KeyList>,
+}
+
+/// Built-in color palette that `[theme]` overrides are applied on top of.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ThemeName {
+    #[default]
+    Dark,
+    Light,
+    HighContrast,
+    #[serde(rename = "16-color")]
+    Ansi16,
+}
+
+impl std::fmt::Display for ThemeName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            ThemeName::Dark => "dark",
+            ThemeName::Light => "light",
+            ThemeName::HighContrast => "high-contrast",
+            ThemeName::Ansi16 => "16-color",
+        })
+    }
+}
+
+/// `[theme]`: a palette name plus per-role colors, e.g. `accent = "#0087af"`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ThemeConfig {
+    #[serde(default)]
+    pub name: ThemeName,
+    #[serde(flatten)]
+    pub colors: BTreeMap<String, String>,
+}
+
 impl AppConfig {
     fn normalize_epic_key(key: &str) -> Option<String> {
         let trimmed = key.trim();
@@ -209,6 +402,47 @@ mod tests {
                 name: "My bugs".to_string(),
                 jql: "type = Bug AND assignee = currentUser()".to_string(),
             }],
+            templates: vec![TicketTemplate {
+                name: "Bug report".to_string(),
+                issue_type: Some("Bug".to_string()),
+                description: Some("h3. Steps to reproduce\n\nh3. Expected\n".to_string()),
+                labels: vec!["triage".to_string()],
+                ..Default::default()
+            }],
+            custom_fields: BTreeMap::from([(
+                "story-points".to_string(),
+                "customfield_10555".to_string(),
+            )]),
+            comment_templates: vec![CommentTemplate {
+                name: "Sprint rollover".to_string(),
+                body: "{key} rolls over to next sprint".to_string(),
+            }],
+            standup: StandupConfig { window_hours: 72 },
+            dashboard: DashboardConfig {
+                wip_limit: Some(3),
+                wip_limits: BTreeMap::from([("alice".to_string(), 5)]),
+                weeks: 8,
+            },
+            git: GitConfig {
+                branch_template: "feat/{key}-{slug}".to_string(),
+                open_branch_ticket: false,
+                review_on_push: true,
+            },
+            keys: KeysConfig {
+                preset: KeyPreset::Vim,
+                bindings: [
+                    (
+                        "refresh".to_string(),
+                        KeyList::Many(vec!["r".to_string(), "F5".to_string()]),
+                    ),
+                    ("export".to_string(), KeyList::One("Ctrl+e".to_string())),
+                ]
+                .into(),
+            },
+            theme: ThemeConfig {
+                name: ThemeName::HighContrast,
+                colors: [("accent".to_string(), "#0087af".to_string())].into(),
+            },
         }
     }
 
@@ -235,6 +469,23 @@ mod tests {
         assert_eq!(parsed.filters.len(), 1);
         assert_eq!(parsed.filters[0].name, "My bugs");
         assert_eq!(parsed.filters[0].jql, config.filters[0].jql);
+        assert_eq!(parsed.templates.len(), 1);
+        assert_eq!(parsed.templates[0].labels, vec!["triage".to_string()]);
+        assert_eq!(
+            parsed.custom_fields.get("story-points").map(String::as_str),
+            Some("customfield_10555")
+        );
+        assert_eq!(
+            parsed.comment_templates[0].body,
+            config.comment_templates[0].body
+        );
+        assert_eq!(parsed.standup.window_hours, 72);
+        assert_eq!(parsed.dashboard.wip_limit_for("alice"), Some(5));
+        assert_eq!(parsed.dashboard.wip_limit_for("bob"), Some(3));
+        assert_eq!(parsed.dashboard.weeks, 8);
+        assert_eq!(parsed.git.branch_template, "feat/{key}-{slug}");
+        assert!(!parsed.git.open_branch_ticket);
+        assert_eq!(parsed.keys, config.keys);
     }
 
     #[test]
@@ -254,6 +505,55 @@ team_name = "My Team"
         assert_eq!(config.statuses.done, default_done_statuses());
         assert!(config.team.is_empty());
         assert!(config.filters.is_empty());
+        assert!(config.templates.is_empty());
+        assert_eq!(config.standup.window_hours, 24);
+        assert_eq!(config.dashboard.wip_limit_for("anyone"), None);
+        assert_eq!(config.dashboard.weeks, 12);
+        assert_eq!(config.git.branch_template, "{key}-{slug}");
+        assert!(!config.git.open_branch_ticket && !config.git.review_on_push);
+        assert_eq!(config.keys.preset, KeyPreset::Default);
+        assert!(config.keys.bindings.is_empty());
+        assert_eq!(config.theme, ThemeConfig::default());
+    }
+
+    #[test]
+    fn theme_names_use_their_config_spelling() {
+        let config: AppConfig = toml::from_str(
+            r#"
+[jira]
+project = "TEST"
+team_name = "My Team"
+
+[theme]
+name = "16-color"
+muted = "8"
+"#,
+        )
+        .expect("parse theme");
+        assert_eq!(config.theme.name, ThemeName::Ansi16);
+        assert_eq!(config.theme.colors["muted"], "8");
+    }
+
+    #[test]
+    fn templates_parse_with_only_a_name() {
+        let toml_str = r#"
+[jira]
+project = "TEST"
+team_name = "My Team"
+
+[[templates]]
+name = "Spike"
+
+[[templates]]
+name = "Bug report"
+issue_type = "Bug"
+story_points = 2
+"#;
+        let config: AppConfig = toml::from_str(toml_str).expect("parse templates");
+        assert_eq!(config.templates.len(), 2);
+        assert!(config.templates[0].issue_type.is_none());
+        assert!(config.templates[0].labels.is_empty());
+        assert_eq!(config.templates[1].story_points, Some(2.0));
     }
 
     #[test]
@@ -294,6 +594,14 @@ team_name = "My Team"
             statuses: StatusConfig::default(),
             resolutions: default_resolutions(),
             filters: vec![],
+            templates: vec![],
+            custom_fields: BTreeMap::new(),
+            comment_templates: vec![],
+            standup: Default::default(),
+            dashboard: Default::default(),
+            git: Default::default(),
+            keys: Default::default(),
+            theme: Default::default(),
         };
 
         assert_eq!(
@@ -319,6 +627,14 @@ team_name = "My Team"
             statuses: StatusConfig::default(),
             resolutions: default_resolutions(),
             filters: vec![],
+            templates: vec![],
+            custom_fields: BTreeMap::new(),
+            comment_templates: vec![],
+            standup: Default::default(),
+            dashboard: Default::default(),
+            git: Default::default(),
+            keys: Default::default(),
+            theme: Default::default(),
         };
 
         assert_eq!(
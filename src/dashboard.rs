@@ -0,0 +1,270 @@
+use crate::cache::{ActivityKind, Status, TeamMember, Ticket};
+use crate::config::DashboardConfig;
+use crate::standup::parse_timestamp;
+
+const DAY_SECS: u64 = 24 * 3600;
+const WEEK_SECS: u64 = 7 * DAY_SECS;
+
+/// Cycle-time histogram buckets: label and exclusive upper bound in days.
+pub const CYCLE_TIME_BUCKETS: [(&str, f64); 6] = [
+    ("<1d", 1.0),
+    ("1-3d", 3.0),
+    ("3-7d", 7.0),
+    ("1-2w", 14.0),
+    ("2-4w", 28.0),
+    (">4w", f64::INFINITY),
+];
+
+/// One member's row on the dashboard.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MemberLoad {
+    pub name: String,
+    /// Non-zero ticket counts in `Status::all` order, then any other statuses.
+    pub counts: Vec<(Status, usize)>,
+    /// In Progress + In Review tickets.
+    pub wip: usize,
+    pub wip_limit: Option<usize>,
+}
+
+impl MemberLoad {
+    pub fn over_limit(&self) -> bool {
+        self.wip_limit.is_some_and(|limit| self.wip > limit)
+    }
+}
+
+pub fn member_loads(
+    members: &[&TeamMember],
+    tickets: &[Ticket],
+    config: &DashboardConfig,
+) -> Vec<MemberLoad> {
+    members
+        .iter()
+        .map(|member| {
+            let assigned: Vec<&Ticket> = tickets
+                .iter()
+                .filter(|t| t.assignee_email.as_deref() == Some(member.email.as_str()))
+                .collect();
+            let mut counts: Vec<(Status, usize)> = Status::all()
+                .iter()
+                .map(|s| {
+                    (
+                        s.clone(),
+                        assigned.iter().filter(|t| &t.status == s).count(),
+                    )
+                })
+                .collect();
+            for t in &assigned {
+                if let Status::Other(_) = t.status {
+                    match counts.iter_mut().find(|(s, _)| *s == t.status) {
+                        Some((_, n)) => *n += 1,
+                        None => counts.push((t.status.clone(), 1)),
+                    }
+                }
+            }
+            counts.retain(|(_, n)| *n > 0);
+            let wip = assigned
+                .iter()
+                .filter(|t| matches!(t.status, Status::InProgress | Status::InReview))
+                .count();
+            MemberLoad {
+                name: member.name.clone(),
+                counts,
+                wip,
+                wip_limit: config.wip_limit_for(&member.name),
+            }
+        })
+        .collect()
+}
+
+/// When the ticket last moved to a done status, from its cached activity.
+pub fn closed_at(ticket: &Ticket) -> Option<u64> {
+    // Activity is newest first.
+    ticket.activity.iter().find_map(|entry| match &entry.kind {
+        ActivityKind::StatusChange { to, .. } if Status::from_str(to) == Status::Closed => {
+            parse_timestamp(&entry.timestamp)
+        }
+        _ => None,
+    })
+}
+
+/// When the ticket first moved to In Progress.
+fn started_at(ticket: &Ticket) -> Option<u64> {
+    ticket
+        .activity
+        .iter()
+        .rev()
+        .find_map(|entry| match &entry.kind {
+            ActivityKind::StatusChange { to, .. } if Status::from_str(to) == Status::InProgress => {
+                parse_timestamp(&entry.timestamp)
+            }
+            _ => None,
+        })
+}
+
+/// Closed tickets per week, oldest week first; the last bucket is the week ending `now`.
+pub fn closed_per_week(tickets: &[&Ticket], now: u64, weeks: usize) -> Vec<u64> {
+    let mut buckets = vec![0u64; weeks];
+    for ticket in tickets {
+        if ticket.status != Status::Closed {
+            continue;
+        }
+        let Some(ts) = closed_at(ticket) else {
+            continue;
+        };
+        let age = (now.saturating_sub(ts) / WEEK_SECS) as usize;
+        if age < weeks {
+            buckets[weeks - 1 - age] += 1;
+        }
+    }
+    buckets
+}
+
+/// Days from first In Progress to closing, for closed tickets with both in their history.
+pub fn cycle_times_days(tickets: &[&Ticket]) -> Vec<f64> {
+    tickets
+        .iter()
+        .filter(|t| t.status == Status::Closed)
+        .filter_map(|t| {
+            let (start, end) = (started_at(t)?, closed_at(t)?);
+            (end >= start).then(|| (end - start) as f64 / DAY_SECS as f64)
+        })
+        .collect()
+}
+
+pub fn cycle_time_histogram(days: &[f64]) -> Vec<(&'static str, u64)> {
+    CYCLE_TIME_BUCKETS
+        .iter()
+        .enumerate()
+        .map(|(i, (label, upper))| {
+            let lower = if i == 0 {
+                0.0
+            } else {
+                CYCLE_TIME_BUCKETS[i - 1].1
+            };
+            let count = days.iter().filter(|d| **d >= lower && **d < *upper).count();
+            (*label, count as u64)
+        })
+        .collect()
+}
+
+/// Nearest-rank percentile (`pct` in 0..=100).
+pub fn percentile(days: &[f64], pct: usize) -> Option<f64> {
+    if days.is_empty() {
+        return None;
+    }
+    let mut sorted = days.to_vec();
+    sorted.sort_by(|a, b| a.total_cmp(b));
+    let rank = (pct * sorted.len()).div_ceil(100).max(1);
+    Some(sorted[rank - 1])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cache::ActivityEntry;
+
+    fn change(ts: &str, from: &str, to: &str) -> ActivityEntry {
+        ActivityEntry {
+            timestamp: ts.to_string(),
+            author: "Dev".to_string(),
+            author_email: None,
+            kind: ActivityKind::StatusChange {
+                from: from.to_string(),
+                to: to.to_string(),
+            },
+        }
+    }
+
+    fn ticket(key: &str, status: Status, email: &str, activity: Vec<ActivityEntry>) -> Ticket {
+        Ticket {
+            assignee_email: Some(email.to_string()),
+            detail_loaded: true,
+            activity,
+            ..Ticket::for_test(key, key, status)
+        }
+    }
+
+    #[test]
+    fn member_loads_flag_members_over_their_wip_limit() {
+        let alice = TeamMember {
+            name: "alice".to_string(),
+            email: "a@example.com".to_string(),
+        };
+        let bob = TeamMember {
+            name: "bob".to_string(),
+            email: "b@example.com".to_string(),
+        };
+        let tickets = vec![
+            ticket("AMP-1", Status::InProgress, "a@example.com", vec![]),
+            ticket("AMP-2", Status::InReview, "a@example.com", vec![]),
+            ticket("AMP-3", Status::ToDo, "a@example.com", vec![]),
+            ticket("AMP-4", Status::InProgress, "b@example.com", vec![]),
+        ];
+        let config = DashboardConfig {
+            wip_limit: Some(1),
+            wip_limits: [("bob".to_string(), 2)].into(),
+            weeks: 12,
+        };
+
+        let loads = member_loads(&[&alice, &bob], &tickets, &config);
+        assert_eq!(loads[0].wip, 2);
+        assert!(loads[0].over_limit());
+        assert_eq!(
+            loads[0].counts,
+            vec![
+                (Status::InProgress, 1),
+                (Status::ToDo, 1),
+                (Status::InReview, 1)
+            ]
+        );
+        assert!(!loads[1].over_limit());
+    }
+
+    #[test]
+    fn throughput_and_cycle_time_come_from_status_changes() {
+        let now = parse_timestamp("2026-03-01T00:00:00").unwrap();
+        let done = ticket(
+            "AMP-1",
+            Status::Closed,
+            "a@example.com",
+            vec![
+                change("2026-02-27T00:00:00.000+0000", "In Review", "Done"),
+                change("2026-02-24T00:00:00.000+0000", "To Do", "In Progress"),
+            ],
+        );
+        let older = ticket(
+            "AMP-2",
+            Status::Closed,
+            "a@example.com",
+            vec![
+                change("2026-02-15T12:00:00.000+0000", "In Progress", "Closed"),
+                change("2026-02-15T00:00:00.000+0000", "To Do", "In Progress"),
+            ],
+        );
+        let reopened = ticket(
+            "AMP-3",
+            Status::InProgress,
+            "a@example.com",
+            vec![change("2026-02-28T00:00:00.000+0000", "Open", "Done")],
+        );
+        let tickets = [&done, &older, &reopened];
+
+        assert_eq!(closed_per_week(&tickets, now, 3), vec![0, 1, 1]);
+        let days = cycle_times_days(&tickets);
+        assert_eq!(days, vec![3.0, 0.5]);
+        assert_eq!(
+            cycle_time_histogram(&days),
+            vec![
+                ("<1d", 1),
+                ("1-3d", 0),
+                ("3-7d", 1),
+                ("1-2w", 0),
+                ("2-4w", 0),
+                (">4w", 0)
+            ]
+        );
+        assert_eq!(percentile(&days, 50), Some(0.5));
+        assert_eq!(percentile(&days, 85), Some(3.0));
+        assert_eq!(percentile(&[], 50), None);
+    }
+}
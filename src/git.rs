@@ -0,0 +1,198 @@
+use std::collections::{HashMap, HashSet};
+use std::process::Command;
+
+use anyhow::{Context, Result};
+
+/// Run git in the current directory and return trimmed stdout.
+fn run_git(args: &[&str]) -> Result<String> {
+    let output = Command::new("git")
+        .args(args)
+        .output()
+        .with_context(|| format!("Failed to run: git {}", args.join(" ")))?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        anyhow::bail!("git {} failed: {}", args.join(" "), stderr.trim());
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// The checked-out branch, or None outside a repo or on a detached HEAD.
+pub fn current_branch() -> Option<String> {
+    let branch = run_git(&["rev-parse", "--abbrev-ref", "HEAD"]).ok()?;
+    (!branch.is_empty() && branch != "HEAD").then_some(branch)
+}
+
+/// First `PROJECT-123` in `text`, matched case-insensitively and returned upper-cased.
+pub fn find_ticket_key(text: &str, project: &str) -> Option<String> {
+    find_ticket_keys(text, project).into_iter().next()
+}
+
+/// Every distinct `PROJECT-123` in `text`, in order of first appearance.
+pub fn find_ticket_keys(text: &str, project: &str) -> Vec<String> {
+    let upper = text.to_ascii_uppercase();
+    let prefix = format!("{}-", project.to_ascii_uppercase());
+    let bytes = upper.as_bytes();
+    let mut keys: Vec<String> = Vec::new();
+    let mut from = 0;
+    while let Some(pos) = upper[from..].find(&prefix) {
+        let start = from + pos;
+        let digits_start = start + prefix.len();
+        let digits_end = upper[digits_start..]
+            .find(|c: char| !c.is_ascii_digit())
+            .map_or(upper.len(), |i| digits_start + i);
+        let starts_word = start == 0 || !bytes[start - 1].is_ascii_alphanumeric();
+        if starts_word && digits_end > digits_start {
+            let key = &upper[start..digits_end];
+            if !keys.iter().any(|k| k == key) {
+                keys.push(key.to_string());
+            }
+        }
+        from = digits_end.max(digits_start);
+    }
+    keys
+}
+
+/// Fill `{key}` and `{slug}` (the summary, lower-cased and dash-separated, at most 40 chars).
+pub fn branch_name(template: &str, key: &str, summary: &str) -> String {
+    let mut slug = String::new();
+    for word in summary
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|w| !w.is_empty())
+    {
+        if !slug.is_empty() && slug.len() + word.len() >= 40 {
+            break;
+        }
+        if !slug.is_empty() {
+            slug.push('-');
+        }
+        slug.push_str(&word.to_ascii_lowercase());
+    }
+    template
+        .replace("{key}", key)
+        .replace("{slug}", &slug)
+        .trim_end_matches(['-', '/'])
+        .to_string()
+}
+
+pub fn create_branch(name: &str) -> Result<()> {
+    run_git(&["switch", "-c", name]).map(|_| ())
+}
+
+/// Ticket keys of branches that exist on a remote, from the local remote-tracking refs.
+pub fn pushed_ticket_keys(project: &str) -> Result<HashSet<String>> {
+    let refs = run_git(&["for-each-ref", "--format=%(refname:short)", "refs/remotes"])?;
+    Ok(refs
+        .lines()
+        .filter_map(|branch| find_ticket_key(branch, project))
+        .collect())
+}
+
+/// A commit whose message mentions a ticket key.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommitRef {
+    pub hash: String,
+    pub author: String,
+    /// Author date as `YYYY-MM-DD`.
+    pub date: String,
+    pub subject: String,
+}
+
+/// How many commits `ticket_commits` reads, newest first across all local refs.
+const COMMIT_SCAN_LIMIT: &str = "5000";
+
+/// Commits on any local or remote-tracking ref whose message mentions a ticket key,
+/// grouped by key, newest first. Merge commits of pull requests match by branch name.
+pub fn ticket_commits(project: &str) -> Result<HashMap<String, Vec<CommitRef>>> {
+    let log = run_git(&[
+        "log",
+        "--all",
+        "--max-count",
+        COMMIT_SCAN_LIMIT,
+        "--date=short",
+        "--format=%h%x1f%an%x1f%ad%x1f%s%x1f%b%x1e",
+    ])?;
+    Ok(parse_commit_log(&log, project))
+}
+
+fn parse_commit_log(log: &str, project: &str) -> HashMap<String, Vec<CommitRef>> {
+    let mut by_key: HashMap<String, Vec<CommitRef>> = HashMap::new();
+    for record in log.split('\x1e') {
+        let mut fields = record.trim_start_matches('\n').splitn(5, '\x1f');
+        let (Some(hash), Some(author), Some(date), Some(subject)) =
+            (fields.next(), fields.next(), fields.next(), fields.next())
+        else {
+            continue;
+        };
+        let body = fields.next().unwrap_or("");
+        let commit = CommitRef {
+            hash: hash.to_string(),
+            author: author.to_string(),
+            date: date.to_string(),
+            subject: subject.to_string(),
+        };
+        for key in find_ticket_keys(&format!("{}\n{}", subject, body), project) {
+            by_key.entry(key).or_default().push(commit.clone());
+        }
+    }
+    by_key
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_keys_in_branch_names() {
+        assert_eq!(
+            find_ticket_key("feat/amp-123-fix-parser", "AMP").as_deref(),
+            Some("AMP-123")
+        );
+        assert_eq!(
+            find_ticket_key("origin/AMP-7", "AMP").as_deref(),
+            Some("AMP-7")
+        );
+        assert_eq!(find_ticket_key("camp-12-and-AMP-", "AMP"), None);
+        assert_eq!(
+            find_ticket_key("camp-12-AMP-4", "amp").as_deref(),
+            Some("AMP-4")
+        );
+        assert_eq!(find_ticket_key("main", "AMP"), None);
+        assert_eq!(
+            find_ticket_keys("AMP-1, amp-2 and AMP-1 again", "AMP"),
+            vec!["AMP-1", "AMP-2"]
+        );
+    }
+
+    #[test]
+    fn commit_log_is_grouped_by_mentioned_key() {
+        let log = "abc1234\x1fAda\x1f2026-03-02\x1fAMP-1: fix parser\x1fAlso touches AMP-2\n\x1e\n\
+                   def5678\x1fBob\x1f2026-03-01\x1fMerge pull request #9 from org/amp-1-parser\x1f\x1e\n\
+                   0a0a0a0\x1fBob\x1f2026-02-28\x1fUnrelated cleanup\x1f\x1e";
+        let commits = parse_commit_log(log, "AMP");
+
+        let amp1: Vec<&str> = commits["AMP-1"].iter().map(|c| c.hash.as_str()).collect();
+        assert_eq!(amp1, vec!["abc1234", "def5678"]);
+        assert_eq!(
+            commits["AMP-2"][0],
+            CommitRef {
+                hash: "abc1234".to_string(),
+                author: "Ada".to_string(),
+                date: "2026-03-02".to_string(),
+                subject: "AMP-1: fix parser".to_string(),
+            }
+        );
+        assert_eq!(commits.len(), 2);
+    }
+
+    #[test]
+    fn branch_names_follow_the_template() {
+        assert_eq!(
+            branch_name("{key}-{slug}", "AMP-1", "Fix: parser crashes on `null`!"),
+            "AMP-1-fix-parser-crashes-on-null"
+        );
+        assert_eq!(branch_name("feat/{key}", "AMP-2", "Anything"), "feat/AMP-2");
+        let long = branch_name("{key}-{slug}", "AMP-3", &"word ".repeat(30));
+        assert!(long.len() <= "AMP-3-".len() + 40, "{}", long);
+        assert_eq!(branch_name("{key}-{slug}", "AMP-4", "???"), "AMP-4");
+    }
+}
@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::path::PathBuf;
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
@@ -7,7 +7,10 @@ use tokio::process::Command;
 use tokio::sync::mpsc;
 use tokio::time::timeout;
 
-use crate::cache::{ActivityEntry, ActivityKind, Cache, Epic, Status, TeamMember, Ticket};
+use crate::cache::{
+    ActivityEntry, ActivityKind, Cache, Epic, Status, TeamMember, Ticket, TimeTracking, Viewed,
+    Watchers, Worklog,
+};
 use crate::config::AppConfig;
 
 const JIRA_BASE_URL: &str = "https://jira.mongodb.org/browse";
@@ -33,6 +36,45 @@ pub struct StartupCacheSnapshot {
     pub age_secs: u64,
 }
 
+/// A command that ran and exited non-zero. `output` is what it printed about the failure,
+/// kept apart from the command line, which echoes keys, summaries and descriptions.
+#[derive(Debug)]
+pub struct CommandFailed {
+    pub command: String,
+    pub output: String,
+}
+
+impl std::fmt::Display for CommandFailed {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} failed: {}", self.command, self.output)
+    }
+}
+
+impl std::error::Error for CommandFailed {}
+
+/// Whether a failed call is safe to send again: Jira rate limited it, or it never reached
+/// the server. Only the command's output is checked, never its arguments. Other failures
+/// may already have been applied, and repeating a create or a comment would duplicate it.
+pub fn is_retryable(err: &anyhow::Error) -> bool {
+    let Some(failed) = err.downcast_ref::<CommandFailed>() else {
+        return false;
+    };
+    let output = failed.output.to_lowercase();
+    [
+        "status 429",
+        "http 429",
+        "error: 429",
+        "too many requests",
+        "rate limit",
+        "could not resolve",
+        "connection refused",
+        "failed to connect",
+        "network is unreachable",
+    ]
+    .iter()
+    .any(|marker| output.contains(marker))
+}
+
 /// Run a CLI command and return stdout as a String.
 async fn run_cmd(program: &str, args: &[&str]) -> Result<String> {
     let output = Command::new(program)
@@ -41,9 +83,165 @@ async fn run_cmd(program: &str, args: &[&str]) -> Result<String> {
         .await
         .with_context(|| format!("Failed to run: {} {}", program, args.join(" ")))?;
 
+    if !output.status.success() {
+        return Err(CommandFailed {
+            command: format!("{} {}", program, args.join(" ")),
+            output: String::from_utf8_lossy(&output.stderr).to_string(),
+        }
+        .into());
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// The fields lazyjira needs from jira-cli's own config file.
+#[derive(Debug, serde::Deserialize)]
+struct JiraCliConfig {
+    server: Option<String>,
+    login: Option<String>,
+    auth_type: Option<String>,
+}
+
+/// Where and how to send REST calls: the API root and the `Authorization` header value.
+#[derive(Debug, PartialEq, Eq)]
+struct RestTarget {
+    base_url: String,
+    authorization: String,
+}
+
+/// jira-cli's config: `$JIRA_CONFIG_FILE`, else `.jira/.config.yml` under the XDG config dir.
+fn jira_cli_config_path() -> Option<PathBuf> {
+    if let Some(path) = std::env::var_os("JIRA_CONFIG_FILE") {
+        return Some(PathBuf::from(path));
+    }
+    let config_dir = std::env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+    Some(config_dir.join(".jira").join(".config.yml"))
+}
+
+/// REST API root and credentials of the server jira-cli is configured for.
+fn rest_target() -> Result<RestTarget> {
+    let token = std::env::var("JIRA_API_TOKEN")
+        .context("JIRA_API_TOKEN must be set for requests jira-cli can't make")?;
+    let path = jira_cli_config_path().context("Cannot locate the jira-cli config (HOME unset)")?;
+    let raw = std::fs::read_to_string(&path).with_context(|| {
+        format!(
+            "Failed to read jira-cli config {}; run `jira init`",
+            path.display()
+        )
+    })?;
+    rest_target_from(&raw, &token).with_context(|| format!("In jira-cli config {}", path.display()))
+}
+
+/// Jira Cloud API tokens use Basic auth with the jira-cli `login`; Server/DC personal access
+/// tokens (`auth_type: bearer`) are sent as a Bearer token.
+fn rest_target_from(raw: &str, token: &str) -> Result<RestTarget> {
+    let config: JiraCliConfig = serde_yaml::from_str(raw).context("Failed to parse")?;
+    let server = config
+        .server
+        .map(|server| server.trim().trim_end_matches('/').to_string())
+        .filter(|server| !server.is_empty())
+        .context("No `server` is set; run `jira init`")?;
+    let bearer = config
+        .auth_type
+        .is_some_and(|auth| auth.trim().eq_ignore_ascii_case("bearer"));
+    let authorization = if bearer {
+        format!("Bearer {}", token)
+    } else {
+        let login = config
+            .login
+            .filter(|login| !login.trim().is_empty())
+            .context("No `login` is set; run `jira init`")?;
+        format!(
+            "Basic {}",
+            base64_encode(format!("{}:{}", login.trim(), token).as_bytes())
+        )
+    };
+    Ok(RestTarget {
+        base_url: format!("{}/rest/api/2", server),
+        authorization,
+    })
+}
+
+/// Standard base64 with padding, for the Basic auth header.
+fn base64_encode(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut out = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let n = chunk
+            .iter()
+            .enumerate()
+            .fold(0u32, |n, (i, b)| n | (u32::from(*b) << (16 - 8 * i)));
+        for i in 0..4 {
+            if i <= chunk.len() {
+                out.push(ALPHABET[(n >> (18 - 6 * i) & 63) as usize] as char);
+            } else {
+                out.push('=');
+            }
+        }
+    }
+    out
+}
+
+/// Call the Jira REST API directly for operations jira-cli does not expose, against the
+/// server from jira-cli's config. Authenticates with the same `JIRA_API_TOKEN`, login and
+/// auth type jira-cli uses; the header is passed on stdin so the token never shows up in
+/// the process list.
+async fn run_rest(method: &str, path: &str, body: Option<&str>) -> Result<String> {
+    use tokio::io::AsyncWriteExt;
+
+    let target = rest_target()?;
+    let url = format!("{}/{}", target.base_url, path);
+
+    let mut args = vec![
+        "-sS",
+        "--fail-with-body",
+        "-X",
+        method,
+        "-H",
+        "@-",
+        "-H",
+        "Accept: application/json",
+    ];
+    if let Some(body) = body {
+        args.extend([
+            "-H",
+            "Content-Type: application/json",
+            "--data-binary",
+            body,
+        ]);
+    }
+    args.push(&url);
+
+    let mut child = Command::new("curl")
+        .args(&args)
+        .stdin(std::process::Stdio::piped())
+        .stdout(std::process::Stdio::piped())
+        .stderr(std::process::Stdio::piped())
+        .spawn()
+        .with_context(|| format!("Failed to run: curl -X {} {}", method, url))?;
+
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin
+            .write_all(format!("Authorization: {}\n", target.authorization).as_bytes())
+            .await
+            .context("Failed to pass credentials to curl")?;
+    }
+
+    let output = child
+        .wait_with_output()
+        .await
+        .with_context(|| format!("Failed to run: curl -X {} {}", method, url))?;
+
     if !output.status.success() {
         let stderr = String::from_utf8_lossy(&output.stderr);
-        anyhow::bail!("{} {} failed: {}", program, args.join(" "), stderr);
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        return Err(CommandFailed {
+            command: format!("{} {}", method, url),
+            output: format!("{}{}", stderr.trim(), stdout.trim()),
+        }
+        .into());
     }
 
     Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
@@ -123,6 +321,11 @@ fn parse_ticket_line(line: &str) -> Option<Ticket> {
         detail_loaded: false,
         url,
         activity: Vec::new(),
+        time_tracking: TimeTracking::default(),
+        watchers: Watchers::default(),
+        resolution: None,
+        priority: None,
+        custom_fields: BTreeMap::new(),
     })
 }
 
@@ -356,6 +559,11 @@ pub async fn fetch_ticket_detail(key: &str) -> Result<Ticket> {
     // Sort newest first
     activity.sort_by(|a, b| b.timestamp.cmp(&a.timestamp));
 
+    let time_tracking = parse_time_tracking(fields);
+
+    // The issue payload usually only carries a count; the detail view fetches the names.
+    let watchers = parse_watches(&fields["watches"]);
+
     let ticket_key = json["key"].as_str().unwrap_or(key).to_string();
     let url = format!("{}/{}", JIRA_BASE_URL, ticket_key);
 
@@ -373,9 +581,102 @@ pub async fn fetch_ticket_detail(key: &str) -> Result<Ticket> {
         detail_loaded: true,
         url,
         activity,
+        time_tracking,
+        watchers,
+        resolution: fields["resolution"]["name"].as_str().map(|s| s.to_string()),
+        priority: fields["priority"]["name"].as_str().map(|s| s.to_string()),
+        custom_fields: parse_custom_fields(fields),
     })
 }
 
+/// Collect scalar `customfield_*` values (strings, numbers, and `{"value": ..}` options)
+/// so bulk edits can tell which tickets already have a value.
+fn parse_custom_fields(fields: &serde_json::Value) -> BTreeMap<String, String> {
+    let Some(map) = fields.as_object() else {
+        return BTreeMap::new();
+    };
+    map.iter()
+        .filter(|(id, _)| id.starts_with("customfield_"))
+        .filter_map(|(id, value)| {
+            let text = match value {
+                serde_json::Value::String(s) => s.clone(),
+                serde_json::Value::Number(n) => n.to_string(),
+                serde_json::Value::Object(o) => o.get("value")?.as_str()?.to_string(),
+                _ => return None,
+            };
+            Some((id.clone(), text))
+        })
+        .collect()
+}
+
+/// Parse the `watches` object from an issue (or the watchers endpoint).
+fn parse_watches(watches: &serde_json::Value) -> Watchers {
+    let names: Vec<String> = watches["watchers"]
+        .as_array()
+        .map(|entries| {
+            entries
+                .iter()
+                .filter_map(|w| w["displayName"].as_str().map(|s| s.to_string()))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    Watchers {
+        count: watches["watchCount"]
+            .as_u64()
+            .map(|c| c as usize)
+            .unwrap_or(names.len()),
+        is_watching: watches["isWatching"].as_bool().unwrap_or(false),
+        names,
+    }
+}
+
+/// Watcher display names, which the issue payload leaves out.
+pub async fn fetch_watcher_names(key: &str) -> Result<Vec<String>> {
+    let output = run_rest("GET", &format!("issue/{}/watchers", key), None).await?;
+    let json: serde_json::Value = serde_json::from_str(&output)
+        .with_context(|| format!("Failed to parse watchers for {}", key))?;
+    Ok(parse_watches(&json).names)
+}
+
+/// Parse estimates and worklog entries from an issue's `fields` object.
+fn parse_time_tracking(fields: &serde_json::Value) -> TimeTracking {
+    let tracking = &fields["timetracking"];
+    let text = |value: &serde_json::Value| value.as_str().map(|s| s.to_string());
+
+    let mut worklogs: Vec<Worklog> = fields["worklog"]["worklogs"]
+        .as_array()
+        .map(|entries| {
+            entries
+                .iter()
+                .map(|entry| Worklog {
+                    author: entry["author"]["displayName"]
+                        .as_str()
+                        .unwrap_or("Unknown")
+                        .to_string(),
+                    started: entry["started"].as_str().unwrap_or("").to_string(),
+                    time_spent: entry["timeSpent"].as_str().unwrap_or("").to_string(),
+                    time_spent_seconds: entry["timeSpentSeconds"].as_u64().unwrap_or(0),
+                    comment: entry["comment"]
+                        .as_str()
+                        .map(|s| s.trim().to_string())
+                        .filter(|s| !s.is_empty()),
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    // Newest first, matching the activity timeline.
+    worklogs.sort_by(|a, b| b.started.cmp(&a.started));
+
+    TimeTracking {
+        original_estimate: text(&tracking["originalEstimate"]),
+        remaining_estimate: text(&tracking["remainingEstimate"]),
+        time_spent: text(&tracking["timeSpent"]),
+        worklogs,
+    }
+}
+
 /// Fetch tickets assigned to a specific user, setting assignee_email on results.
 async fn fetch_tickets_for_user(
     config: &AppConfig,
@@ -424,6 +725,32 @@ async fn fetch_tickets_for_user(
     Ok(tickets)
 }
 
+/// Fetch tickets the current user watches, regardless of assignee.
+async fn fetch_watched_tickets(config: &AppConfig, scope: TicketFetchScope) -> Result<Vec<Ticket>> {
+    let active_query = format!(
+        "watcher = currentUser() AND status in {}",
+        config.active_status_clause()
+    );
+
+    let mut tickets_by_key: HashMap<String, Ticket> = HashMap::new();
+    let mut batches = vec![fetch_tickets_for_query(config, &active_query).await?];
+    if let TicketFetchScope::ActiveAndRecentDone = scope {
+        let recent_done_query = format!(
+            "watcher = currentUser() AND status in {} AND updated >= {}",
+            config.done_status_clause(),
+            config.done_window()
+        );
+        batches.push(fetch_tickets_for_query(config, &recent_done_query).await?);
+    }
+    for ticket in batches.into_iter().flatten() {
+        tickets_by_key.insert(ticket.key.clone(), ticket);
+    }
+
+    let mut tickets: Vec<Ticket> = tickets_by_key.into_values().collect();
+    tickets.sort_by(|a, b| a.key.cmp(&b.key));
+    Ok(tickets)
+}
+
 fn unassigned_team_active_query(config: &AppConfig) -> String {
     format!(
         "assignee is EMPTY AND \"Assigned Teams\" = \"{}\" AND status in {}",
@@ -598,6 +925,7 @@ async fn fetch_epics(config: &AppConfig) -> Result<Vec<Epic>> {
 const EPICS_CACHE_PREFIX: &str = "lazyjira_epics_cache";
 const DETAILS_CACHE_PREFIX: &str = "lazyjira_ticket_details_cache";
 const FULL_CACHE_PREFIX: &str = "lazyjira_full_cache";
+const RECENT_CACHE_PREFIX: &str = "lazyjira_recently_viewed";
 
 fn cache_file_name(prefix: &str, project: &str) -> String {
     format!("{prefix}_{project}.json")
@@ -615,6 +943,11 @@ fn details_cache_path(project: &str) -> PathBuf {
     temp_cache_path(DETAILS_CACHE_PREFIX, project)
 }
 
+/// Kept next to the details cache, which holds what the recent tickets need to show.
+fn recent_cache_path(project: &str) -> PathBuf {
+    temp_cache_path(RECENT_CACHE_PREFIX, project)
+}
+
 fn full_cache_dir() -> PathBuf {
     match std::env::var("HOME") {
         Ok(home) => PathBuf::from(home).join(".cache").join(FULL_CACHE_DIR_NAME),
@@ -626,7 +959,7 @@ fn full_cache_path(project: &str) -> PathBuf {
     full_cache_dir().join(cache_file_name(FULL_CACHE_PREFIX, project))
 }
 
-fn now_unix_secs() -> u64 {
+pub fn now_unix_secs() -> u64 {
     match SystemTime::now().duration_since(UNIX_EPOCH) {
         Ok(duration) => duration.as_secs(),
         Err(_) => 0,
@@ -671,10 +1004,7 @@ fn load_epics_cache(project: &str) -> Vec<Epic> {
         Err(_) => return Vec::new(),
     };
 
-    match serde_json::from_str::<Vec<Epic>>(&content) {
-        Ok(epics) => epics,
-        Err(_) => Vec::new(),
-    }
+    serde_json::from_str::<Vec<Epic>>(&content).unwrap_or_default()
 }
 
 fn save_epics_cache(project: &str, epics: &[Epic]) -> Result<()> {
@@ -704,6 +1034,21 @@ fn save_details_cache(project: &str, details_by_key: &HashMap<String, Ticket>) -
     Ok(())
 }
 
+pub fn load_recently_viewed(project: &str) -> Vec<Viewed> {
+    std::fs::read_to_string(recent_cache_path(project))
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+pub fn save_recently_viewed(project: &str, viewed: &[Viewed]) -> Result<()> {
+    let path = recent_cache_path(project);
+    let json = serde_json::to_string(viewed).context("Failed to serialize recently viewed")?;
+    std::fs::write(&path, json)
+        .with_context(|| format!("Failed to write recently viewed file: {}", path.display()))?;
+    Ok(())
+}
+
 fn hydrate_ticket_from_details_cache(
     ticket: &mut Ticket,
     details_by_key: &HashMap<String, Ticket>,
@@ -735,6 +1080,10 @@ fn hydrate_ticket_from_details_cache(
     if !detail.activity.is_empty() {
         ticket.activity = detail.activity.clone();
     }
+    ticket.time_tracking = detail.time_tracking.clone();
+    ticket.watchers = detail.watchers.clone();
+    ticket.priority = detail.priority.clone();
+    ticket.custom_fields = detail.custom_fields.clone();
 }
 
 fn hydrate_tickets_from_details_cache(
@@ -900,9 +1249,27 @@ async fn fetch_with_scope(config: &AppConfig, scope: TicketFetchScope) -> Result
     }
     team_tickets.extend(unassigned_team_tickets);
 
+    // Best effort: a failed watched query keeps the last snapshot's list instead of failing
+    // My Work and Team with it.
+    let (mut watched_tickets, watched_error) = match fetch_watched_tickets(config, scope).await {
+        Ok(tickets) => (tickets, None),
+        Err(e) => {
+            let previous = load_startup_cache_snapshot(project)
+                .map(|snapshot| snapshot.cache.watched_tickets)
+                .unwrap_or_default();
+            (previous, Some(format!("{:#}", e)))
+        }
+    };
+
     attach_epics_to_tickets(&mut my_tickets, &mut team_tickets, &epics);
+    attach_epics_to_tickets(&mut watched_tickets, &mut [], &epics);
     hydrate_tickets_from_details_cache(&mut my_tickets, &details_by_key);
     hydrate_tickets_from_details_cache(&mut team_tickets, &details_by_key);
+    hydrate_tickets_from_details_cache(&mut watched_tickets, &details_by_key);
+    // The query result is authoritative even if a cached detail predates the watch.
+    for ticket in &mut watched_tickets {
+        ticket.watchers.is_watching = true;
+    }
     for epic in &mut epics {
         hydrate_tickets_from_details_cache(&mut epic.children, &details_by_key);
     }
@@ -911,8 +1278,10 @@ async fn fetch_with_scope(config: &AppConfig, scope: TicketFetchScope) -> Result
     Ok(Cache {
         my_tickets,
         team_tickets,
+        watched_tickets,
         epics,
         team_members,
+        watched_error,
     })
 }
 
@@ -926,6 +1295,13 @@ pub async fn fetch_all(config: &AppConfig) -> Result<Cache> {
     fetch_with_scope(config, TicketFetchScope::ActiveAndRecentDone).await
 }
 
+/// Clear a ticket's resolution. jira-cli can only set one, so this goes through the REST API.
+pub async fn clear_resolution(key: &str) -> Result<()> {
+    let body = r#"{"fields":{"resolution":null}}"#;
+    run_rest("PUT", &format!("issue/{}", key), Some(body)).await?;
+    Ok(())
+}
+
 /// Move a ticket to a new status via `jira issue move`.
 pub async fn move_ticket(key: &str, status: &str, resolution: Option<&str>) -> Result<()> {
     match resolution {
@@ -945,9 +1321,141 @@ pub async fn add_comment(key: &str, body: &str) -> Result<()> {
     Ok(())
 }
 
-/// Assign a ticket to a user via `jira issue assign`.
+/// Assign a ticket to a user via `jira issue assign`. An empty email unassigns it.
 pub async fn assign_ticket(key: &str, email: &str) -> Result<()> {
-    run_cmd("jira", &["issue", "assign", key, email]).await?;
+    let assignee = if email.is_empty() { "x" } else { email };
+    run_cmd("jira", &["issue", "assign", key, assignee]).await?;
+    Ok(())
+}
+
+/// Log time against a ticket via `jira issue worklog add`.
+/// `started` uses Jira's `YYYY-MM-DD HH:MM:SS` format; `None` means now.
+pub async fn add_worklog(
+    key: &str,
+    time_spent: &str,
+    started: Option<&str>,
+    comment: Option<&str>,
+) -> Result<()> {
+    let mut args = vec!["issue", "worklog", "add", key, time_spent, "--no-input"];
+
+    if let Some(started) = started {
+        args.push("--started");
+        args.push(started);
+    }
+
+    if let Some(comment) = comment.filter(|c| !c.trim().is_empty()) {
+        args.push("--comment");
+        args.push(comment);
+    }
+
+    run_cmd("jira", &args).await?;
+    Ok(())
+}
+
+/// Start watching a ticket via `jira issue watch`.
+pub async fn watch_ticket(key: &str) -> Result<()> {
+    let me = fetch_my_email().await?;
+    run_cmd("jira", &["issue", "watch", key, &me]).await?;
+    Ok(())
+}
+
+/// Stop watching a ticket. jira-cli has no unwatch command, so this goes
+/// through the REST API.
+pub async fn unwatch_ticket(key: &str) -> Result<()> {
+    let myself = run_rest("GET", "myself", None).await?;
+    let json: serde_json::Value =
+        serde_json::from_str(&myself).context("Failed to parse the current Jira user")?;
+    let query = watcher_query(&json).context("Jira did not return an account id or user name")?;
+    run_rest("DELETE", &format!("issue/{}/watchers?{}", key, query), None).await?;
+    Ok(())
+}
+
+/// The watcher removal parameter for a `myself` response: Cloud identifies users by
+/// `accountId`, Server and Data Center by the account `name` (not the email).
+fn watcher_query(myself: &serde_json::Value) -> Option<String> {
+    let (param, value) = match myself["accountId"].as_str() {
+        Some(id) => ("accountId", id),
+        None => ("username", myself["name"].as_str()?),
+    };
+    Some(format!("{}={}", param, percent_encode(value)))
+}
+
+/// Percent-encode everything but RFC 3986 unreserved characters, for query values.
+fn percent_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+/// Link two tickets via `jira issue link` (e.g. link type "Relates").
+pub async fn link_tickets(inward_key: &str, outward_key: &str, link_type: &str) -> Result<()> {
+    run_cmd(
+        "jira",
+        &["issue", "link", inward_key, outward_key, link_type],
+    )
+    .await?;
+    Ok(())
+}
+
+/// Link a ticket to an epic via `jira epic add`, or unlink it with `jira epic remove`.
+pub async fn set_ticket_epic(key: &str, epic_key: Option<&str>) -> Result<()> {
+    match epic_key {
+        Some(epic) => run_cmd("jira", &["epic", "add", epic, key]).await?,
+        None => run_cmd("jira", &["epic", "remove", key]).await?,
+    };
+    Ok(())
+}
+
+/// Add and remove labels via `jira issue edit`; jira-cli removes labels prefixed with `-`.
+pub async fn edit_ticket_labels(key: &str, add: &[String], remove: &[String]) -> Result<()> {
+    let args = edit_labels_args(key, add, remove);
+    let args_ref = args.iter().map(|s| s.as_str()).collect::<Vec<_>>();
+    run_cmd("jira", &args_ref).await?;
+    Ok(())
+}
+
+fn edit_labels_args(key: &str, add: &[String], remove: &[String]) -> Vec<String> {
+    let mut args = vec![
+        "issue".to_string(),
+        "edit".to_string(),
+        key.to_string(),
+        "--no-input".to_string(),
+    ];
+    for label in add {
+        args.push("-l".to_string());
+        args.push(label.clone());
+    }
+    for label in remove {
+        args.push("-l".to_string());
+        args.push(format!("-{}", label));
+    }
+    args
+}
+
+/// Set a ticket's priority via `jira issue edit`.
+pub async fn set_ticket_priority(key: &str, priority: &str) -> Result<()> {
+    run_cmd(
+        "jira",
+        &["issue", "edit", key, "--no-input", "-y", priority],
+    )
+    .await?;
+    Ok(())
+}
+
+/// Set a custom field via `jira issue edit --custom`. `name` must be mapped in jira-cli's config.
+pub async fn set_custom_field(key: &str, name: &str, value: &str) -> Result<()> {
+    let custom = format!("{}={}", name, value);
+    run_cmd(
+        "jira",
+        &["issue", "edit", key, "--no-input", "--custom", &custom],
+    )
+    .await?;
     Ok(())
 }
 
@@ -980,54 +1488,158 @@ pub async fn fetch_jql_query(config: &AppConfig, jql: &str) -> Result<Vec<Ticket
     fetch_tickets_for_query(config, jql).await
 }
 
-/// Create a new ticket via `jira issue create`, with optional body and labels.
-pub async fn create_ticket_with_fields(
-    project: &str,
-    issue_type: &str,
-    summary: &str,
-    assignee_email: Option<&str>,
-    epic_key: Option<&str>,
-    description: Option<&str>,
-    labels: Option<&[String]>,
-) -> Result<String> {
-    let mut args: Vec<String> = vec![
-        "issue".to_string(),
-        "create".to_string(),
-        "-t".to_string(),
-        issue_type.to_string(),
+/// Fields for a new ticket. Optional values left empty are not sent.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct NewTicket {
+    pub issue_type: String,
+    pub summary: String,
+    pub assignee_email: Option<String>,
+    pub epic_key: Option<String>,
+    /// Parent issue for sub-tasks; takes precedence over `epic_key`.
+    pub parent_key: Option<String>,
+    pub description: Option<String>,
+    pub labels: Vec<String>,
+    pub priority: Option<String>,
+    pub components: Vec<String>,
+    pub story_points: Option<f64>,
+    /// `YYYY-MM-DD`.
+    pub due_date: Option<String>,
+    /// jira-cli custom field name -> value.
+    pub custom_fields: BTreeMap<String, String>,
+}
+
+fn create_ticket_args(project: &str, ticket: &NewTicket) -> Vec<String> {
+    let is_epic = ticket.issue_type.eq_ignore_ascii_case("epic");
+    // Epics go through `jira epic create`, which also fills the required Epic Name.
+    let mut args: Vec<String> = if is_epic {
+        vec![
+            "epic".to_string(),
+            "create".to_string(),
+            "-n".to_string(),
+            ticket.summary.clone(),
+        ]
+    } else {
+        vec![
+            "issue".to_string(),
+            "create".to_string(),
+            "-t".to_string(),
+            ticket.issue_type.clone(),
+        ]
+    };
+    args.extend([
         "-s".to_string(),
-        summary.to_string(),
+        ticket.summary.clone(),
         "--no-input".to_string(),
         "-p".to_string(),
         project.to_string(),
-    ];
+    ]);
 
-    if let Some(email) = assignee_email {
+    if let Some(email) = ticket.assignee_email.as_ref() {
         args.push("-a".to_string());
-        args.push(email.to_string());
+        args.push(email.clone());
     }
 
-    if let Some(ek) = epic_key {
+    // jira-cli uses the same flag for the epic link and a sub-task's parent.
+    if let Some(parent) = ticket
+        .parent_key
+        .as_ref()
+        .or(ticket.epic_key.as_ref())
+        .filter(|_| !is_epic)
+    {
         args.push("-P".to_string());
-        args.push(ek.to_string());
+        args.push(parent.clone());
     }
 
-    if let Some(body) = description {
+    push_field_args(&mut args, ticket);
+    args
+}
+
+/// Flags shared by `jira issue create` and `jira issue edit`.
+fn push_field_args(args: &mut Vec<String>, ticket: &NewTicket) {
+    if let Some(body) = ticket.description.as_ref() {
         if !body.trim().is_empty() {
             args.push("-b".to_string());
-            args.push(body.to_string());
+            args.push(body.clone());
         }
     }
 
-    if let Some(values) = labels {
-        for label in values {
-            if !label.trim().is_empty() {
-                args.push("-l".to_string());
-                args.push(label.to_string());
-            }
+    for label in &ticket.labels {
+        if !label.trim().is_empty() {
+            args.push("-l".to_string());
+            args.push(label.clone());
+        }
+    }
+
+    if let Some(priority) = ticket.priority.as_ref() {
+        args.push("-y".to_string());
+        args.push(priority.clone());
+    }
+
+    for component in &ticket.components {
+        if !component.trim().is_empty() {
+            args.push("-C".to_string());
+            args.push(component.clone());
         }
     }
 
+    // Story points is a custom field; jira-cli maps the name through its own config.
+    if let Some(points) = ticket.story_points {
+        args.push("--custom".to_string());
+        args.push(format!("story-points={}", points));
+    }
+
+    for (name, value) in &ticket.custom_fields {
+        args.push("--custom".to_string());
+        args.push(format!("{}={}", name, value));
+    }
+}
+
+fn update_ticket_args(key: &str, ticket: &NewTicket) -> Vec<String> {
+    let mut args = vec![
+        "issue".to_string(),
+        "edit".to_string(),
+        key.to_string(),
+        "--no-input".to_string(),
+    ];
+    if !ticket.summary.trim().is_empty() {
+        args.push("-s".to_string());
+        args.push(ticket.summary.clone());
+    }
+    push_field_args(&mut args, ticket);
+    args
+}
+
+/// Update an existing ticket with the non-empty fields of `ticket`. The issue type and
+/// parent are ignored; assignee and epic go through their own commands.
+pub async fn update_ticket_with_fields(key: &str, ticket: &NewTicket) -> Result<()> {
+    let args = update_ticket_args(key, ticket);
+    if args.len() > 4 {
+        let args_ref = args.iter().map(|s| s.as_str()).collect::<Vec<_>>();
+        run_cmd("jira", &args_ref).await?;
+    }
+    if let Some(email) = ticket.assignee_email.as_deref() {
+        assign_ticket(key, email).await?;
+    }
+    if let Some(epic) = ticket.epic_key.as_deref() {
+        set_ticket_epic(key, Some(epic)).await?;
+    }
+    if let Some(due) = ticket.due_date.as_deref() {
+        set_due_date(key, due).await?;
+    }
+    Ok(())
+}
+
+/// Set the system `duedate` field. jira-cli has no flag for it, so this goes through the
+/// REST API.
+pub async fn set_due_date(key: &str, due: &str) -> Result<()> {
+    let body = serde_json::json!({ "fields": { "duedate": due } }).to_string();
+    run_rest("PUT", &format!("issue/{}", key), Some(&body)).await?;
+    Ok(())
+}
+
+/// Create a new ticket via `jira issue create`.
+pub async fn create_ticket_with_fields(project: &str, ticket: &NewTicket) -> Result<String> {
+    let args = create_ticket_args(project, ticket);
     let args_ref = args.iter().map(|s| s.as_str()).collect::<Vec<_>>();
     let output = run_cmd("jira", &args_ref).await?;
     // jira-cli typically outputs something like "Issue AMP-1234 created"
@@ -1037,29 +1649,15 @@ pub async fn create_ticket_with_fields(
         .find(|w| w.contains('-'))
         .map(|w| w.to_string())
         .unwrap_or(output.trim().to_string());
+    if let Some(due) = ticket.due_date.as_deref() {
+        // Not a retryable failure: sending the create again would duplicate the ticket.
+        set_due_date(&key, due).await.map_err(|e| {
+            anyhow::anyhow!("Created {} but could not set its due date: {:#}", key, e)
+        })?;
+    }
     Ok(key)
 }
 
-/// Create a new ticket via `jira issue create`.
-pub async fn create_ticket(
-    project: &str,
-    issue_type: &str,
-    summary: &str,
-    assignee_email: Option<&str>,
-    epic_key: Option<&str>,
-) -> Result<String> {
-    create_ticket_with_fields(
-        project,
-        issue_type,
-        summary,
-        assignee_email,
-        epic_key,
-        None,
-        None,
-    )
-    .await
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1068,19 +1666,8 @@ mod tests {
 
     fn test_ticket(key: &str, status: Status) -> Ticket {
         Ticket {
-            key: key.to_string(),
-            summary: format!("Summary for {}", key),
-            status,
-            assignee: None,
-            assignee_email: None,
-            reporter: None,
-            description: None,
-            labels: Vec::new(),
-            epic_key: None,
-            epic_name: None,
-            detail_loaded: false,
             url: format!("https://example.atlassian.net/browse/{}", key),
-            activity: Vec::new(),
+            ..Ticket::for_test(key, &format!("Summary for {}", key), status)
         }
     }
 
@@ -1121,6 +1708,14 @@ mod tests {
             statuses: StatusConfig::default(),
             resolutions: crate::config::default_resolutions(),
             filters: vec![],
+            templates: vec![],
+            custom_fields: Default::default(),
+            comment_templates: vec![],
+            standup: Default::default(),
+            dashboard: Default::default(),
+            git: Default::default(),
+            keys: Default::default(),
+            theme: Default::default(),
         };
         let query = unassigned_team_active_query(&config);
         assert!(query.contains("assignee is EMPTY"));
@@ -1154,4 +1749,161 @@ mod tests {
 
         assert_eq!(epics[0].children[0].status, Status::ToDo);
     }
+
+    #[test]
+    fn parse_watches_reads_count_flag_and_names() {
+        let watches = serde_json::json!({
+            "watchCount": 2,
+            "isWatching": true,
+            "watchers": [
+                {"displayName": "Alex Doe"},
+                {"displayName": "Sam Roe"}
+            ]
+        });
+        let parsed = parse_watches(&watches);
+        assert_eq!(parsed.count, 2);
+        assert!(parsed.is_watching);
+        assert_eq!(parsed.names, vec!["Alex Doe", "Sam Roe"]);
+
+        let empty = parse_watches(&serde_json::Value::Null);
+        assert_eq!(empty.count, 0);
+        assert!(!empty.is_watching);
+    }
+
+    #[test]
+    fn create_ticket_args_include_optional_fields() {
+        let ticket = NewTicket {
+            issue_type: "Bug".to_string(),
+            summary: "Crash on save".to_string(),
+            labels: vec!["triage".to_string(), " ".to_string()],
+            priority: Some("Major - P3".to_string()),
+            components: vec!["Editor".to_string()],
+            story_points: Some(3.0),
+            ..Default::default()
+        };
+        let args = create_ticket_args("AMP", &ticket);
+        let joined = args.join(" ");
+        assert!(joined.contains("-l triage"));
+        assert_eq!(args.iter().filter(|a| *a == "-l").count(), 1);
+        assert!(joined.contains("-y Major - P3"));
+        assert!(joined.contains("-C Editor"));
+        assert!(joined.contains("--custom story-points=3"));
+        assert!(!args.contains(&"-b".to_string()));
+    }
+
+    #[test]
+    fn update_ticket_args_only_send_present_fields() {
+        let mut custom_fields = BTreeMap::new();
+        custom_fields.insert("team".to_string(), "Parsers".to_string());
+        let ticket = NewTicket {
+            issue_type: "Task".to_string(),
+            due_date: Some("2026-11-01".to_string()),
+            custom_fields,
+            assignee_email: Some("dev@example.com".to_string()),
+            ..Default::default()
+        };
+        let args = update_ticket_args("AMP-7", &ticket);
+        assert_eq!(args[..4], ["issue", "edit", "AMP-7", "--no-input"]);
+        assert!(!args.contains(&"-s".to_string()));
+        assert!(!args.contains(&"-t".to_string()));
+        assert!(!args.contains(&"-a".to_string()));
+        let joined = args.join(" ");
+        assert!(!joined.contains("due"));
+        assert!(joined.contains("--custom team=Parsers"));
+    }
+
+    #[test]
+    fn create_ticket_args_prefer_parent_over_epic() {
+        let ticket = NewTicket {
+            issue_type: "Sub-task".to_string(),
+            summary: "Write tests".to_string(),
+            epic_key: Some("AMP-100".to_string()),
+            parent_key: Some("AMP-42".to_string()),
+            ..Default::default()
+        };
+        let args = create_ticket_args("AMP", &ticket);
+        let parent_pos = args.iter().position(|a| a == "-P").expect("-P flag");
+        assert_eq!(args[parent_pos + 1], "AMP-42");
+        assert_eq!(args.iter().filter(|a| *a == "-P").count(), 1);
+    }
+
+    #[test]
+    fn create_ticket_args_use_epic_create_for_epics() {
+        let ticket = NewTicket {
+            issue_type: "Epic".to_string(),
+            summary: "Parser rewrite".to_string(),
+            epic_key: Some("AMP-100".to_string()),
+            ..Default::default()
+        };
+        let args = create_ticket_args("AMP", &ticket);
+        assert_eq!(args[..4], ["epic", "create", "-n", "Parser rewrite"]);
+        assert!(!args.contains(&"-t".to_string()));
+        assert!(!args.contains(&"-P".to_string()));
+    }
+
+    #[test]
+    fn edit_labels_args_prefix_removed_labels() {
+        let args = edit_labels_args("AMP-1", &["triage".to_string()], &["stale".to_string()]);
+        assert_eq!(
+            args,
+            vec![
+                "issue",
+                "edit",
+                "AMP-1",
+                "--no-input",
+                "-l",
+                "triage",
+                "-l",
+                "-stale"
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_custom_fields_keeps_scalar_values() {
+        let fields = serde_json::json!({
+            "summary": "ignored",
+            "customfield_1": "text",
+            "customfield_2": 3.5,
+            "customfield_3": {"value": "Option A"},
+            "customfield_4": null,
+            "customfield_5": ["list"],
+        });
+        let parsed = parse_custom_fields(&fields);
+        assert_eq!(parsed.len(), 3);
+        assert_eq!(parsed["customfield_2"], "3.5");
+        assert_eq!(parsed["customfield_3"], "Option A");
+    }
+
+    #[test]
+    fn rest_target_comes_from_the_jira_cli_config() {
+        let cloud = "installation: Cloud\nserver: https://amp.atlassian.net/\nlogin: me@x.com\n";
+        assert_eq!(
+            rest_target_from(cloud, "tok").unwrap(),
+            RestTarget {
+                base_url: "https://amp.atlassian.net/rest/api/2".to_string(),
+                authorization: "Basic bWVAeC5jb206dG9r".to_string(),
+            }
+        );
+        let server = "installation: Local\nserver: https://jira.example.com\nauth_type: bearer\n";
+        assert_eq!(
+            rest_target_from(server, "pat").unwrap().authorization,
+            "Bearer pat"
+        );
+        for raw in ["login: me\n", "server: https://amp.atlassian.net\n"] {
+            let err = format!("{:#}", rest_target_from(raw, "tok").unwrap_err());
+            assert!(err.contains("jira init"), "{}", err);
+        }
+        assert_eq!(base64_encode(b"ab"), "YWI=");
+        assert_eq!(base64_encode(b"a"), "YQ==");
+    }
+
+    #[test]
+    fn unwatch_identifies_the_user_by_account_and_encodes_it() {
+        let server = serde_json::json!({"name": "jane+jira", "emailAddress": "jane+jira@x.com"});
+        assert_eq!(watcher_query(&server).unwrap(), "username=jane%2Bjira");
+        let cloud = serde_json::json!({"accountId": "5b10:ac8d", "name": "ignored"});
+        assert_eq!(watcher_query(&cloud).unwrap(), "accountId=5b10%3Aac8d");
+        assert_eq!(watcher_query(&serde_json::json!({})), None);
+    }
 }
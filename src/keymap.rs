@@ -0,0 +1,1050 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use anyhow::{bail, Context, Result};
+use crossterm::event::{KeyCode, KeyModifiers};
+
+use crate::config::{KeyPreset, KeysConfig};
+
+/// A key with the modifiers that matter for matching (Ctrl and Alt; Shift is in the char).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeyBinding {
+    pub code: KeyCode,
+    pub modifiers: KeyModifiers,
+}
+
+impl KeyBinding {
+    pub fn new(code: KeyCode, modifiers: KeyModifiers) -> Self {
+        Self {
+            code,
+            modifiers: modifiers & (KeyModifiers::CONTROL | KeyModifiers::ALT),
+        }
+    }
+
+    /// Parse `j`, `?`, `Space`, `Enter`, `F5`, `Ctrl+z`, `Alt+x`, `Shift+Tab` and the like.
+    pub fn parse(text: &str) -> Result<Self> {
+        let mut modifiers = KeyModifiers::NONE;
+        let mut rest = text.trim();
+        while let Some((prefix, key)) = rest.split_once('+').filter(|(_, key)| !key.is_empty()) {
+            match prefix.to_ascii_lowercase().as_str() {
+                "ctrl" | "c" => modifiers |= KeyModifiers::CONTROL,
+                "alt" | "m" => modifiers |= KeyModifiers::ALT,
+                "shift" | "s" if key.eq_ignore_ascii_case("tab") => {
+                    return Ok(Self::new(KeyCode::BackTab, modifiers));
+                }
+                _ => bail!("Unknown modifier '{}' in key '{}'", prefix, text),
+            }
+            rest = key;
+        }
+
+        let mut chars = rest.chars();
+        let code = match (chars.next(), chars.next()) {
+            (Some(c), None) if modifiers.is_empty() => KeyCode::Char(c),
+            (Some(c), None) => KeyCode::Char(c.to_ascii_lowercase()),
+            _ => match rest.to_ascii_lowercase().as_str() {
+                "space" => KeyCode::Char(' '),
+                "enter" | "return" => KeyCode::Enter,
+                "esc" | "escape" => KeyCode::Esc,
+                "tab" => KeyCode::Tab,
+                "backtab" => KeyCode::BackTab,
+                "backspace" => KeyCode::Backspace,
+                "delete" | "del" => KeyCode::Delete,
+                "up" => KeyCode::Up,
+                "down" => KeyCode::Down,
+                "left" => KeyCode::Left,
+                "right" => KeyCode::Right,
+                "home" => KeyCode::Home,
+                "end" => KeyCode::End,
+                "pageup" => KeyCode::PageUp,
+                "pagedown" => KeyCode::PageDown,
+                name => match name.strip_prefix('f').map(str::parse::<u8>) {
+                    Some(Ok(n)) if (1..=12).contains(&n) => KeyCode::F(n),
+                    _ => bail!("Unknown key '{}'", text),
+                },
+            },
+        };
+        Ok(Self::new(code, modifiers))
+    }
+}
+
+impl fmt::Display for KeyBinding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.modifiers.contains(KeyModifiers::CONTROL) {
+            write!(f, "Ctrl+")?;
+        }
+        if self.modifiers.contains(KeyModifiers::ALT) {
+            write!(f, "Alt+")?;
+        }
+        match self.code {
+            KeyCode::Char(' ') => write!(f, "Space"),
+            KeyCode::Char(c) if !self.modifiers.is_empty() => {
+                write!(f, "{}", c.to_ascii_uppercase())
+            }
+            KeyCode::Char(c) => write!(f, "{}", c),
+            KeyCode::BackTab => write!(f, "Shift+Tab"),
+            KeyCode::F(n) => write!(f, "F{}", n),
+            KeyCode::PageUp => write!(f, "PageUp"),
+            KeyCode::PageDown => write!(f, "PageDown"),
+            code => write!(f, "{:?}", code),
+        }
+    }
+}
+
+/// Where a key press is handled; each action belongs to one or more scopes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Scope {
+    /// The list tabs (My Work, Team, Epics, Unassigned, Watching, Dashboard).
+    Main,
+    Filters,
+    Detail,
+    /// Pickers, menus and scrolling overlays (move/resolution pickers, bulk menu, undo history,
+    /// assign, export, standup, help).
+    Picker,
+    /// Lists under a text field (search, command palette, go to key), where plain keys type.
+    Input,
+}
+
+impl Scope {
+    pub fn all() -> &'static [Scope] {
+        &[
+            Scope::Main,
+            Scope::Filters,
+            Scope::Detail,
+            Scope::Picker,
+            Scope::Input,
+        ]
+    }
+}
+
+/// Heading an action is listed under in the `?` overlay.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Section {
+    Navigation,
+    Filtering,
+    Actions,
+    Epics,
+    Detail,
+    Filters,
+    Pickers,
+}
+
+impl Section {
+    pub fn all() -> &'static [Section] {
+        &[
+            Section::Navigation,
+            Section::Filtering,
+            Section::Actions,
+            Section::Epics,
+            Section::Detail,
+            Section::Filters,
+            Section::Pickers,
+        ]
+    }
+
+    pub fn title(&self) -> &'static str {
+        match self {
+            Section::Navigation => "Navigation",
+            Section::Filtering => "Filtering (My Work + Team)",
+            Section::Actions => "Actions",
+            Section::Epics => "Epics Tab",
+            Section::Detail => "Detail View",
+            Section::Filters => "Filters Tab",
+            Section::Pickers => "Pickers, Menus and Search",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Action {
+    Quit,
+    NextTab,
+    CommandPalette,
+    GoToKey,
+    NavBack,
+    NavForward,
+    Down,
+    Up,
+    Open,
+    TogglePreview,
+    ToggleSelect,
+    SelectAll,
+    ClearSelection,
+    BulkActions,
+    FoldGroup,
+    FoldAll,
+    Undo,
+    UndoHistory,
+    Standup,
+    GitBranch,
+    Export,
+    Help,
+    ToggleDone,
+    FocusInProgress,
+    FocusReadyForWork,
+    FocusNeedsTriage,
+    FocusInReview,
+    Search,
+    CreateTicket,
+    BulkUpload,
+    Refresh,
+    NewEpic,
+    MoveToEpic,
+    RemoveFromEpic,
+    SidebarPane,
+    NewFilter,
+    EditFilter,
+    DeleteFilter,
+    CloseDetail,
+    ScrollUp,
+    ScrollDown,
+    OpenInBrowser,
+    MoveStatus,
+    Comment,
+    Assign,
+    EditFields,
+    CloseEpic,
+    ActivityHistory,
+    LogWork,
+    WorkTimer,
+    Watch,
+    NewFromTicket,
+    DetailGitBranch,
+    PickerDown,
+    PickerUp,
+    InputDown,
+    InputUp,
+}
+
+struct ActionInfo {
+    action: Action,
+    /// Name used in `[keys]`.
+    name: &'static str,
+    section: Section,
+    scopes: &'static [Scope],
+    description: &'static str,
+    default_keys: &'static [&'static str],
+}
+
+const LISTS: &[Scope] = &[Scope::Main, Scope::Filters];
+const MAIN: &[Scope] = &[Scope::Main];
+const FILTERS: &[Scope] = &[Scope::Filters];
+const DETAIL: &[Scope] = &[Scope::Detail];
+const ALL: &[Scope] = &[Scope::Main, Scope::Filters, Scope::Detail];
+const PICKER: &[Scope] = &[Scope::Picker];
+const INPUT: &[Scope] = &[Scope::Input];
+
+/// Every action in help order.
+const ACTIONS: &[ActionInfo] = &[
+    ActionInfo {
+        action: Action::NextTab,
+        name: "next_tab",
+        section: Section::Navigation,
+        scopes: LISTS,
+        description: "switch tab (Filters: results pane first)",
+        default_keys: &["Tab"],
+    },
+    ActionInfo {
+        action: Action::CommandPalette,
+        name: "command_palette",
+        section: Section::Navigation,
+        scopes: ALL,
+        description: "command palette (search and run any action)",
+        default_keys: &[":", "Ctrl+p"],
+    },
+    ActionInfo {
+        action: Action::GoToKey,
+        name: "go_to_key",
+        section: Section::Navigation,
+        scopes: ALL,
+        description: "open a ticket or epic by key, or a recently viewed one",
+        default_keys: &["J"],
+    },
+    ActionInfo {
+        action: Action::NavBack,
+        name: "back",
+        section: Section::Navigation,
+        scopes: ALL,
+        description: "back to the previous ticket or epic (reopens the last one from a list)",
+        default_keys: &["Ctrl+o"],
+    },
+    ActionInfo {
+        action: Action::Down,
+        name: "down",
+        section: Section::Navigation,
+        scopes: LISTS,
+        description: "move selection down",
+        default_keys: &["j", "Down"],
+    },
+    ActionInfo {
+        action: Action::Up,
+        name: "up",
+        section: Section::Navigation,
+        scopes: LISTS,
+        description: "move selection up",
+        default_keys: &["k", "Up"],
+    },
+    ActionInfo {
+        action: Action::ToggleSelect,
+        name: "toggle_select",
+        section: Section::Navigation,
+        scopes: LISTS,
+        description: "toggle ticket or group selection",
+        default_keys: &["Space"],
+    },
+    ActionInfo {
+        action: Action::SelectAll,
+        name: "select_all",
+        section: Section::Navigation,
+        scopes: LISTS,
+        description: "select all visible tickets",
+        default_keys: &["A"],
+    },
+    ActionInfo {
+        action: Action::ClearSelection,
+        name: "clear_selection",
+        section: Section::Navigation,
+        scopes: LISTS,
+        description: "clear selected tickets",
+        default_keys: &["u"],
+    },
+    ActionInfo {
+        action: Action::BulkActions,
+        name: "bulk_actions",
+        section: Section::Navigation,
+        scopes: LISTS,
+        description: "open bulk actions (move/assign/epic/labels/priority/comment/field)",
+        default_keys: &["B"],
+    },
+    ActionInfo {
+        action: Action::Open,
+        name: "open",
+        section: Section::Navigation,
+        scopes: LISTS,
+        description: "open detail (ticket or epic) / run filter",
+        default_keys: &["Enter"],
+    },
+    ActionInfo {
+        action: Action::TogglePreview,
+        name: "toggle_preview",
+        section: Section::Navigation,
+        scopes: LISTS,
+        description: "toggle split view with a live preview of the selected row",
+        default_keys: &["P"],
+    },
+    ActionInfo {
+        action: Action::FoldGroup,
+        name: "fold_group",
+        section: Section::Navigation,
+        scopes: MAIN,
+        description: "fold/unfold group",
+        default_keys: &["z"],
+    },
+    ActionInfo {
+        action: Action::FoldAll,
+        name: "fold_all",
+        section: Section::Navigation,
+        scopes: MAIN,
+        description: "fold/unfold all groups",
+        default_keys: &["Z"],
+    },
+    ActionInfo {
+        action: Action::Undo,
+        name: "undo",
+        section: Section::Navigation,
+        scopes: MAIN,
+        description: "undo last move/assign/edit/bulk run",
+        default_keys: &["Ctrl+z"],
+    },
+    ActionInfo {
+        action: Action::UndoHistory,
+        name: "undo_history",
+        section: Section::Navigation,
+        scopes: MAIN,
+        description: "undo history",
+        default_keys: &["H"],
+    },
+    ActionInfo {
+        action: Action::Standup,
+        name: "standup",
+        section: Section::Navigation,
+        scopes: MAIN,
+        description: "standup report",
+        default_keys: &["R"],
+    },
+    ActionInfo {
+        action: Action::GitBranch,
+        name: "git_branch",
+        section: Section::Navigation,
+        scopes: MAIN,
+        description: "create git branch for selected ticket",
+        default_keys: &["G"],
+    },
+    ActionInfo {
+        action: Action::Export,
+        name: "export",
+        section: Section::Navigation,
+        scopes: LISTS,
+        description: "export visible tickets (CSV/JSON/Markdown)",
+        default_keys: &["S"],
+    },
+    ActionInfo {
+        action: Action::Help,
+        name: "help",
+        section: Section::Navigation,
+        scopes: LISTS,
+        description: "show/hide this help",
+        default_keys: &["?"],
+    },
+    ActionInfo {
+        action: Action::Quit,
+        name: "quit",
+        section: Section::Navigation,
+        scopes: LISTS,
+        description: "quit",
+        default_keys: &["q"],
+    },
+    ActionInfo {
+        action: Action::ToggleDone,
+        name: "toggle_done",
+        section: Section::Filtering,
+        scopes: MAIN,
+        description: "toggle Done tickets",
+        default_keys: &["d"],
+    },
+    ActionInfo {
+        action: Action::FocusInProgress,
+        name: "focus_in_progress",
+        section: Section::Filtering,
+        scopes: MAIN,
+        description: "focus In Progress",
+        default_keys: &["p"],
+    },
+    ActionInfo {
+        action: Action::FocusReadyForWork,
+        name: "focus_ready_for_work",
+        section: Section::Filtering,
+        scopes: MAIN,
+        description: "focus Ready for Work",
+        default_keys: &["w"],
+    },
+    ActionInfo {
+        action: Action::FocusNeedsTriage,
+        name: "focus_needs_triage",
+        section: Section::Filtering,
+        scopes: MAIN,
+        description: "focus Needs Triage",
+        default_keys: &["n"],
+    },
+    ActionInfo {
+        action: Action::FocusInReview,
+        name: "focus_in_review",
+        section: Section::Filtering,
+        scopes: MAIN,
+        description: "focus In Review",
+        default_keys: &["v"],
+    },
+    ActionInfo {
+        action: Action::Search,
+        name: "search",
+        section: Section::Filtering,
+        scopes: LISTS,
+        description: "search (tickets, labels, and team member names)",
+        default_keys: &["/"],
+    },
+    ActionInfo {
+        action: Action::CreateTicket,
+        name: "create_ticket",
+        section: Section::Actions,
+        scopes: MAIN,
+        description: "create ticket",
+        default_keys: &["c"],
+    },
+    ActionInfo {
+        action: Action::BulkUpload,
+        name: "bulk_upload",
+        section: Section::Actions,
+        scopes: LISTS,
+        description: "bulk upload tickets from CSV/JSON/YAML/Markdown",
+        default_keys: &["U"],
+    },
+    ActionInfo {
+        action: Action::Refresh,
+        name: "refresh",
+        section: Section::Actions,
+        scopes: LISTS,
+        description: "refresh tickets",
+        default_keys: &["r"],
+    },
+    ActionInfo {
+        action: Action::NewEpic,
+        name: "new_epic",
+        section: Section::Epics,
+        scopes: MAIN,
+        description: "create epic",
+        default_keys: &["N"],
+    },
+    ActionInfo {
+        action: Action::MoveToEpic,
+        name: "move_to_epic",
+        section: Section::Epics,
+        scopes: MAIN,
+        description: "move selected tickets to another epic",
+        default_keys: &["E"],
+    },
+    ActionInfo {
+        action: Action::RemoveFromEpic,
+        name: "remove_from_epic",
+        section: Section::Epics,
+        scopes: MAIN,
+        description: "remove selected tickets from their epic",
+        default_keys: &["X"],
+    },
+    ActionInfo {
+        action: Action::CloseDetail,
+        name: "close_detail",
+        section: Section::Detail,
+        scopes: DETAIL,
+        description: "close detail",
+        default_keys: &["Esc"],
+    },
+    ActionInfo {
+        action: Action::NavForward,
+        name: "forward",
+        section: Section::Detail,
+        scopes: DETAIL,
+        description: "forward again after going back (terminals send Ctrl+I as Tab)",
+        default_keys: &["Ctrl+i", "Tab"],
+    },
+    ActionInfo {
+        action: Action::ScrollUp,
+        name: "scroll_up",
+        section: Section::Detail,
+        scopes: DETAIL,
+        description: "scroll detail up",
+        default_keys: &["Up"],
+    },
+    ActionInfo {
+        action: Action::ScrollDown,
+        name: "scroll_down",
+        section: Section::Detail,
+        scopes: DETAIL,
+        description: "scroll detail down",
+        default_keys: &["Down"],
+    },
+    ActionInfo {
+        action: Action::OpenInBrowser,
+        name: "open_in_browser",
+        section: Section::Detail,
+        scopes: DETAIL,
+        description: "open ticket in browser",
+        default_keys: &["o"],
+    },
+    ActionInfo {
+        action: Action::MoveStatus,
+        name: "move_status",
+        section: Section::Detail,
+        scopes: DETAIL,
+        description: "move ticket",
+        default_keys: &["m"],
+    },
+    ActionInfo {
+        action: Action::Comment,
+        name: "comment",
+        section: Section::Detail,
+        scopes: DETAIL,
+        description: "add comment",
+        default_keys: &["C"],
+    },
+    ActionInfo {
+        action: Action::Assign,
+        name: "assign",
+        section: Section::Detail,
+        scopes: DETAIL,
+        description: "assign/reassign ticket",
+        default_keys: &["a"],
+    },
+    ActionInfo {
+        action: Action::EditFields,
+        name: "edit_fields",
+        section: Section::Detail,
+        scopes: DETAIL,
+        description: "edit summary and labels",
+        default_keys: &["e"],
+    },
+    ActionInfo {
+        action: Action::ActivityHistory,
+        name: "activity_history",
+        section: Section::Detail,
+        scopes: DETAIL,
+        description: "view activity history",
+        default_keys: &["h"],
+    },
+    ActionInfo {
+        action: Action::LogWork,
+        name: "log_work",
+        section: Section::Detail,
+        scopes: DETAIL,
+        description: "log work",
+        default_keys: &["l"],
+    },
+    ActionInfo {
+        action: Action::WorkTimer,
+        name: "work_timer",
+        section: Section::Detail,
+        scopes: DETAIL,
+        description: "start/stop work timer",
+        default_keys: &["t"],
+    },
+    ActionInfo {
+        action: Action::Watch,
+        name: "watch",
+        section: Section::Detail,
+        scopes: DETAIL,
+        description: "watch/unwatch ticket",
+        default_keys: &["w"],
+    },
+    ActionInfo {
+        action: Action::NewFromTicket,
+        name: "new_from_ticket",
+        section: Section::Detail,
+        scopes: DETAIL,
+        description: "new ticket from this (clone, sub-task, follow-up)",
+        default_keys: &["n"],
+    },
+    ActionInfo {
+        action: Action::DetailGitBranch,
+        name: "detail_git_branch",
+        section: Section::Detail,
+        scopes: DETAIL,
+        description: "create git branch for this ticket",
+        default_keys: &["g"],
+    },
+    ActionInfo {
+        action: Action::CloseEpic,
+        name: "close_epic",
+        section: Section::Detail,
+        scopes: DETAIL,
+        description: "close epic once all children are done (epic detail)",
+        default_keys: &["x"],
+    },
+    ActionInfo {
+        action: Action::SidebarPane,
+        name: "sidebar_pane",
+        section: Section::Filters,
+        scopes: FILTERS,
+        description: "switch back to sidebar",
+        default_keys: &["Shift+Tab"],
+    },
+    ActionInfo {
+        action: Action::NewFilter,
+        name: "new_filter",
+        section: Section::Filters,
+        scopes: FILTERS,
+        description: "new filter",
+        default_keys: &["n"],
+    },
+    ActionInfo {
+        action: Action::EditFilter,
+        name: "edit_filter",
+        section: Section::Filters,
+        scopes: FILTERS,
+        description: "edit selected filter",
+        default_keys: &["e"],
+    },
+    ActionInfo {
+        action: Action::DeleteFilter,
+        name: "delete_filter",
+        section: Section::Filters,
+        scopes: FILTERS,
+        description: "delete selected filter",
+        default_keys: &["x"],
+    },
+    ActionInfo {
+        action: Action::PickerDown,
+        name: "picker_down",
+        section: Section::Pickers,
+        scopes: PICKER,
+        description: "next entry in a picker or menu (scrolls help, history and standup)",
+        default_keys: &["j", "Down"],
+    },
+    ActionInfo {
+        action: Action::PickerUp,
+        name: "picker_up",
+        section: Section::Pickers,
+        scopes: PICKER,
+        description: "previous entry in a picker or menu",
+        default_keys: &["k", "Up"],
+    },
+    ActionInfo {
+        action: Action::InputDown,
+        name: "input_down",
+        section: Section::Pickers,
+        scopes: INPUT,
+        description: "next match while searching, in the command palette or go to key",
+        default_keys: &["Down", "Ctrl+j", "Ctrl+n", "Tab"],
+    },
+    ActionInfo {
+        action: Action::InputUp,
+        name: "input_up",
+        section: Section::Pickers,
+        scopes: INPUT,
+        description: "previous match while searching, in the command palette or go to key",
+        default_keys: &["Up", "Ctrl+k", "Ctrl+p", "Shift+Tab"],
+    },
+];
+
+impl Action {
+    fn info(&self) -> &'static ActionInfo {
+        ACTIONS
+            .iter()
+            .find(|info| info.action == *self)
+            .expect("every action is listed in ACTIONS")
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.info().name
+    }
+
+    pub fn description(&self) -> &'static str {
+        self.info().description
+    }
+
+    pub fn section(&self) -> Section {
+        self.info().section
+    }
+
+    /// Actions handled in `scope`, in help order.
+    pub fn in_scope(scope: Scope) -> impl Iterator<Item = Action> {
+        ACTIONS
+            .iter()
+            .filter(move |info| info.scopes.contains(&scope))
+            .map(|info| info.action)
+    }
+
+    pub fn from_name(name: &str) -> Option<Action> {
+        ACTIONS
+            .iter()
+            .find(|info| info.name == name)
+            .map(|info| info.action)
+    }
+}
+
+/// Keys added on top of the defaults by each preset; a preset key is taken away from any
+/// default binding it would clash with.
+fn preset_extras(preset: KeyPreset) -> &'static [(Action, &'static [&'static str])] {
+    match preset {
+        KeyPreset::Default => &[],
+        KeyPreset::Vim => &[
+            (Action::ScrollDown, &["j", "Ctrl+e"]),
+            (Action::ScrollUp, &["k", "Ctrl+y"]),
+            (Action::CloseDetail, &["q"]),
+            (Action::NextTab, &["L"]),
+        ],
+        KeyPreset::Emacs => &[
+            (Action::Down, &["Ctrl+n"]),
+            (Action::Up, &["Ctrl+p"]),
+            (Action::ScrollDown, &["Ctrl+n"]),
+            (Action::ScrollUp, &["Ctrl+p"]),
+            (Action::CloseDetail, &["Ctrl+g"]),
+            (Action::Search, &["Ctrl+s"]),
+            (Action::Quit, &["Ctrl+x"]),
+            (Action::CommandPalette, &["Alt+x"]),
+            (Action::PickerDown, &["Ctrl+n"]),
+            (Action::PickerUp, &["Ctrl+p"]),
+        ],
+    }
+}
+
+/// Active key bindings: a preset with the `[keys]` overrides applied.
+#[derive(Debug, Clone)]
+pub struct Keymap {
+    bindings: HashMap<Action, Vec<KeyBinding>>,
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        Self::preset(KeyPreset::Default)
+    }
+}
+
+impl Keymap {
+    pub fn preset(preset: KeyPreset) -> Self {
+        let mut bindings: HashMap<Action, Vec<KeyBinding>> = ACTIONS
+            .iter()
+            .map(|info| {
+                let keys = info
+                    .default_keys
+                    .iter()
+                    .map(|k| KeyBinding::parse(k).expect("default keys parse"))
+                    .collect();
+                (info.action, keys)
+            })
+            .collect();
+        let extras = preset_extras(preset);
+        for (action, keys) in extras {
+            let scopes = action.info().scopes;
+            for key in keys
+                .iter()
+                .map(|k| KeyBinding::parse(k).expect("preset keys parse"))
+            {
+                for info in ACTIONS.iter().filter(|info| info.action != *action) {
+                    if info.scopes.iter().any(|scope| scopes.contains(scope)) {
+                        if let Some(list) = bindings.get_mut(&info.action) {
+                            list.retain(|bound| *bound != key);
+                        }
+                    }
+                }
+            }
+        }
+        for (action, keys) in extras {
+            let list = bindings.entry(*action).or_default();
+            list.extend(
+                keys.iter()
+                    .map(|k| KeyBinding::parse(k).expect("preset keys parse")),
+            );
+        }
+        Self { bindings }
+    }
+
+    /// Build from `[keys]`; each listed action's keys replace the preset's.
+    pub fn from_config(config: &KeysConfig) -> Result<Self> {
+        let mut keymap = Self::preset(config.preset);
+        for (name, keys) in &config.bindings {
+            let action = Action::from_name(name)
+                .with_context(|| format!("Unknown action '{}' in [keys]", name))?;
+            let parsed = keys
+                .keys()
+                .into_iter()
+                .map(KeyBinding::parse)
+                .collect::<Result<Vec<_>>>()
+                .with_context(|| format!("Invalid key for '{}' in [keys]", name))?;
+            keymap.bindings.insert(action, parsed);
+        }
+        keymap.check_conflicts()?;
+        Ok(keymap)
+    }
+
+    fn check_conflicts(&self) -> Result<()> {
+        for scope in Scope::all() {
+            let mut seen: HashMap<KeyBinding, Action> = HashMap::new();
+            for info in ACTIONS.iter().filter(|info| info.scopes.contains(scope)) {
+                for key in self.keys(info.action) {
+                    if let Some(other) = seen.insert(*key, info.action) {
+                        bail!(
+                            "Key '{}' is bound to both '{}' and '{}' in [keys]",
+                            key,
+                            other.name(),
+                            info.name
+                        );
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// The action bound to a key press in `scope`.
+    pub fn action(&self, scope: Scope, code: KeyCode, modifiers: KeyModifiers) -> Option<Action> {
+        let pressed = KeyBinding::new(code, modifiers);
+        ACTIONS
+            .iter()
+            .filter(|info| info.scopes.contains(&scope))
+            .find(|info| self.keys(info.action).contains(&pressed))
+            .map(|info| info.action)
+    }
+
+    pub fn keys(&self, action: Action) -> &[KeyBinding] {
+        self.bindings.get(&action).map_or(&[], Vec::as_slice)
+    }
+
+    /// First key for an action, for footer hints.
+    pub fn hint(&self, action: Action) -> Option<String> {
+        self.keys(action).first().map(ToString::to_string)
+    }
+
+    /// Down and up keys joined for list footers, e.g. `j/k`.
+    pub fn nav_hint(&self, down: Action, up: Action) -> String {
+        [down, up]
+            .iter()
+            .filter_map(|action| self.hint(*action))
+            .collect::<Vec<_>>()
+            .join("/")
+    }
+
+    /// Navigation keys of pickers, menus and scrolling overlays.
+    pub fn picker_hint(&self) -> String {
+        self.nav_hint(Action::PickerDown, Action::PickerUp)
+    }
+
+    /// Navigation keys of lists under a text field.
+    pub fn input_hint(&self) -> String {
+        self.nav_hint(Action::InputDown, Action::InputUp)
+    }
+
+    /// Help lines per section: the action's keys joined with ` / ` and its description.
+    /// Unbound actions are left out.
+    pub fn help_sections(&self) -> Vec<(Section, Vec<(String, &'static str)>)> {
+        Section::all()
+            .iter()
+            .map(|section| {
+                let rows = ACTIONS
+                    .iter()
+                    .filter(|info| info.section == *section)
+                    .filter(|info| !self.keys(info.action).is_empty())
+                    .map(|info| {
+                        let keys: Vec<String> = self
+                            .keys(info.action)
+                            .iter()
+                            .map(ToString::to_string)
+                            .collect();
+                        (keys.join(" / "), info.description)
+                    })
+                    .collect();
+                (*section, rows)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::KeyList;
+
+    #[test]
+    fn keys_parse_and_display() {
+        let ctrl_z = KeyBinding::parse("Ctrl+Z").unwrap();
+        assert_eq!(
+            ctrl_z,
+            KeyBinding::new(KeyCode::Char('z'), KeyModifiers::CONTROL)
+        );
+        assert_eq!(ctrl_z.to_string(), "Ctrl+Z");
+        assert_eq!(KeyBinding::parse("Space").unwrap().code, KeyCode::Char(' '));
+        assert_eq!(KeyBinding::parse("F5").unwrap().code, KeyCode::F(5));
+        assert_eq!(KeyBinding::parse("+").unwrap().code, KeyCode::Char('+'));
+        assert_eq!(
+            KeyBinding::parse("Shift+Tab").unwrap().to_string(),
+            "Shift+Tab"
+        );
+        assert!(KeyBinding::parse("Hyper+x").is_err());
+        assert!(KeyBinding::parse("F13").is_err());
+    }
+
+    #[test]
+    fn default_keymap_matches_existing_keys_per_scope() {
+        let keymap = Keymap::default();
+        let none = KeyModifiers::NONE;
+        assert_eq!(
+            keymap.action(Scope::Main, KeyCode::Char('n'), none),
+            Some(Action::FocusNeedsTriage)
+        );
+        assert_eq!(
+            keymap.action(Scope::Filters, KeyCode::Char('n'), none),
+            Some(Action::NewFilter)
+        );
+        assert_eq!(
+            keymap.action(Scope::Detail, KeyCode::Char('n'), none),
+            Some(Action::NewFromTicket)
+        );
+        assert_eq!(
+            keymap.action(Scope::Main, KeyCode::Char('z'), KeyModifiers::CONTROL),
+            Some(Action::Undo)
+        );
+        assert_eq!(
+            keymap.action(Scope::Main, KeyCode::Char('A'), KeyModifiers::SHIFT),
+            Some(Action::SelectAll)
+        );
+        assert_eq!(
+            keymap.action(Scope::Filters, KeyCode::BackTab, KeyModifiers::SHIFT),
+            Some(Action::SidebarPane)
+        );
+        assert_eq!(
+            keymap.action(Scope::Filters, KeyCode::Char('d'), none),
+            None
+        );
+    }
+
+    #[test]
+    fn config_overrides_replace_preset_keys() {
+        let config = KeysConfig {
+            preset: KeyPreset::Vim,
+            bindings: [
+                (
+                    "refresh".to_string(),
+                    KeyList::Many(vec!["r".to_string(), "F5".to_string()]),
+                ),
+                ("standup".to_string(), KeyList::Many(vec![])),
+            ]
+            .into(),
+        };
+        let keymap = Keymap::from_config(&config).unwrap();
+        assert_eq!(
+            keymap.action(Scope::Filters, KeyCode::F(5), KeyModifiers::NONE),
+            Some(Action::Refresh)
+        );
+        assert_eq!(
+            keymap.action(Scope::Detail, KeyCode::Char('j'), KeyModifiers::NONE),
+            Some(Action::ScrollDown)
+        );
+        assert_eq!(
+            keymap.action(Scope::Main, KeyCode::Char('R'), KeyModifiers::NONE),
+            None
+        );
+        let navigation = &keymap.help_sections()[0].1;
+        assert!(navigation.iter().all(|(_, desc)| *desc != "standup report"));
+    }
+
+    #[test]
+    fn invalid_keys_config_is_rejected() {
+        let unknown = KeysConfig {
+            preset: KeyPreset::Default,
+            bindings: [("launch".to_string(), KeyList::One("l".to_string()))].into(),
+        };
+        assert!(Keymap::from_config(&unknown).is_err());
+
+        let clash = KeysConfig {
+            preset: KeyPreset::Default,
+            bindings: [("refresh".to_string(), KeyList::One("d".to_string()))].into(),
+        };
+        let err = Keymap::from_config(&clash).unwrap_err().to_string();
+        assert!(err.contains("'d'"), "{}", err);
+
+        for preset in [KeyPreset::Default, KeyPreset::Vim, KeyPreset::Emacs] {
+            Keymap::preset(preset).check_conflicts().unwrap();
+        }
+    }
+
+    #[test]
+    fn preset_keys_take_precedence_over_defaults() {
+        let emacs = Keymap::preset(KeyPreset::Emacs);
+        let ctrl = KeyModifiers::CONTROL;
+        assert_eq!(
+            emacs.action(Scope::Main, KeyCode::Char('p'), ctrl),
+            Some(Action::Up)
+        );
+        assert_eq!(
+            emacs.action(Scope::Detail, KeyCode::Char('p'), ctrl),
+            Some(Action::ScrollUp)
+        );
+        assert_eq!(
+            emacs.action(Scope::Detail, KeyCode::Char('x'), KeyModifiers::ALT),
+            Some(Action::CommandPalette)
+        );
+        assert_eq!(
+            Keymap::default().action(Scope::Filters, KeyCode::Char('p'), ctrl),
+            Some(Action::CommandPalette)
+        );
+        assert_eq!(
+            emacs.action(Scope::Picker, KeyCode::Char('n'), ctrl),
+            Some(Action::PickerDown)
+        );
+        assert_eq!(
+            emacs.action(Scope::Picker, KeyCode::Char('j'), KeyModifiers::NONE),
+            Some(Action::PickerDown)
+        );
+        assert_eq!(
+            emacs.action(Scope::Input, KeyCode::Char('j'), KeyModifiers::NONE),
+            None
+        );
+    }
+}
@@ -1,30 +1,47 @@
 mod app;
+mod bulk_report;
 mod bulk_upload;
+mod burndown;
 mod cache;
+mod cli;
 mod config;
+mod dashboard;
+mod git;
 mod jira_client;
+mod keymap;
+mod palette;
 mod setup;
+mod standup;
+mod theme;
+mod ui_state;
+mod view_export;
 mod views;
 mod widgets;
+mod worklog;
 
-use anyhow::Result;
+use anyhow::{Context, Result};
 use crossterm::{
-    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyModifiers},
+    event::{
+        self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyModifiers, MouseButton,
+        MouseEvent, MouseEventKind,
+    },
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
 use ratatui::{backend::CrosstermBackend, Terminal};
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::io;
 use std::process::Command;
 use std::time::Duration;
 use tokio::sync::mpsc::UnboundedSender;
 
-use crate::cache::Status;
+use crate::cache::{ActivityKind, Status};
 use crate::config::AppConfig;
+use crate::keymap::{Action, Scope};
 use app::{
-    App, BulkAction, BulkState, BulkSummary, BulkTarget, BulkUploadPreview, BulkUploadState,
-    BulkUploadSummary, DetailMode, FilterFocus, Tab, TicketSyncStage,
+    App, BulkAction, BulkPreviewRow, BulkState, BulkSummary, BulkTarget, BulkUploadPreview,
+    BulkUploadState, BulkUploadSummary, ClickTarget, DetailLayout, DetailMode, FilterFocus,
+    JournalChange, Tab, TicketSyncStage,
 };
 
 #[derive(Debug, Clone, Copy)]
@@ -42,22 +59,108 @@ enum BackgroundMessage {
     },
     TicketDetailFetched {
         key: String,
-        result: std::result::Result<crate::cache::Ticket, String>,
+        result: std::result::Result<Box<crate::cache::Ticket>, String>,
+    },
+    WatcherNamesFetched {
+        key: String,
+        result: std::result::Result<Vec<String>, String>,
     },
     TicketCreated(std::result::Result<String, String>),
+    EpicCreated(std::result::Result<String, String>),
+    EpicClosed {
+        key: String,
+        result: std::result::Result<(), String>,
+    },
+    DerivedTicketCreated {
+        derived: app::DerivedFrom,
+        result: std::result::Result<String, String>,
+        /// Set when the ticket was created but linking it back failed.
+        link_error: Option<String>,
+    },
     CommentAdded(std::result::Result<String, String>),
+    /// A single move, assign or edit; `journal` is recorded for undo only if it succeeded.
+    TicketMoved {
+        key: String,
+        status: Status,
+        journal: (String, Vec<JournalChange>),
+        result: std::result::Result<(), String>,
+    },
     TicketAssigned {
         key: String,
+        journal: (String, Vec<JournalChange>),
         result: std::result::Result<(), String>,
     },
     TicketEdited {
         key: String,
+        journal: (String, Vec<JournalChange>),
+        result: std::result::Result<(), String>,
+    },
+    WorklogAdded {
+        key: String,
+        time_spent: String,
+        result: std::result::Result<(), String>,
+    },
+    WatchToggled {
+        key: String,
+        watching: bool,
         result: std::result::Result<(), String>,
     },
     BulkCompleted(BulkSummary),
+    /// A retry of the failed tickets from `previous` finished with `retry`.
+    BulkRetryCompleted {
+        previous: BulkSummary,
+        retry: BulkSummary,
+    },
     BulkUploadPreviewReady(std::result::Result<BulkUploadPreview, String>),
     BulkUploadCompleted(BulkUploadSummary),
     FilterResults(std::result::Result<Vec<crate::cache::Ticket>, String>),
+    /// Ticket keys that have a branch on a remote, from the periodic git scan.
+    RemoteBranchesScanned(HashSet<String>),
+    /// Local commits mentioning ticket keys, from the periodic git scan.
+    CommitsScanned(HashMap<String, Vec<git::CommitRef>>),
+}
+
+/// Scan remote-tracking branches every minute while lazyjira runs inside a git repo.
+fn spawn_git_push_watch(tx: &UnboundedSender<BackgroundMessage>, project: &str) {
+    const SCAN_INTERVAL: Duration = Duration::from_secs(60);
+    let tx = tx.clone();
+    let project = project.to_string();
+    tokio::spawn(async move {
+        loop {
+            let project = project.clone();
+            let scan = tokio::task::spawn_blocking(move || git::pushed_ticket_keys(&project)).await;
+            let Ok(Ok(keys)) = scan else {
+                return;
+            };
+            if tx
+                .send(BackgroundMessage::RemoteBranchesScanned(keys))
+                .is_err()
+            {
+                return;
+            }
+            tokio::time::sleep(SCAN_INTERVAL).await;
+        }
+    });
+}
+
+/// Rescan local commit messages for ticket keys every minute while inside a git repo.
+fn spawn_git_commit_watch(tx: &UnboundedSender<BackgroundMessage>, project: &str) {
+    const SCAN_INTERVAL: Duration = Duration::from_secs(60);
+    let tx = tx.clone();
+    let project = project.to_string();
+    tokio::spawn(async move {
+        loop {
+            let project = project.clone();
+            let scan = tokio::task::spawn_blocking(move || git::ticket_commits(&project)).await;
+            let Ok(Ok(commits)) = scan else {
+                return;
+            };
+            if tx.send(BackgroundMessage::CommitsScanned(commits)).is_err() {
+                return;
+            }
+            tokio::time::sleep(SCAN_INTERVAL).await;
+        }
+    });
 }
 
 fn spawn_epics_refresh(tx: &UnboundedSender<BackgroundMessage>, config: &AppConfig) {
@@ -94,6 +197,7 @@ fn spawn_ticket_detail_fetch(tx: &UnboundedSender<BackgroundMessage>, key: Strin
     tokio::spawn(async move {
         let result = jira_client::fetch_ticket_detail(&key)
             .await
+            .map(Box::new)
             .map_err(|e| e.to_string());
         let _ = tx.send(BackgroundMessage::TicketDetailFetched { key, result });
     });
@@ -115,6 +219,7 @@ fn spawn_ticket_detail_prefetch(tx: &UnboundedSender<BackgroundMessage>, keys: V
                 tasks.spawn(async move {
                     let result = jira_client::fetch_ticket_detail(&key)
                         .await
+                        .map(Box::new)
                         .map_err(|e| e.to_string());
                     (key, result)
                 });
@@ -130,6 +235,7 @@ fn spawn_ticket_detail_prefetch(tx: &UnboundedSender<BackgroundMessage>, keys: V
                 tasks.spawn(async move {
                     let result = jira_client::fetch_ticket_detail(&next_key)
                         .await
+                        .map(Box::new)
                         .map_err(|e| e.to_string());
                     (next_key, result)
                 });
@@ -138,6 +244,38 @@ fn spawn_ticket_detail_prefetch(tx: &UnboundedSender<BackgroundMessage>, keys: V
     });
 }
 
+/// Open the epic overlay and fetch the children's history for its burndown.
+fn open_epic_detail(app: &mut App, key: String, bg_tx: &UnboundedSender<BackgroundMessage>) {
+    let prefetch_keys = app
+        .missing_epic_child_detail_keys(&key)
+        .into_iter()
+        .filter(|k| app.begin_detail_fetch(k))
+        .collect::<Vec<_>>();
+    spawn_ticket_detail_prefetch(bg_tx, prefetch_keys);
+    app.open_epic_detail(key);
+}
+
+/// Add a failed watched query to the refresh flash; the rest of the refresh succeeded.
+fn note_watched_refresh_failure(app: &mut App) {
+    if let Some(e) = app.cache.watched_error.take() {
+        let note = format!("Watching tab not refreshed: {}", e);
+        app.flash = Some(match app.flash.take() {
+            Some(flash) => format!("{} ({})", flash, note),
+            None => note,
+        });
+    }
+}
+
+/// Append config problems found at startup to the flash instead of replacing it.
+fn note_startup_warnings(app: &mut App, warnings: Vec<String>) {
+    for warning in warnings {
+        app.flash = Some(match app.flash.take() {
+            Some(flash) => format!("{} ({})", flash, warning),
+            None => warning,
+        });
+    }
+}
+
 fn queue_detail_prefetch(app: &mut App, bg_tx: &UnboundedSender<BackgroundMessage>) {
     let prefetch_keys = app
         .missing_detail_ticket_keys()
@@ -153,32 +291,139 @@ fn bulk_target_already_applied(ticket: &crate::cache::Ticket, target: &BulkTarge
         BulkTarget::Assign { member_email, .. } => {
             ticket.assignee_email.as_deref() == Some(member_email.as_str())
         }
+        BulkTarget::Epic { epic_key, .. } => &ticket.epic_key == epic_key,
+        // Labels are only known once full detail is loaded.
+        BulkTarget::AddLabels { labels } => {
+            ticket.detail_loaded && labels.iter().all(|l| ticket.labels.contains(l))
+        }
+        BulkTarget::RemoveLabels { labels } => {
+            ticket.detail_loaded && !labels.iter().any(|l| ticket.labels.contains(l))
+        }
+        BulkTarget::Priority { priority } => ticket.priority.as_deref() == Some(priority.as_str()),
+        BulkTarget::Comment { body } => {
+            let rendered = render_bulk_comment(body, ticket);
+            ticket.activity.iter().any(|entry| {
+                matches!(&entry.kind, ActivityKind::Comment { body } if body.trim() == rendered.trim())
+            })
+        }
+        BulkTarget::CustomField {
+            field_id, value, ..
+        } => ticket.custom_fields.get(field_id) == Some(value),
+        BulkTarget::Summary { summary } => &ticket.summary == summary,
+        BulkTarget::Undo { .. } => false,
+    }
+}
+
+/// Fill `{key}`, `{summary}`, `{status}` and `{assignee}` in a bulk comment template.
+fn render_bulk_comment(template: &str, ticket: &crate::cache::Ticket) -> String {
+    template
+        .replace("{key}", &ticket.key)
+        .replace("{summary}", &ticket.summary)
+        .replace("{status}", ticket.status.as_str())
+        .replace(
+            "{assignee}",
+            ticket.assignee.as_deref().unwrap_or("Unassigned"),
+        )
+}
+
+/// The target to run for one ticket; comment templates are rendered per ticket.
+fn resolve_bulk_target(app: &App, key: &str, target: &BulkTarget) -> BulkTarget {
+    match (target, app.find_ticket(key)) {
+        (BulkTarget::Comment { body }, Some(ticket)) => BulkTarget::Comment {
+            body: render_bulk_comment(body, ticket),
+        },
+        _ => target.clone(),
+    }
+}
+
+/// Split free-form label input on commas and whitespace.
+fn parse_label_input(input: &str) -> Vec<String> {
+    let mut labels: Vec<String> = Vec::new();
+    for label in input.split(|c: char| c == ',' || c.is_whitespace()) {
+        if !label.is_empty() && !labels.iter().any(|l| l == label) {
+            labels.push(label.to_string());
+        }
     }
+    labels
 }
 
 fn partition_bulk_targets(
     app: &App,
     targets: &[String],
     target: &BulkTarget,
-) -> (Vec<String>, usize) {
+) -> (Vec<String>, Vec<String>) {
     let mut attempt = Vec::new();
-    let mut skipped = 0usize;
+    let mut skipped = Vec::new();
     for key in targets {
         match app.find_ticket(key) {
-            Some(ticket) if bulk_target_already_applied(ticket, target) => skipped += 1,
-            Some(_) => attempt.push(key.clone()),
-            None => skipped += 1,
+            Some(ticket) if !bulk_target_already_applied(ticket, target) => {
+                attempt.push(key.clone())
+            }
+            _ => skipped.push(key.clone()),
         }
     }
     (attempt, skipped)
 }
 
+/// Dry-run rows for a bulk action: each ticket's current value, the planned change and
+/// why it would be skipped.
+fn bulk_dry_run_rows(app: &App, targets: &[String], target: &BulkTarget) -> Vec<BulkPreviewRow> {
+    targets
+        .iter()
+        .map(|key| match app.find_ticket(key) {
+            Some(ticket) => BulkPreviewRow {
+                key: key.clone(),
+                current: target.current_value(ticket),
+                planned: resolve_bulk_target(app, key, target).describe(),
+                skip: bulk_target_already_applied(ticket, target)
+                    .then(|| "already applied".to_string()),
+            },
+            None => BulkPreviewRow {
+                key: key.clone(),
+                current: "-".to_string(),
+                planned: target.describe(),
+                skip: Some("not in cache".to_string()),
+            },
+        })
+        .collect()
+}
+
+/// Start executing a confirmed bulk action, skipping tickets that already match.
+fn start_bulk_run(
+    app: &mut App,
+    targets: Vec<String>,
+    target: BulkTarget,
+    bg_tx: &UnboundedSender<BackgroundMessage>,
+) {
+    let (attempt_keys, skipped) = partition_bulk_targets(app, &targets, &target);
+    app.pending_bulk_journal = Some((
+        format!("Bulk: {}", target.describe()),
+        app.journal_changes_for(&attempt_keys, &target),
+    ));
+    let attempts = attempt_keys
+        .into_iter()
+        .map(|key| {
+            let resolved = resolve_bulk_target(app, &key, &target);
+            (key, resolved)
+        })
+        .collect();
+    app.flash = Some(format!(
+        "Running bulk action on {} tickets...",
+        targets.len()
+    ));
+    app.bulk_state = Some(BulkState::Running {
+        targets: targets.clone(),
+        target: target.clone(),
+    });
+    spawn_bulk_execution(bg_tx, targets, attempts, target, skipped, None);
+}
+
 fn summarize_bulk_results(
     action: BulkAction,
     target: BulkTarget,
     total: usize,
     attempted: usize,
-    skipped: usize,
+    skipped_keys: Vec<String>,
     results: Vec<(String, std::result::Result<(), String>)>,
 ) -> BulkSummary {
     let mut succeeded = 0usize;
@@ -203,76 +448,201 @@ fn summarize_bulk_results(
         total,
         attempted,
         succeeded,
-        skipped,
+        skipped: skipped_keys.len(),
         failed,
         successful_keys,
+        skipped_keys,
         failed_details,
     }
 }
 
-fn apply_bulk_successes(app: &mut App, summary: &BulkSummary) {
-    match &summary.target {
-        BulkTarget::Move { status, .. } => {
-            for key in &summary.successful_keys {
-                app.update_ticket_status(key, status.clone());
+/// Mirror a successful bulk (or undo) operation on one ticket into the cache.
+fn apply_bulk_target(app: &mut App, key: &str, target: &BulkTarget) {
+    match target {
+        BulkTarget::Move {
+            status,
+            resolution,
+            clear_resolution,
+        } => {
+            app.update_ticket_status(key, status.clone());
+            if resolution.is_some() || *clear_resolution {
+                app.edit_cached_ticket(key, |ticket| ticket.resolution = resolution.clone());
             }
         }
+        BulkTarget::Assign { member_email, .. } if member_email.is_empty() => {
+            app.edit_cached_ticket(key, |ticket| {
+                ticket.assignee = None;
+                ticket.assignee_email = None;
+            });
+        }
         BulkTarget::Assign {
             member_email,
             member_name,
-        } => {
-            for key in &summary.successful_keys {
-                app.update_ticket_assignee(key, member_name, member_email);
+        } => app.update_ticket_assignee(key, member_name, member_email),
+        BulkTarget::Epic {
+            epic_key,
+            epic_name,
+        } => app.update_ticket_epic(key, epic_key.as_deref(), epic_name.as_deref()),
+        BulkTarget::AddLabels { labels } => app.edit_cached_ticket(key, |ticket| {
+            for label in labels {
+                if !ticket.labels.contains(label) {
+                    ticket.labels.push(label.clone());
+                }
             }
+        }),
+        BulkTarget::RemoveLabels { labels } => {
+            app.edit_cached_ticket(key, |ticket| ticket.labels.retain(|l| !labels.contains(l)))
+        }
+        BulkTarget::Priority { priority } => {
+            app.edit_cached_ticket(key, |ticket| ticket.priority = Some(priority.clone()))
         }
+        // The comment shows up in activity on the next detail fetch.
+        BulkTarget::Comment { .. } => {}
+        BulkTarget::CustomField {
+            field_id, value, ..
+        } => app.edit_cached_ticket(key, |ticket| {
+            ticket.custom_fields.insert(field_id.clone(), value.clone());
+        }),
+        BulkTarget::Summary { summary } => {
+            app.edit_cached_ticket(key, |ticket| ticket.summary = summary.clone())
+        }
+        // Undo runs apply each ticket's own inverse; see `finish_bulk_run`.
+        BulkTarget::Undo { .. } => {}
+    }
+}
+
+fn apply_bulk_successes(app: &mut App, summary: &BulkSummary) {
+    for key in &summary.successful_keys {
+        apply_bulk_target(app, key, &summary.target);
+    }
+    app.clamp_selection();
+}
+
+/// Update the cache and the undo journal once a bulk or undo run completes.
+fn finish_bulk_run(app: &mut App, summary: &BulkSummary) {
+    if summary.action != BulkAction::Undo {
+        apply_bulk_successes(app, summary);
+        if let Some((label, changes)) = app.pending_bulk_journal.take() {
+            let changes = changes
+                .into_iter()
+                .filter(|c| summary.successful_keys.contains(&c.key))
+                .collect();
+            app.record_journal(label, changes);
+        }
+        return;
+    }
+
+    let Some(entry) = app.undo_in_flight.take() else {
+        return;
+    };
+    let (done, failed): (Vec<_>, Vec<_>) = entry
+        .changes
+        .into_iter()
+        .partition(|c| summary.successful_keys.contains(&c.key));
+    for change in &done {
+        apply_bulk_target(app, &change.key, &change.inverse);
     }
+    // Keep what could not be reverted so it can be retried from the history list.
+    app.record_journal(entry.label, failed);
     app.clamp_selection();
 }
 
+/// Replay the inverse operations of a journal entry as a bulk run.
+fn begin_undo(app: &mut App, index: usize, bg_tx: &UnboundedSender<BackgroundMessage>) {
+    if app.undo_in_flight.is_some() || matches!(app.bulk_state, Some(BulkState::Running { .. })) {
+        app.flash = Some("Another bulk action is still running".to_string());
+        return;
+    }
+    if index >= app.journal.len() {
+        app.flash = Some("Nothing to undo".to_string());
+        return;
+    }
+    let entry = app.journal.remove(index);
+    let targets: Vec<String> = entry.changes.iter().map(|c| c.key.clone()).collect();
+    let attempts = entry
+        .changes
+        .iter()
+        .map(|c| (c.key.clone(), c.inverse.clone()))
+        .collect();
+    let target = BulkTarget::Undo {
+        label: entry.label.clone(),
+    };
+    app.flash = Some(format!("Undoing {}...", entry.label));
+    app.undo_history = None;
+    app.bulk_state = Some(BulkState::Running {
+        targets: targets.clone(),
+        target: target.clone(),
+    });
+    app.undo_in_flight = Some(entry);
+    spawn_bulk_execution(bg_tx, targets, attempts, target, Vec::new(), None);
+}
+
+async fn run_bulk_target(key: &str, target: BulkTarget) -> Result<()> {
+    match target {
+        BulkTarget::Move {
+            status,
+            resolution,
+            clear_resolution,
+        } => match jira_client::move_ticket(key, status.as_str(), resolution.as_deref()).await {
+            Ok(()) if clear_resolution => jira_client::clear_resolution(key).await,
+            result => result,
+        },
+        BulkTarget::Assign { member_email, .. } => {
+            jira_client::assign_ticket(key, &member_email).await
+        }
+        BulkTarget::Epic { epic_key, .. } => {
+            jira_client::set_ticket_epic(key, epic_key.as_deref()).await
+        }
+        BulkTarget::AddLabels { labels } => {
+            jira_client::edit_ticket_labels(key, &labels, &[]).await
+        }
+        BulkTarget::RemoveLabels { labels } => {
+            jira_client::edit_ticket_labels(key, &[], &labels).await
+        }
+        BulkTarget::Priority { priority } => jira_client::set_ticket_priority(key, &priority).await,
+        BulkTarget::Comment { body } => jira_client::add_comment(key, &body).await,
+        BulkTarget::CustomField { name, value, .. } => {
+            jira_client::set_custom_field(key, &name, &value).await
+        }
+        BulkTarget::Summary { summary } => {
+            jira_client::edit_ticket(key, Some(&summary), None).await
+        }
+        BulkTarget::Undo { .. } => Err(anyhow::anyhow!("undo runs execute recorded inverses")),
+    }
+}
+
 fn spawn_bulk_execution(
     tx: &UnboundedSender<BackgroundMessage>,
     targets: Vec<String>,
-    attempt_keys: Vec<String>,
+    attempts: Vec<(String, BulkTarget)>,
     target: BulkTarget,
-    skipped: usize,
+    skipped: Vec<String>,
+    previous: Option<BulkSummary>,
 ) {
     const MAX_CONCURRENCY: usize = 6;
+    // Retries go slower and back off, since failures are often rate limits.
+    let (concurrency, backoff): (usize, &'static [u64]) = if previous.is_some() {
+        (RETRY_CONCURRENCY, &RETRY_BACKOFF_MS)
+    } else {
+        (MAX_CONCURRENCY, &[])
+    };
     let tx = tx.clone();
     tokio::spawn(async move {
-        let action = match target {
-            BulkTarget::Move { .. } => BulkAction::Move,
-            BulkTarget::Assign { .. } => BulkAction::Assign,
-        };
+        let action = target.action();
         let total = targets.len();
-        let attempted = attempt_keys.len();
-        if attempt_keys.is_empty() {
-            let summary =
-                summarize_bulk_results(action, target, total, attempted, skipped, Vec::new());
-            let _ = tx.send(BackgroundMessage::BulkCompleted(summary));
-            return;
-        }
-
-        let mut iter = attempt_keys.into_iter();
+        let attempted = attempts.len();
+        let mut iter = attempts.into_iter();
         let mut tasks = tokio::task::JoinSet::new();
         let mut results = Vec::new();
 
-        let initial_workers = MAX_CONCURRENCY.min(attempted);
+        let initial_workers = concurrency.min(attempted);
         for _ in 0..initial_workers {
-            if let Some(key) = iter.next() {
-                let run_target = target.clone();
+            if let Some((key, run_target)) = iter.next() {
                 tasks.spawn(async move {
-                    let result = match run_target {
-                        BulkTarget::Move { status, resolution } => {
-                            jira_client::move_ticket(&key, status.as_str(), resolution.as_deref())
-                                .await
-                                .map_err(|e| e.to_string())
-                        }
-                        BulkTarget::Assign { member_email, .. } => {
-                            jira_client::assign_ticket(&key, &member_email)
-                                .await
-                                .map_err(|e| e.to_string())
-                        }
-                    };
+                    let result =
+                        with_backoff(backoff, || run_bulk_target(&key, run_target.clone()))
+                            .await
+                            .map_err(|e| e.to_string());
                     (key, result)
                 });
             }
@@ -283,34 +653,99 @@ fn spawn_bulk_execution(
                 Ok(outcome) => results.push(outcome),
                 Err(err) => results.push(("unknown".to_string(), Err(err.to_string()))),
             }
-            if let Some(next_key) = iter.next() {
-                let run_target = target.clone();
+            if let Some((next_key, run_target)) = iter.next() {
                 tasks.spawn(async move {
-                    let result = match run_target {
-                        BulkTarget::Move { status, resolution } => jira_client::move_ticket(
-                            &next_key,
-                            status.as_str(),
-                            resolution.as_deref(),
-                        )
-                        .await
-                        .map_err(|e| e.to_string()),
-                        BulkTarget::Assign { member_email, .. } => {
-                            jira_client::assign_ticket(&next_key, &member_email)
-                                .await
-                                .map_err(|e| e.to_string())
-                        }
-                    };
+                    let result =
+                        with_backoff(backoff, || run_bulk_target(&next_key, run_target.clone()))
+                            .await
+                            .map_err(|e| e.to_string());
                     (next_key, result)
                 });
             }
         }
 
         let summary = summarize_bulk_results(action, target, total, attempted, skipped, results);
-        let _ = tx.send(BackgroundMessage::BulkCompleted(summary));
+        let _ = tx.send(match previous {
+            Some(previous) => BackgroundMessage::BulkRetryCompleted {
+                previous,
+                retry: summary,
+            },
+            None => BackgroundMessage::BulkCompleted(summary),
+        });
+    });
+}
+
+/// Delays before each re-attempt when retrying failed bulk items.
+const RETRY_BACKOFF_MS: [u64; 3] = [1_000, 2_000, 4_000];
+const RETRY_CONCURRENCY: usize = 2;
+
+/// Run `op`, re-running it after each delay in `backoff` while it fails with a retryable
+/// error.
+async fn with_backoff<T, F, Fut>(backoff: &[u64], mut op: F) -> Result<T>
+where
+    F: FnMut() -> Fut,
+    Fut: std::future::Future<Output = Result<T>>,
+{
+    let mut result = op().await;
+    for delay in backoff {
+        match &result {
+            Err(err) if jira_client::is_retryable(err) => {}
+            _ => break,
+        }
+        tokio::time::sleep(Duration::from_millis(*delay)).await;
+        result = op().await;
+    }
+    result
+}
+
+/// Fold a retry of the failed tickets into the original run's summary.
+fn merge_bulk_retry(mut previous: BulkSummary, retry: BulkSummary) -> BulkSummary {
+    previous.succeeded += retry.succeeded;
+    previous.successful_keys.extend(retry.successful_keys);
+    previous.failed = retry.failed;
+    previous.failed_details = retry.failed_details;
+    previous
+}
+
+/// Re-run only the failed tickets of a finished bulk run.
+fn begin_bulk_retry(
+    app: &mut App,
+    summary: BulkSummary,
+    bg_tx: &UnboundedSender<BackgroundMessage>,
+) {
+    if summary.failed_details.is_empty() {
+        app.flash = Some("No failed tickets to retry".to_string());
+        app.bulk_state = Some(BulkState::Result { summary });
+        return;
+    }
+    if summary.action == BulkAction::Undo {
+        app.flash = Some("Failed undo changes stay in the undo history (H)".to_string());
+        app.bulk_state = Some(BulkState::Result { summary });
+        return;
+    }
+    let target = summary.target.clone();
+    let keys: Vec<String> = summary
+        .failed_details
+        .iter()
+        .map(|(key, _)| key.clone())
+        .collect();
+    app.pending_bulk_journal = Some((
+        format!("Bulk: {}", target.describe()),
+        app.journal_changes_for(&keys, &target),
+    ));
+    let attempts = keys
+        .iter()
+        .map(|key| (key.clone(), resolve_bulk_target(app, key, &target)))
+        .collect();
+    app.flash = Some(format!("Retrying {} failed tickets...", keys.len()));
+    app.bulk_state = Some(BulkState::Running {
+        targets: keys.clone(),
+        target: target.clone(),
     });
+    spawn_bulk_execution(bg_tx, keys, attempts, target, Vec::new(), Some(summary));
 }
 
-fn build_bulk_upload_context(app: &App) -> bulk_upload::BulkUploadContext {
+fn build_bulk_upload_context(app: &App, config: &AppConfig) -> bulk_upload::BulkUploadContext {
     let known_epic_keys: HashSet<String> = app
         .cache
         .epics
@@ -332,7 +767,46 @@ fn build_bulk_upload_context(app: &App) -> bulk_upload::BulkUploadContext {
         }
     }
 
-    bulk_upload::BulkUploadContext::new(known_epic_keys, existing_summaries)
+    let mut context = bulk_upload::BulkUploadContext::new(known_epic_keys, existing_summaries);
+    for ticket in app
+        .cache
+        .my_tickets
+        .iter()
+        .chain(app.cache.team_tickets.iter())
+        .chain(app.cache.watched_tickets.iter())
+        .chain(app.cache.epics.iter().flat_map(|e| e.children.iter()))
+        .chain(app.filter_results.iter())
+        .chain(app.adhoc_tickets.iter())
+    {
+        context
+            .existing_tickets
+            .entry(ticket.key.clone())
+            .or_insert_with(|| ticket.clone());
+    }
+    context.custom_fields = config.custom_fields.clone();
+    for member in &app.cache.team_members {
+        let email = member.email.clone();
+        let local = email
+            .split('@')
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+        let name = member.name.to_ascii_lowercase();
+        let first = name
+            .split_whitespace()
+            .next()
+            .unwrap_or_default()
+            .to_string();
+        for alias in [local, first, name.replace(' ', "")] {
+            if !alias.is_empty() {
+                context
+                    .assignees
+                    .entry(alias)
+                    .or_insert_with(|| email.clone());
+            }
+        }
+    }
+    context
 }
 
 fn spawn_bulk_upload_preview(
@@ -342,68 +816,158 @@ fn spawn_bulk_upload_preview(
 ) {
     let tx = tx.clone();
     tokio::spawn(async move {
-        let result = bulk_upload::parse_csv_preview(&path, &context).map_err(|e| e.to_string());
+        let result = bulk_upload::parse_preview(&path, &context).map_err(|e| e.to_string());
         let _ = tx.send(BackgroundMessage::BulkUploadPreviewReady(result));
     });
 }
 
+/// Create (or, for rows with a key, update) the valid rows of `preview`. With `previous`,
+/// this is a retry of its failed rows: each call backs off on failure and the outcome is
+/// merged into `previous`.
 fn spawn_bulk_upload_execution(
     tx: &UnboundedSender<BackgroundMessage>,
     preview: BulkUploadPreview,
     project: String,
+    previous: Option<BulkUploadSummary>,
 ) {
+    let backoff: &'static [u64] = if previous.is_some() {
+        &RETRY_BACKOFF_MS
+    } else {
+        &[]
+    };
     let tx = tx.clone();
     tokio::spawn(async move {
-        let mut created_keys = Vec::new();
-        let mut failed_details = Vec::new();
-        let attempt_rows = preview
-            .rows
-            .iter()
-            .filter(|row| row.errors.is_empty())
-            .cloned()
-            .collect::<Vec<_>>();
-        let attempted = attempt_rows.len();
+        let summary = execute_bulk_upload(preview, &project, backoff).await;
+        let summary = match previous {
+            Some(previous) => merge_upload_retry(previous, summary),
+            None => summary,
+        };
+        let _ = tx.send(BackgroundMessage::BulkUploadCompleted(summary));
+    });
+}
 
-        for row in attempt_rows {
-            let labels = if row.labels.is_empty() {
-                None
-            } else {
-                Some(row.labels.as_slice())
-            };
+/// Create or update the valid, non-no-op rows of `preview` one at a time.
+async fn execute_bulk_upload(
+    preview: BulkUploadPreview,
+    project: &str,
+    backoff: &[u64],
+) -> BulkUploadSummary {
+    let mut created_keys = Vec::new();
+    let mut updated_keys = Vec::new();
+    let mut failed_details = Vec::new();
+    let attempt_rows = preview
+        .rows
+        .iter()
+        .filter(|row| row.errors.is_empty() && !row.is_noop_update())
+        .cloned()
+        .collect::<Vec<_>>();
+    let attempted = attempt_rows.len();
 
-            let result = jira_client::create_ticket_with_fields(
-                &project,
-                row.issue_type.as_str(),
-                row.summary.as_str(),
-                row.assignee_email.as_deref(),
-                row.epic_key.as_deref(),
-                row.description.as_deref(),
-                labels,
-            )
-            .await
-            .map_err(|e| e.to_string());
+    for row in attempt_rows {
+        let ticket = upload_row_ticket(&row);
+        let result = with_backoff(backoff, || async {
+            match row.key.as_deref() {
+                Some(key) => jira_client::update_ticket_with_fields(key, &ticket)
+                    .await
+                    .map(|()| key.to_string()),
+                None => jira_client::create_ticket_with_fields(project, &ticket).await,
+            }
+        })
+        .await
+        .map_err(|e| e.to_string());
 
-            match result {
-                Ok(key) => created_keys.push(key),
-                Err(err) => failed_details.push((row.row_number, row.summary, err)),
+        match (result, row.key.is_some()) {
+            (Ok(key), true) => updated_keys.push(key),
+            (Ok(key), false) => created_keys.push(key),
+            (Err(err), _) => {
+                let label = row.key.clone().unwrap_or(row.summary);
+                failed_details.push((row.row_number, label, err));
             }
         }
+    }
 
-        let summary = BulkUploadSummary {
-            source_path: preview.source_path,
-            total_rows: preview.total_rows,
-            attempted,
-            succeeded: created_keys.len(),
-            failed: failed_details.len(),
-            created_keys,
-            failed_details,
-        };
-        let _ = tx.send(BackgroundMessage::BulkUploadCompleted(summary));
-    });
+    BulkUploadSummary {
+        source_path: preview.source_path,
+        total_rows: preview.total_rows,
+        attempted,
+        succeeded: created_keys.len() + updated_keys.len(),
+        failed: failed_details.len(),
+        created_keys,
+        updated_keys,
+        failed_details,
+    }
+}
+
+fn upload_row_ticket(row: &app::BulkUploadRow) -> jira_client::NewTicket {
+    jira_client::NewTicket {
+        issue_type: row.issue_type.clone(),
+        summary: row.summary.clone(),
+        assignee_email: row.assignee_email.clone(),
+        epic_key: row.epic_key.clone(),
+        parent_key: row.parent_key.clone(),
+        description: row.description.clone(),
+        labels: row.labels.clone(),
+        priority: row.priority.clone(),
+        components: row.components.clone(),
+        story_points: row.story_points.as_deref().and_then(|p| p.parse().ok()),
+        due_date: row.due_date.clone(),
+        custom_fields: row.custom_fields.clone(),
+    }
+}
+
+fn merge_upload_retry(
+    mut previous: BulkUploadSummary,
+    retry: BulkUploadSummary,
+) -> BulkUploadSummary {
+    previous.succeeded += retry.succeeded;
+    previous.created_keys.extend(retry.created_keys);
+    previous.updated_keys.extend(retry.updated_keys);
+    previous.failed = retry.failed;
+    previous.failed_details = retry.failed_details;
+    previous
+}
+
+/// Upload only the rows of `preview` that failed in `summary`.
+fn begin_upload_retry(
+    app: &mut App,
+    summary: BulkUploadSummary,
+    preview: BulkUploadPreview,
+    bg_tx: &UnboundedSender<BackgroundMessage>,
+    config: &AppConfig,
+) {
+    let failed_rows: HashSet<usize> = summary
+        .failed_details
+        .iter()
+        .map(|(row_number, _, _)| *row_number)
+        .collect();
+    if failed_rows.is_empty() {
+        app.flash = Some("No failed rows to retry".to_string());
+        app.bulk_upload_state = Some(BulkUploadState::Result { summary, preview });
+        return;
+    }
+    let retry = BulkUploadPreview {
+        rows: preview
+            .rows
+            .iter()
+            .filter(|row| failed_rows.contains(&row.row_number))
+            .cloned()
+            .collect(),
+        ..preview.clone()
+    };
+    app.flash = Some(format!("Retrying {} failed rows...", failed_rows.len()));
+    app.bulk_upload_state = Some(BulkUploadState::Running { preview });
+    spawn_bulk_upload_execution(bg_tx, retry, config.jira.project.clone(), Some(summary));
 }
 
 #[tokio::main]
 async fn main() -> Result<()> {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if let Some(command) = cli::parse(&args)? {
+        let config = config::load_config()?
+            .context("No config found; run lazyjira once to complete setup")?;
+        return cli::run(command, &config).await;
+    }
+
     maybe_run_dev_mode()?;
 
     // Setup terminal
@@ -413,6 +977,10 @@ async fn main() -> Result<()> {
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
+    let no_color = theme::no_color_requested();
+    if no_color {
+        theme::set(theme::Theme::NO_COLOR);
+    }
     let mut config = match config::load_config()? {
         Some(config) => config,
         None => setup::run_setup(&mut terminal).await?,
@@ -420,6 +988,21 @@ async fn main() -> Result<()> {
 
     let mut app = App::new();
     app.set_epics_i_care_about(config.epics_i_care_about_ordered());
+    app.restore_ui_state(ui_state::load(), config.filters.len());
+    app.recently_viewed = jira_client::load_recently_viewed(&config.jira.project);
+    // Shown once the cache-load flash is set, which would otherwise replace them.
+    let mut startup_warnings = Vec::new();
+    match keymap::Keymap::from_config(&config.keys) {
+        Ok(keymap) => app.keymap = keymap,
+        Err(e) => startup_warnings.push(format!("{:#}; using default keys", e)),
+    }
+    match theme::Theme::from_config(&config.theme, no_color) {
+        Ok(theme) => theme::set(theme),
+        Err(e) => {
+            theme::set(theme::Theme::preset(config.theme.name));
+            startup_warnings.push(format!("{:#}; using the {} theme", e, config.theme.name));
+        }
+    }
     let (bg_tx, mut bg_rx) = tokio::sync::mpsc::unbounded_channel();
     let detail_cache_tx = jira_client::spawn_detail_cache_writer(&config.jira.project);
 
@@ -437,14 +1020,29 @@ async fn main() -> Result<()> {
         app.loading = false;
         app.ticket_sync_stage = Some(TicketSyncStage::Full);
         app.flash = Some("Loaded active tickets. Syncing recently done...".to_string());
+        note_watched_refresh_failure(&mut app);
         spawn_cache_refresh(&bg_tx, CacheRefreshPhase::Full, &config);
     }
+    note_startup_warnings(&mut app, startup_warnings);
 
     spawn_epics_refresh(&bg_tx, &config);
     app.epics_refreshing = true;
     queue_detail_prefetch(&mut app, &bg_tx);
 
+    app.branch_ticket_key = git::current_branch()
+        .and_then(|branch| git::find_ticket_key(&branch, &config.jira.project));
+    if config.git.open_branch_ticket {
+        if let Some(key) = app.branch_ticket_key.clone() {
+            open_ticket_by_key(&mut app, key, &bg_tx);
+        }
+    }
+    if config.git.review_on_push {
+        spawn_git_push_watch(&bg_tx, &config.jira.project);
+    }
+    spawn_git_commit_watch(&bg_tx, &config.jira.project);
+
     let mut draw_needed = true;
+    let mut last_timer_minute: Option<u64> = None;
 
     // Main loop
     loop {
@@ -461,6 +1059,11 @@ async fn main() -> Result<()> {
                                 &mut app.cache.team_tickets,
                                 &epics,
                             );
+                            jira_client::attach_epics_to_tickets(
+                                &mut app.cache.watched_tickets,
+                                &mut [],
+                                &epics,
+                            );
                             app.cache.epics = epics;
                             app.mark_cache_changed();
                             app.clamp_selection();
@@ -482,6 +1085,7 @@ async fn main() -> Result<()> {
                         queue_detail_prefetch(&mut app, &bg_tx);
                         app.flash =
                             Some("Active tickets refreshed. Syncing recently done...".to_string());
+                        note_watched_refresh_failure(&mut app);
                         spawn_cache_refresh(&bg_tx, CacheRefreshPhase::Full, &config);
                     }
                     (CacheRefreshPhase::ActiveOnly, Err(e))
@@ -509,6 +1113,7 @@ async fn main() -> Result<()> {
                         } else {
                             app.flash = Some("Ticket cache is up to date".to_string());
                         }
+                        note_watched_refresh_failure(&mut app);
                     }
                     (CacheRefreshPhase::Full, Err(e))
                         if app.ticket_sync_stage == Some(TicketSyncStage::Full) =>
@@ -531,6 +1136,7 @@ async fn main() -> Result<()> {
                             app.flash =
                                 Some("Refreshed! Syncing epic relationships...".to_string());
                         }
+                        note_watched_refresh_failure(&mut app);
                         if !app.epics_refreshing {
                             app.epics_refreshing = true;
                             spawn_epics_refresh(&bg_tx, &config);
@@ -544,18 +1150,35 @@ async fn main() -> Result<()> {
                 },
                 BackgroundMessage::TicketDetailFetched { key, result } => {
                     app.end_detail_fetch(&key);
-                    match result {
-                        Ok(detail) => {
-                            app.enrich_ticket(&key, &detail);
-                            if detail_cache_tx.send(detail).is_err() {
-                                app.flash = Some(
-                                    "Detail cache writer unavailable; skipping write".to_string(),
-                                );
+                    let open_when_ready = app.pending_detail_open.as_deref() == Some(key.as_str());
+                    if open_when_ready {
+                        app.pending_detail_open = None;
+                        match &result {
+                            Ok(detail) => {
+                                app.insert_adhoc_ticket((**detail).clone());
+                                app.open_detail(key.clone());
                             }
+                            Err(e) => {
+                                app.flash = Some(format!("Could not open {}: {}", key, e));
+                            }
+                        }
+                    }
+                    if let Ok(detail) = result {
+                        app.enrich_ticket(&key, &detail);
+                        if detail_cache_tx.send(*detail).is_err() {
+                            app.flash =
+                                Some("Detail cache writer unavailable; skipping write".to_string());
                         }
-                        Err(_) => {}
                     }
                 }
+                BackgroundMessage::WatcherNamesFetched { key, result } => match result {
+                    Ok(names) => app.set_watcher_names(&key, names),
+                    Err(e) => {
+                        if app.detail_ticket_key.as_deref() == Some(key.as_str()) {
+                            app.flash = Some(format!("Watcher list unavailable: {}", e));
+                        }
+                    }
+                },
                 BackgroundMessage::TicketCreated(result) => {
                     match result {
                         Ok(key) => {
@@ -572,6 +1195,59 @@ async fn main() -> Result<()> {
                         }
                     }
                 }
+                BackgroundMessage::EpicCreated(result) => match result {
+                    Ok(key) => {
+                        app.flash = Some(format!("Created epic {}", key));
+                        if !app.epics_refreshing {
+                            app.epics_refreshing = true;
+                            spawn_epics_refresh(&bg_tx, &config);
+                        }
+                    }
+                    Err(e) => {
+                        app.flash = Some(format!("Create failed: {}", e));
+                    }
+                },
+                BackgroundMessage::EpicClosed { key, result } => match result {
+                    Ok(()) => {
+                        app.remove_epic(&key);
+                        app.clamp_selection();
+                        app.flash = Some(format!("Closed epic {}", key));
+                    }
+                    Err(e) => {
+                        app.flash = Some(format!("Closing {} failed: {}", key, e));
+                    }
+                },
+                BackgroundMessage::DerivedTicketCreated {
+                    derived,
+                    result,
+                    link_error,
+                } => match result {
+                    Ok(key) => {
+                        let relation = match derived.kind {
+                            app::DeriveKind::Clone => "clone of",
+                            app::DeriveKind::SubTask => "sub-task of",
+                            app::DeriveKind::FollowUp => "follow-up to",
+                        };
+                        open_ticket_by_key(&mut app, key.clone(), &bg_tx);
+                        app.flash = Some(match link_error {
+                            Some(e) => format!(
+                                "Created {} but linking to {} failed: {}",
+                                key, derived.source_key, e
+                            ),
+                            None => {
+                                format!("Created {} ({} {})", key, relation, derived.source_key)
+                            }
+                        });
+                        if !app.loading {
+                            app.loading = true;
+                            app.ticket_sync_stage = None;
+                            spawn_cache_refresh(&bg_tx, CacheRefreshPhase::Manual, &config);
+                        }
+                    }
+                    Err(e) => {
+                        app.flash = Some(format!("Create failed: {}", e));
+                    }
+                },
                 BackgroundMessage::CommentAdded(result) => match result {
                     Ok(key) => {
                         app.flash = Some(format!("Comment added to {}", key));
@@ -580,34 +1256,100 @@ async fn main() -> Result<()> {
                         app.flash = Some(format!("Comment failed: {}", e));
                     }
                 },
-                BackgroundMessage::TicketAssigned { key, result } => match result {
+                BackgroundMessage::TicketMoved {
+                    key,
+                    status,
+                    journal: (label, changes),
+                    result,
+                } => match result {
+                    Ok(()) => {
+                        app.record_journal(label, changes);
+                        app.flash = Some(format!("Moved {} to {}.", key, status.as_str()));
+                        sync_work_timer_after_move(&mut app, &key, &status);
+                    }
+                    Err(e) => {
+                        app.flash = Some(format!("Move failed for {}: {}", key, e));
+                    }
+                },
+                BackgroundMessage::TicketAssigned {
+                    key,
+                    journal: (label, changes),
+                    result,
+                } => match result {
                     Ok(()) => {
+                        app.record_journal(label, changes);
                         app.flash = Some(format!("Assigned {}", key));
                     }
                     Err(e) => {
                         app.flash = Some(format!("Assign failed for {}: {}", key, e));
                     }
                 },
-                BackgroundMessage::TicketEdited { key, result } => match result {
+                BackgroundMessage::TicketEdited {
+                    key,
+                    journal: (label, changes),
+                    result,
+                } => match result {
                     Ok(()) => {
+                        app.record_journal(label, changes);
                         app.flash = Some(format!("Updated {}", key));
                     }
                     Err(e) => {
                         app.flash = Some(format!("Edit failed for {}: {}", key, e));
                     }
                 },
+                BackgroundMessage::WorklogAdded {
+                    key,
+                    time_spent,
+                    result,
+                } => match result {
+                    Ok(()) => {
+                        app.flash = Some(format!("Logged {} on {}", time_spent, key));
+                        if app.begin_detail_fetch(&key) {
+                            spawn_ticket_detail_fetch(&bg_tx, key);
+                        }
+                    }
+                    Err(e) => {
+                        app.flash = Some(format!("Log work failed for {}: {}", key, e));
+                    }
+                },
+                BackgroundMessage::WatchToggled {
+                    key,
+                    watching,
+                    result,
+                } => match result {
+                    Ok(()) if watching => {
+                        app.flash = Some(format!("Watching {}", key));
+                    }
+                    Ok(()) => {
+                        app.flash = Some(format!("Stopped watching {}", key));
+                    }
+                    Err(e) => {
+                        // Roll back the optimistic update.
+                        app.set_watching(&key, !watching);
+                        app.flash = Some(format!("Watch update failed for {}: {}", key, e));
+                    }
+                },
                 BackgroundMessage::BulkCompleted(summary) => {
-                    apply_bulk_successes(&mut app, &summary);
-                    let action_label = match summary.action {
-                        BulkAction::Move => "move",
-                        BulkAction::Assign => "assign",
-                    };
+                    finish_bulk_run(&mut app, &summary);
                     app.flash = Some(format!(
                         "Bulk {} complete: {} succeeded, {} failed, {} skipped",
-                        action_label, summary.succeeded, summary.failed, summary.skipped
+                        summary.action.label().to_lowercase(),
+                        summary.succeeded,
+                        summary.failed,
+                        summary.skipped
                     ));
                     app.bulk_state = Some(BulkState::Result { summary });
                 }
+                BackgroundMessage::BulkRetryCompleted { previous, retry } => {
+                    finish_bulk_run(&mut app, &retry);
+                    app.flash = Some(format!(
+                        "Retry complete: {} succeeded, {} still failing",
+                        retry.succeeded, retry.failed
+                    ));
+                    app.bulk_state = Some(BulkState::Result {
+                        summary: merge_bulk_retry(previous, retry),
+                    });
+                }
                 BackgroundMessage::BulkUploadPreviewReady(result) => match result {
                     Ok(preview) => {
                         let total_rows = preview.total_rows;
@@ -627,14 +1369,15 @@ async fn main() -> Result<()> {
                         {
                             *loading = false;
                         }
-                        app.flash = Some(format!("CSV preview failed: {}", e));
+                        app.flash = Some(format!("Upload preview failed: {}", e));
                     }
                 },
                 BackgroundMessage::BulkUploadCompleted(summary) => {
                     let succeeded = summary.succeeded;
                     let failed = summary.failed;
-                    if matches!(app.bulk_upload_state, Some(BulkUploadState::Running { .. })) {
-                        app.bulk_upload_state = Some(BulkUploadState::Result { summary });
+                    if let Some(BulkUploadState::Running { preview }) = app.bulk_upload_state.take()
+                    {
+                        app.bulk_upload_state = Some(BulkUploadState::Result { summary, preview });
                     }
                     app.flash = Some(format!(
                         "Bulk upload complete: {} succeeded, {} failed",
@@ -646,6 +1389,13 @@ async fn main() -> Result<()> {
                         spawn_cache_refresh(&bg_tx, CacheRefreshPhase::Manual, &config);
                     }
                 }
+                BackgroundMessage::RemoteBranchesScanned(keys) => {
+                    let keys = app.newly_pushed_review_keys(keys);
+                    app.queue_review_prompts(keys);
+                }
+                BackgroundMessage::CommitsScanned(commits) => {
+                    app.ticket_commits = commits;
+                }
                 BackgroundMessage::FilterResults(result) => {
                     app.filter_loading = false;
                     match result {
@@ -669,9 +1419,19 @@ async fn main() -> Result<()> {
             }
         }
 
+        request_watcher_names(&mut app, &bg_tx);
         if state_changed {
             draw_needed = true;
         }
+        // Keep the running work timer display ticking without a keypress.
+        let timer_minute = app
+            .work_timer
+            .as_ref()
+            .map(|timer| timer.elapsed_secs(jira_client::now_unix_secs()) / 60);
+        if timer_minute != last_timer_minute {
+            last_timer_minute = timer_minute;
+            draw_needed = true;
+        }
         if draw_needed {
             terminal.draw(|f| ui(f, &app, &config))?;
             draw_needed = false;
@@ -686,7 +1446,7 @@ async fn main() -> Result<()> {
                     if app.is_filter_edit_open() {
                         handle_filter_edit_keys(&mut app, key.code, &mut config);
                     } else if app.is_bulk_upload_open() {
-                        handle_bulk_upload_keys(&mut app, key.code, &bg_tx, &config);
+                        handle_bulk_upload_keys(&mut app, key.code, key.modifiers, &bg_tx, &config);
                     } else if app.is_create_ticket_open() {
                         handle_create_ticket_keys(
                             &mut app,
@@ -699,27 +1459,53 @@ async fn main() -> Result<()> {
                     } else if app.is_comment_open() {
                         handle_comment_keys(&mut app, key.code, key.modifiers, &bg_tx);
                     } else if app.is_assign_open() {
-                        handle_assign_keys(&mut app, key.code, &bg_tx);
+                        handle_assign_keys(&mut app, key.code, key.modifiers, &bg_tx);
                     } else if app.is_edit_open() {
                         handle_edit_keys(&mut app, key.code, &bg_tx);
+                    } else if app.is_worklog_open() {
+                        handle_worklog_keys(&mut app, key.code, &bg_tx);
                     } else if app.is_bulk_open() {
-                        handle_bulk_keys(&mut app, key.code, &bg_tx, &config);
+                        handle_bulk_keys(&mut app, key.code, key.modifiers, &bg_tx, &config);
+                    } else if app.is_export_picker_open() {
+                        handle_export_picker_keys(&mut app, key.code, key.modifiers);
+                    } else if app.is_undo_history_open() {
+                        handle_undo_history_keys(&mut app, key.code, key.modifiers, &bg_tx);
+                    } else if app.is_standup_open() {
+                        handle_standup_keys(&mut app, key.code, key.modifiers);
+                    } else if app.is_git_branch_open() {
+                        handle_git_branch_keys(&mut app, key.code);
+                    } else if app.is_command_palette_open() {
+                        handle_command_palette_keys(
+                            &mut app,
+                            key.code,
+                            key.modifiers,
+                            &bg_tx,
+                            &mut config,
+                        );
+                    } else if app.is_goto_key_open() {
+                        handle_goto_key_keys(&mut app, key.code, key.modifiers, &bg_tx, &config);
                     } else if app.show_keybindings {
-                        handle_keybindings_keys(&mut app, key.code);
+                        handle_keybindings_keys(&mut app, key.code, key.modifiers);
+                    } else if app.is_review_prompt_open() {
+                        handle_review_prompt_keys(&mut app, key.code, &bg_tx);
                     } else if app.is_detail_open() {
-                        handle_detail_keys(&mut app, key.code, &config);
+                        handle_detail_keys(&mut app, key.code, key.modifiers, &bg_tx, &config);
                     } else if app.search.is_some() {
                         handle_search_keys(&mut app, key.code, key.modifiers, &bg_tx).await;
                     } else if app.active_tab == Tab::Filters {
-                        handle_filter_keys(&mut app, key.code, &bg_tx, &mut config);
+                        handle_filter_keys(&mut app, key.code, key.modifiers, &bg_tx, &mut config);
                     } else {
                         handle_main_keys(&mut app, key.code, key.modifiers, &bg_tx, &config).await;
                     }
                     draw_needed = true;
                 }
+                Event::Mouse(mouse) => {
+                    draw_needed |= handle_mouse(&mut app, mouse, &bg_tx, &config);
+                }
                 Event::Resize(_, _) => draw_needed = true,
                 _ => {}
             }
+            request_preview_detail(&mut app, &bg_tx);
         }
 
         if app.should_quit {
@@ -736,6 +1522,13 @@ async fn main() -> Result<()> {
     )?;
     terminal.show_cursor()?;
 
+    if let Err(e) = ui_state::save(&app.ui_state()) {
+        eprintln!("Failed to save UI state: {:#}", e);
+    }
+    if let Err(e) = jira_client::save_recently_viewed(&config.jira.project, &app.recently_viewed) {
+        eprintln!("Failed to save recently viewed: {:#}", e);
+    }
+
     Ok(())
 }
 
@@ -762,6 +1555,8 @@ fn maybe_run_dev_mode() -> Result<()> {
         println!("  --dev, --rebuild   Build from source and run (debug)");
         println!("  --dev-release      Build from source and run (release)");
         println!("  -h, --help         Show this help");
+        println!();
+        println!("{}", cli::USAGE);
         std::process::exit(0);
     }
 
@@ -800,10 +1595,11 @@ fn format_age_minutes(age_secs: u64) -> String {
 
 fn ui(f: &mut ratatui::Frame, app: &App, config: &AppConfig) {
     use ratatui::layout::{Constraint, Direction, Layout};
-    use ratatui::style::{Color, Modifier, Style};
+    use ratatui::style::{Modifier, Style};
     use ratatui::text::{Line, Span};
     use ratatui::widgets::{Block, Borders, Tabs};
 
+    let theme = theme::current();
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
@@ -812,9 +1608,20 @@ fn ui(f: &mut ratatui::Frame, app: &App, config: &AppConfig) {
             Constraint::Length(1), // Status bar
         ])
         .split(f.area());
+    app.clear_click_targets();
 
     // Tab bar
     let tab_titles: Vec<Line> = Tab::all().iter().map(|t| Line::from(t.title())).collect();
+    // Titles are padded by one space each side and separated by a one-column divider.
+    let mut tab_x = chunks[0].x + 1;
+    for tab in Tab::all() {
+        let width = tab.title().chars().count() as u16 + 2;
+        app.add_click_target(
+            ratatui::layout::Rect::new(tab_x, chunks[0].y + 1, width, 1),
+            ClickTarget::Tab(*tab),
+        );
+        tab_x += width + 1;
+    }
     let tabs = Tabs::new(tab_titles)
         .block(Block::default().borders(Borders::ALL).title(" lazyjira "))
         .select(match app.active_tab {
@@ -822,36 +1629,54 @@ fn ui(f: &mut ratatui::Frame, app: &App, config: &AppConfig) {
             Tab::Team => 1,
             Tab::Epics => 2,
             Tab::Unassigned => 3,
-            Tab::Filters => 4,
+            Tab::Watching => 4,
+            Tab::Filters => 5,
+            Tab::Dashboard => 6,
         })
-        .style(Style::default().fg(Color::Gray))
+        .style(Style::default().fg(theme.subtle))
         .highlight_style(
             Style::default()
-                .fg(Color::Yellow)
+                .fg(theme.highlight)
                 .add_modifier(Modifier::BOLD),
         );
     f.render_widget(tabs, chunks[0]);
 
-    // Content area
+    // Content area, with the preview pane on the right in split view
+    let (list_area, preview_area) = if app.is_split_view() {
+        let panes = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(chunks[1]);
+        (panes[0], Some(panes[1]))
+    } else {
+        (chunks[1], None)
+    };
     if app.loading {
         let loading = ratatui::widgets::Paragraph::new("Loading...")
             .block(Block::default().borders(Borders::ALL));
-        f.render_widget(loading, chunks[1]);
+        f.render_widget(loading, list_area);
     } else {
         match app.active_tab {
-            Tab::MyWork => views::my_work::render(f, chunks[1], app),
-            Tab::Team => views::team::render(f, chunks[1], app),
-            Tab::Epics => views::epics::render(f, chunks[1], app),
-            Tab::Unassigned => views::unassigned::render(f, chunks[1], app),
-            Tab::Filters => views::filters::render(f, chunks[1], app, config),
+            Tab::MyWork => views::my_work::render(f, list_area, app),
+            Tab::Team => views::team::render(f, list_area, app),
+            Tab::Epics => views::epics::render(f, list_area, app),
+            Tab::Unassigned => views::unassigned::render(f, list_area, app),
+            Tab::Watching => views::watching::render(f, list_area, app),
+            Tab::Filters => views::filters::render(f, list_area, app, config),
+            Tab::Dashboard => views::dashboard::render(f, list_area, app, config),
+        }
+    }
+    if let Some(area) = preview_area {
+        if !app.loading && !app.is_detail_open() {
+            widgets::ticket_detail::render_preview(f, app, area);
         }
     }
 
     // Status bar
     let status_text = if let Some(ref flash) = app.flash {
-        Span::styled(flash.as_str(), Style::default().fg(Color::Red))
+        Span::styled(flash.as_str(), Style::default().fg(theme.error))
     } else if let Some(ref search) = app.search {
-        Span::styled(format!("/{}", search), Style::default().fg(Color::Yellow))
+        Span::styled(format!("/{}", search), Style::default().fg(theme.highlight))
     } else {
         let selected_count = app.selected_ticket_count();
         if app.active_tab == Tab::Filters {
@@ -859,12 +1684,30 @@ fn ui(f: &mut ratatui::Frame, app: &App, config: &AppConfig) {
                 FilterFocus::Sidebar => "sidebar",
                 FilterFocus::Results => "results",
             };
+            let k = |action| app.keymap.hint(action).unwrap_or_else(|| "-".to_string());
             Span::styled(
                 format!(
-                    " j/k: navigate  Space: mark  A: all  u: clear  B: bulk  U: upload  sel:{}  Tab/S-Tab: switch pane({})  Enter: run/open  n: new  e: edit  x: delete  ?: keys  q: quit ",
-                    selected_count, pane
+                    " {}/{}: navigate  {}: mark  {}: all  {}: clear  {}: bulk  {}: upload  sel:{}  {}/{}: switch pane({})  {}: run/open  {}: new  {}: edit  {}: delete  {}: commands  {}: keys  {}: quit ",
+                    k(Action::Down),
+                    k(Action::Up),
+                    k(Action::ToggleSelect),
+                    k(Action::SelectAll),
+                    k(Action::ClearSelection),
+                    k(Action::BulkActions),
+                    k(Action::BulkUpload),
+                    selected_count,
+                    k(Action::NextTab),
+                    k(Action::SidebarPane),
+                    pane,
+                    k(Action::Open),
+                    k(Action::NewFilter),
+                    k(Action::EditFilter),
+                    k(Action::DeleteFilter),
+                    k(Action::CommandPalette),
+                    k(Action::Help),
+                    k(Action::Quit),
                 ),
-                Style::default().fg(Color::DarkGray),
+                Style::default().fg(theme.muted),
             )
         } else {
             let done_state = if app.show_done { "on" } else { "off" };
@@ -887,26 +1730,75 @@ fn ui(f: &mut ratatui::Frame, app: &App, config: &AppConfig) {
                 .as_ref()
                 .map(|s| s.as_str())
                 .unwrap_or("all");
+            let k = |action| app.keymap.hint(action).unwrap_or_else(|| "-".to_string());
             Span::styled(
                 format!(
-                    " Tab: switch  j/k: navigate  Space: mark  A: all  u: clear  B: bulk  U: upload  sel:{}  Enter: detail  z: fold  d: done({})  p/w/n/v: focus({})  ?: keys  t:{}  c:{}  e:{}  r: refresh  /: search  q: quit ",
-                    selected_count, done_state, focus_state, ticket_state, freshness_state, epic_state
+                    " {}: switch  {}/{}: navigate  {}: mark  {}: all  {}: clear  {}: bulk  {}: upload  sel:{}  {}: detail  {}: fold  {}: done({})  {}/{}/{}/{}: focus({})  {}: commands  {}: keys  t:{}  c:{}  e:{}  {}: refresh  {}: search  {}: quit ",
+                    k(Action::NextTab),
+                    k(Action::Down),
+                    k(Action::Up),
+                    k(Action::ToggleSelect),
+                    k(Action::SelectAll),
+                    k(Action::ClearSelection),
+                    k(Action::BulkActions),
+                    k(Action::BulkUpload),
+                    selected_count,
+                    k(Action::Open),
+                    k(Action::FoldGroup),
+                    k(Action::ToggleDone),
+                    done_state,
+                    k(Action::FocusInProgress),
+                    k(Action::FocusReadyForWork),
+                    k(Action::FocusNeedsTriage),
+                    k(Action::FocusInReview),
+                    focus_state,
+                    k(Action::CommandPalette),
+                    k(Action::Help),
+                    ticket_state,
+                    freshness_state,
+                    epic_state,
+                    k(Action::Refresh),
+                    k(Action::Search),
+                    k(Action::Quit),
                 ),
-                Style::default().fg(Color::DarkGray),
+                Style::default().fg(theme.muted),
             )
         }
     };
+    let mut status_spans = Vec::new();
+    if let Some(timer) = app.work_timer.as_ref() {
+        status_spans.push(Span::styled(
+            format!(
+                " timer:{} {} ",
+                timer.ticket_key,
+                worklog::format_elapsed(timer.elapsed_secs(jira_client::now_unix_secs()))
+            ),
+            Style::default().fg(theme.highlight),
+        ));
+    }
+    if let Some(key) = app.branch_ticket_key.as_ref() {
+        status_spans.push(Span::styled(
+            format!(" branch:{} ", key),
+            Style::default().fg(theme.special),
+        ));
+    }
+    status_spans.push(status_text);
     f.render_widget(
-        ratatui::widgets::Paragraph::new(Line::from(status_text)),
+        ratatui::widgets::Paragraph::new(Line::from(status_spans)),
         chunks[2],
     );
 
-    // Detail overlay
+    // Detail overlay, or the preview pane in split view
     if app.is_detail_open() {
-        widgets::ticket_detail::render(f, app, &config.resolutions);
+        widgets::ticket_detail::render(f, app, &config.resolutions, preview_area);
+    }
+    // Under the other modals: it comes from a background scan, so whatever the user opened
+    // keeps the keys until it is closed.
+    if app.is_review_prompt_open() {
+        widgets::review_prompt::render(f, app);
     }
     if app.is_create_ticket_open() {
-        widgets::create_ticket::render(f, app);
+        widgets::create_ticket::render(f, app, &config.templates);
     }
     if app.is_comment_open() {
         widgets::comment::render(f, app);
@@ -917,25 +1809,47 @@ fn ui(f: &mut ratatui::Frame, app: &App, config: &AppConfig) {
     if app.is_edit_open() {
         widgets::edit_fields::render(f, app);
     }
+    if app.is_worklog_open() {
+        widgets::worklog::render(f, app);
+    }
     if app.is_filter_edit_open() {
         render_filter_edit_modal(f, app);
     }
     if app.is_bulk_open() {
-        widgets::bulk_actions::render(f, app, &config.resolutions);
+        widgets::bulk_actions::render(f, app, config);
     }
     if app.is_bulk_upload_open() {
         widgets::bulk_upload::render(f, app);
     }
+    if app.is_undo_history_open() {
+        widgets::undo_history::render(f, app);
+    }
+    if app.is_export_picker_open() {
+        widgets::export_view::render(f, app);
+    }
+    if app.is_standup_open() {
+        widgets::standup::render(f, app);
+    }
+    if app.is_git_branch_open() {
+        widgets::git_branch::render(f, app);
+    }
     if app.show_keybindings {
-        widgets::keybindings_help::render(f);
+        widgets::keybindings_help::render(f, &app.keymap, app.keybindings_scroll);
+    }
+    if app.is_command_palette_open() {
+        widgets::command_palette::render(f, app);
+    }
+    if app.is_goto_key_open() {
+        widgets::goto_key::render(f, app);
     }
 }
 
 fn render_filter_edit_modal(f: &mut ratatui::Frame, app: &App) {
-    use ratatui::style::{Color, Style};
+    use ratatui::style::Style;
     use ratatui::text::{Line, Span};
     use ratatui::widgets::Paragraph;
 
+    let theme = theme::current();
     let state = match &app.filter_edit {
         Some(s) => s,
         None => return,
@@ -956,16 +1870,341 @@ fn render_filter_edit_modal(f: &mut ratatui::Frame, app: &App) {
     lines.push(Line::from(""));
     lines.push(Line::from(Span::styled(
         "Tab: switch field  Enter: save  Esc: cancel",
-        Style::default().fg(Color::DarkGray),
+        Style::default().fg(theme.muted),
     )));
 
     let widget = Paragraph::new(lines);
     f.render_widget(widget, inner);
 }
 
-fn handle_keybindings_keys(app: &mut App, key: KeyCode) {
+fn handle_undo_history_keys(
+    app: &mut App,
+    key: KeyCode,
+    modifiers: KeyModifiers,
+    bg_tx: &UnboundedSender<BackgroundMessage>,
+) {
+    let nav = app.keymap.action(Scope::Picker, key, modifiers);
+    let selected = match app.undo_history {
+        Some(s) => s,
+        None => return,
+    };
+    match key {
+        KeyCode::Esc | KeyCode::Char('q') => app.undo_history = None,
+        _ if nav == Some(Action::PickerDown) && selected + 1 < app.journal.len() => {
+            app.undo_history = Some(selected + 1);
+        }
+        _ if nav == Some(Action::PickerUp) => {
+            app.undo_history = Some(selected.saturating_sub(1));
+        }
+        KeyCode::Enter => {
+            // The list shows newest first.
+            if let Some(index) = app.journal.len().checked_sub(selected + 1) {
+                begin_undo(app, index, bg_tx);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn handle_standup_keys(app: &mut App, key: KeyCode, modifiers: KeyModifiers) {
+    let nav = app.keymap.action(Scope::Picker, key, modifiers);
+    let Some(state) = &mut app.standup else {
+        return;
+    };
+    match key {
+        KeyCode::Esc | KeyCode::Char('q') => app.standup = None,
+        _ if nav == Some(Action::PickerDown) => state.scroll = state.scroll.saturating_add(1),
+        _ if nav == Some(Action::PickerUp) => state.scroll = state.scroll.saturating_sub(1),
+        KeyCode::Char('t') => {
+            state.scope = state.scope.toggle();
+            app.refresh_standup();
+        }
+        KeyCode::Char('f') => {
+            state.format = state.format.toggle();
+            app.refresh_standup();
+        }
+        KeyCode::Char('+') | KeyCode::Char('=') => {
+            state.window_hours = state.window_hours.saturating_add(24);
+            app.refresh_standup();
+        }
+        KeyCode::Char('-') if state.window_hours > 24 => {
+            state.window_hours -= 24;
+            app.refresh_standup();
+        }
+        KeyCode::Char('y') => {
+            app.flash = Some(match standup::copy_to_clipboard(&state.text) {
+                Ok(tool) => format!("Standup copied to clipboard ({})", tool),
+                Err(e) => format!("Copy failed: {}", e),
+            });
+        }
+        _ => {}
+    }
+}
+
+fn open_git_branch_prompt(app: &mut App, key: String, config: &AppConfig) {
+    let summary = app
+        .find_ticket(&key)
+        .map(|t| t.summary.clone())
+        .unwrap_or_default();
+    let name = git::branch_name(&config.git.branch_template, &key, &summary);
+    app.git_branch = Some(app::GitBranchState {
+        ticket_key: key,
+        name,
+    });
+}
+
+fn open_command_palette(app: &mut App, config: &AppConfig, scope: Scope) {
+    let entries = palette::entries(app, config, scope);
+    app.command_palette = Some(palette::PaletteState::new(scope, entries));
+}
+
+fn handle_command_palette_keys(
+    app: &mut App,
+    key: KeyCode,
+    modifiers: KeyModifiers,
+    bg_tx: &UnboundedSender<BackgroundMessage>,
+    config: &mut AppConfig,
+) {
+    let Some(palette) = app.command_palette.as_mut() else {
+        return;
+    };
+    let ctrl = modifiers.contains(KeyModifiers::CONTROL);
+    let input = app.keymap.action(Scope::Input, key, modifiers);
+    match key {
+        KeyCode::Esc => app.command_palette = None,
+        _ if input == Some(Action::InputDown) => palette.move_down(),
+        _ if input == Some(Action::InputUp) => palette.move_up(),
+        KeyCode::Backspace => palette.pop_char(),
+        KeyCode::Char(c) if !ctrl => palette.push_char(c),
+        KeyCode::Enter => {
+            let scope = palette.scope;
+            let command = palette.chosen().map(|entry| entry.command.clone());
+            app.command_palette = None;
+            if let Some(command) = command {
+                run_palette_command(app, scope, command, bg_tx, config);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn run_palette_command(
+    app: &mut App,
+    scope: Scope,
+    command: palette::Command,
+    bg_tx: &UnboundedSender<BackgroundMessage>,
+    config: &mut AppConfig,
+) {
+    match command {
+        palette::Command::Run(action) => match scope {
+            Scope::Main => run_main_action(app, action, bg_tx, config),
+            Scope::Filters => run_filter_action(app, action, bg_tx, config),
+            Scope::Detail => run_detail_action(app, action, bg_tx, config),
+            // The palette only opens from the list tabs and the detail view.
+            Scope::Picker | Scope::Input => {}
+        },
+        palette::Command::SwitchTab(tab) => app.set_tab(tab),
+        palette::Command::RunFilter(index) => {
+            app.set_tab(Tab::Filters);
+            app.filter_focus = FilterFocus::Sidebar;
+            app.filter_sidebar_idx = index;
+            run_selected_filter(app, bg_tx, config);
+        }
+        palette::Command::OpenEpic(key) => open_epic_detail(app, key, bg_tx),
+    }
+}
+
+fn handle_goto_key_keys(
+    app: &mut App,
+    key: KeyCode,
+    modifiers: KeyModifiers,
+    bg_tx: &UnboundedSender<BackgroundMessage>,
+    config: &AppConfig,
+) {
+    let Some(state) = app.goto_key.as_mut() else {
+        return;
+    };
+    let recent = app.recently_viewed.len();
+    let input = app.keymap.action(Scope::Input, key, modifiers);
+    match key {
+        KeyCode::Esc => app.goto_key = None,
+        _ if input == Some(Action::InputDown) => {
+            state.selected = match state.selected {
+                None if recent > 0 => Some(0),
+                Some(i) if i + 1 < recent => Some(i + 1),
+                other => other,
+            };
+        }
+        _ if input == Some(Action::InputUp) => {
+            state.selected = state.selected.and_then(|i| i.checked_sub(1))
+        }
+        KeyCode::Backspace => {
+            state.input.pop();
+            state.selected = None;
+        }
+        KeyCode::Char(c) if !c.is_whitespace() => {
+            state.input.push(c.to_ascii_uppercase());
+            state.selected = None;
+        }
+        KeyCode::Enter => {
+            let target = match state.selected {
+                Some(i) => app.recently_viewed.get(i).map(|v| v.key().to_string()),
+                None => parse_goto_key(&state.input, &config.jira.project),
+            };
+            let Some(key) = target else {
+                app.flash = Some(format!("\"{}\" is not a ticket key", state.input));
+                return;
+            };
+            app.goto_key = None;
+            if app.cache.epics.iter().any(|epic| epic.key == key) {
+                open_epic_detail(app, key, bg_tx);
+            } else {
+                open_ticket_by_key(app, key, bg_tx);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// `ABC-123`, or a bare number in the configured project.
+fn parse_goto_key(input: &str, project: &str) -> Option<String> {
+    let input = input.trim().to_ascii_uppercase();
+    let (prefix, number) = match input.rsplit_once('-') {
+        Some((prefix, number)) => (prefix.to_string(), number),
+        None => (project.to_ascii_uppercase(), input.as_str()),
+    };
+    let valid_prefix = prefix.starts_with(|c: char| c.is_ascii_alphabetic())
+        && prefix
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_');
+    let valid_number = !number.is_empty() && number.chars().all(|c| c.is_ascii_digit());
+    (valid_prefix && valid_number).then(|| format!("{}-{}", prefix, number))
+}
+
+/// Reopen the previous (or next) ticket or epic from the detail history.
+fn navigate_history(app: &mut App, back: bool, bg_tx: &UnboundedSender<BackgroundMessage>) {
+    let entry = if back {
+        app.nav_back()
+    } else {
+        app.nav_forward()
+    };
+    match entry {
+        Some(crate::cache::Viewed::Epic(key))
+            if app.cache.epics.iter().any(|epic| epic.key == key) =>
+        {
+            open_epic_detail(app, key, bg_tx)
+        }
+        Some(viewed) => open_ticket_by_key(app, viewed.key().to_string(), bg_tx),
+        None => {
+            app.flash = Some(
+                if back {
+                    "No earlier view"
+                } else {
+                    "No later view"
+                }
+                .to_string(),
+            )
+        }
+    }
+}
+
+fn handle_review_prompt_keys(
+    app: &mut App,
+    key: KeyCode,
+    bg_tx: &UnboundedSender<BackgroundMessage>,
+) {
+    let Some(ticket_key) = app.review_prompts.first().cloned() else {
+        return;
+    };
+    match key {
+        KeyCode::Char('y') | KeyCode::Enter => {
+            app.review_prompts.remove(0);
+            perform_ticket_move(app, ticket_key, Status::InReview, None, bg_tx);
+        }
+        KeyCode::Char('n') | KeyCode::Esc => {
+            app.review_prompts.remove(0);
+        }
+        _ => {}
+    }
+}
+
+fn handle_git_branch_keys(app: &mut App, key: KeyCode) {
+    let Some(state) = &mut app.git_branch else {
+        return;
+    };
+    match key {
+        KeyCode::Esc => app.git_branch = None,
+        KeyCode::Backspace => {
+            state.name.pop();
+        }
+        KeyCode::Char(c) if !c.is_whitespace() => state.name.push(c),
+        KeyCode::Enter if !state.name.is_empty() => {
+            let name = state.name.clone();
+            let ticket_key = state.ticket_key.clone();
+            app.git_branch = None;
+            app.flash = Some(match git::create_branch(&name) {
+                Ok(()) => {
+                    app.branch_ticket_key = Some(ticket_key);
+                    format!("Switched to new branch {}", name)
+                }
+                Err(e) => format!("Branch failed: {}", e),
+            });
+        }
+        _ => {}
+    }
+}
+
+fn handle_export_picker_keys(app: &mut App, key: KeyCode, modifiers: KeyModifiers) {
+    let nav = app.keymap.action(Scope::Picker, key, modifiers);
+    let selected = match app.export_picker {
+        Some(s) => s,
+        None => return,
+    };
+    match key {
+        KeyCode::Esc | KeyCode::Char('q') => app.export_picker = None,
+        _ if nav == Some(Action::PickerDown)
+            && selected + 1 < view_export::ExportFormat::ALL.len() =>
+        {
+            app.export_picker = Some(selected + 1);
+        }
+        _ if nav == Some(Action::PickerUp) => {
+            app.export_picker = Some(selected.saturating_sub(1));
+        }
+        KeyCode::Enter => {
+            app.export_picker = None;
+            let format = view_export::ExportFormat::ALL[selected];
+            let path = view_export::default_file_name(
+                app.active_tab.title(),
+                format,
+                jira_client::now_unix_secs(),
+            );
+            app.flash = Some(match export_current_view(app, &path, format) {
+                Ok(count) => format!("Exported {} tickets to {}", count, path),
+                Err(e) => format!("Export failed: {}", e),
+            });
+        }
+        _ => {}
+    }
+}
+
+/// Write the tickets visible in the active tab to `path`; returns how many were written.
+fn export_current_view(app: &App, path: &str, format: view_export::ExportFormat) -> Result<usize> {
+    let rows = app.visible_tickets_for_export();
+    let contents = view_export::render_export(&rows, format)?;
+    std::fs::write(path, contents)?;
+    Ok(rows.len())
+}
+
+fn handle_keybindings_keys(app: &mut App, key: KeyCode, modifiers: KeyModifiers) {
+    let nav = app.keymap.action(Scope::Picker, key, modifiers);
     match key {
         KeyCode::Esc | KeyCode::Char('?') | KeyCode::Char('q') => app.close_keybindings(),
+        _ if nav == Some(Action::PickerDown) => {
+            app.keybindings_scroll = app.keybindings_scroll.saturating_add(1)
+        }
+        _ if nav == Some(Action::PickerUp) => {
+            app.keybindings_scroll = app.keybindings_scroll.saturating_sub(1)
+        }
         _ => {}
     }
 }
@@ -995,12 +2234,26 @@ fn perform_ticket_move(
     ticket_key: String,
     new_status: Status,
     resolution: Option<String>,
+    bg_tx: &UnboundedSender<BackgroundMessage>,
 ) {
     let status_str = new_status.as_str().to_string();
     let key_clone = ticket_key.clone();
 
+    let changes = app.journal_changes_for(
+        std::slice::from_ref(&ticket_key),
+        &BulkTarget::Move {
+            status: new_status.clone(),
+            resolution: resolution.clone(),
+            clear_resolution: false,
+        },
+    );
+    let journal = (format!("Move {} to {}", ticket_key, status_str), changes);
+
     // Optimistic update
-    app.update_ticket_status(&ticket_key, new_status);
+    app.update_ticket_status(&ticket_key, new_status.clone());
+    if resolution.is_some() {
+        app.edit_cached_ticket(&ticket_key, |ticket| ticket.resolution = resolution.clone());
+    }
     app.detail_mode = DetailMode::View;
     let flash_msg = match &resolution {
         Some(r) => format!(
@@ -1011,31 +2264,140 @@ fn perform_ticket_move(
     };
     app.flash = Some(flash_msg);
 
-    // Fire and forget the CLI call
+    let tx = bg_tx.clone();
     tokio::spawn(async move {
-        let _ = jira_client::move_ticket(&key_clone, &status_str, resolution.as_deref()).await;
+        let result = jira_client::move_ticket(&key_clone, &status_str, resolution.as_deref())
+            .await
+            .map_err(|e| e.to_string());
+        let _ = tx.send(BackgroundMessage::TicketMoved {
+            key: key_clone,
+            status: new_status,
+            journal,
+            result,
+        });
     });
 }
 
-/// Returns true if the given status is a terminal/done status that requires a resolution.
-fn is_terminal_status(status: &Status) -> bool {
-    matches!(status, Status::Closed)
+/// Start the timer when a ticket has entered In Progress, and propose a worklog when it
+/// has left. Runs on the move result, so a failed move leaves the timer alone.
+fn sync_work_timer_after_move(app: &mut App, ticket_key: &str, new_status: &Status) {
+    let now = jira_client::now_unix_secs();
+    if *new_status == Status::InProgress {
+        if app.work_timer.is_none() {
+            app.start_work_timer(ticket_key, now);
+            if let Some(flash) = app.flash.as_mut() {
+                flash.push_str(" Timer started.");
+            }
+        }
+    } else if let Some(elapsed) = app.stop_work_timer(ticket_key, now) {
+        app.open_worklog(
+            ticket_key.to_string(),
+            worklog::timer_duration(elapsed),
+            worklog::format_date(worklog::today()),
+        );
+    }
 }
 
-/// Either perform the move directly, or redirect to the resolution picker for terminal statuses.
-fn perform_or_pick_resolution(app: &mut App, ticket_key: String, new_status: Status) {
-    if is_terminal_status(&new_status) {
-        app.detail_mode = DetailMode::ResolutionPicker {
-            target_status: new_status,
-            selected: 0,
-        };
+/// Open a ticket's detail by key, fetching it first when it is not cached.
+fn open_ticket_by_key(app: &mut App, key: String, bg_tx: &UnboundedSender<BackgroundMessage>) {
+    if app.find_ticket(&key).is_some() {
+        let detail_loaded = app.is_ticket_detail_loaded(&key);
+        app.open_detail(key.clone());
+        if !detail_loaded && app.begin_detail_fetch(&key) {
+            spawn_ticket_detail_fetch(bg_tx, key);
+        }
+        return;
+    }
+
+    app.pending_detail_open = Some(key.clone());
+    app.flash = Some(format!("Loading {}...", key));
+    if app.begin_detail_fetch(&key) {
+        spawn_ticket_detail_fetch(bg_tx, key);
+    }
+}
+
+fn toggle_watch(app: &mut App, ticket_key: String, bg_tx: &UnboundedSender<BackgroundMessage>) {
+    let watching = !app
+        .find_ticket(&ticket_key)
+        .map(|t| t.watchers.is_watching)
+        .unwrap_or(false);
+
+    // Optimistic update
+    app.set_watching(&ticket_key, watching);
+    app.flash = Some(if watching {
+        format!("Watching {}...", ticket_key)
+    } else {
+        format!("Unwatching {}...", ticket_key)
+    });
+
+    let tx = bg_tx.clone();
+    tokio::spawn(async move {
+        let result = if watching {
+            jira_client::watch_ticket(&ticket_key).await
+        } else {
+            jira_client::unwatch_ticket(&ticket_key).await
+        }
+        .map_err(|e| e.to_string());
+        let _ = tx.send(BackgroundMessage::WatchToggled {
+            key: ticket_key,
+            watching,
+            result,
+        });
+    });
+}
+
+fn toggle_work_timer(app: &mut App, ticket_key: String) {
+    let now = jira_client::now_unix_secs();
+    if let Some(elapsed) = app.stop_work_timer(&ticket_key, now) {
+        let duration = worklog::timer_duration(elapsed);
+        app.flash = Some(format!("Timer stopped after {}", duration));
+        app.open_worklog(ticket_key, duration, worklog::format_date(worklog::today()));
+        return;
+    }
+    if let Some(timer) = app.work_timer.as_ref() {
+        app.flash = Some(format!("Timer already running for {}", timer.ticket_key));
+        return;
+    }
+    let in_progress = app
+        .find_ticket(&ticket_key)
+        .map(|t| t.status == Status::InProgress)
+        .unwrap_or(false);
+    if !in_progress {
+        app.flash = Some(format!(
+            "Move {} to In Progress to start the timer",
+            ticket_key
+        ));
+        return;
+    }
+    app.start_work_timer(&ticket_key, now);
+    app.flash = Some(format!("Timer started for {}", ticket_key));
+}
+
+/// Returns true if the given status is a terminal/done status that requires a resolution.
+fn is_terminal_status(status: &Status) -> bool {
+    matches!(status, Status::Closed)
+}
+
+/// Either perform the move directly, or redirect to the resolution picker for terminal statuses.
+fn perform_or_pick_resolution(
+    app: &mut App,
+    ticket_key: String,
+    new_status: Status,
+    bg_tx: &UnboundedSender<BackgroundMessage>,
+) {
+    if is_terminal_status(&new_status) {
+        app.detail_mode = DetailMode::ResolutionPicker {
+            target_status: new_status,
+            selected: 0,
+        };
         app.flash = Some("Select a resolution:".to_string());
     } else {
-        perform_ticket_move(app, ticket_key, new_status, None);
+        perform_ticket_move(app, ticket_key, new_status, None, bg_tx);
     }
 }
 
-fn begin_bulk_from_selection(app: &mut App) {
+/// Selected tickets in view order, falling back to the ticket under the cursor.
+fn bulk_targets_from_selection(app: &mut App) -> Option<Vec<String>> {
     let mut targets = app.selected_visible_ticket_keys_in_order();
     if targets.is_empty() {
         if let Some(key) = app.selected_ticket_key() {
@@ -1044,20 +2406,74 @@ fn begin_bulk_from_selection(app: &mut App) {
     }
     if targets.is_empty() {
         app.flash = Some("No tickets selected".to_string());
-        return;
+        return None;
+    }
+    Some(targets)
+}
+
+fn begin_bulk_from_selection(app: &mut App) {
+    if let Some(targets) = bulk_targets_from_selection(app) {
+        app.bulk_state = Some(BulkState::ActionPicker {
+            targets,
+            selected: 0,
+        });
+    }
+}
+
+/// Close an epic once every child is Closed, via the resolution picker in epic detail.
+fn begin_close_epic(app: &mut App, epic_key: &str) {
+    match app.epic_open_children(epic_key) {
+        Some(0) => {
+            app.detail_mode = DetailMode::ResolutionPicker {
+                target_status: Status::Closed,
+                selected: 0,
+            };
+            app.flash = Some("Select a resolution:".to_string());
+        }
+        Some(open) => {
+            app.flash = Some(format!(
+                "Cannot close {}: {} child ticket(s) not done",
+                epic_key, open
+            ));
+        }
+        None => {
+            app.flash = Some(format!("Epic {} is not loaded", epic_key));
+        }
     }
-    app.bulk_state = Some(BulkState::ActionPicker {
-        targets,
-        selected: 0,
+}
+
+fn spawn_close_epic(
+    app: &mut App,
+    epic_key: String,
+    resolution: String,
+    bg_tx: &UnboundedSender<BackgroundMessage>,
+) {
+    app.detail_mode = DetailMode::View;
+    app.flash = Some(format!(
+        "Closing {} (resolution: {})...",
+        epic_key, resolution
+    ));
+    let tx = bg_tx.clone();
+    tokio::spawn(async move {
+        let result =
+            jira_client::move_ticket(&epic_key, Status::Closed.as_str(), Some(&resolution))
+                .await
+                .map_err(|e| e.to_string());
+        let _ = tx.send(BackgroundMessage::EpicClosed {
+            key: epic_key,
+            result,
+        });
     });
 }
 
 fn handle_bulk_keys(
     app: &mut App,
     key: KeyCode,
+    modifiers: KeyModifiers,
     bg_tx: &UnboundedSender<BackgroundMessage>,
     config: &AppConfig,
 ) {
+    let nav = app.keymap.action(Scope::Picker, key, modifiers);
     let state = match app.bulk_state.clone() {
         Some(state) => state,
         None => return,
@@ -1065,44 +2481,74 @@ fn handle_bulk_keys(
     match state {
         BulkState::ActionPicker { targets, selected } => match key {
             KeyCode::Esc => app.bulk_state = None,
-            KeyCode::Char('j') | KeyCode::Down => {
-                let new_sel = (selected + 1).min(1);
+            _ if nav == Some(Action::PickerDown) => {
+                let new_sel = (selected + 1).min(BulkAction::MENU.len() - 1);
                 app.bulk_state = Some(BulkState::ActionPicker {
                     targets,
                     selected: new_sel,
                 });
             }
-            KeyCode::Char('k') | KeyCode::Up => {
+            _ if nav == Some(Action::PickerUp) => {
                 app.bulk_state = Some(BulkState::ActionPicker {
                     targets,
                     selected: selected.saturating_sub(1),
                 });
             }
             KeyCode::Enter => {
-                app.bulk_state = Some(if selected == 0 {
-                    BulkState::MoveStatusPicker {
+                let Some(action) = BulkAction::MENU.get(selected) else {
+                    return;
+                };
+                if *action == BulkAction::CustomField && config.custom_fields.is_empty() {
+                    app.flash =
+                        Some("No custom fields configured ([custom_fields] in config)".to_string());
+                    return;
+                }
+                app.bulk_state = Some(match action {
+                    BulkAction::Move => BulkState::MoveStatusPicker {
                         targets,
                         selected: 0,
-                    }
-                } else {
-                    BulkState::AssignPicker {
+                    },
+                    BulkAction::Assign => BulkState::AssignPicker {
                         targets,
                         selected: 0,
-                    }
+                    },
+                    BulkAction::Epic => BulkState::EpicPicker {
+                        targets,
+                        selected: 0,
+                    },
+                    BulkAction::AddLabels | BulkAction::RemoveLabels => BulkState::LabelsInput {
+                        targets,
+                        remove: *action == BulkAction::RemoveLabels,
+                        input: String::new(),
+                    },
+                    BulkAction::Priority => BulkState::PriorityPicker {
+                        targets,
+                        selected: 0,
+                    },
+                    BulkAction::Comment => BulkState::CommentTemplatePicker {
+                        targets,
+                        selected: 0,
+                    },
+                    BulkAction::CustomField => BulkState::CustomFieldPicker {
+                        targets,
+                        selected: 0,
+                    },
+                    // Not offered in the menu.
+                    BulkAction::Summary | BulkAction::Undo => return,
                 });
             }
             _ => {}
         },
         BulkState::MoveStatusPicker { targets, selected } => match key {
             KeyCode::Esc => app.bulk_state = None,
-            KeyCode::Char('j') | KeyCode::Down => {
+            _ if nav == Some(Action::PickerDown) => {
                 let max = Status::all().len().saturating_sub(1);
                 app.bulk_state = Some(BulkState::MoveStatusPicker {
                     targets,
                     selected: (selected + 1).min(max),
                 });
             }
-            KeyCode::Char('k') | KeyCode::Up => {
+            _ if nav == Some(Action::PickerUp) => {
                 app.bulk_state = Some(BulkState::MoveStatusPicker {
                     targets,
                     selected: selected.saturating_sub(1),
@@ -1124,6 +2570,7 @@ fn handle_bulk_keys(
                         target: BulkTarget::Move {
                             status,
                             resolution: None,
+                            clear_resolution: false,
                         },
                     });
                 }
@@ -1136,7 +2583,7 @@ fn handle_bulk_keys(
             selected,
         } => match key {
             KeyCode::Esc => app.bulk_state = None,
-            KeyCode::Char('j') | KeyCode::Down => {
+            _ if nav == Some(Action::PickerDown) => {
                 let max = config.resolutions.len().saturating_sub(1);
                 app.bulk_state = Some(BulkState::MoveResolutionPicker {
                     targets,
@@ -1144,7 +2591,7 @@ fn handle_bulk_keys(
                     selected: (selected + 1).min(max),
                 });
             }
-            KeyCode::Char('k') | KeyCode::Up => {
+            _ if nav == Some(Action::PickerUp) => {
                 app.bulk_state = Some(BulkState::MoveResolutionPicker {
                     targets,
                     status,
@@ -1155,7 +2602,11 @@ fn handle_bulk_keys(
                 let resolution = config.resolutions.get(selected).cloned();
                 app.bulk_state = Some(BulkState::Confirm {
                     targets,
-                    target: BulkTarget::Move { status, resolution },
+                    target: BulkTarget::Move {
+                        status,
+                        resolution,
+                        clear_resolution: false,
+                    },
                 });
             }
             _ => {}
@@ -1164,13 +2615,13 @@ fn handle_bulk_keys(
             let max = app.cache.team_members.len().saturating_sub(1);
             match key {
                 KeyCode::Esc => app.bulk_state = None,
-                KeyCode::Char('j') | KeyCode::Down => {
+                _ if nav == Some(Action::PickerDown) => {
                     app.bulk_state = Some(BulkState::AssignPicker {
                         targets,
                         selected: (selected + 1).min(max),
                     });
                 }
-                KeyCode::Char('k') | KeyCode::Up => {
+                _ if nav == Some(Action::PickerUp) => {
                     app.bulk_state = Some(BulkState::AssignPicker {
                         targets,
                         selected: selected.saturating_sub(1),
@@ -1192,1399 +2643,3249 @@ fn handle_bulk_keys(
                 _ => {}
             }
         }
-        BulkState::Confirm { targets, target } => match key {
-            KeyCode::Esc => app.bulk_state = None,
-            KeyCode::Enter | KeyCode::Char('y') | KeyCode::Char('Y') => {
-                let (attempt_keys, skipped) = partition_bulk_targets(app, &targets, &target);
-                app.flash = Some(format!(
-                    "Running bulk action on {} tickets...",
-                    targets.len()
-                ));
-                app.bulk_state = Some(BulkState::Running {
-                    targets: targets.clone(),
-                    target: target.clone(),
-                });
-                spawn_bulk_execution(bg_tx, targets, attempt_keys, target, skipped);
-            }
-            _ => {}
-        },
-        BulkState::Running { .. } => {
-            if matches!(key, KeyCode::Esc) {
-                app.bulk_state = None;
-            }
-        }
-        BulkState::Result { .. } => match key {
-            KeyCode::Esc | KeyCode::Enter | KeyCode::Char('q') => app.bulk_state = None,
-            _ => {}
-        },
-    }
-}
-
-fn handle_detail_keys(app: &mut App, key: KeyCode, config: &AppConfig) {
-    match app.detail_mode.clone() {
-        DetailMode::View => {
-            let ticket_detail_key = app
-                .detail_ticket_key
-                .as_ref()
-                .and_then(|k| app.find_ticket(k).map(|_| k.clone()));
-
+        BulkState::EpicPicker { targets, selected } => {
+            let max = app.cache.epics.len(); // options are 0..=max
             match key {
-                KeyCode::Esc => app.close_detail(),
-                KeyCode::Up => app.scroll_detail_up(),
-                KeyCode::Down => app.scroll_detail_down(),
-                KeyCode::Char('o') => {
-                    if let Some(key) = ticket_detail_key.as_ref() {
-                        if let Some(ticket) = app.find_ticket(key) {
-                            let url = ticket.url.clone();
-                            let _ = std::process::Command::new("open").arg(&url).spawn();
-                        }
-                    } else if let Some(epic_key) = app.detail_epic_key.as_ref() {
-                        let url = format!("https://jira.mongodb.org/browse/{}", epic_key);
-                        let _ = std::process::Command::new("open").arg(&url).spawn();
-                    }
-                }
-                KeyCode::Char('m') => {
-                    if ticket_detail_key.is_some() {
-                        app.detail_mode = DetailMode::MovePicker {
-                            selected: 0,
-                            confirm_target: None,
-                        };
-                    }
-                }
-                KeyCode::Char('C') => {
-                    if let Some(key) = ticket_detail_key {
-                        app.comment_state = Some(app::CommentState {
-                            ticket_key: key,
-                            body: String::new(),
-                        });
-                    }
-                }
-                KeyCode::Char('a') => {
-                    if let Some(key) = app
-                        .detail_ticket_key
-                        .as_ref()
-                        .and_then(|k| app.find_ticket(k).map(|_| k.clone()))
-                    {
-                        app.assign_state = Some(app::AssignState {
-                            ticket_key: key,
-                            selected: 0,
-                        });
-                    }
+                KeyCode::Esc => app.bulk_state = None,
+                _ if nav == Some(Action::PickerDown) => {
+                    app.bulk_state = Some(BulkState::EpicPicker {
+                        targets,
+                        selected: (selected + 1).min(max),
+                    });
                 }
-                KeyCode::Char('e') => {
-                    if let Some(key) = app
-                        .detail_ticket_key
-                        .as_ref()
-                        .and_then(|k| app.find_ticket(k).map(|_| k.clone()))
-                    {
-                        let ticket = app.find_ticket(&key);
-                        let summary = ticket.map(|t| t.summary.clone()).unwrap_or_default();
-                        let labels = ticket.map(|t| t.labels.join(", ")).unwrap_or_default();
-                        app.edit_state = Some(app::EditFieldsState {
-                            ticket_key: key,
-                            focused_field: 0,
-                            summary,
-                            labels,
-                        });
-                    }
+                _ if nav == Some(Action::PickerUp) => {
+                    app.bulk_state = Some(BulkState::EpicPicker {
+                        targets,
+                        selected: selected.saturating_sub(1),
+                    });
                 }
-                KeyCode::Char('h') => {
-                    if app
-                        .detail_ticket_key
-                        .as_ref()
-                        .and_then(|k| app.find_ticket(k))
-                        .is_some()
-                    {
-                        app.detail_mode = DetailMode::History { scroll: 0 };
-                    }
+                KeyCode::Enter => {
+                    let epic = selected
+                        .checked_sub(1)
+                        .and_then(|idx| app.cache.epics.get(idx));
+                    app.bulk_state = Some(BulkState::Confirm {
+                        targets,
+                        target: BulkTarget::Epic {
+                            epic_key: epic.map(|e| e.key.clone()),
+                            epic_name: epic.map(|e| e.summary.clone()),
+                        },
+                    });
                 }
                 _ => {}
             }
         }
-        DetailMode::MovePicker {
-            selected,
-            confirm_target,
+        BulkState::LabelsInput {
+            targets,
+            remove,
+            mut input,
         } => match key {
-            KeyCode::Esc => app.detail_mode = DetailMode::View,
-            KeyCode::Char('j') | KeyCode::Down => {
-                if let Some((_, options)) = current_move_options(app) {
-                    let new_sel = (selected + 1).min(options.len().saturating_sub(1));
-                    app.detail_mode = DetailMode::MovePicker {
-                        selected: new_sel,
-                        confirm_target: None,
-                    };
-                }
-            }
-            KeyCode::Char('k') | KeyCode::Up => {
-                app.detail_mode = DetailMode::MovePicker {
-                    selected: selected.saturating_sub(1),
-                    confirm_target: None,
-                };
-            }
+            KeyCode::Esc => app.bulk_state = None,
             KeyCode::Enter => {
-                if let Some(target) = confirm_target {
-                    if let Some((ticket_key, options)) = current_move_options(app) {
-                        if options.contains(&target) {
-                            perform_or_pick_resolution(app, ticket_key, target);
-                        }
-                    }
-                } else if let Some((ticket_key, options)) = current_move_options(app) {
-                    if let Some(new_status) = options.get(selected).cloned() {
-                        queue_move_confirmation(app, &ticket_key, selected, new_status);
-                    }
+                let labels = parse_label_input(&input);
+                if labels.is_empty() {
+                    app.flash = Some("Enter at least one label".to_string());
+                    return;
                 }
+                let target = if remove {
+                    BulkTarget::RemoveLabels { labels }
+                } else {
+                    BulkTarget::AddLabels { labels }
+                };
+                app.bulk_state = Some(BulkState::Confirm { targets, target });
             }
-            KeyCode::Char('y') | KeyCode::Char('Y') => {
-                if let Some(target) = confirm_target {
-                    if let Some((ticket_key, options)) = current_move_options(app) {
-                        if options.contains(&target) {
-                            perform_or_pick_resolution(app, ticket_key, target);
-                        }
-                    }
-                }
+            KeyCode::Backspace => {
+                input.pop();
+                app.bulk_state = Some(BulkState::LabelsInput {
+                    targets,
+                    remove,
+                    input,
+                });
             }
             KeyCode::Char(c) => {
-                if let Some(target_status) = Status::from_move_shortcut(c) {
-                    if let Some((ticket_key, options)) = current_move_options(app) {
-                        if let Some(target_idx) = options.iter().position(|s| *s == target_status) {
-                            if c.is_ascii_uppercase() {
-                                perform_or_pick_resolution(app, ticket_key, target_status);
-                            } else {
-                                queue_move_confirmation(
-                                    app,
-                                    &ticket_key,
-                                    target_idx,
-                                    target_status,
-                                );
-                            }
-                        } else {
-                            app.flash = Some(format!(
-                                "{} is already {}",
-                                ticket_key,
-                                target_status.as_str()
-                            ));
-                        }
-                    }
-                }
+                input.push(c);
+                app.bulk_state = Some(BulkState::LabelsInput {
+                    targets,
+                    remove,
+                    input,
+                });
             }
             _ => {}
         },
-        DetailMode::ResolutionPicker {
-            target_status,
-            selected,
-        } => match key {
-            KeyCode::Esc => {
-                app.detail_mode = DetailMode::MovePicker {
-                    selected: 0,
-                    confirm_target: None,
-                };
-            }
-            KeyCode::Char('j') | KeyCode::Down => {
-                let max = config.resolutions.len().saturating_sub(1);
-                app.detail_mode = DetailMode::ResolutionPicker {
-                    target_status,
-                    selected: (selected + 1).min(max),
-                };
+        BulkState::PriorityPicker { targets, selected } => match key {
+            KeyCode::Esc => app.bulk_state = None,
+            _ if nav == Some(Action::PickerDown) => {
+                app.bulk_state = Some(BulkState::PriorityPicker {
+                    targets,
+                    selected: (selected + 1).min(app::PRIORITIES.len() - 1),
+                });
             }
-            KeyCode::Char('k') | KeyCode::Up => {
-                app.detail_mode = DetailMode::ResolutionPicker {
-                    target_status,
+            _ if nav == Some(Action::PickerUp) => {
+                app.bulk_state = Some(BulkState::PriorityPicker {
+                    targets,
                     selected: selected.saturating_sub(1),
-                };
+                });
             }
             KeyCode::Enter => {
-                if let Some(resolution) = config.resolutions.get(selected) {
-                    if let Some(ticket_key) = app.detail_ticket_key.clone() {
-                        perform_ticket_move(
-                            app,
-                            ticket_key,
-                            target_status,
-                            Some(resolution.clone()),
-                        );
-                    }
-                }
+                app.bulk_state = Some(BulkState::Confirm {
+                    targets,
+                    target: BulkTarget::Priority {
+                        priority: app::PRIORITIES[selected].to_string(),
+                    },
+                });
             }
             _ => {}
         },
-        DetailMode::History { scroll } => match key {
-            KeyCode::Esc => app.detail_mode = DetailMode::View,
-            KeyCode::Down | KeyCode::Char('j') => {
-                app.detail_mode = DetailMode::History { scroll: scroll + 1 };
+        BulkState::CommentTemplatePicker { targets, selected } => match key {
+            KeyCode::Esc => app.bulk_state = None,
+            _ if nav == Some(Action::PickerDown) => {
+                let max = config.comment_templates.len(); // options are 0..=max
+                app.bulk_state = Some(BulkState::CommentTemplatePicker {
+                    targets,
+                    selected: (selected + 1).min(max),
+                });
             }
-            KeyCode::Up | KeyCode::Char('k') => {
-                app.detail_mode = DetailMode::History {
-                    scroll: scroll.saturating_sub(1),
-                };
+            _ if nav == Some(Action::PickerUp) => {
+                app.bulk_state = Some(BulkState::CommentTemplatePicker {
+                    targets,
+                    selected: selected.saturating_sub(1),
+                });
+            }
+            KeyCode::Enter => {
+                let body = selected
+                    .checked_sub(1)
+                    .and_then(|idx| config.comment_templates.get(idx))
+                    .map(|t| t.body.clone())
+                    .unwrap_or_default();
+                app.bulk_state = Some(BulkState::CommentInput { targets, body });
             }
             _ => {}
         },
-    }
-}
-
-async fn handle_search_keys(
-    app: &mut App,
-    key: KeyCode,
-    modifiers: KeyModifiers,
-    bg_tx: &UnboundedSender<BackgroundMessage>,
-) {
-    match key {
-        KeyCode::Esc => {
-            app.search = None;
-            app.clamp_selection();
-        }
-        KeyCode::Enter => {
-            if let Some(group_id) = app.selected_header_group_id() {
-                if app.active_tab == Tab::Epics {
-                    app.open_epic_detail(group_id);
-                } else if app.is_collapsed(app.active_tab, &group_id) {
-                    app.toggle_group_collapse(&group_id);
-                }
-            } else if let Some(key) = app.selected_ticket_key() {
-                let detail_loaded = app.is_ticket_detail_loaded(&key);
-                app.open_detail(key.clone());
-                if !detail_loaded && app.begin_detail_fetch(&key) {
-                    spawn_ticket_detail_fetch(bg_tx, key);
+        BulkState::CommentInput { targets, mut body } => match key {
+            KeyCode::Esc => app.bulk_state = None,
+            KeyCode::Enter => {
+                if body.trim().is_empty() {
+                    app.flash = Some("Comment body is required".to_string());
+                    return;
                 }
+                app.bulk_state = Some(BulkState::Confirm {
+                    targets,
+                    target: BulkTarget::Comment { body },
+                });
             }
-        }
-        KeyCode::Char('U') => {
-            app.search = None;
-            app.bulk_upload_state = Some(BulkUploadState::PathInput {
-                path: String::new(),
-                loading: false,
-            });
-        }
-        KeyCode::Backspace => {
-            if let Some(ref mut s) = app.search {
-                s.pop();
-                if s.is_empty() {
-                    app.search = None;
-                }
+            KeyCode::Backspace => {
+                body.pop();
+                app.bulk_state = Some(BulkState::CommentInput { targets, body });
             }
-            app.clamp_selection();
-        }
-        KeyCode::Down => app.move_selection_down(),
-        KeyCode::Up => app.move_selection_up(),
-        KeyCode::Char('j') if modifiers.contains(KeyModifiers::CONTROL) => {
-            app.move_selection_down()
-        }
-        KeyCode::Char('k') if modifiers.contains(KeyModifiers::CONTROL) => app.move_selection_up(),
-        KeyCode::Char('n') if modifiers.contains(KeyModifiers::CONTROL) => {
-            app.move_selection_down()
-        }
-        KeyCode::Char('p') if modifiers.contains(KeyModifiers::CONTROL) => app.move_selection_up(),
-        KeyCode::Char(c) => {
-            if let Some(ref mut s) = app.search {
-                s.push(c);
+            KeyCode::Char(c) => {
+                body.push(c);
+                app.bulk_state = Some(BulkState::CommentInput { targets, body });
+            }
+            _ => {}
+        },
+        BulkState::CustomFieldPicker { targets, selected } => match key {
+            KeyCode::Esc => app.bulk_state = None,
+            _ if nav == Some(Action::PickerDown) => {
+                let max = config.custom_fields.len().saturating_sub(1);
+                app.bulk_state = Some(BulkState::CustomFieldPicker {
+                    targets,
+                    selected: (selected + 1).min(max),
+                });
+            }
+            _ if nav == Some(Action::PickerUp) => {
+                app.bulk_state = Some(BulkState::CustomFieldPicker {
+                    targets,
+                    selected: selected.saturating_sub(1),
+                });
             }
-            app.clamp_selection();
-        }
-        _ => {}
-    }
-}
-
-fn handle_bulk_upload_keys(
-    app: &mut App,
-    key: KeyCode,
-    bg_tx: &UnboundedSender<BackgroundMessage>,
-    config: &AppConfig,
-) {
-    let state = match app.bulk_upload_state.clone() {
-        Some(s) => s,
-        None => return,
-    };
-
-    match state {
-        BulkUploadState::PathInput { mut path, loading } => match key {
-            KeyCode::Esc => app.bulk_upload_state = None,
             KeyCode::Enter => {
-                if loading {
+                let Some((name, field_id)) = config.custom_fields.iter().nth(selected) else {
                     return;
-                }
-                let trimmed = path.trim().to_string();
-                if trimmed.is_empty() {
-                    app.flash = Some("CSV path is required".to_string());
+                };
+                app.bulk_state = Some(BulkState::CustomFieldInput {
+                    targets,
+                    name: name.clone(),
+                    field_id: field_id.clone(),
+                    value: String::new(),
+                });
+            }
+            _ => {}
+        },
+        BulkState::CustomFieldInput {
+            targets,
+            name,
+            field_id,
+            mut value,
+        } => match key {
+            KeyCode::Esc => app.bulk_state = None,
+            KeyCode::Enter => {
+                let value = value.trim().to_string();
+                if value.is_empty() {
+                    app.flash = Some(format!("Enter a value for {}", name));
                     return;
                 }
-                app.bulk_upload_state = Some(BulkUploadState::PathInput {
-                    path: trimmed.clone(),
-                    loading: true,
+                app.bulk_state = Some(BulkState::Confirm {
+                    targets,
+                    target: BulkTarget::CustomField {
+                        name,
+                        field_id,
+                        value,
+                    },
                 });
-                let context = build_bulk_upload_context(app);
-                spawn_bulk_upload_preview(bg_tx, trimmed, context);
             }
             KeyCode::Backspace => {
-                path.pop();
-                app.bulk_upload_state = Some(BulkUploadState::PathInput { path, loading });
+                value.pop();
+                app.bulk_state = Some(BulkState::CustomFieldInput {
+                    targets,
+                    name,
+                    field_id,
+                    value,
+                });
             }
             KeyCode::Char(c) => {
-                path.push(c);
-                app.bulk_upload_state = Some(BulkUploadState::PathInput { path, loading });
+                value.push(c);
+                app.bulk_state = Some(BulkState::CustomFieldInput {
+                    targets,
+                    name,
+                    field_id,
+                    value,
+                });
             }
             _ => {}
         },
-        BulkUploadState::Preview {
-            preview,
-            mut selected,
-        } => match key {
-            KeyCode::Esc => app.bulk_upload_state = None,
-            KeyCode::Char('j') | KeyCode::Down => {
-                if selected + 1 < preview.rows.len() {
-                    selected += 1;
-                }
-                app.bulk_upload_state = Some(BulkUploadState::Preview { preview, selected });
-            }
-            KeyCode::Char('k') | KeyCode::Up => {
-                selected = selected.saturating_sub(1);
-                app.bulk_upload_state = Some(BulkUploadState::Preview { preview, selected });
+        BulkState::Confirm { targets, target } => match key {
+            KeyCode::Esc => app.bulk_state = None,
+            KeyCode::Enter | KeyCode::Char('y') | KeyCode::Char('Y') => {
+                start_bulk_run(app, targets, target, bg_tx);
             }
-            KeyCode::Char('r') => {
-                app.bulk_upload_state = Some(BulkUploadState::PathInput {
-                    path: preview.source_path.clone(),
-                    loading: true,
+            KeyCode::Char('d') => {
+                let rows = bulk_dry_run_rows(app, &targets, &target);
+                app.bulk_state = Some(BulkState::DryRun {
+                    targets,
+                    target,
+                    rows,
+                    selected: 0,
                 });
-                let context = build_bulk_upload_context(app);
-                spawn_bulk_upload_preview(bg_tx, preview.source_path, context);
             }
-            KeyCode::Enter | KeyCode::Char('y') => {
-                if !preview.can_submit() {
-                    app.flash = Some(
-                        "Upload blocked: fix invalid rows in the CSV and reload preview"
-                            .to_string(),
-                    );
-                    return;
-                }
-                app.bulk_upload_state = Some(BulkUploadState::Running {
-                    preview: preview.clone(),
+            _ => {}
+        },
+        BulkState::DryRun {
+            targets,
+            target,
+            rows,
+            selected,
+        } => match key {
+            KeyCode::Esc => app.bulk_state = Some(BulkState::Confirm { targets, target }),
+            KeyCode::Enter | KeyCode::Char('y') | KeyCode::Char('Y') => {
+                start_bulk_run(app, targets, target, bg_tx);
+            }
+            _ if matches!(nav, Some(Action::PickerDown | Action::PickerUp)) => {
+                let selected = if nav == Some(Action::PickerDown) {
+                    (selected + 1).min(rows.len().saturating_sub(1))
+                } else {
+                    selected.saturating_sub(1)
+                };
+                app.bulk_state = Some(BulkState::DryRun {
+                    targets,
+                    target,
+                    rows,
+                    selected,
                 });
-                spawn_bulk_upload_execution(bg_tx, preview, config.jira.project.clone());
             }
             _ => {}
         },
-        BulkUploadState::Running { .. } => {
-            if key == KeyCode::Esc {
-                app.bulk_upload_state = None;
+        BulkState::Running { .. } => {
+            if matches!(key, KeyCode::Esc) {
+                app.bulk_state = None;
             }
         }
-        BulkUploadState::Result { summary } => match key {
-            KeyCode::Esc | KeyCode::Enter | KeyCode::Char('q') => app.bulk_upload_state = None,
-            KeyCode::Char('r') => {
-                app.bulk_upload_state = Some(BulkUploadState::PathInput {
-                    path: summary.source_path.clone(),
-                    loading: true,
+        BulkState::Result { summary } => match key {
+            KeyCode::Esc | KeyCode::Enter | KeyCode::Char('q') => app.bulk_state = None,
+            KeyCode::Char('f') => begin_bulk_retry(app, summary, bg_tx),
+            KeyCode::Char('c') | KeyCode::Char('J') => {
+                let format = if key == KeyCode::Char('c') {
+                    bulk_report::ReportFormat::Csv
+                } else {
+                    bulk_report::ReportFormat::Json
+                };
+                let path = std::path::PathBuf::from(bulk_report::default_file_name(
+                    &summary,
+                    format,
+                    jira_client::now_unix_secs(),
+                ));
+                app.flash = Some(match bulk_report::write_report(&summary, &path, format) {
+                    Ok(()) => format!("Report written to {}", path.display()),
+                    Err(e) => format!("Report export failed: {}", e),
                 });
-                let context = build_bulk_upload_context(app);
-                spawn_bulk_upload_preview(bg_tx, summary.source_path, context);
+                app.bulk_state = Some(BulkState::Result { summary });
             }
             _ => {}
         },
     }
 }
 
-async fn handle_create_ticket_keys(
+fn run_detail_action(
     app: &mut App,
-    key: KeyCode,
-    _modifiers: KeyModifiers,
+    action: Action,
     bg_tx: &UnboundedSender<BackgroundMessage>,
     config: &AppConfig,
 ) {
-    let state = match &mut app.create_ticket {
-        Some(s) => s,
-        None => return,
-    };
-
-    match key {
-        KeyCode::Esc => {
-            app.create_ticket = None;
-        }
-        KeyCode::Tab => {
-            let state = app.create_ticket.as_mut().unwrap();
-            state.focused_field = (state.focused_field + 1) % 4;
+    let ticket_detail_key = app
+        .detail_ticket_key
+        .as_ref()
+        .and_then(|k| app.find_ticket(k).map(|_| k.clone()));
+
+    match action {
+        Action::CommandPalette => open_command_palette(app, config, Scope::Detail),
+        Action::GoToKey => app.goto_key = Some(Default::default()),
+        Action::NavBack => navigate_history(app, true, bg_tx),
+        Action::NavForward => navigate_history(app, false, bg_tx),
+        Action::CloseDetail => app.close_detail(),
+        Action::ScrollUp => app.scroll_detail_up(),
+        Action::ScrollDown => app.scroll_detail_down(),
+        Action::OpenInBrowser => {
+            if let Some(key) = ticket_detail_key.as_ref() {
+                if let Some(ticket) = app.find_ticket(key) {
+                    let url = ticket.url.clone();
+                    let _ = std::process::Command::new("open").arg(&url).spawn();
+                }
+            } else if let Some(epic_key) = app.detail_epic_key.as_ref() {
+                let url = format!("https://jira.mongodb.org/browse/{}", epic_key);
+                let _ = std::process::Command::new("open").arg(&url).spawn();
+            }
         }
-        KeyCode::BackTab => {
-            let state = app.create_ticket.as_mut().unwrap();
-            state.focused_field = if state.focused_field == 0 {
-                3
-            } else {
-                state.focused_field - 1
+        Action::MoveStatus if ticket_detail_key.is_some() => {
+            app.detail_mode = DetailMode::MovePicker {
+                selected: 0,
+                confirm_target: None,
             };
         }
-        KeyCode::Enter => {
-            if state.summary.trim().is_empty() {
-                app.flash = Some("Summary is required".to_string());
-                return;
+        Action::Comment => {
+            if let Some(key) = ticket_detail_key {
+                app.comment_state = Some(app::CommentState {
+                    ticket_key: key,
+                    body: String::new(),
+                });
             }
-
-            let issue_type = app::ISSUE_TYPES[state.issue_type_idx].to_string();
-            let summary = state.summary.clone();
-
-            let assignee_email = if state.assignee_idx == 0 {
-                None
-            } else {
-                app.cache
-                    .team_members
-                    .get(state.assignee_idx - 1)
-                    .map(|m| m.email.clone())
-            };
-
-            let epic_key = if state.epic_idx == 0 {
-                None
-            } else {
-                app.cache
-                    .epics
-                    .get(state.epic_idx - 1)
-                    .map(|e| e.key.clone())
-            };
-
-            app.create_ticket = None;
-            app.flash = Some("Creating ticket...".to_string());
-
-            let project = config.jira.project.clone();
-            let tx = bg_tx.clone();
-            tokio::spawn(async move {
-                let result = jira_client::create_ticket(
-                    &project,
-                    &issue_type,
-                    &summary,
-                    assignee_email.as_deref(),
-                    epic_key.as_deref(),
-                )
-                .await
-                .map_err(|e| e.to_string());
-                let _ = tx.send(BackgroundMessage::TicketCreated(result));
-            });
-        }
-        KeyCode::Char(c) if state.focused_field == 1 => {
-            state.summary.push(c);
         }
-        KeyCode::Backspace if state.focused_field == 1 => {
-            state.summary.pop();
+        Action::Assign => {
+            if let Some(key) = app
+                .detail_ticket_key
+                .as_ref()
+                .and_then(|k| app.find_ticket(k).map(|_| k.clone()))
+            {
+                app.assign_state = Some(app::AssignState {
+                    ticket_key: key,
+                    selected: 0,
+                });
+            }
         }
-        KeyCode::Char('j') | KeyCode::Down => match state.focused_field {
-            0 => {
-                if state.issue_type_idx < app::ISSUE_TYPES.len() - 1 {
-                    state.issue_type_idx += 1;
-                }
+        Action::EditFields => {
+            if let Some(key) = app
+                .detail_ticket_key
+                .as_ref()
+                .and_then(|k| app.find_ticket(k).map(|_| k.clone()))
+            {
+                let ticket = app.find_ticket(&key);
+                let summary = ticket.map(|t| t.summary.clone()).unwrap_or_default();
+                let labels = ticket.map(|t| t.labels.join(", ")).unwrap_or_default();
+                app.edit_state = Some(app::EditFieldsState {
+                    ticket_key: key,
+                    focused_field: 0,
+                    summary,
+                    labels,
+                });
             }
-            2 => {
-                let max = app.cache.team_members.len(); // options are 0..=max
-                if state.assignee_idx < max {
-                    state.assignee_idx += 1;
-                }
+        }
+        Action::CloseEpic if ticket_detail_key.is_none() => {
+            if let Some(epic_key) = app.detail_epic_key.clone() {
+                begin_close_epic(app, &epic_key);
             }
-            3 => {
-                let max = app.cache.epics.len(); // options are 0..=max
-                if state.epic_idx < max {
-                    state.epic_idx += 1;
-                }
+        }
+        Action::ActivityHistory if ticket_detail_key.is_some() => {
+            app.detail_mode = DetailMode::History { scroll: 0 };
+        }
+        Action::LogWork => {
+            if let Some(key) = ticket_detail_key {
+                app.open_worklog(key, String::new(), worklog::format_date(worklog::today()));
             }
-            _ => {}
-        },
-        KeyCode::Char('k') | KeyCode::Up => match state.focused_field {
-            0 => {
-                state.issue_type_idx = state.issue_type_idx.saturating_sub(1);
+        }
+        Action::WorkTimer => {
+            if let Some(key) = ticket_detail_key {
+                toggle_work_timer(app, key);
             }
-            2 => {
-                state.assignee_idx = state.assignee_idx.saturating_sub(1);
+        }
+        Action::Watch => {
+            if let Some(key) = ticket_detail_key {
+                toggle_watch(app, key, bg_tx);
             }
-            3 => {
-                state.epic_idx = state.epic_idx.saturating_sub(1);
+        }
+        Action::NewFromTicket if ticket_detail_key.is_some() => {
+            app.detail_mode = DetailMode::DerivePicker { selected: 0 };
+        }
+        Action::DetailGitBranch => {
+            if let Some(key) = ticket_detail_key {
+                open_git_branch_prompt(app, key, config);
             }
-            _ => {}
-        },
+        }
         _ => {}
     }
 }
 
-fn handle_comment_keys(
+fn handle_detail_keys(
     app: &mut App,
     key: KeyCode,
     modifiers: KeyModifiers,
     bg_tx: &UnboundedSender<BackgroundMessage>,
+    config: &AppConfig,
 ) {
-    match key {
-        KeyCode::Esc => {
-            app.comment_state = None;
+    let nav = app.keymap.action(Scope::Picker, key, modifiers);
+    match app.detail_mode.clone() {
+        DetailMode::View => {
+            if let Some(action) = app.keymap.action(Scope::Detail, key, modifiers) {
+                run_detail_action(app, action, bg_tx, config);
+            }
         }
-        KeyCode::Enter => {
-            if modifiers.contains(KeyModifiers::SHIFT) {
-                if let Some(ref mut state) = app.comment_state {
-                    state.body.push('\n');
+        DetailMode::DerivePicker { selected } => {
+            let kinds = app::DeriveKind::all();
+            let chosen = match key {
+                KeyCode::Esc => {
+                    app.detail_mode = DetailMode::View;
+                    None
                 }
-                return;
-            }
-
-            let state = match &app.comment_state {
-                Some(s) => s,
-                None => return,
+                _ if nav == Some(Action::PickerDown) => {
+                    app.detail_mode = DetailMode::DerivePicker {
+                        selected: (selected + 1).min(kinds.len() - 1),
+                    };
+                    None
+                }
+                _ if nav == Some(Action::PickerUp) => {
+                    app.detail_mode = DetailMode::DerivePicker {
+                        selected: selected.saturating_sub(1),
+                    };
+                    None
+                }
+                KeyCode::Enter => kinds.get(selected).copied(),
+                KeyCode::Char(c) => app::DeriveKind::from_shortcut(c),
+                _ => None,
             };
-            if state.body.trim().is_empty() {
-                app.flash = Some("Comment body is required".to_string());
-                return;
-            }
-            let ticket_key = state.ticket_key.clone();
-            let body = state.body.clone();
-            app.comment_state = None;
-            app.flash = Some(format!("Adding comment to {}...", ticket_key));
-
-            let tx = bg_tx.clone();
-            let key_clone = ticket_key.clone();
-            tokio::spawn(async move {
-                let result = jira_client::add_comment(&key_clone, &body)
-                    .await
-                    .map(|_| key_clone)
-                    .map_err(|e| e.to_string());
-                let _ = tx.send(BackgroundMessage::CommentAdded(result));
-            });
-        }
-        // Some terminals encode Shift+Enter (or modified Enter) as Ctrl+J.
-        // Treat it as newline in the comment editor.
-        KeyCode::Char('j') if modifiers.contains(KeyModifiers::CONTROL) => {
-            if let Some(ref mut state) = app.comment_state {
-                state.body.push('\n');
+            if let (Some(kind), Some(source_key)) = (chosen, app.detail_ticket_key.clone()) {
+                app.detail_mode = DetailMode::View;
+                app.open_derived_ticket_form(kind, &source_key);
             }
         }
-        KeyCode::Backspace => {
-            if let Some(ref mut state) = app.comment_state {
-                state.body.pop();
+        DetailMode::MovePicker {
+            selected,
+            confirm_target,
+        } => match key {
+            KeyCode::Esc => app.detail_mode = DetailMode::View,
+            _ if nav == Some(Action::PickerDown) => {
+                if let Some((_, options)) = current_move_options(app) {
+                    let new_sel = (selected + 1).min(options.len().saturating_sub(1));
+                    app.detail_mode = DetailMode::MovePicker {
+                        selected: new_sel,
+                        confirm_target: None,
+                    };
+                }
             }
-        }
-        KeyCode::Char(c) => {
-            if let Some(ref mut state) = app.comment_state {
-                state.body.push(c);
+            _ if nav == Some(Action::PickerUp) => {
+                app.detail_mode = DetailMode::MovePicker {
+                    selected: selected.saturating_sub(1),
+                    confirm_target: None,
+                };
             }
-        }
-        _ => {}
-    }
-}
-
-fn handle_assign_keys(app: &mut App, key: KeyCode, bg_tx: &UnboundedSender<BackgroundMessage>) {
-    let member_count = app.cache.team_members.len();
-    match key {
-        KeyCode::Esc => {
-            app.assign_state = None;
-        }
-        KeyCode::Char('j') | KeyCode::Down => {
-            if let Some(ref mut state) = app.assign_state {
-                if member_count > 0 && state.selected < member_count - 1 {
-                    state.selected += 1;
+            KeyCode::Enter => {
+                if let Some(target) = confirm_target {
+                    if let Some((ticket_key, options)) = current_move_options(app) {
+                        if options.contains(&target) {
+                            perform_or_pick_resolution(app, ticket_key, target, bg_tx);
+                        }
+                    }
+                } else if let Some((ticket_key, options)) = current_move_options(app) {
+                    if let Some(new_status) = options.get(selected).cloned() {
+                        queue_move_confirmation(app, &ticket_key, selected, new_status);
+                    }
                 }
             }
-        }
-        KeyCode::Char('k') | KeyCode::Up => {
-            if let Some(ref mut state) = app.assign_state {
-                state.selected = state.selected.saturating_sub(1);
-            }
-        }
-        KeyCode::Enter => {
-            let state = match &app.assign_state {
-                Some(s) => s,
-                None => return,
-            };
-            let member = match app.cache.team_members.get(state.selected) {
-                Some(m) => m,
-                None => return,
-            };
-            let ticket_key = state.ticket_key.clone();
-            let email = member.email.clone();
-            let name = member.name.clone();
-
-            // Optimistic cache update
-            for ticket in &mut app.cache.my_tickets {
-                if ticket.key == ticket_key {
-                    ticket.assignee = Some(name.clone());
-                    ticket.assignee_email = Some(email.clone());
+            KeyCode::Char('y') | KeyCode::Char('Y') => {
+                if let Some(target) = confirm_target {
+                    if let Some((ticket_key, options)) = current_move_options(app) {
+                        if options.contains(&target) {
+                            perform_or_pick_resolution(app, ticket_key, target, bg_tx);
+                        }
+                    }
                 }
             }
-            for ticket in &mut app.cache.team_tickets {
-                if ticket.key == ticket_key {
-                    ticket.assignee = Some(name.clone());
-                    ticket.assignee_email = Some(email.clone());
+            KeyCode::Char(c) => {
+                if let Some(target_status) = Status::from_move_shortcut(c) {
+                    if let Some((ticket_key, options)) = current_move_options(app) {
+                        if let Some(target_idx) = options.iter().position(|s| *s == target_status) {
+                            if c.is_ascii_uppercase() {
+                                perform_or_pick_resolution(app, ticket_key, target_status, bg_tx);
+                            } else {
+                                queue_move_confirmation(
+                                    app,
+                                    &ticket_key,
+                                    target_idx,
+                                    target_status,
+                                );
+                            }
+                        } else {
+                            app.flash = Some(format!(
+                                "{} is already {}",
+                                ticket_key,
+                                target_status.as_str()
+                            ));
+                        }
+                    }
                 }
             }
-            for epic in &mut app.cache.epics {
-                for ticket in &mut epic.children {
-                    if ticket.key == ticket_key {
-                        ticket.assignee = Some(name.clone());
-                        ticket.assignee_email = Some(email.clone());
+            _ => {}
+        },
+        DetailMode::ResolutionPicker {
+            target_status,
+            selected,
+        } => match key {
+            KeyCode::Esc => {
+                app.detail_mode = if app.detail_ticket_key.is_some() {
+                    DetailMode::MovePicker {
+                        selected: 0,
+                        confirm_target: None,
+                    }
+                } else {
+                    DetailMode::View
+                };
+            }
+            _ if nav == Some(Action::PickerDown) => {
+                let max = config.resolutions.len().saturating_sub(1);
+                app.detail_mode = DetailMode::ResolutionPicker {
+                    target_status,
+                    selected: (selected + 1).min(max),
+                };
+            }
+            _ if nav == Some(Action::PickerUp) => {
+                app.detail_mode = DetailMode::ResolutionPicker {
+                    target_status,
+                    selected: selected.saturating_sub(1),
+                };
+            }
+            KeyCode::Enter => {
+                if let Some(resolution) = config.resolutions.get(selected) {
+                    if let Some(ticket_key) = app.detail_ticket_key.clone() {
+                        perform_ticket_move(
+                            app,
+                            ticket_key,
+                            target_status,
+                            Some(resolution.clone()),
+                            bg_tx,
+                        );
+                    } else if let Some(epic_key) = app.detail_epic_key.clone() {
+                        spawn_close_epic(app, epic_key, resolution.clone(), bg_tx);
                     }
                 }
             }
-            app.mark_cache_changed();
-
-            app.assign_state = None;
-            app.flash = Some(format!("Assigning {} to {}...", ticket_key, name));
-
-            let tx = bg_tx.clone();
-            let key_clone = ticket_key.clone();
-            let email_clone = email.clone();
-            tokio::spawn(async move {
-                let result = jira_client::assign_ticket(&key_clone, &email_clone)
-                    .await
-                    .map_err(|e| e.to_string());
-                let _ = tx.send(BackgroundMessage::TicketAssigned {
-                    key: key_clone,
-                    result,
-                });
-            });
-        }
-        _ => {}
+            _ => {}
+        },
+        DetailMode::History { scroll } => match key {
+            KeyCode::Esc => app.detail_mode = DetailMode::View,
+            _ if nav == Some(Action::PickerDown) => {
+                app.detail_mode = DetailMode::History { scroll: scroll + 1 };
+            }
+            _ if nav == Some(Action::PickerUp) => {
+                app.detail_mode = DetailMode::History {
+                    scroll: scroll.saturating_sub(1),
+                };
+            }
+            _ => {}
+        },
     }
 }
 
-fn handle_edit_keys(app: &mut App, key: KeyCode, bg_tx: &UnboundedSender<BackgroundMessage>) {
+async fn handle_search_keys(
+    app: &mut App,
+    key: KeyCode,
+    modifiers: KeyModifiers,
+    bg_tx: &UnboundedSender<BackgroundMessage>,
+) {
+    let input = app.keymap.action(Scope::Input, key, modifiers);
     match key {
         KeyCode::Esc => {
-            app.edit_state = None;
+            app.search = None;
+            app.clamp_selection();
         }
-        KeyCode::Tab => {
-            if let Some(ref mut state) = app.edit_state {
-                state.focused_field = (state.focused_field + 1) % 2;
+        KeyCode::Enter => open_selected_item(app, bg_tx),
+        KeyCode::Char('U') => {
+            app.search = None;
+            app.bulk_upload_state = Some(BulkUploadState::PathInput {
+                path: String::new(),
+                loading: false,
+            });
+        }
+        KeyCode::Backspace => {
+            if let Some(ref mut s) = app.search {
+                s.pop();
+                if s.is_empty() {
+                    app.search = None;
+                }
             }
+            app.clamp_selection();
         }
-        KeyCode::BackTab => {
-            if let Some(ref mut state) = app.edit_state {
-                state.focused_field = if state.focused_field == 0 { 1 } else { 0 };
+        _ if input == Some(Action::InputDown) => app.move_selection_down(),
+        _ if input == Some(Action::InputUp) => app.move_selection_up(),
+        KeyCode::Char(c) => {
+            if let Some(ref mut s) = app.search {
+                s.push(c);
             }
+            app.clamp_selection();
         }
-        KeyCode::Enter => {
-            let state = match &app.edit_state {
-                Some(s) => s,
-                None => return,
-            };
+        _ => {}
+    }
+}
 
-            let ticket_key = state.ticket_key.clone();
-            let new_summary = state.summary.clone();
-            let new_labels_str = state.labels.clone();
-            let new_labels: Vec<String> = new_labels_str
-                .split(',')
-                .map(|s| s.trim().to_string())
-                .filter(|s| !s.is_empty())
-                .collect();
+fn handle_bulk_upload_keys(
+    app: &mut App,
+    key: KeyCode,
+    modifiers: KeyModifiers,
+    bg_tx: &UnboundedSender<BackgroundMessage>,
+    config: &AppConfig,
+) {
+    let nav = app.keymap.action(Scope::Picker, key, modifiers);
+    let state = match app.bulk_upload_state.clone() {
+        Some(s) => s,
+        None => return,
+    };
 
-            // Optimistic cache update
-            for ticket in &mut app.cache.my_tickets {
-                if ticket.key == ticket_key {
-                    ticket.summary = new_summary.clone();
-                    ticket.labels = new_labels.clone();
+    match state {
+        BulkUploadState::PathInput { mut path, loading } => match key {
+            KeyCode::Esc => app.bulk_upload_state = None,
+            KeyCode::Enter => {
+                if loading {
+                    return;
                 }
-            }
-            for ticket in &mut app.cache.team_tickets {
-                if ticket.key == ticket_key {
-                    ticket.summary = new_summary.clone();
-                    ticket.labels = new_labels.clone();
+                let trimmed = path.trim().to_string();
+                if trimmed.is_empty() {
+                    app.flash = Some("File path is required".to_string());
+                    return;
                 }
+                app.bulk_upload_state = Some(BulkUploadState::PathInput {
+                    path: trimmed.clone(),
+                    loading: true,
+                });
+                let context = build_bulk_upload_context(app, config);
+                spawn_bulk_upload_preview(bg_tx, trimmed, context);
             }
-            for epic in &mut app.cache.epics {
-                for ticket in &mut epic.children {
-                    if ticket.key == ticket_key {
-                        ticket.summary = new_summary.clone();
-                        ticket.labels = new_labels.clone();
-                    }
+            KeyCode::Backspace => {
+                path.pop();
+                app.bulk_upload_state = Some(BulkUploadState::PathInput { path, loading });
+            }
+            KeyCode::Char(c) => {
+                path.push(c);
+                app.bulk_upload_state = Some(BulkUploadState::PathInput { path, loading });
+            }
+            _ => {}
+        },
+        BulkUploadState::Preview {
+            preview,
+            mut selected,
+        } => match key {
+            KeyCode::Esc => app.bulk_upload_state = None,
+            _ if nav == Some(Action::PickerDown) => {
+                if selected + 1 < preview.rows.len() {
+                    selected += 1;
                 }
+                app.bulk_upload_state = Some(BulkUploadState::Preview { preview, selected });
             }
-            app.mark_cache_changed();
-
-            app.edit_state = None;
-            app.flash = Some(format!("Updating {}...", ticket_key));
-
-            let tx = bg_tx.clone();
-            let key_clone = ticket_key.clone();
-            let summary_clone = new_summary.clone();
-            let labels_clone = new_labels.clone();
-            tokio::spawn(async move {
-                let summary_opt = if summary_clone.is_empty() {
-                    None
-                } else {
-                    Some(summary_clone.as_str())
-                };
-                let labels_opt = if labels_clone.is_empty() {
-                    None
-                } else {
-                    Some(labels_clone.as_slice())
-                };
-                let result = jira_client::edit_ticket(&key_clone, summary_opt, labels_opt)
-                    .await
-                    .map_err(|e| e.to_string());
-                let _ = tx.send(BackgroundMessage::TicketEdited {
-                    key: key_clone,
-                    result,
+            _ if nav == Some(Action::PickerUp) => {
+                selected = selected.saturating_sub(1);
+                app.bulk_upload_state = Some(BulkUploadState::Preview { preview, selected });
+            }
+            KeyCode::Char('r') => {
+                app.bulk_upload_state = Some(BulkUploadState::PathInput {
+                    path: preview.source_path.clone(),
+                    loading: true,
                 });
-            });
-        }
-        KeyCode::Backspace => {
-            if let Some(ref mut state) = app.edit_state {
-                match state.focused_field {
-                    0 => {
-                        state.summary.pop();
-                    }
-                    1 => {
-                        state.labels.pop();
-                    }
-                    _ => {}
-                }
+                let context = build_bulk_upload_context(app, config);
+                spawn_bulk_upload_preview(bg_tx, preview.source_path, context);
             }
-        }
-        KeyCode::Char(c) => {
-            if let Some(ref mut state) = app.edit_state {
-                match state.focused_field {
-                    0 => state.summary.push(c),
-                    1 => state.labels.push(c),
-                    _ => {}
+            KeyCode::Enter | KeyCode::Char('y') => {
+                if !preview.can_submit() {
+                    app.flash = Some(
+                        "Upload blocked: fix invalid rows in the file and reload preview"
+                            .to_string(),
+                    );
+                    return;
                 }
+                app.bulk_upload_state = Some(BulkUploadState::Running {
+                    preview: preview.clone(),
+                });
+                spawn_bulk_upload_execution(bg_tx, preview, config.jira.project.clone(), None);
+            }
+            _ => {}
+        },
+        BulkUploadState::Running { .. } => {
+            if key == KeyCode::Esc {
+                app.bulk_upload_state = None;
             }
         }
-        _ => {}
+        BulkUploadState::Result { summary, preview } => match key {
+            KeyCode::Esc | KeyCode::Enter | KeyCode::Char('q') => app.bulk_upload_state = None,
+            KeyCode::Char('f') => begin_upload_retry(app, summary, preview, bg_tx, config),
+            KeyCode::Char('r') => {
+                app.bulk_upload_state = Some(BulkUploadState::PathInput {
+                    path: summary.source_path.clone(),
+                    loading: true,
+                });
+                let context = build_bulk_upload_context(app, config);
+                spawn_bulk_upload_preview(bg_tx, summary.source_path, context);
+            }
+            _ => {}
+        },
     }
 }
 
-fn handle_filter_edit_keys(app: &mut App, key: KeyCode, config: &mut AppConfig) {
-    let state = match &mut app.filter_edit {
+/// Turn the create form into a Jira request, validating free-text fields.
+fn build_new_ticket(
+    cache: &crate::cache::Cache,
+    state: &app::CreateTicketState,
+) -> std::result::Result<jira_client::NewTicket, String> {
+    let summary = state.summary.trim();
+    if summary.is_empty() {
+        return Err("Summary is required".to_string());
+    }
+
+    let story_points = match state.story_points.trim() {
+        "" => None,
+        raw => match raw.parse::<f64>() {
+            Ok(points) if points >= 0.0 => Some(points),
+            _ => return Err(format!("Story points must be a number, got '{}'", raw)),
+        },
+    };
+
+    let split_list = |value: &str| -> Vec<String> {
+        value
+            .split(',')
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty())
+            .collect()
+    };
+
+    let assignee_email = if state.assignee_idx == 0 {
+        None
+    } else {
+        cache
+            .team_members
+            .get(state.assignee_idx - 1)
+            .map(|m| m.email.clone())
+    };
+
+    let epic_key = if state.epic_idx == 0 {
+        None
+    } else {
+        cache.epics.get(state.epic_idx - 1).map(|e| e.key.clone())
+    };
+
+    let parent_key = state
+        .derived_from
+        .as_ref()
+        .filter(|d| d.kind == app::DeriveKind::SubTask)
+        .map(|d| d.source_key.clone());
+    let issue_type = if parent_key.is_some() {
+        app::SUBTASK_ISSUE_TYPE
+    } else {
+        app::ISSUE_TYPES[state.issue_type_idx]
+    };
+
+    Ok(jira_client::NewTicket {
+        issue_type: issue_type.to_string(),
+        summary: summary.to_string(),
+        assignee_email,
+        epic_key: epic_key.filter(|_| parent_key.is_none() && !state.is_epic()),
+        parent_key,
+        description: Some(state.description.trim_end().to_string()).filter(|d| !d.is_empty()),
+        labels: split_list(&state.labels),
+        priority: state
+            .priority_idx
+            .checked_sub(1)
+            .and_then(|idx| app::PRIORITIES.get(idx))
+            .map(|p| p.to_string()),
+        components: split_list(&state.components),
+        story_points,
+        ..Default::default()
+    })
+}
+
+async fn handle_create_ticket_keys(
+    app: &mut App,
+    key: KeyCode,
+    modifiers: KeyModifiers,
+    bg_tx: &UnboundedSender<BackgroundMessage>,
+    config: &AppConfig,
+) {
+    use app::CreateTicketState as Field;
+
+    let state = match &mut app.create_ticket {
         Some(s) => s,
         None => return,
     };
 
     match key {
         KeyCode::Esc => {
-            app.filter_edit = None;
+            app.create_ticket = None;
         }
-        KeyCode::Tab | KeyCode::BackTab => {
-            state.focused_field = if state.focused_field == 0 { 1 } else { 0 };
+        KeyCode::Tab => {
+            state.focused_field = (state.focused_field + 1) % Field::FIELD_COUNT;
+        }
+        KeyCode::BackTab => {
+            state.focused_field =
+                (state.focused_field + Field::FIELD_COUNT - 1) % Field::FIELD_COUNT;
+        }
+        KeyCode::Enter
+            if state.focused_field == Field::DESCRIPTION
+                && modifiers.contains(KeyModifiers::SHIFT) =>
+        {
+            state.description.push('\n');
+        }
+        KeyCode::Char('j')
+            if state.focused_field == Field::DESCRIPTION
+                && modifiers.contains(KeyModifiers::CONTROL) =>
+        {
+            state.description.push('\n');
         }
         KeyCode::Enter => {
-            if state.name.trim().is_empty() || state.jql.trim().is_empty() {
-                app.flash = Some("Both name and JQL are required".to_string());
-                return;
-            }
-            let filter = crate::config::SavedFilter {
-                name: state.name.trim().to_string(),
-                jql: state.jql.trim().to_string(),
+            let ticket = match build_new_ticket(&app.cache, state) {
+                Ok(ticket) => ticket,
+                Err(e) => {
+                    app.flash = Some(e);
+                    return;
+                }
             };
 
-            if let Some(idx) = state.editing_idx {
-                if idx < config.filters.len() {
-                    config.filters[idx] = filter;
-                }
+            let derived = state.derived_from.clone();
+            let is_epic = state.is_epic();
+            app.create_ticket = None;
+            app.flash = Some(if is_epic {
+                "Creating epic...".to_string()
             } else {
-                config.filters.push(filter);
-                app.filter_sidebar_idx = config.filters.len() - 1;
-            }
+                "Creating ticket...".to_string()
+            });
 
-            match crate::config::save_config(config) {
-                Ok(()) => {
-                    app.flash = Some("Filter saved".to_string());
+            let project = config.jira.project.clone();
+            let tx = bg_tx.clone();
+            tokio::spawn(async move {
+                let result = jira_client::create_ticket_with_fields(&project, &ticket)
+                    .await
+                    .map_err(|e| e.to_string());
+                if is_epic {
+                    let _ = tx.send(BackgroundMessage::EpicCreated(result));
+                    return;
                 }
-                Err(e) => {
-                    app.flash = Some(format!("Failed to save filter: {}", e));
+                let Some(derived) = derived else {
+                    let _ = tx.send(BackgroundMessage::TicketCreated(result));
+                    return;
+                };
+
+                let mut link_error = None;
+                if let (Ok(new_key), app::DeriveKind::FollowUp) = (&result, derived.kind) {
+                    if let Err(e) =
+                        jira_client::link_tickets(new_key, &derived.source_key, "Relates").await
+                    {
+                        link_error = Some(e.to_string());
+                    }
                 }
+                let _ = tx.send(BackgroundMessage::DerivedTicketCreated {
+                    derived,
+                    result,
+                    link_error,
+                });
+            });
+        }
+        KeyCode::Char(c) if state.is_text_field() => {
+            if let Some(text) = state.focused_text_mut() {
+                text.push(c);
             }
-            app.filter_edit = None;
         }
-        KeyCode::Backspace => match state.focused_field {
-            0 => {
-                state.name.pop();
+        KeyCode::Backspace if state.is_text_field() => {
+            if let Some(text) = state.focused_text_mut() {
+                text.pop();
             }
-            1 => {
-                state.jql.pop();
+        }
+        KeyCode::Char('j') | KeyCode::Down => match state.focused_field {
+            Field::TEMPLATE if state.template_idx < config.templates.len() => {
+                let previous = state
+                    .template_idx
+                    .checked_sub(1)
+                    .and_then(|idx| config.templates.get(idx));
+                state.template_idx += 1;
+                state.apply_template(previous, config.templates.get(state.template_idx - 1));
+            }
+            Field::TYPE
+                if !state.is_subtask() && state.issue_type_idx < app::ISSUE_TYPES.len() - 1 =>
+            {
+                state.issue_type_idx += 1;
+            }
+            Field::PRIORITY if state.priority_idx < app::PRIORITIES.len() => {
+                state.priority_idx += 1;
+            }
+            Field::ASSIGNEE => {
+                let max = app.cache.team_members.len(); // options are 0..=max
+                if state.assignee_idx < max {
+                    state.assignee_idx += 1;
+                }
+            }
+            Field::EPIC => {
+                let max = app.cache.epics.len(); // options are 0..=max
+                if state.epic_idx < max {
+                    state.epic_idx += 1;
+                }
             }
             _ => {}
         },
-        KeyCode::Char(c) => match state.focused_field {
-            0 => state.name.push(c),
-            1 => state.jql.push(c),
+        KeyCode::Char('k') | KeyCode::Up => match state.focused_field {
+            Field::TEMPLATE if state.template_idx > 0 => {
+                let previous = config.templates.get(state.template_idx - 1);
+                state.template_idx -= 1;
+                let template = state
+                    .template_idx
+                    .checked_sub(1)
+                    .and_then(|idx| config.templates.get(idx));
+                state.apply_template(previous, template);
+            }
+            Field::TYPE if !state.is_subtask() => {
+                state.issue_type_idx = state.issue_type_idx.saturating_sub(1);
+            }
+            Field::PRIORITY => {
+                state.priority_idx = state.priority_idx.saturating_sub(1);
+            }
+            Field::ASSIGNEE => {
+                state.assignee_idx = state.assignee_idx.saturating_sub(1);
+            }
+            Field::EPIC => {
+                state.epic_idx = state.epic_idx.saturating_sub(1);
+            }
             _ => {}
         },
         _ => {}
     }
 }
 
-fn handle_filter_keys(
+fn handle_comment_keys(
     app: &mut App,
     key: KeyCode,
+    modifiers: KeyModifiers,
     bg_tx: &UnboundedSender<BackgroundMessage>,
-    config: &mut AppConfig,
 ) {
     match key {
-        KeyCode::Char('q') => app.should_quit = true,
-        KeyCode::Char('?') => app.toggle_keybindings(),
-        KeyCode::Tab => {
-            if app.filter_focus == FilterFocus::Sidebar {
-                if !app.filter_results.is_empty() {
-                    app.filter_focus = FilterFocus::Results;
-                    app.selected_index = 0;
-                } else {
-                    app.next_tab();
+        KeyCode::Esc => {
+            app.comment_state = None;
+        }
+        KeyCode::Enter => {
+            if modifiers.contains(KeyModifiers::SHIFT) {
+                if let Some(ref mut state) = app.comment_state {
+                    state.body.push('\n');
                 }
-            } else {
-                app.next_tab();
+                return;
             }
-        }
-        KeyCode::BackTab => {
-            if app.filter_focus == FilterFocus::Results {
-                app.filter_focus = FilterFocus::Sidebar;
+
+            let state = match &app.comment_state {
+                Some(s) => s,
+                None => return,
+            };
+            if state.body.trim().is_empty() {
+                app.flash = Some("Comment body is required".to_string());
+                return;
             }
-        }
-        KeyCode::Char('n') => {
-            app.filter_edit = Some(app::FilterEditState {
-                focused_field: 0,
-                name: String::new(),
-                jql: String::new(),
-                editing_idx: None,
+            let ticket_key = state.ticket_key.clone();
+            let body = state.body.clone();
+            app.comment_state = None;
+            app.flash = Some(format!("Adding comment to {}...", ticket_key));
+
+            let tx = bg_tx.clone();
+            let key_clone = ticket_key.clone();
+            tokio::spawn(async move {
+                let result = jira_client::add_comment(&key_clone, &body)
+                    .await
+                    .map(|_| key_clone)
+                    .map_err(|e| e.to_string());
+                let _ = tx.send(BackgroundMessage::CommentAdded(result));
             });
         }
-        KeyCode::Char('e') => {
-            if app.filter_focus == FilterFocus::Sidebar {
-                if let Some(filter) = config.filters.get(app.filter_sidebar_idx) {
-                    app.filter_edit = Some(app::FilterEditState {
-                        focused_field: 0,
-                        name: filter.name.clone(),
-                        jql: filter.jql.clone(),
-                        editing_idx: Some(app.filter_sidebar_idx),
-                    });
-                }
+        // Some terminals encode Shift+Enter (or modified Enter) as Ctrl+J.
+        // Treat it as newline in the comment editor.
+        KeyCode::Char('j') if modifiers.contains(KeyModifiers::CONTROL) => {
+            if let Some(ref mut state) = app.comment_state {
+                state.body.push('\n');
             }
         }
-        KeyCode::Char('x') => {
-            if app.filter_focus == FilterFocus::Sidebar && !config.filters.is_empty() {
-                if app.filter_sidebar_idx < config.filters.len() {
-                    let removed_name = config.filters.remove(app.filter_sidebar_idx).name;
-                    match crate::config::save_config(config) {
-                        Ok(()) => {
-                            app.flash = Some(format!("Deleted filter '{}'", removed_name));
-                            if app.filter_sidebar_idx > 0
-                                && app.filter_sidebar_idx >= config.filters.len()
-                            {
-                                app.filter_sidebar_idx = config.filters.len().saturating_sub(1);
-                            }
-                        }
-                        Err(e) => {
-                            app.flash = Some(format!("Failed to delete filter: {}", e));
-                        }
-                    }
-                }
+        KeyCode::Backspace => {
+            if let Some(ref mut state) = app.comment_state {
+                state.body.pop();
             }
         }
-        KeyCode::Char('U') => {
-            app.bulk_upload_state = Some(BulkUploadState::PathInput {
-                path: String::new(),
-                loading: false,
-            });
-        }
-        KeyCode::Char(' ') => {
-            if app.filter_focus == FilterFocus::Results {
-                app.toggle_selection_at_cursor();
+        KeyCode::Char(c) => {
+            if let Some(ref mut state) = app.comment_state {
+                state.body.push(c);
             }
         }
-        KeyCode::Char('A') => {
-            if app.filter_focus == FilterFocus::Results {
-                app.select_all_visible_tickets();
-                app.flash = Some(format!(
-                    "Selected {} tickets",
-                    app.selected_visible_ticket_keys_in_order().len()
-                ));
-            }
+        _ => {}
+    }
+}
+
+fn handle_assign_keys(
+    app: &mut App,
+    key: KeyCode,
+    modifiers: KeyModifiers,
+    bg_tx: &UnboundedSender<BackgroundMessage>,
+) {
+    let nav = app.keymap.action(Scope::Picker, key, modifiers);
+    let member_count = app.cache.team_members.len();
+    match key {
+        KeyCode::Esc => {
+            app.assign_state = None;
         }
-        KeyCode::Char('u') => {
-            if app.filter_focus == FilterFocus::Results {
-                app.clear_selected_tickets();
-                app.flash = Some("Selection cleared".to_string());
+        _ if nav == Some(Action::PickerDown) => {
+            if let Some(ref mut state) = app.assign_state {
+                if member_count > 0 && state.selected < member_count - 1 {
+                    state.selected += 1;
+                }
             }
         }
-        KeyCode::Char('B') => {
-            if app.filter_focus == FilterFocus::Results {
-                begin_bulk_from_selection(app);
+        _ if nav == Some(Action::PickerUp) => {
+            if let Some(ref mut state) = app.assign_state {
+                state.selected = state.selected.saturating_sub(1);
             }
         }
-        KeyCode::Char('j') | KeyCode::Down => match app.filter_focus {
-            FilterFocus::Sidebar => {
-                if !config.filters.is_empty() && app.filter_sidebar_idx < config.filters.len() - 1 {
-                    app.filter_sidebar_idx += 1;
+        KeyCode::Enter => {
+            let state = match &app.assign_state {
+                Some(s) => s,
+                None => return,
+            };
+            let member = match app.cache.team_members.get(state.selected) {
+                Some(m) => m,
+                None => return,
+            };
+            let ticket_key = state.ticket_key.clone();
+            let email = member.email.clone();
+            let name = member.name.clone();
+
+            let changes = app.journal_changes_for(
+                std::slice::from_ref(&ticket_key),
+                &BulkTarget::Assign {
+                    member_email: email.clone(),
+                    member_name: name.clone(),
+                },
+            );
+            let journal = (format!("Assign {} to {}", ticket_key, name), changes);
+
+            // Optimistic cache update
+            for ticket in &mut app.cache.my_tickets {
+                if ticket.key == ticket_key {
+                    ticket.assignee = Some(name.clone());
+                    ticket.assignee_email = Some(email.clone());
                 }
             }
-            FilterFocus::Results => app.move_selection_down(),
-        },
-        KeyCode::Char('k') | KeyCode::Up => match app.filter_focus {
-            FilterFocus::Sidebar => {
-                app.filter_sidebar_idx = app.filter_sidebar_idx.saturating_sub(1);
+            for ticket in &mut app.cache.team_tickets {
+                if ticket.key == ticket_key {
+                    ticket.assignee = Some(name.clone());
+                    ticket.assignee_email = Some(email.clone());
+                }
             }
-            FilterFocus::Results => app.move_selection_up(),
-        },
-        KeyCode::Enter => match app.filter_focus {
-            FilterFocus::Sidebar => {
-                // Run the selected filter
-                if let Some(filter) = config.filters.get(app.filter_sidebar_idx) {
-                    app.filter_loading = true;
-                    app.filter_results.clear();
-                    app.mark_cache_changed();
-                    app.flash = Some(format!("Running filter '{}'...", filter.name));
-
-                    let tx = bg_tx.clone();
-                    let cfg = config.clone();
-                    let jql = filter.jql.clone();
-                    tokio::spawn(async move {
-                        let result = jira_client::fetch_jql_query(&cfg, &jql)
-                            .await
-                            .map_err(|e| e.to_string());
-                        let _ = tx.send(BackgroundMessage::FilterResults(result));
-                    });
+            for ticket in &mut app.cache.watched_tickets {
+                if ticket.key == ticket_key {
+                    ticket.assignee = Some(name.clone());
+                    ticket.assignee_email = Some(email.clone());
                 }
             }
-            FilterFocus::Results => {
-                if let Some(key) = app.selected_ticket_key() {
-                    let detail_loaded = app.is_ticket_detail_loaded(&key);
-                    app.open_detail(key.clone());
-                    if !detail_loaded && app.begin_detail_fetch(&key) {
-                        spawn_ticket_detail_fetch(bg_tx, key);
+            for epic in &mut app.cache.epics {
+                for ticket in &mut epic.children {
+                    if ticket.key == ticket_key {
+                        ticket.assignee = Some(name.clone());
+                        ticket.assignee_email = Some(email.clone());
                     }
                 }
             }
-        },
-        KeyCode::Char('/') => app.search = Some(String::new()),
-        KeyCode::Char('r') => {
-            if app.loading {
-                app.flash = Some("Refresh already in progress".to_string());
-            } else {
-                app.loading = true;
-                app.ticket_sync_stage = None;
-                app.flash = Some("Refreshing tickets...".to_string());
-                spawn_cache_refresh(bg_tx, CacheRefreshPhase::Manual, config);
-            }
+            app.mark_cache_changed();
+
+            app.assign_state = None;
+            app.flash = Some(format!("Assigning {} to {}...", ticket_key, name));
+
+            let tx = bg_tx.clone();
+            let key_clone = ticket_key.clone();
+            let email_clone = email.clone();
+            tokio::spawn(async move {
+                let result = jira_client::assign_ticket(&key_clone, &email_clone)
+                    .await
+                    .map_err(|e| e.to_string());
+                let _ = tx.send(BackgroundMessage::TicketAssigned {
+                    key: key_clone,
+                    journal,
+                    result,
+                });
+            });
         }
         _ => {}
     }
 }
 
-async fn handle_main_keys(
-    app: &mut App,
-    key: KeyCode,
-    _modifiers: KeyModifiers,
-    bg_tx: &UnboundedSender<BackgroundMessage>,
-    config: &AppConfig,
-) {
+fn handle_edit_keys(app: &mut App, key: KeyCode, bg_tx: &UnboundedSender<BackgroundMessage>) {
     match key {
-        KeyCode::Char('q') => app.should_quit = true,
-        KeyCode::Tab => app.next_tab(),
-        KeyCode::Char('j') | KeyCode::Down => app.move_selection_down(),
-        KeyCode::Char('k') | KeyCode::Up => app.move_selection_up(),
-        KeyCode::Char(' ') => app.toggle_selection_at_cursor(),
-        KeyCode::Char('A') => {
-            app.select_all_visible_tickets();
-            app.flash = Some(format!(
-                "Selected {} tickets",
-                app.selected_visible_ticket_keys_in_order().len()
-            ));
-        }
-        KeyCode::Char('u') => {
-            app.clear_selected_tickets();
-            app.flash = Some("Selection cleared".to_string());
-        }
-        KeyCode::Char('B') => begin_bulk_from_selection(app),
-        KeyCode::Char('/') => app.search = Some(String::new()),
-        KeyCode::Char('?') => app.toggle_keybindings(),
-        KeyCode::Char('d') => {
-            app.toggle_show_done();
-            app.flash = Some(if app.show_done {
-                "Showing Done tickets".to_string()
-            } else {
-                "Hiding Done tickets".to_string()
-            });
-        }
-        KeyCode::Char('p') => {
-            app.toggle_status_focus(Status::InProgress);
-            app.flash = Some(
-                app.status_focus
-                    .as_ref()
-                    .map(|s| format!("Focus: {}", s.as_str()))
-                    .unwrap_or_else(|| "Focus: all".to_string()),
-            );
-        }
-        KeyCode::Char('w') => {
-            app.toggle_status_focus(Status::ReadyForWork);
-            app.flash = Some(
-                app.status_focus
-                    .as_ref()
-                    .map(|s| format!("Focus: {}", s.as_str()))
-                    .unwrap_or_else(|| "Focus: all".to_string()),
-            );
-        }
-        KeyCode::Char('n') => {
-            app.toggle_status_focus(Status::NeedsTriage);
-            app.flash = Some(
-                app.status_focus
-                    .as_ref()
-                    .map(|s| format!("Focus: {}", s.as_str()))
-                    .unwrap_or_else(|| "Focus: all".to_string()),
-            );
-        }
-        KeyCode::Char('v') => {
-            app.toggle_status_focus(Status::InReview);
-            app.flash = Some(
-                app.status_focus
-                    .as_ref()
-                    .map(|s| format!("Focus: {}", s.as_str()))
-                    .unwrap_or_else(|| "Focus: all".to_string()),
-            );
+        KeyCode::Esc => {
+            app.edit_state = None;
         }
-        KeyCode::Char('r') => {
-            if app.loading {
-                app.flash = Some("Refresh already in progress".to_string());
-            } else {
-                app.loading = true;
-                app.ticket_sync_stage = None;
-                app.flash = Some("Refreshing tickets...".to_string());
-                spawn_cache_refresh(bg_tx, CacheRefreshPhase::Manual, config);
+        KeyCode::Tab => {
+            if let Some(ref mut state) = app.edit_state {
+                state.focused_field = (state.focused_field + 1) % 2;
             }
         }
-        KeyCode::Char('z') => {
-            if let Some(group_id) = app.selected_group_id() {
-                app.toggle_group_collapse(&group_id);
+        KeyCode::BackTab => {
+            if let Some(ref mut state) = app.edit_state {
+                state.focused_field = if state.focused_field == 0 { 1 } else { 0 };
             }
         }
-        KeyCode::Char('Z') => {
-            app.toggle_all_groups_collapse();
-        }
-        KeyCode::Char('c') => {
-            app.create_ticket = Some(app::CreateTicketState {
-                focused_field: 0,
-                issue_type_idx: 0,
-                summary: String::new(),
-                assignee_idx: 0,
-                epic_idx: 0,
-            });
-        }
-        KeyCode::Char('U') => {
-            app.bulk_upload_state = Some(BulkUploadState::PathInput {
-                path: String::new(),
-                loading: false,
-            });
-        }
         KeyCode::Enter => {
-            if let Some(group_id) = app.selected_header_group_id() {
-                if app.active_tab == Tab::Epics {
-                    app.open_epic_detail(group_id);
-                } else if app.is_collapsed(app.active_tab, &group_id) {
-                    app.toggle_group_collapse(&group_id);
+            let state = match &app.edit_state {
+                Some(s) => s,
+                None => return,
+            };
+
+            let ticket_key = state.ticket_key.clone();
+            let new_summary = state.summary.clone();
+            let new_labels_str = state.labels.clone();
+            let new_labels: Vec<String> = new_labels_str
+                .split(',')
+                .map(|s| s.trim().to_string())
+                .filter(|s| !s.is_empty())
+                .collect();
+
+            // `jira issue edit -l` only appends, so only added labels need reverting.
+            let keys = std::slice::from_ref(&ticket_key);
+            let mut changes = Vec::new();
+            if !new_summary.is_empty() {
+                changes.extend(app.journal_changes_for(
+                    keys,
+                    &BulkTarget::Summary {
+                        summary: new_summary.clone(),
+                    },
+                ));
+            }
+            changes.extend(app.journal_changes_for(
+                keys,
+                &BulkTarget::AddLabels {
+                    labels: new_labels.clone(),
+                },
+            ));
+            let journal = (format!("Edit {}", ticket_key), changes);
+
+            // Optimistic cache update
+            for ticket in &mut app.cache.my_tickets {
+                if ticket.key == ticket_key {
+                    ticket.summary = new_summary.clone();
+                    ticket.labels = new_labels.clone();
+                }
+            }
+            for ticket in &mut app.cache.team_tickets {
+                if ticket.key == ticket_key {
+                    ticket.summary = new_summary.clone();
+                    ticket.labels = new_labels.clone();
+                }
+            }
+            for ticket in &mut app.cache.watched_tickets {
+                if ticket.key == ticket_key {
+                    ticket.summary = new_summary.clone();
+                    ticket.labels = new_labels.clone();
                 }
-            } else if let Some(key) = app.selected_ticket_key() {
-                let detail_loaded = app.is_ticket_detail_loaded(&key);
-                app.open_detail(key.clone());
-                if !detail_loaded && app.begin_detail_fetch(&key) {
-                    spawn_ticket_detail_fetch(bg_tx, key);
+            }
+            for epic in &mut app.cache.epics {
+                for ticket in &mut epic.children {
+                    if ticket.key == ticket_key {
+                        ticket.summary = new_summary.clone();
+                        ticket.labels = new_labels.clone();
+                    }
                 }
             }
-        }
-        _ => {}
+            app.mark_cache_changed();
+
+            app.edit_state = None;
+            app.flash = Some(format!("Updating {}...", ticket_key));
+
+            let tx = bg_tx.clone();
+            let key_clone = ticket_key.clone();
+            let summary_clone = new_summary.clone();
+            let labels_clone = new_labels.clone();
+            tokio::spawn(async move {
+                let summary_opt = if summary_clone.is_empty() {
+                    None
+                } else {
+                    Some(summary_clone.as_str())
+                };
+                let labels_opt = if labels_clone.is_empty() {
+                    None
+                } else {
+                    Some(labels_clone.as_slice())
+                };
+                let result = jira_client::edit_ticket(&key_clone, summary_opt, labels_opt)
+                    .await
+                    .map_err(|e| e.to_string());
+                let _ = tx.send(BackgroundMessage::TicketEdited {
+                    key: key_clone,
+                    journal,
+                    result,
+                });
+            });
+        }
+        KeyCode::Backspace => {
+            if let Some(ref mut state) = app.edit_state {
+                match state.focused_field {
+                    0 => {
+                        state.summary.pop();
+                    }
+                    1 => {
+                        state.labels.pop();
+                    }
+                    _ => {}
+                }
+            }
+        }
+        KeyCode::Char(c) => {
+            if let Some(ref mut state) = app.edit_state {
+                match state.focused_field {
+                    0 => state.summary.push(c),
+                    1 => state.labels.push(c),
+                    _ => {}
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+fn handle_worklog_keys(app: &mut App, key: KeyCode, bg_tx: &UnboundedSender<BackgroundMessage>) {
+    let Some(state) = app.worklog_state.as_mut() else {
+        return;
+    };
+
+    match key {
+        KeyCode::Esc => {
+            app.worklog_state = None;
+        }
+        KeyCode::Tab => {
+            state.focused_field = (state.focused_field + 1) % 3;
+        }
+        KeyCode::BackTab => {
+            state.focused_field = (state.focused_field + 2) % 3;
+        }
+        KeyCode::Enter => {
+            let request = match worklog::prepare_request(state, worklog::today()) {
+                Ok(request) => request,
+                Err(e) => {
+                    app.flash = Some(e.to_string());
+                    return;
+                }
+            };
+            app.worklog_state = None;
+            app.flash = Some(format!(
+                "Logging {} on {}...",
+                request.time_spent, request.ticket_key
+            ));
+
+            let tx = bg_tx.clone();
+            tokio::spawn(async move {
+                let result = jira_client::add_worklog(
+                    &request.ticket_key,
+                    &request.time_spent,
+                    request.started.as_deref(),
+                    request.comment.as_deref(),
+                )
+                .await
+                .map_err(|e| e.to_string());
+                let _ = tx.send(BackgroundMessage::WorklogAdded {
+                    key: request.ticket_key,
+                    time_spent: request.time_spent,
+                    result,
+                });
+            });
+        }
+        KeyCode::Backspace => match state.focused_field {
+            0 => {
+                state.duration.pop();
+            }
+            1 => {
+                state.date.pop();
+            }
+            _ => {
+                state.comment.pop();
+            }
+        },
+        KeyCode::Char(c) => match state.focused_field {
+            0 => state.duration.push(c),
+            1 => state.date.push(c),
+            _ => state.comment.push(c),
+        },
+        _ => {}
+    }
+}
+
+fn handle_filter_edit_keys(app: &mut App, key: KeyCode, config: &mut AppConfig) {
+    let state = match &mut app.filter_edit {
+        Some(s) => s,
+        None => return,
+    };
+
+    match key {
+        KeyCode::Esc => {
+            app.filter_edit = None;
+        }
+        KeyCode::Tab | KeyCode::BackTab => {
+            state.focused_field = if state.focused_field == 0 { 1 } else { 0 };
+        }
+        KeyCode::Enter => {
+            if state.name.trim().is_empty() || state.jql.trim().is_empty() {
+                app.flash = Some("Both name and JQL are required".to_string());
+                return;
+            }
+            let filter = crate::config::SavedFilter {
+                name: state.name.trim().to_string(),
+                jql: state.jql.trim().to_string(),
+            };
+
+            if let Some(idx) = state.editing_idx {
+                if idx < config.filters.len() {
+                    config.filters[idx] = filter;
+                }
+            } else {
+                config.filters.push(filter);
+                app.filter_sidebar_idx = config.filters.len() - 1;
+            }
+
+            match crate::config::save_config(config) {
+                Ok(()) => {
+                    app.flash = Some("Filter saved".to_string());
+                }
+                Err(e) => {
+                    app.flash = Some(format!("Failed to save filter: {}", e));
+                }
+            }
+            app.filter_edit = None;
+        }
+        KeyCode::Backspace => match state.focused_field {
+            0 => {
+                state.name.pop();
+            }
+            1 => {
+                state.jql.pop();
+            }
+            _ => {}
+        },
+        KeyCode::Char(c) => match state.focused_field {
+            0 => state.name.push(c),
+            1 => state.jql.push(c),
+            _ => {}
+        },
+        _ => {}
+    }
+}
+
+fn handle_filter_keys(
+    app: &mut App,
+    key: KeyCode,
+    modifiers: KeyModifiers,
+    bg_tx: &UnboundedSender<BackgroundMessage>,
+    config: &mut AppConfig,
+) {
+    if let Some(action) = app.keymap.action(Scope::Filters, key, modifiers) {
+        run_filter_action(app, action, bg_tx, config);
+    }
+}
+
+fn run_filter_action(
+    app: &mut App,
+    action: Action,
+    bg_tx: &UnboundedSender<BackgroundMessage>,
+    config: &mut AppConfig,
+) {
+    match action {
+        Action::Quit => app.should_quit = true,
+        Action::CommandPalette => open_command_palette(app, config, Scope::Filters),
+        Action::GoToKey => app.goto_key = Some(Default::default()),
+        Action::NavBack => navigate_history(app, true, bg_tx),
+        Action::Help => app.toggle_keybindings(),
+        Action::TogglePreview => toggle_detail_layout(app, bg_tx),
+        Action::NextTab => {
+            if app.filter_focus == FilterFocus::Sidebar {
+                if !app.filter_results.is_empty() {
+                    app.filter_focus = FilterFocus::Results;
+                    app.selected_index = 0;
+                } else {
+                    app.next_tab();
+                }
+            } else {
+                app.next_tab();
+            }
+        }
+        Action::SidebarPane if app.filter_focus == FilterFocus::Results => {
+            app.filter_focus = FilterFocus::Sidebar;
+        }
+        Action::NewFilter => {
+            app.filter_edit = Some(app::FilterEditState {
+                focused_field: 0,
+                name: String::new(),
+                jql: String::new(),
+                editing_idx: None,
+            });
+        }
+        Action::EditFilter if app.filter_focus == FilterFocus::Sidebar => {
+            if let Some(filter) = config.filters.get(app.filter_sidebar_idx) {
+                app.filter_edit = Some(app::FilterEditState {
+                    focused_field: 0,
+                    name: filter.name.clone(),
+                    jql: filter.jql.clone(),
+                    editing_idx: Some(app.filter_sidebar_idx),
+                });
+            }
+        }
+        Action::DeleteFilter
+            if app.filter_focus == FilterFocus::Sidebar
+                && app.filter_sidebar_idx < config.filters.len() =>
+        {
+            let removed_name = config.filters.remove(app.filter_sidebar_idx).name;
+            match crate::config::save_config(config) {
+                Ok(()) => {
+                    app.flash = Some(format!("Deleted filter '{}'", removed_name));
+                    if app.filter_sidebar_idx > 0 && app.filter_sidebar_idx >= config.filters.len()
+                    {
+                        app.filter_sidebar_idx = config.filters.len().saturating_sub(1);
+                    }
+                }
+                Err(e) => {
+                    app.flash = Some(format!("Failed to delete filter: {}", e));
+                }
+            }
+        }
+        Action::BulkUpload => {
+            app.bulk_upload_state = Some(BulkUploadState::PathInput {
+                path: String::new(),
+                loading: false,
+            });
+        }
+        Action::ToggleSelect if app.filter_focus == FilterFocus::Results => {
+            app.toggle_selection_at_cursor();
+        }
+        Action::SelectAll if app.filter_focus == FilterFocus::Results => {
+            app.select_all_visible_tickets();
+            app.flash = Some(format!(
+                "Selected {} tickets",
+                app.selected_visible_ticket_keys_in_order().len()
+            ));
+        }
+        Action::ClearSelection if app.filter_focus == FilterFocus::Results => {
+            app.clear_selected_tickets();
+            app.flash = Some("Selection cleared".to_string());
+        }
+        Action::BulkActions if app.filter_focus == FilterFocus::Results => {
+            begin_bulk_from_selection(app);
+        }
+        Action::Export => app.export_picker = Some(0),
+        Action::Down => match app.filter_focus {
+            FilterFocus::Sidebar => {
+                if !config.filters.is_empty() && app.filter_sidebar_idx < config.filters.len() - 1 {
+                    app.filter_sidebar_idx += 1;
+                }
+            }
+            FilterFocus::Results => app.move_selection_down(),
+        },
+        Action::Up => match app.filter_focus {
+            FilterFocus::Sidebar => {
+                app.filter_sidebar_idx = app.filter_sidebar_idx.saturating_sub(1);
+            }
+            FilterFocus::Results => app.move_selection_up(),
+        },
+        Action::Open => match app.filter_focus {
+            FilterFocus::Sidebar => run_selected_filter(app, bg_tx, config),
+            FilterFocus::Results => open_selected_item(app, bg_tx),
+        },
+        Action::Search => app.search = Some(String::new()),
+        Action::Refresh => start_manual_refresh(app, bg_tx, config),
+        _ => {}
+    }
+}
+
+fn toggle_detail_layout(app: &mut App, bg_tx: &UnboundedSender<BackgroundMessage>) {
+    app.toggle_detail_layout();
+    app.flash = Some(match app.detail_layout {
+        DetailLayout::Split => "Split view: the selected row is previewed on the right".to_string(),
+        DetailLayout::Overlay => "Detail opens over the list".to_string(),
+    });
+    request_preview_detail(app, bg_tx);
+}
+
+/// Fetch the watcher names of the open ticket; detail fetches only carry the count.
+fn request_watcher_names(app: &mut App, bg_tx: &UnboundedSender<BackgroundMessage>) {
+    let Some(key) = app.watcher_names_fetch_key() else {
+        return;
+    };
+    let tx = bg_tx.clone();
+    tokio::spawn(async move {
+        let result = jira_client::fetch_watcher_names(&key)
+            .await
+            .map_err(|e| e.to_string());
+        let _ = tx.send(BackgroundMessage::WatcherNamesFetched { key, result });
+    });
+}
+
+/// Fetch the detail of the ticket in the preview pane when it is not loaded yet.
+fn request_preview_detail(app: &mut App, bg_tx: &UnboundedSender<BackgroundMessage>) {
+    if let Some(key) = app.preview_fetch_key() {
+        if app.begin_detail_fetch(&key) {
+            spawn_ticket_detail_fetch(bg_tx, key);
+        }
+    }
+}
+
+fn run_selected_filter(
+    app: &mut App,
+    bg_tx: &UnboundedSender<BackgroundMessage>,
+    config: &AppConfig,
+) {
+    let Some(filter) = config.filters.get(app.filter_sidebar_idx) else {
+        return;
+    };
+    app.filter_loading = true;
+    app.filter_results.clear();
+    app.mark_cache_changed();
+    app.flash = Some(format!("Running filter '{}'...", filter.name));
+
+    let tx = bg_tx.clone();
+    let cfg = config.clone();
+    let jql = filter.jql.clone();
+    tokio::spawn(async move {
+        let result = jira_client::fetch_jql_query(&cfg, &jql)
+            .await
+            .map_err(|e| e.to_string());
+        let _ = tx.send(BackgroundMessage::FilterResults(result));
+    });
+}
+
+/// Clicks select rows, tabs and filters (double-click opens or runs them); the wheel
+/// scrolls the detail view, the help overlay or the list under the pointer.
+/// Returns false for events that are ignored, such as pointer moves.
+fn handle_mouse(
+    app: &mut App,
+    mouse: MouseEvent,
+    bg_tx: &UnboundedSender<BackgroundMessage>,
+    config: &AppConfig,
+) -> bool {
+    let scroll_down = match mouse.kind {
+        MouseEventKind::ScrollDown => true,
+        MouseEventKind::ScrollUp => false,
+        MouseEventKind::Down(MouseButton::Left) => {
+            if !app.show_keybindings && !app.is_detail_open() && !app.is_modal_open() {
+                handle_click(app, mouse.column, mouse.row, bg_tx, config);
+            }
+            return true;
+        }
+        _ => return false,
+    };
+
+    if let Some(palette) = app.command_palette.as_mut() {
+        if scroll_down {
+            palette.move_down();
+        } else {
+            palette.move_up();
+        }
+    } else if app.show_keybindings {
+        app.keybindings_scroll = if scroll_down {
+            app.keybindings_scroll.saturating_add(1)
+        } else {
+            app.keybindings_scroll.saturating_sub(1)
+        };
+    } else if app.is_detail_open() {
+        match app.detail_mode {
+            DetailMode::View if scroll_down => app.scroll_detail_down(),
+            DetailMode::View => app.scroll_detail_up(),
+            DetailMode::History { scroll } => {
+                app.detail_mode = DetailMode::History {
+                    scroll: if scroll_down {
+                        scroll + 1
+                    } else {
+                        scroll.saturating_sub(1)
+                    },
+                };
+            }
+            _ => {}
+        }
+    } else if app.is_modal_open() {
+        // Modals scroll with their own keys.
+    } else if app.active_tab == Tab::Filters
+        && matches!(
+            app.click_target_at(mouse.column, mouse.row),
+            Some(ClickTarget::Filter(_))
+        )
+    {
+        app.filter_sidebar_idx = if scroll_down {
+            (app.filter_sidebar_idx + 1).min(config.filters.len().saturating_sub(1))
+        } else {
+            app.filter_sidebar_idx.saturating_sub(1)
+        };
+    } else if scroll_down {
+        app.move_selection_down();
+    } else {
+        app.move_selection_up();
+    }
+    true
+}
+
+fn handle_click(
+    app: &mut App,
+    column: u16,
+    row: u16,
+    bg_tx: &UnboundedSender<BackgroundMessage>,
+    config: &AppConfig,
+) {
+    let Some(target) = app.click_target_at(column, row) else {
+        return;
+    };
+    let double = app.register_click(target, std::time::Instant::now());
+    match target {
+        ClickTarget::Tab(tab) => app.set_tab(tab),
+        ClickTarget::Item(index) => {
+            if app.active_tab == Tab::Filters {
+                app.filter_focus = FilterFocus::Results;
+            }
+            app.selected_index = index;
+            app.clamp_selection();
+            if double {
+                open_selected_item(app, bg_tx);
+            }
+        }
+        ClickTarget::Filter(index) => {
+            app.filter_focus = FilterFocus::Sidebar;
+            app.filter_sidebar_idx = index;
+            if double {
+                run_selected_filter(app, bg_tx, config);
+            }
+        }
+    }
+}
+
+async fn handle_main_keys(
+    app: &mut App,
+    key: KeyCode,
+    modifiers: KeyModifiers,
+    bg_tx: &UnboundedSender<BackgroundMessage>,
+    config: &AppConfig,
+) {
+    if let Some(action) = app.keymap.action(Scope::Main, key, modifiers) {
+        run_main_action(app, action, bg_tx, config);
+    }
+}
+
+fn run_main_action(
+    app: &mut App,
+    action: Action,
+    bg_tx: &UnboundedSender<BackgroundMessage>,
+    config: &AppConfig,
+) {
+    match action {
+        Action::Quit => app.should_quit = true,
+        Action::CommandPalette => open_command_palette(app, config, Scope::Main),
+        Action::GoToKey => app.goto_key = Some(Default::default()),
+        Action::NavBack => navigate_history(app, true, bg_tx),
+        Action::NextTab => app.next_tab(),
+        Action::Down => app.move_selection_down(),
+        Action::Up => app.move_selection_up(),
+        Action::ToggleSelect => app.toggle_selection_at_cursor(),
+        Action::SelectAll => {
+            app.select_all_visible_tickets();
+            app.flash = Some(format!(
+                "Selected {} tickets",
+                app.selected_visible_ticket_keys_in_order().len()
+            ));
+        }
+        Action::ClearSelection => {
+            app.clear_selected_tickets();
+            app.flash = Some("Selection cleared".to_string());
+        }
+        Action::BulkActions => begin_bulk_from_selection(app),
+        Action::NewEpic if app.active_tab == Tab::Epics => {
+            app.create_ticket = Some(app::CreateTicketState::new_epic());
+        }
+        Action::MoveToEpic if app.active_tab == Tab::Epics => {
+            if let Some(targets) = bulk_targets_from_selection(app) {
+                app.bulk_state = Some(BulkState::EpicPicker {
+                    targets,
+                    selected: 0,
+                });
+            }
+        }
+        Action::RemoveFromEpic if app.active_tab == Tab::Epics => {
+            if let Some(targets) = bulk_targets_from_selection(app) {
+                app.bulk_state = Some(BulkState::Confirm {
+                    targets,
+                    target: BulkTarget::Epic {
+                        epic_key: None,
+                        epic_name: None,
+                    },
+                });
+            }
+        }
+        Action::Search => app.search = Some(String::new()),
+        Action::Help => app.toggle_keybindings(),
+        Action::ToggleDone => {
+            app.toggle_show_done();
+            app.flash = Some(if app.show_done {
+                "Showing Done tickets".to_string()
+            } else {
+                "Hiding Done tickets".to_string()
+            });
+        }
+        Action::FocusInProgress => toggle_focus_with_flash(app, Status::InProgress),
+        Action::FocusReadyForWork => toggle_focus_with_flash(app, Status::ReadyForWork),
+        Action::FocusNeedsTriage => toggle_focus_with_flash(app, Status::NeedsTriage),
+        Action::FocusInReview => toggle_focus_with_flash(app, Status::InReview),
+        Action::Refresh => start_manual_refresh(app, bg_tx, config),
+        Action::Undo => match app.journal.len().checked_sub(1) {
+            Some(index) => begin_undo(app, index, bg_tx),
+            None => app.flash = Some("Nothing to undo".to_string()),
+        },
+        Action::UndoHistory => app.undo_history = Some(0),
+        Action::Standup => app.open_standup(config.standup.window_hours),
+        Action::GitBranch => {
+            if let Some(key) = app.selected_ticket_key() {
+                open_git_branch_prompt(app, key, config);
+            }
+        }
+        Action::Export => app.export_picker = Some(0),
+        Action::FoldGroup => {
+            if let Some(group_id) = app.selected_group_id() {
+                app.toggle_group_collapse(&group_id);
+            }
+        }
+        Action::FoldAll => {
+            app.toggle_all_groups_collapse();
+        }
+        Action::CreateTicket => {
+            app.create_ticket = Some(app::CreateTicketState::new());
+        }
+        Action::BulkUpload => {
+            app.bulk_upload_state = Some(BulkUploadState::PathInput {
+                path: String::new(),
+                loading: false,
+            });
+        }
+        Action::Open => open_selected_item(app, bg_tx),
+        Action::TogglePreview => toggle_detail_layout(app, bg_tx),
+        _ => {}
+    }
+}
+
+/// Open the selected row: epic headers open the epic, folded headers unfold, tickets
+/// open their detail.
+fn open_selected_item(app: &mut App, bg_tx: &UnboundedSender<BackgroundMessage>) {
+    if let Some(group_id) = app.selected_header_group_id() {
+        if app.active_tab == Tab::Epics {
+            open_epic_detail(app, group_id, bg_tx);
+        } else if app.is_collapsed(app.active_tab, &group_id) {
+            app.toggle_group_collapse(&group_id);
+        }
+    } else if let Some(key) = app.selected_ticket_key() {
+        let detail_loaded = app.is_ticket_detail_loaded(&key);
+        app.open_detail(key.clone());
+        if !detail_loaded && app.begin_detail_fetch(&key) {
+            spawn_ticket_detail_fetch(bg_tx, key);
+        }
+    }
+}
+
+fn toggle_focus_with_flash(app: &mut App, status: Status) {
+    app.toggle_status_focus(status);
+    app.flash = Some(
+        app.status_focus
+            .as_ref()
+            .map(|s| format!("Focus: {}", s.as_str()))
+            .unwrap_or_else(|| "Focus: all".to_string()),
+    );
+}
+
+fn start_manual_refresh(
+    app: &mut App,
+    bg_tx: &UnboundedSender<BackgroundMessage>,
+    config: &AppConfig,
+) {
+    if app.loading {
+        app.flash = Some("Refresh already in progress".to_string());
+    } else {
+        app.loading = true;
+        app.ticket_sync_stage = None;
+        app.flash = Some("Refreshing tickets...".to_string());
+        spawn_cache_refresh(bg_tx, CacheRefreshPhase::Manual, config);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+
+    fn sample_config() -> AppConfig {
+        AppConfig {
+            jira: crate::config::JiraConfig {
+                project: "AMP".to_string(),
+                team_name: "Code Generation".to_string(),
+                done_window_days: 14,
+                epics_i_care_about: vec![],
+            },
+            team: BTreeMap::new(),
+            statuses: crate::config::StatusConfig::default(),
+            resolutions: crate::config::default_resolutions(),
+            filters: vec![],
+            templates: vec![],
+            custom_fields: Default::default(),
+            comment_templates: vec![],
+            standup: Default::default(),
+            dashboard: Default::default(),
+            git: Default::default(),
+            keys: Default::default(),
+            theme: Default::default(),
+        }
+    }
+
+    fn ticket(key: &str, summary: &str, status: Status) -> crate::cache::Ticket {
+        crate::cache::Ticket {
+            url: format!("https://jira.mongodb.org/browse/{}", key),
+            ..crate::cache::Ticket::for_test(key, summary, status)
+        }
+    }
+
+    #[test]
+    fn summarize_bulk_results_all_success() {
+        let target = BulkTarget::Move {
+            status: Status::InProgress,
+            resolution: None,
+            clear_resolution: false,
+        };
+        let summary = summarize_bulk_results(
+            BulkAction::Move,
+            target.clone(),
+            2,
+            2,
+            Vec::new(),
+            vec![("AMP-1".to_string(), Ok(())), ("AMP-2".to_string(), Ok(()))],
+        );
+        assert_eq!(summary.target, target);
+        assert_eq!(summary.succeeded, 2);
+        assert_eq!(summary.failed, 0);
+        assert_eq!(summary.skipped, 0);
+    }
+
+    #[test]
+    fn summarize_bulk_results_partial_failure_and_skips() {
+        let summary = summarize_bulk_results(
+            BulkAction::Assign,
+            BulkTarget::Assign {
+                member_email: "dev@example.com".to_string(),
+                member_name: "Dev".to_string(),
+            },
+            3,
+            2,
+            vec!["AMP-3".to_string()],
+            vec![
+                ("AMP-1".to_string(), Ok(())),
+                ("AMP-2".to_string(), Err("boom".to_string())),
+            ],
+        );
+        assert_eq!(summary.total, 3);
+        assert_eq!(summary.attempted, 2);
+        assert_eq!(summary.succeeded, 1);
+        assert_eq!(summary.failed, 1);
+        assert_eq!(summary.skipped, 1);
+        assert_eq!(summary.failed_details.len(), 1);
+    }
+
+    #[test]
+    fn summarize_bulk_results_empty_attempts() {
+        let summary = summarize_bulk_results(
+            BulkAction::Move,
+            BulkTarget::Move {
+                status: Status::Closed,
+                resolution: Some("Done".to_string()),
+                clear_resolution: false,
+            },
+            2,
+            0,
+            vec!["AMP-1".to_string(), "AMP-2".to_string()],
+            vec![],
+        );
+        assert_eq!(summary.total, 2);
+        assert_eq!(summary.attempted, 0);
+        assert_eq!(summary.succeeded, 0);
+        assert_eq!(summary.failed, 0);
+        assert_eq!(summary.skipped, 2);
+    }
+
+    #[tokio::test]
+    async fn keybindings_regression_main_navigation_still_works() {
+        let mut app = App::new();
+        app.loading = false;
+        app.active_tab = Tab::MyWork;
+        app.cache.my_tickets = vec![ticket("AMP-1", "A", Status::InProgress)];
+        let (tx, _rx) = tokio::sync::mpsc::unbounded_channel();
+        handle_main_keys(
+            &mut app,
+            KeyCode::Char('j'),
+            KeyModifiers::NONE,
+            &tx,
+            &sample_config(),
+        )
+        .await;
+        assert_eq!(app.selected_index, 1);
+    }
+
+    #[test]
+    fn comment_shift_enter_inserts_newline() {
+        let mut app = App::new();
+        app.comment_state = Some(crate::app::CommentState {
+            ticket_key: "AMP-1".to_string(),
+            body: "hello".to_string(),
+        });
+        let (tx, _rx) = tokio::sync::mpsc::unbounded_channel();
+
+        handle_comment_keys(&mut app, KeyCode::Enter, KeyModifiers::SHIFT, &tx);
+
+        let state = app.comment_state.expect("comment modal should remain open");
+        assert_eq!(state.body, "hello\n");
+    }
+
+    #[test]
+    fn comment_enter_requires_non_empty_body() {
+        let mut app = App::new();
+        app.comment_state = Some(crate::app::CommentState {
+            ticket_key: "AMP-1".to_string(),
+            body: "   ".to_string(),
+        });
+        let (tx, _rx) = tokio::sync::mpsc::unbounded_channel();
+
+        handle_comment_keys(&mut app, KeyCode::Enter, KeyModifiers::NONE, &tx);
+
+        assert!(app.comment_state.is_some());
+        assert_eq!(app.flash.as_deref(), Some("Comment body is required"));
+    }
+
+    #[test]
+    fn comment_ctrl_j_inserts_newline() {
+        let mut app = App::new();
+        app.comment_state = Some(crate::app::CommentState {
+            ticket_key: "AMP-1".to_string(),
+            body: "hello".to_string(),
+        });
+        let (tx, _rx) = tokio::sync::mpsc::unbounded_channel();
+
+        handle_comment_keys(&mut app, KeyCode::Char('j'), KeyModifiers::CONTROL, &tx);
+
+        let state = app.comment_state.expect("comment modal should remain open");
+        assert_eq!(state.body, "hello\n");
+    }
+
+    #[tokio::test]
+    async fn bulk_menu_falls_back_to_current_ticket_when_nothing_selected() {
+        let mut app = App::new();
+        app.loading = false;
+        app.active_tab = Tab::MyWork;
+        app.cache.my_tickets = vec![ticket("AMP-1", "A", Status::InProgress)];
+        app.selected_index = 1; // current ticket row
+
+        let (tx, _rx) = tokio::sync::mpsc::unbounded_channel();
+        handle_main_keys(
+            &mut app,
+            KeyCode::Char('B'),
+            KeyModifiers::NONE,
+            &tx,
+            &sample_config(),
+        )
+        .await;
+
+        match app.bulk_state {
+            Some(BulkState::ActionPicker { targets, .. }) => {
+                assert_eq!(targets, vec!["AMP-1".to_string()]);
+            }
+            _ => panic!("expected bulk action picker"),
+        }
+    }
+
+    #[test]
+    fn bulk_epic_picker_builds_epic_target() {
+        let mut app = App::new();
+        app.cache.epics = vec![crate::cache::Epic {
+            key: "AMP-100".to_string(),
+            summary: "Parser".to_string(),
+            children: vec![],
+        }];
+        app.bulk_state = Some(BulkState::ActionPicker {
+            targets: vec!["AMP-1".to_string()],
+            selected: 2,
+        });
+        let (tx, _rx) = tokio::sync::mpsc::unbounded_channel();
+        let config = sample_config();
+
+        handle_bulk_keys(&mut app, KeyCode::Enter, KeyModifiers::NONE, &tx, &config);
+        handle_bulk_keys(
+            &mut app,
+            KeyCode::Char('j'),
+            KeyModifiers::NONE,
+            &tx,
+            &config,
+        );
+        handle_bulk_keys(&mut app, KeyCode::Enter, KeyModifiers::NONE, &tx, &config);
+
+        match app.bulk_state {
+            Some(BulkState::Confirm { target, .. }) => assert_eq!(
+                target,
+                BulkTarget::Epic {
+                    epic_key: Some("AMP-100".to_string()),
+                    epic_name: Some("Parser".to_string()),
+                }
+            ),
+            _ => panic!("expected bulk confirm"),
+        }
+    }
+
+    #[test]
+    fn bulk_epic_skips_tickets_already_in_epic() {
+        let mut app = App::new();
+        let mut linked = ticket("AMP-1", "A", Status::ToDo);
+        linked.epic_key = Some("AMP-100".to_string());
+        app.cache.my_tickets = vec![linked, ticket("AMP-2", "B", Status::ToDo)];
+        let target = BulkTarget::Epic {
+            epic_key: Some("AMP-100".to_string()),
+            epic_name: None,
+        };
+
+        let (attempt, skipped) =
+            partition_bulk_targets(&app, &["AMP-1".to_string(), "AMP-2".to_string()], &target);
+
+        assert_eq!(attempt, vec!["AMP-2".to_string()]);
+        assert_eq!(skipped, vec!["AMP-1".to_string()]);
+    }
+
+    #[test]
+    fn bulk_label_edits_skip_only_when_detail_confirms() {
+        let mut loaded = ticket("AMP-1", "A", Status::ToDo);
+        loaded.detail_loaded = true;
+        loaded.labels = vec!["triage".to_string()];
+        let mut unloaded = ticket("AMP-2", "B", Status::ToDo);
+        unloaded.labels = vec!["triage".to_string()];
+
+        let add = BulkTarget::AddLabels {
+            labels: vec!["triage".to_string()],
+        };
+        assert!(bulk_target_already_applied(&loaded, &add));
+        assert!(!bulk_target_already_applied(&unloaded, &add));
+
+        let remove = BulkTarget::RemoveLabels {
+            labels: vec!["stale".to_string()],
+        };
+        assert!(bulk_target_already_applied(&loaded, &remove));
+        assert_eq!(
+            parse_label_input("triage, backend  triage"),
+            vec!["triage", "backend"]
+        );
+    }
+
+    #[test]
+    fn bulk_comment_renders_template_and_skips_duplicates() {
+        let mut app = App::new();
+        let mut t = ticket("AMP-1", "Parser crash", Status::InProgress);
+        t.activity = vec![crate::cache::ActivityEntry {
+            timestamp: "2026-01-01T00:00:00".to_string(),
+            author: "Dev".to_string(),
+            author_email: None,
+            kind: ActivityKind::Comment {
+                body: "AMP-1 (Parser crash) rolls over".to_string(),
+            },
+        }];
+        app.cache.my_tickets = vec![t, ticket("AMP-2", "Runner", Status::ToDo)];
+        let target = BulkTarget::Comment {
+            body: "{key} ({summary}) rolls over".to_string(),
+        };
+
+        let (attempt, skipped) =
+            partition_bulk_targets(&app, &["AMP-1".to_string(), "AMP-2".to_string()], &target);
+        assert_eq!(attempt, vec!["AMP-2".to_string()]);
+        assert_eq!(skipped, vec!["AMP-1".to_string()]);
+        assert_eq!(
+            resolve_bulk_target(&app, "AMP-2", &target),
+            BulkTarget::Comment {
+                body: "AMP-2 (Runner) rolls over".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn bulk_priority_and_custom_field_skip_matching_tickets() {
+        let mut t = ticket("AMP-1", "A", Status::ToDo);
+        t.priority = Some("Major - P3".to_string());
+        t.custom_fields
+            .insert("customfield_10555".to_string(), "3".to_string());
+
+        assert!(bulk_target_already_applied(
+            &t,
+            &BulkTarget::Priority {
+                priority: "Major - P3".to_string(),
+            }
+        ));
+        assert!(!bulk_target_already_applied(
+            &t,
+            &BulkTarget::CustomField {
+                name: "story-points".to_string(),
+                field_id: "customfield_10555".to_string(),
+                value: "5".to_string(),
+            }
+        ));
+    }
+
+    #[test]
+    fn bulk_custom_field_flow_uses_configured_mapping() {
+        let mut app = App::new();
+        let mut config = sample_config();
+        config
+            .custom_fields
+            .insert("story-points".to_string(), "customfield_10555".to_string());
+        let custom_idx = BulkAction::MENU
+            .iter()
+            .position(|a| *a == BulkAction::CustomField)
+            .unwrap();
+        app.bulk_state = Some(BulkState::ActionPicker {
+            targets: vec!["AMP-1".to_string()],
+            selected: custom_idx,
+        });
+        let (tx, _rx) = tokio::sync::mpsc::unbounded_channel();
+
+        for key in [
+            KeyCode::Enter,
+            KeyCode::Enter,
+            KeyCode::Char('5'),
+            KeyCode::Enter,
+        ] {
+            handle_bulk_keys(&mut app, key, KeyModifiers::NONE, &tx, &config);
+        }
+
+        match app.bulk_state {
+            Some(BulkState::Confirm { target, .. }) => assert_eq!(
+                target,
+                BulkTarget::CustomField {
+                    name: "story-points".to_string(),
+                    field_id: "customfield_10555".to_string(),
+                    value: "5".to_string(),
+                }
+            ),
+            _ => panic!("expected bulk confirm"),
+        }
+    }
+
+    #[test]
+    fn closing_epic_requires_all_children_done() {
+        let mut app = App::new();
+        app.cache.epics = vec![crate::cache::Epic {
+            key: "AMP-100".to_string(),
+            summary: "Parser".to_string(),
+            children: vec![ticket("AMP-1", "A", Status::InProgress)],
+        }];
+        app.open_epic_detail("AMP-100".to_string());
+
+        begin_close_epic(&mut app, "AMP-100");
+        assert!(matches!(app.detail_mode, DetailMode::View));
+
+        app.cache.epics[0].children[0].status = Status::Closed;
+        begin_close_epic(&mut app, "AMP-100");
+        assert!(matches!(
+            app.detail_mode,
+            DetailMode::ResolutionPicker { .. }
+        ));
+    }
+
+    #[tokio::test]
+    async fn uppercase_n_opens_epic_form_on_epics_tab() {
+        let mut app = App::new();
+        app.loading = false;
+        app.active_tab = Tab::Epics;
+        app.cache.epics = vec![crate::cache::Epic {
+            key: "AMP-100".to_string(),
+            summary: "Parser".to_string(),
+            children: vec![],
+        }];
+        let (tx, _rx) = tokio::sync::mpsc::unbounded_channel();
+        handle_main_keys(
+            &mut app,
+            KeyCode::Char('N'),
+            KeyModifiers::NONE,
+            &tx,
+            &sample_config(),
+        )
+        .await;
+
+        let state = app.create_ticket.as_mut().expect("form open");
+        assert!(state.is_epic());
+        state.summary = "Parser rewrite".to_string();
+        state.epic_idx = 1;
+        let new_ticket = build_new_ticket(&app.cache, state).expect("valid form");
+        assert_eq!(new_ticket.issue_type, "Epic");
+        assert_eq!(new_ticket.epic_key, None);
+    }
+
+    #[tokio::test]
+    async fn uppercase_u_opens_bulk_upload_from_main_tabs() {
+        let mut app = App::new();
+        app.loading = false;
+        app.active_tab = Tab::MyWork;
+        let (tx, _rx) = tokio::sync::mpsc::unbounded_channel();
+        handle_main_keys(
+            &mut app,
+            KeyCode::Char('U'),
+            KeyModifiers::NONE,
+            &tx,
+            &sample_config(),
+        )
+        .await;
+
+        assert!(matches!(
+            app.bulk_upload_state,
+            Some(BulkUploadState::PathInput { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn timer_waits_for_the_move_result() {
+        let mut app = App::new();
+        app.cache.my_tickets = vec![ticket("AMP-1", "A", Status::ToDo)];
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+
+        perform_ticket_move(&mut app, "AMP-1".to_string(), Status::InProgress, None, &tx);
+        assert!(app.work_timer.is_none());
+        match rx.recv().await {
+            Some(BackgroundMessage::TicketMoved { key, status, .. }) => {
+                assert_eq!(key, "AMP-1");
+                assert_eq!(status, Status::InProgress);
+            }
+            _ => panic!("expected the move result"),
+        }
+    }
+
+    #[test]
+    fn leaving_in_progress_stops_timer_and_prefills_worklog() {
+        let mut app = App::new();
+        app.cache.my_tickets = vec![ticket("AMP-1", "A", Status::InProgress)];
+
+        sync_work_timer_after_move(&mut app, "AMP-1", &Status::InProgress);
+        assert!(app.is_timing("AMP-1"));
+
+        sync_work_timer_after_move(&mut app, "AMP-1", &Status::InReview);
+        assert!(app.work_timer.is_none());
+        let state = app.worklog_state.expect("worklog modal should open");
+        assert_eq!(state.ticket_key, "AMP-1");
+        assert_eq!(state.duration, "1m");
+    }
+
+    #[test]
+    fn timer_requires_in_progress_ticket() {
+        let mut app = App::new();
+        app.cache.my_tickets = vec![ticket("AMP-2", "B", Status::ReadyForWork)];
+
+        toggle_work_timer(&mut app, "AMP-2".to_string());
+
+        assert!(app.work_timer.is_none());
+        assert!(app
+            .flash
+            .as_deref()
+            .is_some_and(|f| f.contains("In Progress")));
+    }
+
+    #[test]
+    fn worklog_enter_with_invalid_duration_keeps_modal_open() {
+        let mut app = App::new();
+        app.open_worklog("AMP-3".to_string(), "soon".to_string(), String::new());
+        let (tx, _rx) = tokio::sync::mpsc::unbounded_channel();
+
+        handle_worklog_keys(&mut app, KeyCode::Enter, &tx);
+
+        assert!(app.is_worklog_open());
+        assert!(app.flash.is_some());
+    }
+
+    #[test]
+    fn build_new_ticket_splits_lists_and_validates_story_points() {
+        let app = App::new();
+        let mut state = app::CreateTicketState::new();
+        state.summary = "  Crash on save ".to_string();
+        state.labels = "triage, ,backend".to_string();
+        state.components = "Editor".to_string();
+        state.priority_idx = 3;
+        state.story_points = "2.5".to_string();
+
+        let ticket = build_new_ticket(&app.cache, &state).expect("valid form");
+        assert_eq!(ticket.summary, "Crash on save");
+        assert_eq!(ticket.labels, vec!["triage", "backend"]);
+        assert_eq!(ticket.components, vec!["Editor"]);
+        assert_eq!(ticket.priority.as_deref(), Some(app::PRIORITIES[2]));
+        assert_eq!(ticket.story_points, Some(2.5));
+        assert_eq!(ticket.description, None);
+
+        state.story_points = "lots".to_string();
+        assert!(build_new_ticket(&app.cache, &state).is_err());
+    }
+
+    #[tokio::test]
+    async fn choosing_a_template_prefills_the_create_form() {
+        let mut app = App::new();
+        app.create_ticket = Some(app::CreateTicketState::new());
+        let mut config = sample_config();
+        config.templates = vec![crate::config::TicketTemplate {
+            name: "Bug report".to_string(),
+            issue_type: Some("bug".to_string()),
+            description: Some("h3. Steps".to_string()),
+            labels: vec!["triage".to_string()],
+            ..Default::default()
+        }];
+        let (tx, _rx) = tokio::sync::mpsc::unbounded_channel();
+
+        handle_create_ticket_keys(
+            &mut app,
+            KeyCode::Char('j'),
+            KeyModifiers::NONE,
+            &tx,
+            &config,
+        )
+        .await;
+
+        let state = app.create_ticket.as_ref().expect("form open");
+        assert_eq!(app::ISSUE_TYPES[state.issue_type_idx], "Bug");
+        assert_eq!(state.description, "h3. Steps");
+        assert_eq!(state.labels, "triage");
+
+        handle_create_ticket_keys(
+            &mut app,
+            KeyCode::Char('k'),
+            KeyModifiers::NONE,
+            &tx,
+            &config,
+        )
+        .await;
+        let state = app.create_ticket.as_ref().expect("form open");
+        assert!(state.description.is_empty());
+        assert!(state.labels.is_empty());
+    }
+
+    #[test]
+    fn derive_picker_opens_subtask_form_with_parent() {
+        let mut app = App::new();
+        app.cache.my_tickets = vec![ticket("AMP-42", "Parent work", Status::InProgress)];
+        app.open_detail("AMP-42".to_string());
+        let (tx, _rx) = tokio::sync::mpsc::unbounded_channel();
+        let config = sample_config();
+
+        handle_detail_keys(
+            &mut app,
+            KeyCode::Char('n'),
+            KeyModifiers::NONE,
+            &tx,
+            &config,
+        );
+        assert!(matches!(
+            app.detail_mode,
+            DetailMode::DerivePicker { selected: 0 }
+        ));
+        handle_detail_keys(
+            &mut app,
+            KeyCode::Char('s'),
+            KeyModifiers::NONE,
+            &tx,
+            &config,
+        );
+
+        let mut state = app.create_ticket.clone().expect("create form open");
+        assert_eq!(
+            state.derived_from.as_ref().map(|d| d.kind),
+            Some(app::DeriveKind::SubTask)
+        );
+        state.summary = "Write tests".to_string();
+        let new_ticket = build_new_ticket(&app.cache, &state).expect("valid form");
+        assert_eq!(new_ticket.issue_type, app::SUBTASK_ISSUE_TYPE);
+        assert_eq!(new_ticket.parent_key.as_deref(), Some("AMP-42"));
+    }
+
+    #[tokio::test]
+    async fn templates_keep_sub_task_type_and_copied_fields() {
+        let mut app = App::new();
+        let mut source = ticket("AMP-42", "Parent work", Status::InProgress);
+        source.labels = vec!["backend".to_string()];
+        app.cache.my_tickets = vec![source];
+        app.open_derived_ticket_form(app::DeriveKind::SubTask, "AMP-42");
+        let mut config = sample_config();
+        config.templates = vec![crate::config::TicketTemplate {
+            name: "Bug report".to_string(),
+            issue_type: Some("Epic".to_string()),
+            description: Some("h3. Steps".to_string()),
+            labels: vec!["triage".to_string()],
+            ..Default::default()
+        }];
+        let (tx, _rx) = tokio::sync::mpsc::unbounded_channel();
+        let focus = |app: &mut App, field| {
+            if let Some(state) = app.create_ticket.as_mut() {
+                state.focused_field = field;
+            }
+        };
+
+        focus(&mut app, app::CreateTicketState::TEMPLATE);
+        handle_create_ticket_keys(
+            &mut app,
+            KeyCode::Char('j'),
+            KeyModifiers::NONE,
+            &tx,
+            &config,
+        )
+        .await;
+        let state = app.create_ticket.as_ref().expect("form open");
+        assert_eq!(state.labels, "backend");
+        assert_eq!(state.description, "h3. Steps");
+        assert!(!state.is_epic());
+
+        focus(&mut app, app::CreateTicketState::TYPE);
+        handle_create_ticket_keys(
+            &mut app,
+            KeyCode::Char('j'),
+            KeyModifiers::NONE,
+            &tx,
+            &config,
+        )
+        .await;
+        assert_eq!(
+            app.create_ticket
+                .as_ref()
+                .expect("form open")
+                .issue_type_idx,
+            0
+        );
+
+        focus(&mut app, app::CreateTicketState::TEMPLATE);
+        handle_create_ticket_keys(
+            &mut app,
+            KeyCode::Char('k'),
+            KeyModifiers::NONE,
+            &tx,
+            &config,
+        )
+        .await;
+        let mut state = app.create_ticket.clone().expect("form open");
+        assert_eq!(state.labels, "backend");
+        state.summary = "Write tests".to_string();
+        let new_ticket = build_new_ticket(&app.cache, &state).expect("valid form");
+        assert_eq!(new_ticket.issue_type, app::SUBTASK_ISSUE_TYPE);
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use std::collections::BTreeMap;
+    #[tokio::test]
+    async fn enter_on_epic_header_opens_epic_detail_in_main_mode() {
+        let mut app = App::new();
+        app.loading = false;
+        app.active_tab = Tab::Epics;
+        app.cache.epics = vec![crate::cache::Epic {
+            key: "AMP-500".to_string(),
+            summary: "Epic Header".to_string(),
+            children: vec![],
+        }];
+        app.selected_index = 0;
 
-    fn sample_config() -> AppConfig {
-        AppConfig {
-            jira: crate::config::JiraConfig {
-                project: "AMP".to_string(),
-                team_name: "Code Generation".to_string(),
-                done_window_days: 14,
-                epics_i_care_about: vec![],
-            },
-            team: BTreeMap::new(),
-            statuses: crate::config::StatusConfig::default(),
-            resolutions: crate::config::default_resolutions(),
-            filters: vec![],
-        }
+        let (tx, _rx) = tokio::sync::mpsc::unbounded_channel();
+        handle_main_keys(
+            &mut app,
+            KeyCode::Enter,
+            KeyModifiers::NONE,
+            &tx,
+            &sample_config(),
+        )
+        .await;
+
+        assert_eq!(app.detail_epic_key.as_deref(), Some("AMP-500"));
+        assert!(app.detail_ticket_key.is_none());
     }
 
-    fn ticket(key: &str, summary: &str, status: Status) -> crate::cache::Ticket {
-        crate::cache::Ticket {
-            key: key.to_string(),
-            summary: summary.to_string(),
-            status,
-            assignee: None,
-            assignee_email: None,
-            reporter: None,
-            description: None,
-            labels: Vec::new(),
-            epic_key: None,
-            epic_name: None,
-            detail_loaded: false,
-            url: format!("https://jira.mongodb.org/browse/{}", key),
-            activity: Vec::new(),
-        }
+    #[tokio::test]
+    async fn enter_on_epic_header_opens_epic_detail_in_search_mode() {
+        let mut app = App::new();
+        app.loading = false;
+        app.active_tab = Tab::Epics;
+        app.search = Some(String::new());
+        app.cache.epics = vec![crate::cache::Epic {
+            key: "AMP-501".to_string(),
+            summary: "Epic Header Search".to_string(),
+            children: vec![],
+        }];
+        app.selected_index = 0;
+
+        let (tx, _rx) = tokio::sync::mpsc::unbounded_channel();
+        handle_search_keys(&mut app, KeyCode::Enter, KeyModifiers::NONE, &tx).await;
+
+        assert_eq!(app.detail_epic_key.as_deref(), Some("AMP-501"));
+        assert!(app.detail_ticket_key.is_none());
     }
 
     #[test]
-    fn summarize_bulk_results_all_success() {
+    fn uppercase_u_opens_bulk_upload_from_filters() {
+        let mut app = App::new();
+        app.loading = false;
+        app.active_tab = Tab::Filters;
+        let (tx, _rx) = tokio::sync::mpsc::unbounded_channel();
+        let mut config = sample_config();
+        handle_filter_keys(
+            &mut app,
+            KeyCode::Char('U'),
+            KeyModifiers::NONE,
+            &tx,
+            &mut config,
+        );
+        assert!(matches!(
+            app.bulk_upload_state,
+            Some(BulkUploadState::PathInput { .. })
+        ));
+    }
+
+    #[test]
+    fn bulk_upload_submit_is_blocked_when_preview_has_invalid_rows() {
+        let mut app = App::new();
+        app.bulk_upload_state = Some(BulkUploadState::Preview {
+            preview: BulkUploadPreview {
+                source_path: "/tmp/bulk.csv".to_string(),
+                rows: vec![crate::app::BulkUploadRow {
+                    row_number: 2,
+                    issue_type: "Task".to_string(),
+                    summary: "".to_string(),
+                    assignee_email: None,
+                    epic_key: None,
+                    labels: vec![],
+                    description: None,
+                    errors: vec!["summary is required".to_string()],
+                    warnings: vec![],
+                    ..Default::default()
+                }],
+                total_rows: 1,
+                valid_rows: 0,
+                invalid_rows: 1,
+                warning_count: 0,
+            },
+            selected: 0,
+        });
+
+        let (tx, _rx) = tokio::sync::mpsc::unbounded_channel();
+        handle_bulk_upload_keys(
+            &mut app,
+            KeyCode::Enter,
+            KeyModifiers::NONE,
+            &tx,
+            &sample_config(),
+        );
+
+        assert!(matches!(
+            app.bulk_upload_state,
+            Some(BulkUploadState::Preview { .. })
+        ));
+        assert!(app
+            .flash
+            .as_deref()
+            .unwrap_or("")
+            .contains("Upload blocked"));
+    }
+
+    #[test]
+    fn finish_bulk_run_journals_only_successful_changes() {
+        let mut app = App::new();
+        app.cache.my_tickets = vec![
+            ticket("AMP-1", "A", Status::ToDo),
+            ticket("AMP-2", "B", Status::ToDo),
+        ];
         let target = BulkTarget::Move {
             status: Status::InProgress,
             resolution: None,
+            clear_resolution: false,
         };
+        let keys = vec!["AMP-1".to_string(), "AMP-2".to_string()];
+        app.pending_bulk_journal = Some((
+            "Bulk move".to_string(),
+            app.journal_changes_for(&keys, &target),
+        ));
         let summary = summarize_bulk_results(
             BulkAction::Move,
-            target.clone(),
+            target,
             2,
             2,
-            0,
-            vec![("AMP-1".to_string(), Ok(())), ("AMP-2".to_string(), Ok(()))],
+            Vec::new(),
+            vec![
+                ("AMP-1".to_string(), Ok(())),
+                ("AMP-2".to_string(), Err("boom".to_string())),
+            ],
         );
-        assert_eq!(summary.target, target);
-        assert_eq!(summary.succeeded, 2);
-        assert_eq!(summary.failed, 0);
-        assert_eq!(summary.skipped, 0);
+
+        finish_bulk_run(&mut app, &summary);
+
+        assert_eq!(app.find_ticket("AMP-1").unwrap().status, Status::InProgress);
+        assert_eq!(app.journal.len(), 1);
+        assert_eq!(app.journal[0].changes.len(), 1);
+        assert_eq!(app.journal[0].changes[0].key, "AMP-1");
     }
 
     #[test]
-    fn summarize_bulk_results_partial_failure_and_skips() {
+    fn finish_undo_run_restores_successes_and_keeps_failures() {
+        let mut app = App::new();
+        app.cache.my_tickets = vec![
+            ticket("AMP-1", "A", Status::InProgress),
+            ticket("AMP-2", "B", Status::InProgress),
+        ];
+        let inverse = BulkTarget::Move {
+            status: Status::ToDo,
+            resolution: None,
+            clear_resolution: false,
+        };
+        app.undo_in_flight = Some(crate::app::JournalEntry {
+            label: "Bulk move".to_string(),
+            recorded_at: 0,
+            changes: ["AMP-1", "AMP-2"]
+                .iter()
+                .map(|key| crate::app::JournalChange {
+                    key: key.to_string(),
+                    inverse: inverse.clone(),
+                })
+                .collect(),
+        });
         let summary = summarize_bulk_results(
-            BulkAction::Assign,
-            BulkTarget::Assign {
-                member_email: "dev@example.com".to_string(),
-                member_name: "Dev".to_string(),
+            BulkAction::Undo,
+            BulkTarget::Undo {
+                label: "Bulk move".to_string(),
             },
-            3,
             2,
-            1,
+            2,
+            Vec::new(),
             vec![
                 ("AMP-1".to_string(), Ok(())),
                 ("AMP-2".to_string(), Err("boom".to_string())),
             ],
         );
-        assert_eq!(summary.total, 3);
-        assert_eq!(summary.attempted, 2);
-        assert_eq!(summary.succeeded, 1);
-        assert_eq!(summary.failed, 1);
-        assert_eq!(summary.skipped, 1);
-        assert_eq!(summary.failed_details.len(), 1);
+
+        finish_bulk_run(&mut app, &summary);
+
+        assert!(app.undo_in_flight.is_none());
+        assert_eq!(app.find_ticket("AMP-1").unwrap().status, Status::ToDo);
+        assert_eq!(app.find_ticket("AMP-2").unwrap().status, Status::InProgress);
+        assert_eq!(app.journal.len(), 1);
+        assert_eq!(app.journal[0].label, "Bulk move");
+        assert_eq!(app.journal[0].changes[0].key, "AMP-2");
     }
 
-    #[test]
-    fn summarize_bulk_results_empty_attempts() {
-        let summary = summarize_bulk_results(
-            BulkAction::Move,
-            BulkTarget::Move {
-                status: Status::Closed,
-                resolution: Some("Done".to_string()),
+    #[tokio::test]
+    async fn ctrl_z_undoes_latest_journal_entry() {
+        let mut app = App::new();
+        app.loading = false;
+        app.cache.my_tickets = vec![ticket("AMP-1", "A", Status::ToDo)];
+        let changes = app.journal_changes_for(
+            &["AMP-1".to_string()],
+            &BulkTarget::Move {
+                status: Status::InProgress,
+                resolution: None,
+                clear_resolution: false,
             },
-            2,
-            0,
-            2,
-            vec![],
         );
-        assert_eq!(summary.total, 2);
-        assert_eq!(summary.attempted, 0);
-        assert_eq!(summary.succeeded, 0);
-        assert_eq!(summary.failed, 0);
-        assert_eq!(summary.skipped, 2);
+        app.record_journal("Move AMP-1 to In Progress".to_string(), changes);
+
+        let (tx, _rx) = tokio::sync::mpsc::unbounded_channel();
+        handle_main_keys(
+            &mut app,
+            KeyCode::Char('z'),
+            KeyModifiers::CONTROL,
+            &tx,
+            &sample_config(),
+        )
+        .await;
+
+        assert!(app.journal.is_empty());
+        assert!(app.undo_in_flight.is_some());
+        assert!(matches!(
+            app.bulk_state,
+            Some(BulkState::Running {
+                target: BulkTarget::Undo { .. },
+                ..
+            })
+        ));
+    }
+
+    #[tokio::test]
+    async fn ctrl_z_with_empty_journal_flashes() {
+        let mut app = App::new();
+        app.loading = false;
+        let (tx, _rx) = tokio::sync::mpsc::unbounded_channel();
+        handle_main_keys(
+            &mut app,
+            KeyCode::Char('z'),
+            KeyModifiers::CONTROL,
+            &tx,
+            &sample_config(),
+        )
+        .await;
+
+        assert_eq!(app.flash.as_deref(), Some("Nothing to undo"));
+        assert!(app.bulk_state.is_none());
+    }
+
+    #[tokio::test]
+    async fn standup_overlay_switches_scope_and_window() {
+        let mut app = App::new();
+        app.loading = false;
+        let mut mine = ticket("AMP-1", "Parser", Status::InProgress);
+        mine.assignee_email = Some("me@example.com".to_string());
+        app.cache.my_tickets = vec![mine];
+        app.cache.team_members = vec![
+            crate::cache::TeamMember {
+                name: "Me".to_string(),
+                email: "me@example.com".to_string(),
+            },
+            crate::cache::TeamMember {
+                name: "Bob".to_string(),
+                email: "bob@example.com".to_string(),
+            },
+        ];
+        let (tx, _rx) = tokio::sync::mpsc::unbounded_channel();
+        handle_main_keys(
+            &mut app,
+            KeyCode::Char('R'),
+            KeyModifiers::NONE,
+            &tx,
+            &sample_config(),
+        )
+        .await;
+
+        let text = &app.standup.as_ref().expect("standup open").text;
+        assert!(text.contains("### Me\n"));
+        assert!(text.contains("- AMP-1 Parser (In Progress)"));
+        assert!(!text.contains("### Bob"));
+
+        handle_standup_keys(&mut app, KeyCode::Char('t'), KeyModifiers::NONE);
+        handle_standup_keys(&mut app, KeyCode::Char('+'), KeyModifiers::NONE);
+        let state = app.standup.as_ref().unwrap();
+        assert!(state.text.starts_with("## Standup (last 48h)"));
+        assert!(state.text.contains("### Bob\n"));
+
+        handle_standup_keys(&mut app, KeyCode::Esc, KeyModifiers::NONE);
+        assert!(!app.is_standup_open());
+    }
+
+    #[tokio::test]
+    async fn mouse_selects_opens_and_scrolls() {
+        let mut app = App::new();
+        app.loading = false;
+        app.cache.my_tickets = vec![ticket("AMP-1", "A", Status::InProgress)];
+        let config = sample_config();
+        let (tx, _rx) = tokio::sync::mpsc::unbounded_channel();
+        let click = |column, row| MouseEvent {
+            kind: MouseEventKind::Down(MouseButton::Left),
+            column,
+            row,
+            modifiers: KeyModifiers::NONE,
+        };
+        app.add_click_target(
+            ratatui::layout::Rect::new(1, 1, 10, 1),
+            ClickTarget::Tab(Tab::Team),
+        );
+        app.add_click_target(
+            ratatui::layout::Rect::new(1, 6, 60, 1),
+            ClickTarget::Item(1),
+        );
+
+        assert!(handle_mouse(&mut app, click(5, 6), &tx, &config));
+        assert_eq!(app.selected_index, 1);
+        assert!(!app.is_detail_open());
+        handle_mouse(&mut app, click(5, 6), &tx, &config);
+        assert_eq!(app.detail_ticket_key.as_deref(), Some("AMP-1"));
+
+        let wheel = MouseEvent {
+            kind: MouseEventKind::ScrollDown,
+            column: 5,
+            row: 6,
+            modifiers: KeyModifiers::NONE,
+        };
+        handle_mouse(&mut app, wheel, &tx, &config);
+        assert_eq!(app.detail_scroll, 1);
+        // Clicks behind the detail overlay are ignored.
+        handle_mouse(&mut app, click(2, 1), &tx, &config);
+        assert_eq!(app.active_tab, Tab::MyWork);
+
+        app.close_detail();
+        handle_mouse(&mut app, click(2, 1), &tx, &config);
+        assert_eq!(app.active_tab, Tab::Team);
+        let moved = MouseEvent {
+            kind: MouseEventKind::Moved,
+            ..wheel
+        };
+        assert!(!handle_mouse(&mut app, moved, &tx, &config));
     }
 
     #[tokio::test]
-    async fn keybindings_regression_main_navigation_still_works() {
+    async fn command_palette_runs_actions_for_its_context() {
         let mut app = App::new();
         app.loading = false;
-        app.active_tab = Tab::MyWork;
         app.cache.my_tickets = vec![ticket("AMP-1", "A", Status::InProgress)];
+        let mut config = sample_config();
         let (tx, _rx) = tokio::sync::mpsc::unbounded_channel();
+        let type_in = |app: &mut App, config: &mut AppConfig, text: &str| {
+            for c in text.chars() {
+                handle_command_palette_keys(app, KeyCode::Char(c), KeyModifiers::NONE, &tx, config);
+            }
+            handle_command_palette_keys(app, KeyCode::Enter, KeyModifiers::NONE, &tx, config);
+        };
+
         handle_main_keys(
             &mut app,
-            KeyCode::Char('j'),
+            KeyCode::Char(':'),
             KeyModifiers::NONE,
             &tx,
-            &sample_config(),
+            &config,
         )
         .await;
-        assert_eq!(app.selected_index, 1);
+        assert!(app.is_command_palette_open());
+        type_in(&mut app, &mut config, "toggle done");
+        assert!(!app.is_command_palette_open());
+        assert!(!app.show_done);
+
+        handle_main_keys(
+            &mut app,
+            KeyCode::Char('p'),
+            KeyModifiers::CONTROL,
+            &tx,
+            &config,
+        )
+        .await;
+        type_in(&mut app, &mut config, "go to team");
+        assert_eq!(app.active_tab, Tab::Team);
+
+        app.open_detail("AMP-1".to_string());
+        handle_detail_keys(
+            &mut app,
+            KeyCode::Char(':'),
+            KeyModifiers::NONE,
+            &tx,
+            &config,
+        );
+        let palette = app.command_palette.as_ref().unwrap();
+        assert!(palette
+            .entries
+            .iter()
+            .all(|entry| !entry.label.starts_with("go to")));
+        type_in(&mut app, &mut config, "comment");
+        assert_eq!(
+            app.comment_state.as_ref().map(|c| c.ticket_key.as_str()),
+            Some("AMP-1")
+        );
     }
 
-    #[test]
-    fn comment_shift_enter_inserts_newline() {
+    #[tokio::test]
+    async fn go_to_key_opens_tickets_and_ctrl_o_goes_back() {
         let mut app = App::new();
-        app.comment_state = Some(crate::app::CommentState {
-            ticket_key: "AMP-1".to_string(),
-            body: "hello".to_string(),
-        });
+        app.loading = false;
+        app.cache.my_tickets = vec![
+            ticket("AMP-1", "A", Status::InProgress),
+            ticket("AMP-2", "B", Status::InProgress),
+        ];
+        let config = sample_config();
         let (tx, _rx) = tokio::sync::mpsc::unbounded_channel();
+        let go_to = |app: &mut App, text: &str| {
+            app.goto_key = Some(Default::default());
+            for c in text.chars() {
+                handle_goto_key_keys(app, KeyCode::Char(c), KeyModifiers::NONE, &tx, &config);
+            }
+            handle_goto_key_keys(app, KeyCode::Enter, KeyModifiers::NONE, &tx, &config);
+        };
 
-        handle_comment_keys(&mut app, KeyCode::Enter, KeyModifiers::SHIFT, &tx);
-
-        let state = app.comment_state.expect("comment modal should remain open");
-        assert_eq!(state.body, "hello\n");
+        handle_main_keys(
+            &mut app,
+            KeyCode::Char('J'),
+            KeyModifiers::NONE,
+            &tx,
+            &config,
+        )
+        .await;
+        assert!(app.is_goto_key_open());
+        go_to(&mut app, "amp-1");
+        assert_eq!(app.detail_ticket_key.as_deref(), Some("AMP-1"));
+        go_to(&mut app, "2");
+        assert_eq!(app.detail_ticket_key.as_deref(), Some("AMP-2"));
+
+        go_to(&mut app, "AMP-99");
+        assert!(!app.is_goto_key_open());
+        assert_eq!(app.pending_detail_open.as_deref(), Some("AMP-99"));
+        assert_eq!(app.detail_ticket_key.as_deref(), Some("AMP-2"));
+
+        handle_detail_keys(
+            &mut app,
+            KeyCode::Char('o'),
+            KeyModifiers::CONTROL,
+            &tx,
+            &config,
+        );
+        assert_eq!(app.detail_ticket_key.as_deref(), Some("AMP-1"));
+        handle_detail_keys(&mut app, KeyCode::Tab, KeyModifiers::NONE, &tx, &config);
+        assert_eq!(app.detail_ticket_key.as_deref(), Some("AMP-2"));
+
+        app.goto_key = Some(Default::default());
+        handle_goto_key_keys(&mut app, KeyCode::Down, KeyModifiers::NONE, &tx, &config);
+        handle_goto_key_keys(&mut app, KeyCode::Down, KeyModifiers::NONE, &tx, &config);
+        handle_goto_key_keys(&mut app, KeyCode::Enter, KeyModifiers::NONE, &tx, &config);
+        assert_eq!(app.detail_ticket_key.as_deref(), Some("AMP-1"));
+
+        assert_eq!(parse_goto_key("x", "AMP"), None);
+        assert_eq!(parse_goto_key("AMP-", "AMP"), None);
+        assert_eq!(parse_goto_key("ab2-7", "AMP").as_deref(), Some("AB2-7"));
     }
 
-    #[test]
-    fn comment_enter_requires_non_empty_body() {
+    #[tokio::test]
+    async fn pushed_branches_ask_before_moving_to_review() {
         let mut app = App::new();
-        app.comment_state = Some(crate::app::CommentState {
-            ticket_key: "AMP-1".to_string(),
-            body: "   ".to_string(),
-        });
-        let (tx, _rx) = tokio::sync::mpsc::unbounded_channel();
-
-        handle_comment_keys(&mut app, KeyCode::Enter, KeyModifiers::NONE, &tx);
+        app.cache.my_tickets = vec![
+            ticket("AMP-1", "A", Status::ReadyForWork),
+            ticket("AMP-2", "B", Status::ReadyForWork),
+        ];
+        let keys = |list: &[&str]| list.iter().map(|k| k.to_string()).collect::<HashSet<_>>();
+        app.newly_pushed_review_keys(keys(&[]));
+        let pushed = app.newly_pushed_review_keys(keys(&["AMP-1", "AMP-2"]));
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        app.queue_review_prompts(pushed);
+        assert!(app.is_review_prompt_open());
+        assert_eq!(
+            app.find_ticket("AMP-1").unwrap().status,
+            Status::ReadyForWork
+        );
 
-        assert!(app.comment_state.is_some());
-        assert_eq!(app.flash.as_deref(), Some("Comment body is required"));
+        handle_review_prompt_keys(&mut app, KeyCode::Char('n'), &tx);
+        assert_eq!(app.review_prompts, vec!["AMP-2"]);
+        handle_review_prompt_keys(&mut app, KeyCode::Char('y'), &tx);
+        assert!(!app.is_review_prompt_open());
+        assert_eq!(
+            app.find_ticket("AMP-1").unwrap().status,
+            Status::ReadyForWork
+        );
+        assert_eq!(app.find_ticket("AMP-2").unwrap().status, Status::InReview);
+
+        // Undo only learns about the move once jira-cli reports back.
+        assert!(app.journal.is_empty());
+        match rx.recv().await {
+            Some(BackgroundMessage::TicketMoved {
+                key,
+                journal: (label, changes),
+                ..
+            }) => {
+                assert_eq!(key, "AMP-2");
+                assert_eq!(label, "Move AMP-2 to In Review");
+                assert_eq!(changes.len(), 1);
+            }
+            _ => panic!("expected the move result"),
+        }
     }
 
     #[test]
-    fn comment_ctrl_j_inserts_newline() {
+    fn startup_warnings_survive_the_cache_load_flash() {
         let mut app = App::new();
-        app.comment_state = Some(crate::app::CommentState {
-            ticket_key: "AMP-1".to_string(),
-            body: "hello".to_string(),
-        });
-        let (tx, _rx) = tokio::sync::mpsc::unbounded_channel();
-
-        handle_comment_keys(&mut app, KeyCode::Char('j'), KeyModifiers::CONTROL, &tx);
-
-        let state = app.comment_state.expect("comment modal should remain open");
-        assert_eq!(state.body, "hello\n");
+        app.flash = Some("Loaded cached data. Refreshing active tickets...".to_string());
+        note_startup_warnings(
+            &mut app,
+            vec!["Unknown action 'serch' in [keys]".to_string()],
+        );
+        let flash = app.flash.expect("flash");
+        assert!(flash.starts_with("Loaded cached data."));
+        assert!(flash.contains("Unknown action 'serch'"));
     }
 
     #[tokio::test]
-    async fn bulk_menu_falls_back_to_current_ticket_when_nothing_selected() {
+    async fn main_keys_follow_the_configured_keymap() {
         let mut app = App::new();
         app.loading = false;
-        app.active_tab = Tab::MyWork;
-        app.cache.my_tickets = vec![ticket("AMP-1", "A", Status::InProgress)];
-        app.selected_index = 1; // current ticket row
-
+        let mut config = sample_config();
+        config.keys.bindings.insert(
+            "search".to_string(),
+            crate::config::KeyList::One("Ctrl+f".to_string()),
+        );
+        app.keymap = keymap::Keymap::from_config(&config.keys).unwrap();
         let (tx, _rx) = tokio::sync::mpsc::unbounded_channel();
+
         handle_main_keys(
             &mut app,
-            KeyCode::Char('B'),
+            KeyCode::Char('/'),
             KeyModifiers::NONE,
             &tx,
-            &sample_config(),
+            &config,
+        )
+        .await;
+        assert!(app.search.is_none());
+        handle_main_keys(
+            &mut app,
+            KeyCode::Char('f'),
+            KeyModifiers::CONTROL,
+            &tx,
+            &config,
         )
         .await;
+        assert_eq!(app.search.as_deref(), Some(""));
+    }
 
-        match app.bulk_state {
-            Some(BulkState::ActionPicker { targets, .. }) => {
-                assert_eq!(targets, vec!["AMP-1".to_string()]);
-            }
-            _ => panic!("expected bulk action picker"),
+    #[test]
+    fn emacs_preset_moves_pickers_with_ctrl_n_and_ctrl_p() {
+        let mut app = App::new();
+        app.loading = false;
+        app.keymap = keymap::Keymap::preset(crate::config::KeyPreset::Emacs);
+        app.cache.my_tickets = vec![ticket("AMP-1", "A", Status::ToDo)];
+        for status in [Status::InProgress, Status::InReview] {
+            let target = BulkTarget::Move {
+                status,
+                resolution: None,
+                clear_resolution: false,
+            };
+            let changes = app.journal_changes_for(&["AMP-1".to_string()], &target);
+            app.record_journal(target.describe(), changes);
         }
+        app.undo_history = Some(0);
+        let (tx, _rx) = tokio::sync::mpsc::unbounded_channel();
+
+        handle_undo_history_keys(&mut app, KeyCode::Char('n'), KeyModifiers::CONTROL, &tx);
+        assert_eq!(app.undo_history, Some(1));
+        handle_undo_history_keys(&mut app, KeyCode::Char('p'), KeyModifiers::CONTROL, &tx);
+        assert_eq!(app.undo_history, Some(0));
+
+        app.undo_history = None;
+        app.show_keybindings = true;
+        handle_keybindings_keys(&mut app, KeyCode::Char('n'), KeyModifiers::CONTROL);
+        assert_eq!(app.keybindings_scroll, 1);
     }
 
     #[tokio::test]
-    async fn uppercase_u_opens_bulk_upload_from_main_tabs() {
+    async fn branch_prompt_suggests_name_from_template() {
         let mut app = App::new();
         app.loading = false;
-        app.active_tab = Tab::MyWork;
+        app.cache.my_tickets = vec![ticket("AMP-7", "Fix login redirect", Status::InProgress)];
+        app.selected_index = 1;
+        let mut config = sample_config();
+        config.git.branch_template = "feat/{key}-{slug}".to_string();
         let (tx, _rx) = tokio::sync::mpsc::unbounded_channel();
         handle_main_keys(
             &mut app,
-            KeyCode::Char('U'),
+            KeyCode::Char('G'),
             KeyModifiers::NONE,
             &tx,
-            &sample_config(),
+            &config,
         )
         .await;
 
-        assert!(matches!(
-            app.bulk_upload_state,
-            Some(BulkUploadState::PathInput { .. })
-        ));
+        let state = app.git_branch.as_ref().expect("branch prompt open");
+        assert_eq!(state.name, "feat/AMP-7-fix-login-redirect");
+
+        handle_git_branch_keys(&mut app, KeyCode::Backspace);
+        handle_git_branch_keys(&mut app, KeyCode::Char(' '));
+        assert_eq!(
+            app.git_branch.as_ref().unwrap().name,
+            "feat/AMP-7-fix-login-redirec"
+        );
+        handle_git_branch_keys(&mut app, KeyCode::Esc);
+        assert!(!app.is_git_branch_open());
     }
 
-    #[tokio::test]
-    async fn enter_on_epic_header_opens_epic_detail_in_main_mode() {
+    #[test]
+    fn bulk_dry_run_lists_current_values_and_skips() {
         let mut app = App::new();
-        app.loading = false;
-        app.active_tab = Tab::Epics;
-        app.cache.epics = vec![crate::cache::Epic {
-            key: "AMP-500".to_string(),
-            summary: "Epic Header".to_string(),
-            children: vec![],
-        }];
-        app.selected_index = 0;
+        app.cache.my_tickets = vec![
+            ticket("AMP-1", "A", Status::InProgress),
+            ticket("AMP-2", "B", Status::ToDo),
+        ];
+        let targets = vec![
+            "AMP-1".to_string(),
+            "AMP-2".to_string(),
+            "AMP-9".to_string(),
+        ];
+        let target = BulkTarget::Move {
+            status: Status::InProgress,
+            resolution: None,
+            clear_resolution: false,
+        };
+        app.bulk_state = Some(BulkState::Confirm {
+            targets,
+            target: target.clone(),
+        });
 
         let (tx, _rx) = tokio::sync::mpsc::unbounded_channel();
-        handle_main_keys(
+        handle_bulk_keys(
             &mut app,
-            KeyCode::Enter,
+            KeyCode::Char('d'),
             KeyModifiers::NONE,
             &tx,
             &sample_config(),
-        )
-        .await;
+        );
 
-        assert_eq!(app.detail_epic_key.as_deref(), Some("AMP-500"));
-        assert!(app.detail_ticket_key.is_none());
+        let Some(BulkState::DryRun { rows, .. }) = &app.bulk_state else {
+            panic!("expected dry run");
+        };
+        assert_eq!(rows[0].skip.as_deref(), Some("already applied"));
+        assert_eq!(rows[1].current, "To Do");
+        assert_eq!(rows[1].planned, target.describe());
+        assert!(rows[1].skip.is_none());
+        assert_eq!(rows[2].skip.as_deref(), Some("not in cache"));
+
+        handle_bulk_keys(
+            &mut app,
+            KeyCode::Esc,
+            KeyModifiers::NONE,
+            &tx,
+            &sample_config(),
+        );
+        assert!(matches!(app.bulk_state, Some(BulkState::Confirm { .. })));
     }
 
-    #[tokio::test]
-    async fn enter_on_epic_header_opens_epic_detail_in_search_mode() {
-        let mut app = App::new();
-        app.loading = false;
-        app.active_tab = Tab::Epics;
-        app.search = Some(String::new());
-        app.cache.epics = vec![crate::cache::Epic {
-            key: "AMP-501".to_string(),
-            summary: "Epic Header Search".to_string(),
-            children: vec![],
-        }];
-        app.selected_index = 0;
+    fn command_failed(command: &str, output: &str) -> anyhow::Error {
+        jira_client::CommandFailed {
+            command: command.to_string(),
+            output: output.to_string(),
+        }
+        .into()
+    }
 
-        let (tx, _rx) = tokio::sync::mpsc::unbounded_channel();
-        handle_search_keys(&mut app, KeyCode::Enter, KeyModifiers::NONE, &tx).await;
+    #[tokio::test]
+    async fn with_backoff_retries_until_success() {
+        let mut calls = 0;
+        let result = with_backoff(&[0, 0, 0], || {
+            calls += 1;
+            let attempt = calls;
+            async move {
+                if attempt < 3 {
+                    Err(command_failed(
+                        "jira issue move AMP-1",
+                        "429 Too Many Requests",
+                    ))
+                } else {
+                    Ok(attempt)
+                }
+            }
+        })
+        .await;
+        assert_eq!(result.unwrap(), 3);
 
-        assert_eq!(app.detail_epic_key.as_deref(), Some("AMP-501"));
-        assert!(app.detail_ticket_key.is_none());
+        let result: Result<()> = with_backoff(&[0], || async {
+            Err(command_failed(
+                "jira issue move AMP-1",
+                "HTTP 429 Too Many Requests",
+            ))
+        })
+        .await;
+        assert!(result.is_err());
     }
 
-    #[test]
-    fn uppercase_u_opens_bulk_upload_from_filters() {
-        let mut app = App::new();
-        app.loading = false;
-        app.active_tab = Tab::Filters;
-        let (tx, _rx) = tokio::sync::mpsc::unbounded_channel();
-        let mut config = sample_config();
-        handle_filter_keys(&mut app, KeyCode::Char('U'), &tx, &mut config);
-        assert!(matches!(
-            app.bulk_upload_state,
-            Some(BulkUploadState::PathInput { .. })
-        ));
+    #[tokio::test]
+    async fn with_backoff_does_not_resend_calls_that_may_have_landed() {
+        for (command, output) in [
+            ("jira issue create -s Import", "500 Internal Server Error"),
+            // The key and summary are echoed in the command, not in what jira-cli printed.
+            ("jira issue move AMP-1429 Done", "transition not allowed"),
+            (
+                "jira issue create -s Handle HTTP 429 rate limit",
+                "field required",
+            ),
+        ] {
+            let mut calls = 0;
+            let result: Result<()> = with_backoff(&[0, 0, 0], || {
+                calls += 1;
+                async { Err(command_failed(command, output)) }
+            })
+            .await;
+            assert!(result.is_err());
+            assert_eq!(calls, 1, "{}", command);
+        }
+        assert!(jira_client::is_retryable(&command_failed(
+            "curl -X GET https://example.atlassian.net",
+            "curl: (6) Could not resolve host: example.atlassian.net"
+        )));
     }
 
-    #[test]
-    fn bulk_upload_submit_is_blocked_when_preview_has_invalid_rows() {
+    #[tokio::test]
+    async fn retry_failed_reruns_only_failed_tickets_and_merges() {
         let mut app = App::new();
-        app.bulk_upload_state = Some(BulkUploadState::Preview {
-            preview: BulkUploadPreview {
-                source_path: "/tmp/bulk.csv".to_string(),
-                rows: vec![crate::app::BulkUploadRow {
-                    row_number: 2,
-                    issue_type: "Task".to_string(),
-                    summary: "".to_string(),
-                    assignee_email: None,
-                    epic_key: None,
-                    labels: vec![],
-                    description: None,
-                    errors: vec!["summary is required".to_string()],
-                    warnings: vec![],
-                }],
-                total_rows: 1,
-                valid_rows: 0,
-                invalid_rows: 1,
-                warning_count: 0,
-            },
-            selected: 0,
+        app.cache.my_tickets = vec![
+            ticket("AMP-1", "A", Status::ToDo),
+            ticket("AMP-2", "B", Status::ToDo),
+        ];
+        let target = BulkTarget::Move {
+            status: Status::InProgress,
+            resolution: None,
+            clear_resolution: false,
+        };
+        let previous = summarize_bulk_results(
+            BulkAction::Move,
+            target.clone(),
+            2,
+            2,
+            Vec::new(),
+            vec![
+                ("AMP-1".to_string(), Ok(())),
+                ("AMP-2".to_string(), Err("429".to_string())),
+            ],
+        );
+        app.bulk_state = Some(BulkState::Result {
+            summary: previous.clone(),
         });
 
         let (tx, _rx) = tokio::sync::mpsc::unbounded_channel();
-        handle_bulk_upload_keys(&mut app, KeyCode::Enter, &tx, &sample_config());
+        handle_bulk_keys(
+            &mut app,
+            KeyCode::Char('f'),
+            KeyModifiers::NONE,
+            &tx,
+            &sample_config(),
+        );
+        match &app.bulk_state {
+            Some(BulkState::Running { targets, .. }) => {
+                assert_eq!(targets, &vec!["AMP-2".to_string()])
+            }
+            _ => panic!("expected retry to be running"),
+        }
 
-        assert!(matches!(
-            app.bulk_upload_state,
-            Some(BulkUploadState::Preview { .. })
-        ));
-        assert!(app
-            .flash
-            .as_deref()
-            .unwrap_or("")
-            .contains("Upload blocked"));
+        let retry = summarize_bulk_results(
+            BulkAction::Move,
+            target,
+            1,
+            1,
+            Vec::new(),
+            vec![("AMP-2".to_string(), Ok(()))],
+        );
+        let merged = merge_bulk_retry(previous, retry);
+        assert_eq!(merged.succeeded, 2);
+        assert_eq!(merged.failed, 0);
+        assert!(merged.failed_details.is_empty());
+        assert_eq!(merged.attempted, 2);
+    }
+
+    #[test]
+    fn upload_retry_merges_created_keys() {
+        let previous = BulkUploadSummary {
+            source_path: "/tmp/bulk.csv".to_string(),
+            total_rows: 3,
+            attempted: 3,
+            succeeded: 1,
+            failed: 2,
+            created_keys: vec!["AMP-10".to_string()],
+            updated_keys: vec![],
+            failed_details: vec![
+                (3, "B".to_string(), "429".to_string()),
+                (4, "C".to_string(), "429".to_string()),
+            ],
+        };
+        let retry = BulkUploadSummary {
+            attempted: 2,
+            succeeded: 1,
+            failed: 1,
+            created_keys: vec!["AMP-11".to_string()],
+            failed_details: vec![(4, "C".to_string(), "timeout".to_string())],
+            ..previous.clone()
+        };
+
+        let merged = merge_upload_retry(previous, retry);
+        assert_eq!(merged.succeeded, 2);
+        assert_eq!(merged.failed, 1);
+        assert_eq!(merged.attempted, 3);
+        assert_eq!(merged.created_keys, vec!["AMP-10", "AMP-11"]);
+        assert_eq!(merged.failed_details[0].2, "timeout");
     }
 }
@@ -0,0 +1,228 @@
+use crate::app::{App, FilterFocus, Tab};
+use crate::config::AppConfig;
+use crate::keymap::{Action, Scope, Section};
+
+/// What a palette entry does when chosen.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    Run(Action),
+    SwitchTab(Tab),
+    RunFilter(usize),
+    OpenEpic(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    pub label: String,
+    /// Key bound to the action, shown next to it.
+    pub key: Option<String>,
+    pub command: Command,
+}
+
+/// State of the open command palette.
+#[derive(Debug, Clone)]
+pub struct PaletteState {
+    /// Where it was opened; actions run as if their key was pressed there.
+    pub scope: Scope,
+    pub entries: Vec<Entry>,
+    pub query: String,
+    /// Index into `matches()`.
+    pub selected: usize,
+}
+
+impl PaletteState {
+    pub fn new(scope: Scope, entries: Vec<Entry>) -> Self {
+        Self {
+            scope,
+            entries,
+            query: String::new(),
+            selected: 0,
+        }
+    }
+
+    /// Entries matching the query, best match first; ties keep the listing order.
+    pub fn matches(&self) -> Vec<&Entry> {
+        let mut scored: Vec<(i64, &Entry)> = self
+            .entries
+            .iter()
+            .filter_map(|entry| fuzzy_score(&self.query, &entry.label).map(|score| (score, entry)))
+            .collect();
+        scored.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+        scored.into_iter().map(|(_, entry)| entry).collect()
+    }
+
+    pub fn chosen(&self) -> Option<&Entry> {
+        self.matches().get(self.selected).copied()
+    }
+
+    pub fn push_char(&mut self, c: char) {
+        self.query.push(c);
+        self.selected = 0;
+    }
+
+    pub fn pop_char(&mut self) {
+        self.query.pop();
+        self.selected = 0;
+    }
+
+    pub fn move_down(&mut self) {
+        let count = self.matches().len();
+        if self.selected + 1 < count {
+            self.selected += 1;
+        }
+    }
+
+    pub fn move_up(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+}
+
+/// Subsequence match of `query` (whitespace ignored, case-insensitive) in `text`. Higher is
+/// better: consecutive characters and word starts score extra, a late first match costs.
+pub fn fuzzy_score(query: &str, text: &str) -> Option<i64> {
+    let text: Vec<char> = text.chars().flat_map(char::to_lowercase).collect();
+    let mut score = 0i64;
+    let mut pos = 0;
+    let mut previous: Option<usize> = None;
+    for wanted in query
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .flat_map(char::to_lowercase)
+    {
+        let found = pos + text[pos..].iter().position(|c| *c == wanted)?;
+        score += 1;
+        if previous.is_some_and(|p| p + 1 == found) {
+            score += 5;
+        }
+        if found == 0 || !text[found - 1].is_alphanumeric() {
+            score += 3;
+        }
+        if previous.is_none() {
+            score -= found as i64 / 4;
+        }
+        previous = Some(found);
+        pos = found + 1;
+    }
+    Some(score)
+}
+
+/// Actions that only move the cursor or reopen the palette are left out.
+fn listed(action: Action) -> bool {
+    !matches!(
+        action,
+        Action::CommandPalette | Action::Down | Action::Up | Action::ScrollDown | Action::ScrollUp
+    )
+}
+
+/// Whether an action does anything in the current context.
+fn applies(app: &App, scope: Scope, action: Action) -> bool {
+    match scope {
+        Scope::Main => match action {
+            Action::FocusInProgress
+            | Action::FocusReadyForWork
+            | Action::FocusNeedsTriage
+            | Action::FocusInReview => matches!(app.active_tab, Tab::MyWork | Tab::Team),
+            _ if action.section() == Section::Epics => app.active_tab == Tab::Epics,
+            _ => true,
+        },
+        Scope::Filters => match action {
+            Action::SidebarPane => app.filter_focus == FilterFocus::Results,
+            Action::EditFilter | Action::DeleteFilter => app.filter_focus == FilterFocus::Sidebar,
+            _ => true,
+        },
+        Scope::Detail => {
+            let epic_view = app.detail_ticket_key.is_none();
+            match action {
+                Action::CloseDetail
+                | Action::OpenInBrowser
+                | Action::GoToKey
+                | Action::NavBack
+                | Action::NavForward => true,
+                Action::CloseEpic => epic_view,
+                _ => !epic_view,
+            }
+        }
+        Scope::Picker | Scope::Input => false,
+    }
+}
+
+/// Everything the palette offers in `scope`: bound actions, then tabs, saved filters and
+/// cached epics (not from the detail view).
+pub fn entries(app: &App, config: &AppConfig, scope: Scope) -> Vec<Entry> {
+    let mut entries: Vec<Entry> = Action::in_scope(scope)
+        .filter(|action| listed(*action) && applies(app, scope, *action))
+        .map(|action| Entry {
+            label: action.description().to_string(),
+            key: app.keymap.hint(action),
+            command: Command::Run(action),
+        })
+        .collect();
+    if scope == Scope::Detail {
+        return entries;
+    }
+    entries.extend(
+        Tab::all()
+            .iter()
+            .filter(|tab| **tab != app.active_tab)
+            .map(|tab| Entry {
+                label: format!("go to {} tab", tab.title()),
+                key: None,
+                command: Command::SwitchTab(*tab),
+            }),
+    );
+    entries.extend(config.filters.iter().enumerate().map(|(i, filter)| Entry {
+        label: format!("run filter: {}", filter.name),
+        key: None,
+        command: Command::RunFilter(i),
+    }));
+    entries.extend(app.cache.epics.iter().map(|epic| Entry {
+        label: format!("open epic: {} {}", epic.key, epic.summary),
+        key: None,
+        command: Command::OpenEpic(epic.key.clone()),
+    }));
+    entries
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(label: &str) -> Entry {
+        Entry {
+            label: label.to_string(),
+            key: None,
+            command: Command::SwitchTab(Tab::Team),
+        }
+    }
+
+    #[test]
+    fn fuzzy_matching_prefers_word_starts_and_runs() {
+        assert!(fuzzy_score("bkmv", "open bulk actions (move/assign)").is_some());
+        assert!(fuzzy_score("xyz", "open bulk actions").is_none());
+        assert_eq!(fuzzy_score("", "anything"), Some(0));
+        assert!(
+            fuzzy_score("Move", "move ticket").unwrap() > fuzzy_score("move", "remove").unwrap()
+        );
+
+        let mut palette = PaletteState::new(
+            Scope::Main,
+            vec![
+                entry("toggle Done tickets"),
+                entry("run filter: Done this week"),
+                entry("go to Dashboard tab"),
+            ],
+        );
+        for c in "don".chars() {
+            palette.push_char(c);
+        }
+        let labels: Vec<&str> = palette.matches().iter().map(|e| e.label.as_str()).collect();
+        assert_eq!(labels[0], "toggle Done tickets");
+        assert_eq!(labels.len(), 2);
+
+        palette.move_down();
+        palette.move_down();
+        assert_eq!(palette.selected, 1);
+        palette.pop_char();
+        assert_eq!(palette.selected, 0);
+    }
+}
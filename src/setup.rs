@@ -2,7 +2,7 @@ use anyhow::Result;
 use crossterm::event::{self, Event, KeyCode};
 use ratatui::backend::CrosstermBackend;
 use ratatui::layout::{Constraint, Direction, Layout};
-use ratatui::style::{Color, Modifier, Style};
+use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, Paragraph};
 use ratatui::Terminal;
@@ -111,6 +111,14 @@ fn build_config(state: &SetupState) -> AppConfig {
         statuses: StatusConfig::default(),
         resolutions: crate::config::default_resolutions(),
         filters: vec![],
+        templates: vec![],
+        custom_fields: Default::default(),
+        comment_templates: vec![],
+        standup: Default::default(),
+        dashboard: Default::default(),
+        git: Default::default(),
+        keys: Default::default(),
+        theme: Default::default(),
     }
 }
 
@@ -136,6 +144,7 @@ fn migrate_legacy_team_roster() -> Result<Vec<(String, String)>> {
 }
 
 fn render_setup(f: &mut ratatui::Frame, state: &SetupState) {
+    let theme = crate::theme::current();
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([Constraint::Length(3), Constraint::Min(0)])
@@ -145,7 +154,7 @@ fn render_setup(f: &mut ratatui::Frame, state: &SetupState) {
         .block(Block::default().borders(Borders::ALL))
         .style(
             Style::default()
-                .fg(Color::Yellow)
+                .fg(theme.highlight)
                 .add_modifier(Modifier::BOLD),
         );
     f.render_widget(title, chunks[0]);
@@ -154,7 +163,7 @@ fn render_setup(f: &mut ratatui::Frame, state: &SetupState) {
         Line::from(""),
         Line::from(Span::styled(
             "Welcome! Let's configure lazyjira for your team.",
-            Style::default().fg(Color::White),
+            Style::default().fg(theme.text),
         )),
         Line::from(""),
     ];
@@ -162,64 +171,60 @@ fn render_setup(f: &mut ratatui::Frame, state: &SetupState) {
     match state.step {
         SetupStep::ProjectKey => {
             lines.push(Line::from(vec![
-                Span::styled("Jira Project Key: ", Style::default().fg(Color::Cyan)),
+                Span::styled("Jira Project Key: ", Style::default().fg(theme.accent)),
                 Span::styled(
                     &state.project_key,
-                    Style::default()
-                        .fg(Color::White)
-                        .add_modifier(Modifier::BOLD),
+                    Style::default().fg(theme.text).add_modifier(Modifier::BOLD),
                 ),
-                Span::styled("_", Style::default().fg(Color::DarkGray)),
+                Span::styled("_", Style::default().fg(theme.muted)),
             ]));
             lines.push(Line::from(""));
             lines.push(Line::from(Span::styled(
                 "  (e.g., AMP, SERVER, CLOUD)  Press Enter to continue.",
-                Style::default().fg(Color::DarkGray),
+                Style::default().fg(theme.muted),
             )));
         }
         SetupStep::TeamName => {
             lines.push(Line::from(vec![
-                Span::styled("Project: ", Style::default().fg(Color::DarkGray)),
+                Span::styled("Project: ", Style::default().fg(theme.muted)),
                 Span::raw(&state.project_key),
             ]));
             lines.push(Line::from(""));
             lines.push(Line::from(vec![
                 Span::styled(
                     "Team Name (for unassigned queries): ",
-                    Style::default().fg(Color::Cyan),
+                    Style::default().fg(theme.accent),
                 ),
                 Span::styled(
                     &state.team_name,
-                    Style::default()
-                        .fg(Color::White)
-                        .add_modifier(Modifier::BOLD),
+                    Style::default().fg(theme.text).add_modifier(Modifier::BOLD),
                 ),
-                Span::styled("_", Style::default().fg(Color::DarkGray)),
+                Span::styled("_", Style::default().fg(theme.muted)),
             ]));
             lines.push(Line::from(""));
             lines.push(Line::from(Span::styled(
                 "  Press Enter to continue, Esc to go back.",
-                Style::default().fg(Color::DarkGray),
+                Style::default().fg(theme.muted),
             )));
         }
         SetupStep::Confirm => {
             lines.push(Line::from(Span::styled(
                 "Configuration Summary:",
                 Style::default()
-                    .fg(Color::Cyan)
+                    .fg(theme.accent)
                     .add_modifier(Modifier::BOLD),
             )));
             lines.push(Line::from(""));
             lines.push(Line::from(vec![
-                Span::styled("  Project:    ", Style::default().fg(Color::DarkGray)),
+                Span::styled("  Project:    ", Style::default().fg(theme.muted)),
                 Span::raw(&state.project_key),
             ]));
             lines.push(Line::from(vec![
-                Span::styled("  Team:       ", Style::default().fg(Color::DarkGray)),
+                Span::styled("  Team:       ", Style::default().fg(theme.muted)),
                 Span::raw(&state.team_name),
             ]));
             lines.push(Line::from(vec![
-                Span::styled("  User:       ", Style::default().fg(Color::DarkGray)),
+                Span::styled("  User:       ", Style::default().fg(theme.muted)),
                 Span::raw(&state.user_email),
             ]));
             lines.push(Line::from(""));
@@ -230,13 +235,13 @@ fn render_setup(f: &mut ratatui::Frame, state: &SetupState) {
                 Err(_) => "~/.config/lazyjira/config.toml".to_string(),
             };
             lines.push(Line::from(vec![
-                Span::styled("  Config:     ", Style::default().fg(Color::DarkGray)),
+                Span::styled("  Config:     ", Style::default().fg(theme.muted)),
                 Span::raw(path_str),
             ]));
             lines.push(Line::from(""));
             lines.push(Line::from(Span::styled(
                 "Press Enter or y to save.  Esc or n to start over.",
-                Style::default().fg(Color::Yellow),
+                Style::default().fg(theme.highlight),
             )));
         }
     }
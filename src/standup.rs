@@ -0,0 +1,338 @@
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, NaiveDateTime};
+
+use crate::cache::{ActivityEntry, ActivityKind, Status, TeamMember, Ticket};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StandupScope {
+    Me,
+    Team,
+}
+
+impl StandupScope {
+    pub fn toggle(self) -> Self {
+        match self {
+            StandupScope::Me => StandupScope::Team,
+            StandupScope::Team => StandupScope::Me,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            StandupScope::Me => "Me",
+            StandupScope::Team => "Team",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StandupFormat {
+    Markdown,
+    Plain,
+}
+
+impl StandupFormat {
+    pub fn toggle(self) -> Self {
+        match self {
+            StandupFormat::Markdown => StandupFormat::Plain,
+            StandupFormat::Plain => StandupFormat::Markdown,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            StandupFormat::Markdown => "Markdown",
+            StandupFormat::Plain => "Plain text",
+        }
+    }
+}
+
+/// One person's part of the standup.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PersonSection {
+    pub name: String,
+    /// Status changes and comments by this person inside the window, one line per ticket.
+    pub yesterday: Vec<String>,
+    /// Their In Progress / In Review tickets.
+    pub today: Vec<String>,
+    /// Their tickets in Blocked status or labelled `blocked`.
+    pub blockers: Vec<String>,
+}
+
+/// Jira timestamps ("2024-01-15T10:30:00.000+0000") to unix seconds. Timestamps
+/// without an offset are read as UTC.
+pub fn parse_timestamp(ts: &str) -> Option<u64> {
+    let secs = DateTime::parse_from_str(ts, "%Y-%m-%dT%H:%M:%S%.f%z")
+        .map(|dt| dt.timestamp())
+        .or_else(|_| {
+            NaiveDateTime::parse_from_str(ts, "%Y-%m-%dT%H:%M:%S%.f")
+                .map(|dt| dt.and_utc().timestamp())
+        })
+        .ok()?;
+    u64::try_from(secs).ok()
+}
+
+fn is_by(entry: &ActivityEntry, member: &TeamMember) -> bool {
+    match &entry.author_email {
+        Some(email) => email.eq_ignore_ascii_case(&member.email),
+        None => entry.author == member.name,
+    }
+}
+
+fn is_blocked(ticket: &Ticket) -> bool {
+    ticket.status == Status::Blocked
+        || ticket
+            .labels
+            .iter()
+            .any(|label| label.eq_ignore_ascii_case("blocked"))
+}
+
+fn ticket_line(ticket: &Ticket) -> String {
+    format!("{} {}", ticket.key, ticket.summary)
+}
+
+/// Build a section per member from the cached tickets, looking at activity newer than `since`.
+pub fn build_sections(
+    members: &[TeamMember],
+    tickets: &[&Ticket],
+    since: u64,
+) -> Vec<PersonSection> {
+    members
+        .iter()
+        .map(|member| {
+            let mut section = PersonSection {
+                name: member.name.clone(),
+                ..Default::default()
+            };
+            for ticket in tickets {
+                let mut actions = Vec::new();
+                // Activity is newest first; report it in the order it happened.
+                for entry in ticket.activity.iter().rev() {
+                    if !is_by(entry, member)
+                        || parse_timestamp(&entry.timestamp).is_none_or(|ts| ts < since)
+                    {
+                        continue;
+                    }
+                    match &entry.kind {
+                        ActivityKind::StatusChange { from, to } => {
+                            actions.push(format!("{} -> {}", from, to));
+                        }
+                        ActivityKind::Comment { body } => {
+                            let preview: String = body
+                                .split_whitespace()
+                                .collect::<Vec<_>>()
+                                .join(" ")
+                                .chars()
+                                .take(60)
+                                .collect();
+                            actions.push(format!("commented \"{}\"", preview));
+                        }
+                        _ => {}
+                    }
+                }
+                if !actions.is_empty() {
+                    section.yesterday.push(format!(
+                        "{}: {}",
+                        ticket_line(ticket),
+                        actions.join("; ")
+                    ));
+                }
+
+                let assigned = ticket
+                    .assignee_email
+                    .as_deref()
+                    .is_some_and(|email| email.eq_ignore_ascii_case(&member.email));
+                if !assigned {
+                    continue;
+                }
+                if is_blocked(ticket) {
+                    section.blockers.push(ticket_line(ticket));
+                } else if matches!(ticket.status, Status::InProgress | Status::InReview) {
+                    section.today.push(format!(
+                        "{} ({})",
+                        ticket_line(ticket),
+                        ticket.status.as_str()
+                    ));
+                }
+            }
+            section
+        })
+        .collect()
+}
+
+pub fn render(sections: &[PersonSection], format: StandupFormat, window_hours: u32) -> String {
+    let mut out = match format {
+        StandupFormat::Markdown => format!("## Standup (last {}h)\n", window_hours),
+        StandupFormat::Plain => format!("Standup (last {}h)\n", window_hours),
+    };
+    for section in sections {
+        out.push('\n');
+        match format {
+            StandupFormat::Markdown => out.push_str(&format!("### {}\n", section.name)),
+            StandupFormat::Plain => out.push_str(&format!("{}\n", section.name)),
+        }
+        for (heading, items) in [
+            ("Yesterday", &section.yesterday),
+            ("Today", &section.today),
+            ("Blockers", &section.blockers),
+        ] {
+            let (title, indent) = match format {
+                StandupFormat::Markdown => (format!("**{}**\n", heading), ""),
+                StandupFormat::Plain => (format!("  {}:\n", heading), "    "),
+            };
+            out.push_str(&title);
+            if items.is_empty() {
+                out.push_str(&format!("{}- none\n", indent));
+            }
+            for item in items {
+                out.push_str(&format!("{}- {}\n", indent, item));
+            }
+        }
+    }
+    out
+}
+
+/// Copy `text` with the first clipboard tool found; returns the tool's name.
+pub fn copy_to_clipboard(text: &str) -> Result<&'static str> {
+    let tools: [(&'static str, &[&str]); 4] = [
+        ("pbcopy", &[]),
+        ("wl-copy", &[]),
+        ("xclip", &["-selection", "clipboard"]),
+        ("xsel", &["--clipboard", "--input"]),
+    ];
+    for (program, args) in tools {
+        let mut child = match Command::new(program)
+            .args(args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+        {
+            Ok(child) => child,
+            Err(_) => continue,
+        };
+        if let Some(mut stdin) = child.stdin.take() {
+            stdin
+                .write_all(text.as_bytes())
+                .with_context(|| format!("Failed to write to {}", program))?;
+        }
+        let status = child
+            .wait()
+            .with_context(|| format!("Failed to run: {}", program))?;
+        if status.success() {
+            return Ok(program);
+        }
+    }
+    anyhow::bail!("no clipboard tool found (tried pbcopy, wl-copy, xclip, xsel)")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn member(name: &str) -> TeamMember {
+        TeamMember {
+            name: name.to_string(),
+            email: format!("{}@example.com", name.to_ascii_lowercase()),
+        }
+    }
+
+    fn entry(ts: &str, author: &str, kind: ActivityKind) -> ActivityEntry {
+        ActivityEntry {
+            timestamp: ts.to_string(),
+            author: author.to_string(),
+            author_email: Some(format!("{}@example.com", author.to_ascii_lowercase())),
+            kind,
+        }
+    }
+
+    fn ticket(key: &str, status: Status, assignee: &str, activity: Vec<ActivityEntry>) -> Ticket {
+        Ticket {
+            assignee: Some(assignee.to_string()),
+            assignee_email: Some(format!("{}@example.com", assignee.to_ascii_lowercase())),
+            detail_loaded: true,
+            activity,
+            ..Ticket::for_test(key, &format!("Summary {}", key), status)
+        }
+    }
+
+    #[test]
+    fn parses_jira_and_naive_timestamps() {
+        assert_eq!(
+            parse_timestamp("2026-01-01T01:00:00.000+0100"),
+            parse_timestamp("2026-01-01T00:00:00")
+        );
+        assert_eq!(parse_timestamp("2026-01-01T00:00:00"), Some(1_767_225_600));
+        assert_eq!(parse_timestamp("yesterday"), None);
+    }
+
+    #[test]
+    fn sections_use_activity_inside_the_window_only() {
+        let since = parse_timestamp("2026-01-02T00:00:00").unwrap();
+        let moved = ticket(
+            "AMP-1",
+            Status::InReview,
+            "Bob",
+            vec![
+                entry(
+                    "2026-01-02T10:00:00.000+0000",
+                    "Alice",
+                    ActivityKind::Comment {
+                        body: "Looks\ngood".to_string(),
+                    },
+                ),
+                entry(
+                    "2026-01-02T09:00:00.000+0000",
+                    "Bob",
+                    ActivityKind::StatusChange {
+                        from: "In Progress".to_string(),
+                        to: "In Review".to_string(),
+                    },
+                ),
+                entry(
+                    "2026-01-01T09:00:00.000+0000",
+                    "Bob",
+                    ActivityKind::StatusChange {
+                        from: "To Do".to_string(),
+                        to: "In Progress".to_string(),
+                    },
+                ),
+            ],
+        );
+        let mut blocked = ticket("AMP-2", Status::InProgress, "Bob", Vec::new());
+        blocked.labels.push("Blocked".to_string());
+        let tickets = [&moved, &blocked];
+
+        let sections = build_sections(&[member("Alice"), member("Bob")], &tickets, since);
+        assert_eq!(
+            sections[0].yesterday,
+            vec!["AMP-1 Summary AMP-1: commented \"Looks good\""]
+        );
+        assert!(sections[0].today.is_empty());
+        assert_eq!(
+            sections[1].yesterday,
+            vec!["AMP-1 Summary AMP-1: In Progress -> In Review"]
+        );
+        assert_eq!(sections[1].today, vec!["AMP-1 Summary AMP-1 (In Review)"]);
+        assert_eq!(sections[1].blockers, vec!["AMP-2 Summary AMP-2"]);
+    }
+
+    #[test]
+    fn renders_markdown_and_plain_text() {
+        let sections = vec![PersonSection {
+            name: "Bob".to_string(),
+            yesterday: vec!["AMP-1 Fix: To Do -> In Progress".to_string()],
+            ..Default::default()
+        }];
+        assert_eq!(
+            render(&sections, StandupFormat::Markdown, 24),
+            "## Standup (last 24h)\n\n### Bob\n**Yesterday**\n- AMP-1 Fix: To Do -> In Progress\n\
+             **Today**\n- none\n**Blockers**\n- none\n"
+        );
+        let plain = render(&sections, StandupFormat::Plain, 48);
+        assert!(plain.starts_with("Standup (last 48h)\n\nBob\n  Yesterday:\n    - AMP-1"));
+    }
+}
@@ -0,0 +1,242 @@
+use std::str::FromStr;
+use std::sync::RwLock;
+
+use anyhow::{Context, Result};
+use ratatui::style::{Color, Modifier, Style};
+
+use crate::cache::Status;
+use crate::config::{ThemeConfig, ThemeName};
+
+/// Colors by role; every view and widget styles itself from the active theme.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Theme {
+    /// Primary text: values, titles and selected labels.
+    pub text: Color,
+    /// Hints, borders, separators and labels.
+    pub muted: Color,
+    /// Secondary text such as assignees, dates and inactive tabs.
+    pub subtle: Color,
+    /// Keys, headers and links.
+    pub accent: Color,
+    /// Active tab, focus, search and pending work.
+    pub highlight: Color,
+    pub error: Color,
+    pub success: Color,
+    /// Epics and other out-of-band markers.
+    pub special: Color,
+    pub info: Color,
+    /// Text drawn on an `accent` or status colored background.
+    pub on_accent: Color,
+    /// Background of the selected row; None selects with reverse video instead.
+    pub selection: Option<Color>,
+}
+
+impl Theme {
+    /// For dark terminals; greys come from the 256-color cube so they do not depend on the
+    /// terminal's own "bright black".
+    pub const DARK: Theme = Theme {
+        text: Color::White,
+        muted: Color::Indexed(244),
+        subtle: Color::Indexed(250),
+        accent: Color::Cyan,
+        highlight: Color::Yellow,
+        error: Color::Red,
+        success: Color::Green,
+        special: Color::Magenta,
+        info: Color::Blue,
+        on_accent: Color::Black,
+        selection: Some(Color::Indexed(238)),
+    };
+
+    pub const LIGHT: Theme = Theme {
+        text: Color::Black,
+        muted: Color::Indexed(243),
+        subtle: Color::Indexed(238),
+        accent: Color::Indexed(24),
+        highlight: Color::Indexed(130),
+        error: Color::Indexed(124),
+        success: Color::Indexed(28),
+        special: Color::Indexed(127),
+        info: Color::Indexed(25),
+        on_accent: Color::White,
+        selection: Some(Color::Indexed(253)),
+    };
+
+    pub const HIGH_CONTRAST: Theme = Theme {
+        text: Color::White,
+        muted: Color::Gray,
+        subtle: Color::White,
+        accent: Color::LightCyan,
+        highlight: Color::LightYellow,
+        error: Color::LightRed,
+        success: Color::LightGreen,
+        special: Color::LightMagenta,
+        info: Color::LightBlue,
+        on_accent: Color::Black,
+        selection: None,
+    };
+
+    /// Only the 16 named ANSI colors, for terminals without 256-color support.
+    pub const ANSI16: Theme = Theme {
+        text: Color::White,
+        muted: Color::DarkGray,
+        subtle: Color::Gray,
+        accent: Color::Cyan,
+        highlight: Color::Yellow,
+        error: Color::Red,
+        success: Color::Green,
+        special: Color::Magenta,
+        info: Color::Blue,
+        on_accent: Color::Black,
+        selection: Some(Color::DarkGray),
+    };
+
+    /// Terminal default colors throughout, used when `NO_COLOR` is set.
+    pub const NO_COLOR: Theme = Theme {
+        text: Color::Reset,
+        muted: Color::Reset,
+        subtle: Color::Reset,
+        accent: Color::Reset,
+        highlight: Color::Reset,
+        error: Color::Reset,
+        success: Color::Reset,
+        special: Color::Reset,
+        info: Color::Reset,
+        on_accent: Color::Reset,
+        selection: None,
+    };
+
+    pub fn preset(name: ThemeName) -> Theme {
+        match name {
+            ThemeName::Dark => Theme::DARK,
+            ThemeName::Light => Theme::LIGHT,
+            ThemeName::HighContrast => Theme::HIGH_CONTRAST,
+            ThemeName::Ansi16 => Theme::ANSI16,
+        }
+    }
+
+    /// The `[theme]` preset with its color overrides applied; `no_color` wins over both.
+    pub fn from_config(config: &ThemeConfig, no_color: bool) -> Result<Theme> {
+        if no_color {
+            return Ok(Theme::NO_COLOR);
+        }
+        let mut theme = Theme::preset(config.name);
+        for (role, value) in &config.colors {
+            if role == "selection" {
+                theme.selection = if value.eq_ignore_ascii_case("reverse") {
+                    None
+                } else {
+                    Some(parse_color(role, value)?)
+                };
+                continue;
+            }
+            let slot = match role.as_str() {
+                "text" => &mut theme.text,
+                "muted" => &mut theme.muted,
+                "subtle" => &mut theme.subtle,
+                "accent" => &mut theme.accent,
+                "highlight" => &mut theme.highlight,
+                "error" => &mut theme.error,
+                "success" => &mut theme.success,
+                "special" => &mut theme.special,
+                "info" => &mut theme.info,
+                "on_accent" => &mut theme.on_accent,
+                _ => anyhow::bail!("[theme] has unknown color role `{}`", role),
+            };
+            *slot = parse_color(role, value)?;
+        }
+        Ok(theme)
+    }
+
+    pub fn status(&self, status: &Status) -> Color {
+        match status {
+            Status::NeedsTriage => self.text,
+            Status::ReadyForWork => self.info,
+            Status::InProgress => self.highlight,
+            Status::ToDo => self.text,
+            Status::InReview => self.accent,
+            Status::Blocked => self.error,
+            Status::Closed => self.success,
+            Status::Other(_) => self.special,
+        }
+    }
+
+    /// Style patched onto the selected row.
+    pub fn selection(&self) -> Style {
+        match self.selection {
+            Some(bg) => Style::default().bg(bg),
+            None => Style::default().add_modifier(Modifier::REVERSED),
+        }
+    }
+}
+
+fn parse_color(role: &str, value: &str) -> Result<Color> {
+    Color::from_str(value).ok().with_context(|| {
+        format!(
+            "[theme] {} = \"{}\" is not a color name, 0-255 index or #rrggbb",
+            role, value
+        )
+    })
+}
+
+/// `NO_COLOR` set to anything but the empty string disables colors (https://no-color.org).
+pub fn no_color_requested() -> bool {
+    std::env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty())
+}
+
+// A process-wide palette, so that small rendering helpers do not need a theme parameter.
+static CURRENT: RwLock<Theme> = RwLock::new(Theme::DARK);
+
+pub fn current() -> Theme {
+    *CURRENT.read().unwrap_or_else(|e| e.into_inner())
+}
+
+pub fn set(theme: Theme) {
+    *CURRENT.write().unwrap_or_else(|e| e.into_inner()) = theme;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn overrides_apply_on_top_of_the_preset() {
+        let config = ThemeConfig {
+            name: ThemeName::Light,
+            colors: [
+                ("accent".to_string(), "#0087af".to_string()),
+                ("highlight".to_string(), "light-red".to_string()),
+                ("selection".to_string(), "reverse".to_string()),
+            ]
+            .into(),
+        };
+        let theme = Theme::from_config(&config, false).unwrap();
+        assert_eq!(theme.accent, Color::Rgb(0x00, 0x87, 0xaf));
+        assert_eq!(theme.highlight, Color::LightRed);
+        assert_eq!(theme.text, Theme::LIGHT.text);
+        assert_eq!(
+            theme.selection(),
+            Style::default().add_modifier(Modifier::REVERSED)
+        );
+        assert_eq!(theme.status(&Status::InReview), theme.accent);
+
+        assert_eq!(Theme::from_config(&config, true).unwrap(), Theme::NO_COLOR);
+    }
+
+    #[test]
+    fn bad_overrides_are_reported() {
+        let mut config = ThemeConfig::default();
+        config
+            .colors
+            .insert("accent".to_string(), "not-a-color".to_string());
+        let err = Theme::from_config(&config, false).unwrap_err().to_string();
+        assert!(err.contains("accent"), "{}", err);
+
+        let mut config = ThemeConfig::default();
+        config
+            .colors
+            .insert("border".to_string(), "red".to_string());
+        let err = Theme::from_config(&config, false).unwrap_err().to_string();
+        assert!(err.contains("border"), "{}", err);
+    }
+}
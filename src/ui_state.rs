@@ -0,0 +1,99 @@
+use std::collections::BTreeSet;
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::app::{DetailLayout, Tab, WorkTimer};
+use crate::cache::Status;
+
+/// View state restored at startup, kept apart from `config.toml` because it changes on
+/// every run.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UiState {
+    pub active_tab: Option<Tab>,
+    pub show_done: bool,
+    pub status_focus: Option<Status>,
+    pub filter_sidebar_idx: usize,
+    pub search: Option<String>,
+    pub collapsed_my_work: BTreeSet<String>,
+    pub collapsed_team: BTreeSet<String>,
+    pub collapsed_epics: BTreeSet<String>,
+    pub collapsed_unassigned: BTreeSet<String>,
+    pub collapsed_watching: BTreeSet<String>,
+    pub detail_layout: DetailLayout,
+    /// Kept so quitting with a timer running doesn't lose the tracked time.
+    pub work_timer: Option<WorkTimer>,
+}
+
+/// Matches `App::new`, so a first launch without a state file looks like before.
+impl Default for UiState {
+    fn default() -> Self {
+        Self {
+            active_tab: None,
+            show_done: true,
+            status_focus: None,
+            filter_sidebar_idx: 0,
+            search: None,
+            collapsed_my_work: BTreeSet::new(),
+            collapsed_team: BTreeSet::new(),
+            collapsed_epics: BTreeSet::new(),
+            collapsed_unassigned: BTreeSet::new(),
+            collapsed_watching: BTreeSet::new(),
+            detail_layout: DetailLayout::default(),
+            work_timer: None,
+        }
+    }
+}
+
+/// Returns the state file path (`~/.config/lazyjira/state.json`).
+pub fn state_path() -> Result<PathBuf> {
+    Ok(crate::config::config_dir()?.join("state.json"))
+}
+
+/// Load the saved state; a missing or unreadable file gives the defaults.
+pub fn load() -> UiState {
+    state_path()
+        .ok()
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+pub fn save(state: &UiState) -> Result<()> {
+    let dir = crate::config::config_dir()?;
+    std::fs::create_dir_all(&dir)
+        .with_context(|| format!("Failed to create config directory: {}", dir.display()))?;
+    let path = state_path()?;
+    let json = serde_json::to_string_pretty(state).context("Failed to serialize UI state")?;
+    std::fs::write(&path, json)
+        .with_context(|| format!("Failed to write UI state: {}", path.display()))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn state_round_trips_and_tolerates_missing_fields() {
+        let state = UiState {
+            active_tab: Some(Tab::Epics),
+            show_done: true,
+            status_focus: Some(Status::InReview),
+            filter_sidebar_idx: 2,
+            search: Some("login".to_string()),
+            collapsed_team: ["alice@example.com".to_string()].into(),
+            detail_layout: DetailLayout::Split,
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&state).unwrap();
+        assert_eq!(serde_json::from_str::<UiState>(&json).unwrap(), state);
+
+        let partial: UiState = serde_json::from_str(r#"{"search": "login"}"#).unwrap();
+        assert!(partial.show_done);
+        assert_eq!(partial.active_tab, None);
+        assert_eq!(partial.detail_layout, DetailLayout::Overlay);
+    }
+}
@@ -0,0 +1,197 @@
+use anyhow::{Context, Result};
+use serde::Serialize;
+
+use crate::cache::Ticket;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Csv,
+    Json,
+    Markdown,
+}
+
+impl ExportFormat {
+    pub const ALL: [ExportFormat; 3] = [
+        ExportFormat::Csv,
+        ExportFormat::Json,
+        ExportFormat::Markdown,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "CSV (bulk upload columns)",
+            ExportFormat::Json => "JSON",
+            ExportFormat::Markdown => "Markdown table",
+        }
+    }
+
+    pub fn extension(self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "csv",
+            ExportFormat::Json => "json",
+            ExportFormat::Markdown => "md",
+        }
+    }
+}
+
+/// CSV columns: the bulk upload template columns plus `key`, so a re-import updates the
+/// same tickets. `status` and `group` are informational and ignored by the importer.
+#[derive(Debug, Serialize)]
+struct CsvRow<'a> {
+    key: &'a str,
+    summary: &'a str,
+    #[serde(rename = "type")]
+    issue_type: &'a str,
+    assignee_email: &'a str,
+    epic_key: &'a str,
+    labels: String,
+    description: &'a str,
+    priority: &'a str,
+    status: &'a str,
+    group: &'a str,
+}
+
+#[derive(Debug, Serialize)]
+struct JsonRow<'a> {
+    key: &'a str,
+    summary: &'a str,
+    status: &'a str,
+    assignee: Option<&'a str>,
+    assignee_email: Option<&'a str>,
+    epic_key: Option<&'a str>,
+    labels: &'a [String],
+    priority: Option<&'a str>,
+    group: &'a str,
+    url: &'a str,
+}
+
+/// Render `(group, ticket)` rows, as returned by `App::visible_tickets_for_export`.
+pub fn render_export(rows: &[(String, &Ticket)], format: ExportFormat) -> Result<String> {
+    match format {
+        ExportFormat::Csv => {
+            let mut writer = csv::Writer::from_writer(Vec::new());
+            for (group, t) in rows {
+                writer.serialize(CsvRow {
+                    key: &t.key,
+                    summary: &t.summary,
+                    // Issue types are not cached; an empty type keeps re-imports valid.
+                    issue_type: "",
+                    assignee_email: t.assignee_email.as_deref().unwrap_or_default(),
+                    epic_key: t.epic_key.as_deref().unwrap_or_default(),
+                    labels: t.labels.join("|"),
+                    description: t.description.as_deref().unwrap_or_default(),
+                    priority: t.priority.as_deref().unwrap_or_default(),
+                    status: t.status.as_str(),
+                    group,
+                })?;
+            }
+            let bytes = writer.into_inner().context("Failed to flush CSV export")?;
+            Ok(String::from_utf8(bytes)?)
+        }
+        ExportFormat::Json => {
+            let items: Vec<JsonRow> = rows
+                .iter()
+                .map(|(group, t)| JsonRow {
+                    key: &t.key,
+                    summary: &t.summary,
+                    status: t.status.as_str(),
+                    assignee: t.assignee.as_deref(),
+                    assignee_email: t.assignee_email.as_deref(),
+                    epic_key: t.epic_key.as_deref(),
+                    labels: &t.labels,
+                    priority: t.priority.as_deref(),
+                    group,
+                    url: &t.url,
+                })
+                .collect();
+            Ok(serde_json::to_string_pretty(&items)?)
+        }
+        ExportFormat::Markdown => {
+            let mut out = String::from(
+                "| Key | Summary | Status | Assignee | Group |\n|---|---|---|---|---|\n",
+            );
+            for (group, t) in rows {
+                out.push_str(&format!(
+                    "| [{}]({}) | {} | {} | {} | {} |\n",
+                    t.key,
+                    t.url,
+                    markdown_cell(&t.summary),
+                    t.status.as_str(),
+                    markdown_cell(t.assignee.as_deref().unwrap_or("Unassigned")),
+                    markdown_cell(group)
+                ));
+            }
+            Ok(out)
+        }
+    }
+}
+
+fn markdown_cell(text: &str) -> String {
+    text.replace('|', "\\|").replace('\n', " ")
+}
+
+/// File name like `lazyjira-my-work-1760000000.md`.
+pub fn default_file_name(tab_title: &str, format: ExportFormat, now: u64) -> String {
+    let tab = tab_title.to_ascii_lowercase().replace(' ', "-");
+    format!("lazyjira-{}-{}.{}", tab, now, format.extension())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bulk_upload::{parse_preview, BulkUploadContext};
+    use crate::cache::Status;
+
+    fn ticket(key: &str, summary: &str) -> Ticket {
+        Ticket {
+            assignee: Some("Dev".to_string()),
+            assignee_email: Some("dev@example.com".to_string()),
+            labels: vec!["api".to_string(), "urgent".to_string()],
+            detail_loaded: true,
+            url: format!("https://example.atlassian.net/browse/{}", key),
+            ..Ticket::for_test(key, summary, Status::InProgress)
+        }
+    }
+
+    #[test]
+    fn csv_export_round_trips_as_noop_updates() {
+        let t = ticket("AMP-1", "Fix parser, again");
+        let csv = render_export(&[("In Progress".to_string(), &t)], ExportFormat::Csv).unwrap();
+        assert!(csv.starts_with(
+            "key,summary,type,assignee_email,epic_key,labels,description,priority,status,group\n"
+        ));
+
+        let path =
+            std::env::temp_dir().join(format!("lazyjira_view_export_{}.csv", std::process::id()));
+        std::fs::write(&path, csv).unwrap();
+        let mut context = BulkUploadContext::new(Default::default(), Default::default());
+        context.existing_tickets.insert(t.key.clone(), t.clone());
+        let preview = parse_preview(path.to_str().unwrap(), &context).unwrap();
+        let row = &preview.rows[0];
+        assert!(row.errors.is_empty(), "{:?}", row.errors);
+        assert_eq!(row.summary, "Fix parser, again");
+        assert_eq!(row.labels, vec!["api", "urgent"]);
+        assert!(row.is_noop_update());
+    }
+
+    #[test]
+    fn markdown_export_escapes_pipes() {
+        let t = ticket("AMP-2", "a | b");
+        let md = render_export(&[("Team A".to_string(), &t)], ExportFormat::Markdown).unwrap();
+        let last = md.lines().last().unwrap();
+        assert!(last.contains("a \\| b"));
+        assert!(last.contains("| Dev | Team A |"));
+    }
+
+    #[test]
+    fn json_export_keeps_label_arrays() {
+        let t = ticket("AMP-3", "Json");
+        let json = render_export(&[(String::new(), &t)], ExportFormat::Json).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value[0]["labels"][1], "urgent");
+        assert_eq!(
+            default_file_name("My Work", ExportFormat::Markdown, 7),
+            "lazyjira-my-work-7.md"
+        );
+    }
+}
@@ -0,0 +1,130 @@
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Bar, BarChart, BarGroup, Block, Borders, Paragraph, Sparkline};
+
+use crate::app::App;
+use crate::config::AppConfig;
+use crate::dashboard;
+
+pub fn render(f: &mut ratatui::Frame, area: Rect, app: &App, config: &AppConfig) {
+    let theme = crate::theme::current();
+    let members = app.sorted_team_members();
+    let loads = dashboard::member_loads(&members, &app.cache.team_tickets, &config.dashboard);
+    let tickets = app.all_cached_tickets();
+    let now = crate::jira_client::now_unix_secs();
+
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Percentage(55), Constraint::Percentage(45)])
+        .split(area);
+    let top = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
+        .split(rows[0]);
+    let bottom = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(rows[1]);
+
+    // Per-member counts by status
+    let mut lines: Vec<Line> = Vec::new();
+    if loads.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "(no team members)",
+            Style::default().fg(theme.muted),
+        )));
+    }
+    for load in &loads {
+        let limit = load
+            .wip_limit
+            .map(|l| format!("{}/{}", load.wip, l))
+            .unwrap_or_else(|| load.wip.to_string());
+        let name_style = if load.over_limit() {
+            Style::default()
+                .fg(theme.error)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().add_modifier(Modifier::BOLD)
+        };
+        let mut spans = vec![
+            Span::styled(format!("{:<20}", load.name), name_style),
+            Span::styled(format!(" WIP {:<6}", limit), name_style),
+        ];
+        for (status, count) in &load.counts {
+            spans.push(Span::styled(
+                format!(" {} {}", status.as_str(), count),
+                Style::default().fg(theme.status(status)),
+            ));
+        }
+        lines.push(Line::from(spans));
+    }
+    let counts = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(" Workload by status "),
+    );
+    f.render_widget(counts, top[0]);
+
+    // WIP per member, red when over the limit
+    let bars: Vec<Bar> = loads
+        .iter()
+        .map(|load| {
+            let color = if load.over_limit() {
+                theme.error
+            } else {
+                theme.highlight
+            };
+            Bar::default()
+                .value(load.wip as u64)
+                .label(Line::from(load.name.clone()))
+                .style(Style::default().fg(color))
+                .value_style(Style::default().fg(theme.on_accent).bg(color))
+        })
+        .collect();
+    let wip_chart = BarChart::default()
+        .block(Block::default().borders(Borders::ALL).title(" WIP "))
+        .direction(Direction::Horizontal)
+        .bar_width(1)
+        .bar_gap(0)
+        .data(BarGroup::default().bars(&bars));
+    f.render_widget(wip_chart, top[1]);
+
+    // Closed per week
+    let weekly = dashboard::closed_per_week(&tickets, now, config.dashboard.weeks);
+    let total: u64 = weekly.iter().sum();
+    let sparkline = Sparkline::default()
+        .block(Block::default().borders(Borders::ALL).title(format!(
+            " Closed per week (last {}: {}, this week: {}) ",
+            weekly.len(),
+            total,
+            weekly.last().copied().unwrap_or(0)
+        )))
+        .data(&weekly)
+        .style(Style::default().fg(theme.success));
+    f.render_widget(sparkline, bottom[0]);
+
+    // Cycle time distribution
+    let days = dashboard::cycle_times_days(&tickets);
+    let title = match (
+        dashboard::percentile(&days, 50),
+        dashboard::percentile(&days, 85),
+    ) {
+        (Some(p50), Some(p85)) => format!(
+            " Cycle time ({} tickets, p50 {:.1}d, p85 {:.1}d) ",
+            days.len(),
+            p50,
+            p85
+        ),
+        _ => " Cycle time (no closed tickets with history) ".to_string(),
+    };
+    let histogram = dashboard::cycle_time_histogram(&days);
+    let cycle_chart = BarChart::default()
+        .block(Block::default().borders(Borders::ALL).title(title))
+        .data(&histogram)
+        .bar_width(5)
+        .bar_gap(1)
+        .bar_style(Style::default().fg(theme.accent))
+        .value_style(Style::default().fg(theme.on_accent).bg(theme.accent));
+    f.render_widget(cycle_chart, bottom[1]);
+}
@@ -1,24 +1,11 @@
 use ratatui::layout::Rect;
-use ratatui::style::{Color, Modifier, Style};
+use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, Paragraph};
 
 use crate::app::{App, GroupSelectionState, Tab};
 use crate::cache::Status;
 
-fn status_color(status: &Status) -> Color {
-    match status {
-        Status::NeedsTriage => Color::White,
-        Status::ReadyForWork => Color::Blue,
-        Status::InProgress => Color::Yellow,
-        Status::ToDo => Color::White,
-        Status::InReview => Color::Cyan,
-        Status::Blocked => Color::Red,
-        Status::Closed => Color::Green,
-        Status::Other(_) => Color::Magenta,
-    }
-}
-
 fn truncate(s: &str, max: usize) -> String {
     if s.chars().count() > max {
         let mut result: String = s.chars().take(max.saturating_sub(3)).collect();
@@ -85,28 +72,30 @@ fn progress_bar(done: usize, total: usize, width: usize) -> String {
 }
 
 pub fn render(f: &mut ratatui::Frame, area: Rect, app: &App) {
+    let theme = crate::theme::current();
     let visible_epics = app.epics_visible_epics();
     let (key_w, status_w, summary_w) = child_column_widths(area);
     let heading_style = Style::default()
-        .fg(Color::Gray)
+        .fg(theme.subtle)
         .add_modifier(Modifier::BOLD);
 
     let mut lines: Vec<Line> = Vec::new();
     let mut item_idx: usize = 0;
     let mut selected_visual_line: Option<usize> = None;
+    let mut item_rows: Vec<(usize, usize)> = Vec::new();
 
     if !visible_epics.is_empty() {
         let header_w = 4 + key_w + 3 + status_w + 3 + summary_w;
         lines.push(Line::from(vec![
             Span::styled(format!("    {:<key_w$}", "SEL KEY"), heading_style),
-            Span::styled(" | ", Style::default().fg(Color::DarkGray)),
+            Span::styled(" | ", Style::default().fg(theme.muted)),
             Span::styled(format!("{:<status_w$}", "STATUS"), heading_style),
-            Span::styled(" | ", Style::default().fg(Color::DarkGray)),
+            Span::styled(" | ", Style::default().fg(theme.muted)),
             Span::styled(format!("{:<summary_w$}", "SUMMARY"), heading_style),
         ]));
         lines.push(Line::from(Span::styled(
-            format!("{}", "-".repeat(header_w)),
-            Style::default().fg(Color::DarkGray),
+            "-".repeat(header_w),
+            Style::default().fg(theme.muted),
         )));
         lines.push(Line::from(""));
     }
@@ -116,6 +105,7 @@ pub fn render(f: &mut ratatui::Frame, area: Rect, app: &App) {
         let indicator = if collapsed { ">" } else { "v" };
         let marker = group_marker(app.group_selection_state(&epic.key));
 
+        item_rows.push((lines.len(), item_idx));
         let is_header_selected = item_idx == app.selected_index;
         if is_header_selected {
             selected_visual_line = Some(lines.len());
@@ -143,12 +133,12 @@ pub fn render(f: &mut ratatui::Frame, area: Rect, app: &App) {
         let header_key_style = if is_header_selected {
             Style::default()
                 .add_modifier(Modifier::BOLD)
-                .bg(Color::DarkGray)
+                .patch(theme.selection())
         } else {
             Style::default().add_modifier(Modifier::BOLD)
         };
         let header_summary_style = if is_header_selected {
-            Style::default().bg(Color::DarkGray)
+            theme.selection()
         } else {
             Style::default()
         };
@@ -167,9 +157,9 @@ pub fn render(f: &mut ratatui::Frame, area: Rect, app: &App) {
             ),
         ]));
         let meta_style = if is_header_selected {
-            Style::default().fg(Color::Gray).bg(Color::DarkGray)
+            Style::default().fg(theme.subtle).patch(theme.selection())
         } else {
-            Style::default().fg(Color::DarkGray)
+            Style::default().fg(theme.muted)
         };
         lines.push(Line::from(vec![
             Span::raw("  "),
@@ -185,26 +175,27 @@ pub fn render(f: &mut ratatui::Frame, area: Rect, app: &App) {
         if children.is_empty() {
             lines.push(Line::from(Span::styled(
                 "    (no related tickets)",
-                Style::default().fg(Color::DarkGray),
+                Style::default().fg(theme.muted),
             )));
         } else {
             for ticket in children {
+                item_rows.push((lines.len(), item_idx));
                 let is_selected = item_idx == app.selected_index;
                 if is_selected {
                     selected_visual_line = Some(lines.len());
                 }
 
                 let base = if is_selected {
-                    Style::default().bg(Color::DarkGray)
+                    theme.selection()
                 } else {
                     Style::default()
                 };
                 let status_style = if is_selected {
                     Style::default()
-                        .fg(status_color(&ticket.status))
-                        .bg(Color::DarkGray)
+                        .fg(theme.status(&ticket.status))
+                        .patch(theme.selection())
                 } else {
-                    Style::default().fg(status_color(&ticket.status))
+                    Style::default().fg(theme.status(&ticket.status))
                 };
                 let marker = if app.is_ticket_selected(&ticket.key) {
                     "[x]"
@@ -243,7 +234,7 @@ pub fn render(f: &mut ratatui::Frame, area: Rect, app: &App) {
         } else if let Some(search) = app.search.as_ref().filter(|s| !s.is_empty()) {
             lines.push(Line::from(Span::styled(
                 format!("  No epics match \"{}\"", search),
-                Style::default().fg(Color::DarkGray),
+                Style::default().fg(theme.muted),
             )));
             ""
         } else {
@@ -253,7 +244,7 @@ pub fn render(f: &mut ratatui::Frame, area: Rect, app: &App) {
         if !empty_text.is_empty() {
             lines.push(Line::from(Span::styled(
                 empty_text,
-                Style::default().fg(Color::DarkGray),
+                Style::default().fg(theme.muted),
             )));
         }
     }
@@ -265,6 +256,8 @@ pub fn render(f: &mut ratatui::Frame, area: Rect, app: &App) {
         _ => 0,
     };
 
+    super::record_item_rows(app, area, scroll_y, &item_rows);
+
     let widget = Paragraph::new(lines)
         .block(Block::default().borders(Borders::ALL))
         .scroll((scroll_y, 0));
@@ -1,23 +1,9 @@
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
-use ratatui::style::{Color, Modifier, Style};
+use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, Paragraph};
 
-use crate::app::{App, FilterFocus};
-use crate::cache::Status;
-
-fn status_color(status: &Status) -> Color {
-    match status {
-        Status::NeedsTriage => Color::White,
-        Status::ReadyForWork => Color::Blue,
-        Status::InProgress => Color::Yellow,
-        Status::ToDo => Color::White,
-        Status::InReview => Color::Cyan,
-        Status::Blocked => Color::Red,
-        Status::Closed => Color::Green,
-        Status::Other(_) => Color::Magenta,
-    }
-}
+use crate::app::{App, ClickTarget, FilterFocus};
 
 fn truncate(s: &str, max: usize) -> String {
     if s.chars().count() > max {
@@ -44,11 +30,12 @@ fn render_sidebar(
     app: &App,
     config: &crate::config::AppConfig,
 ) {
+    let theme = crate::theme::current();
     let sidebar_focused = app.filter_focus == FilterFocus::Sidebar;
     let border_style = if sidebar_focused {
-        Style::default().fg(Color::Yellow)
+        Style::default().fg(theme.highlight)
     } else {
-        Style::default().fg(Color::DarkGray)
+        Style::default().fg(theme.muted)
     };
 
     let mut lines = Vec::new();
@@ -56,12 +43,12 @@ fn render_sidebar(
     if config.filters.is_empty() {
         lines.push(Line::from(Span::styled(
             "  No saved filters",
-            Style::default().fg(Color::DarkGray),
+            Style::default().fg(theme.muted),
         )));
         lines.push(Line::from(""));
         lines.push(Line::from(Span::styled(
             "  Press n to create one",
-            Style::default().fg(Color::DarkGray),
+            Style::default().fg(theme.muted),
         )));
     } else {
         for (i, filter) in config.filters.iter().enumerate() {
@@ -70,21 +57,30 @@ fn render_sidebar(
 
             let style = if is_selected && sidebar_focused {
                 Style::default()
-                    .fg(Color::Yellow)
+                    .fg(theme.highlight)
                     .add_modifier(Modifier::BOLD)
-                    .bg(Color::DarkGray)
+                    .patch(theme.selection())
             } else if is_selected {
-                Style::default()
-                    .fg(Color::White)
-                    .add_modifier(Modifier::BOLD)
+                Style::default().fg(theme.text).add_modifier(Modifier::BOLD)
             } else {
-                Style::default().fg(Color::Gray)
+                Style::default().fg(theme.subtle)
             };
 
             lines.push(Line::from(Span::styled(
                 format!("{}{}", prefix, filter.name),
                 style,
             )));
+            if (i as u16) < area.height.saturating_sub(2) {
+                app.add_click_target(
+                    Rect::new(
+                        area.x + 1,
+                        area.y + 1 + i as u16,
+                        area.width.saturating_sub(2),
+                        1,
+                    ),
+                    ClickTarget::Filter(i),
+                );
+            }
         }
     }
 
@@ -97,11 +93,12 @@ fn render_sidebar(
 }
 
 fn render_results(f: &mut ratatui::Frame, area: Rect, app: &App) {
+    let theme = crate::theme::current();
     let results_focused = app.filter_focus == FilterFocus::Results;
     let border_style = if results_focused {
-        Style::default().fg(Color::Yellow)
+        Style::default().fg(theme.highlight)
     } else {
-        Style::default().fg(Color::DarkGray)
+        Style::default().fg(theme.muted)
     };
 
     let mut lines = Vec::new();
@@ -109,12 +106,12 @@ fn render_results(f: &mut ratatui::Frame, area: Rect, app: &App) {
     if app.filter_loading {
         lines.push(Line::from(Span::styled(
             "  Loading...",
-            Style::default().fg(Color::Yellow),
+            Style::default().fg(theme.highlight),
         )));
     } else if app.filter_results.is_empty() {
         lines.push(Line::from(Span::styled(
             "  Select a filter and press Enter to run it",
-            Style::default().fg(Color::DarkGray),
+            Style::default().fg(theme.muted),
         )));
     } else {
         let key_w = 14usize;
@@ -124,21 +121,21 @@ fn render_results(f: &mut ratatui::Frame, area: Rect, app: &App) {
         let summary_w = inner.saturating_sub(fixed).max(12);
 
         let heading_style = Style::default()
-            .fg(Color::Gray)
+            .fg(theme.subtle)
             .add_modifier(Modifier::BOLD);
 
         lines.push(Line::from(vec![
             Span::styled(format!("  {:<key_w$}", "SEL KEY"), heading_style),
-            Span::styled(" | ", Style::default().fg(Color::DarkGray)),
+            Span::styled(" | ", Style::default().fg(theme.muted)),
             Span::styled(format!("{:<status_w$}", "STATUS"), heading_style),
-            Span::styled(" | ", Style::default().fg(Color::DarkGray)),
+            Span::styled(" | ", Style::default().fg(theme.muted)),
             Span::styled(format!("{:<summary_w$}", "SUMMARY"), heading_style),
         ]));
 
         let header_w = 2 + key_w + 3 + status_w + 3 + summary_w;
         lines.push(Line::from(Span::styled(
             "-".repeat(header_w),
-            Style::default().fg(Color::DarkGray),
+            Style::default().fg(theme.muted),
         )));
 
         for (i, ticket) in app.filter_results.iter().enumerate() {
@@ -150,17 +147,17 @@ fn render_results(f: &mut ratatui::Frame, area: Rect, app: &App) {
             };
 
             let base = if is_selected {
-                Style::default().bg(Color::DarkGray)
+                theme.selection()
             } else {
                 Style::default()
             };
 
             let status_style = if is_selected {
                 Style::default()
-                    .fg(status_color(&ticket.status))
-                    .bg(Color::DarkGray)
+                    .fg(theme.status(&ticket.status))
+                    .patch(theme.selection())
             } else {
-                Style::default().fg(status_color(&ticket.status))
+                Style::default().fg(theme.status(&ticket.status))
             };
 
             lines.push(Line::from(vec![
@@ -190,6 +187,11 @@ fn render_results(f: &mut ratatui::Frame, area: Rect, app: &App) {
         0
     };
 
+    if !app.filter_loading {
+        let rows: Vec<(usize, usize)> = (0..app.filter_results.len()).map(|i| (i + 2, i)).collect();
+        super::record_item_rows(app, area, scroll_y, &rows);
+    }
+
     let title = if app.filter_results.is_empty() {
         " Results ".to_string()
     } else {
@@ -1,5 +1,28 @@
+pub mod dashboard;
 pub mod epics;
 pub mod filters;
 pub mod my_work;
 pub mod team;
 pub mod unassigned;
+pub mod watching;
+
+use ratatui::layout::Rect;
+
+use crate::app::{App, ClickTarget};
+
+/// Register the rows of a bordered, scrolled list that are on screen as click targets.
+/// `rows` pairs each row's line in the paragraph with its selectable item index.
+pub fn record_item_rows(app: &App, area: Rect, scroll_y: u16, rows: &[(usize, usize)]) {
+    let inner = area.inner(ratatui::layout::Margin::new(1, 1));
+    for &(line, item) in rows {
+        let Some(offset) = line.checked_sub(scroll_y as usize) else {
+            continue;
+        };
+        if offset < inner.height as usize {
+            app.add_click_target(
+                Rect::new(inner.x, inner.y + offset as u16, inner.width, 1),
+                ClickTarget::Item(item),
+            );
+        }
+    }
+}
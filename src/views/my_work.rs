@@ -1,23 +1,10 @@
 use ratatui::layout::Rect;
-use ratatui::style::{Color, Modifier, Style};
+use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, Paragraph};
 
 use crate::app::{App, GroupSelectionState, Tab};
-use crate::cache::Status;
-
-fn status_color(status: &Status) -> Color {
-    match status {
-        Status::NeedsTriage => Color::White,
-        Status::ReadyForWork => Color::Blue,
-        Status::InProgress => Color::Yellow,
-        Status::ToDo => Color::White,
-        Status::InReview => Color::Cyan,
-        Status::Blocked => Color::Red,
-        Status::Closed => Color::Green,
-        Status::Other(_) => Color::Magenta,
-    }
-}
+use crate::cache::{Status, Ticket};
 
 fn truncate(s: &str, max: usize) -> String {
     if s.chars().count() > max {
@@ -77,32 +64,44 @@ fn my_work_column_widths(area: Rect) -> (usize, usize, usize, usize) {
 }
 
 pub fn render(f: &mut ratatui::Frame, area: Rect, app: &App) {
-    let grouped = app.my_work_visible_by_status();
+    render_status_groups(f, area, app, Tab::MyWork, &app.my_work_visible_by_status());
+}
+
+/// Render tickets grouped under collapsible status headers. Shared with the Watching tab.
+pub fn render_status_groups(
+    f: &mut ratatui::Frame,
+    area: Rect,
+    app: &App,
+    tab: Tab,
+    grouped: &[(&Status, Vec<&Ticket>)],
+) {
+    let theme = crate::theme::current();
     let (key_w, summary_w, epic_w, labels_w) = my_work_column_widths(area);
     let heading_style = Style::default()
-        .fg(Color::Gray)
+        .fg(theme.subtle)
         .add_modifier(Modifier::BOLD);
 
     let mut lines: Vec<Line> = Vec::new();
     let mut item_idx: usize = 0;
     let mut selected_visual_line: Option<usize> = None;
+    let mut item_rows: Vec<(usize, usize)> = Vec::new();
     let mut has_rows = false;
 
-    for (status, tickets) in &grouped {
+    for (status, tickets) in grouped {
         if !has_rows {
             let header_w = 2 + key_w + 3 + summary_w + 3 + epic_w + 3 + labels_w;
             lines.push(Line::from(vec![
                 Span::styled(format!("  {:<key_w$}", "SEL KEY"), heading_style),
-                Span::styled(" | ", Style::default().fg(Color::DarkGray)),
+                Span::styled(" | ", Style::default().fg(theme.muted)),
                 Span::styled(format!("{:<summary_w$}", "SUMMARY"), heading_style),
-                Span::styled(" | ", Style::default().fg(Color::DarkGray)),
+                Span::styled(" | ", Style::default().fg(theme.muted)),
                 Span::styled(format!("{:<epic_w$}", "EPIC"), heading_style),
-                Span::styled(" | ", Style::default().fg(Color::DarkGray)),
+                Span::styled(" | ", Style::default().fg(theme.muted)),
                 Span::styled(format!("{:<labels_w$}", "LABELS"), heading_style),
             ]));
             lines.push(Line::from(Span::styled(
-                format!("{}", "-".repeat(header_w)),
-                Style::default().fg(Color::DarkGray),
+                "-".repeat(header_w),
+                Style::default().fg(theme.muted),
             )));
             lines.push(Line::from(""));
         }
@@ -110,11 +109,12 @@ pub fn render(f: &mut ratatui::Frame, area: Rect, app: &App) {
         let total_count = tickets.len();
         has_rows = true;
 
-        let collapsed = app.is_collapsed(Tab::MyWork, status.as_str());
+        let collapsed = app.is_collapsed(tab, status.as_str());
         let indicator = if collapsed { ">" } else { "v" };
         let marker = group_marker(app.group_selection_state(status.as_str()));
 
         // Status header
+        item_rows.push((lines.len(), item_idx));
         let is_header_selected = item_idx == app.selected_index;
         if is_header_selected {
             selected_visual_line = Some(lines.len());
@@ -128,12 +128,12 @@ pub fn render(f: &mut ratatui::Frame, area: Rect, app: &App) {
         );
         let header_style = if is_header_selected {
             Style::default()
-                .fg(status_color(status))
+                .fg(theme.status(status))
                 .add_modifier(Modifier::BOLD)
-                .bg(Color::DarkGray)
+                .patch(theme.selection())
         } else {
             Style::default()
-                .fg(status_color(status))
+                .fg(theme.status(status))
                 .add_modifier(Modifier::BOLD)
         };
         lines.push(Line::from(Span::styled(header, header_style)));
@@ -146,13 +146,14 @@ pub fn render(f: &mut ratatui::Frame, area: Rect, app: &App) {
 
         // Ticket rows
         for ticket in tickets {
+            item_rows.push((lines.len(), item_idx));
             let is_selected = item_idx == app.selected_index;
             if is_selected {
                 selected_visual_line = Some(lines.len());
             }
 
             let base = if is_selected {
-                Style::default().bg(Color::DarkGray)
+                theme.selection()
             } else {
                 Style::default()
             };
@@ -183,18 +184,20 @@ pub fn render(f: &mut ratatui::Frame, area: Rect, app: &App) {
                 Span::styled(
                     format!("{:<epic_w$}", truncate(epic_str, epic_w)),
                     if is_selected {
-                        Style::default().fg(Color::Gray).bg(Color::DarkGray)
+                        Style::default().fg(theme.subtle).patch(theme.selection())
                     } else {
-                        Style::default().fg(Color::DarkGray)
+                        Style::default().fg(theme.muted)
                     },
                 ),
                 Span::styled(" | ", base),
                 Span::styled(
                     format!("{:<labels_w$}", truncate(&labels_str, labels_w)),
                     if is_selected {
-                        Style::default().fg(Color::Yellow).bg(Color::DarkGray)
+                        Style::default()
+                            .fg(theme.highlight)
+                            .patch(theme.selection())
                     } else {
-                        Style::default().fg(Color::DarkGray)
+                        Style::default().fg(theme.muted)
                     },
                 ),
             ]));
@@ -209,7 +212,7 @@ pub fn render(f: &mut ratatui::Frame, area: Rect, app: &App) {
     if lines.is_empty() {
         lines.push(Line::from(Span::styled(
             "  No tickets found",
-            Style::default().fg(Color::DarkGray),
+            Style::default().fg(theme.muted),
         )));
     }
 
@@ -220,6 +223,8 @@ pub fn render(f: &mut ratatui::Frame, area: Rect, app: &App) {
         _ => 0,
     };
 
+    super::record_item_rows(app, area, scroll_y, &item_rows);
+
     let widget = Paragraph::new(lines)
         .block(Block::default().borders(Borders::ALL))
         .scroll((scroll_y, 0));
@@ -1,23 +1,12 @@
 use ratatui::layout::Rect;
-use ratatui::style::{Color, Modifier, Style};
+use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, Paragraph};
 
 use crate::app::{App, GroupSelectionState, Tab};
-use crate::cache::Status;
-
-fn status_color(status: &Status) -> Color {
-    match status {
-        Status::NeedsTriage => Color::White,
-        Status::ReadyForWork => Color::Blue,
-        Status::InProgress => Color::Yellow,
-        Status::ToDo => Color::White,
-        Status::InReview => Color::Cyan,
-        Status::Blocked => Color::Red,
-        Status::Closed => Color::Green,
-        Status::Other(_) => Color::Magenta,
-    }
-}
+
+/// Appended to the status of To Do tickets that already have commits.
+const COMMITTED_MARK: &str = "[git]";
 
 fn truncate(s: &str, max: usize) -> String {
     if s.chars().count() > max {
@@ -73,15 +62,17 @@ fn team_column_widths(area: Rect) -> (usize, usize, usize, usize, usize) {
 }
 
 pub fn render(f: &mut ratatui::Frame, area: Rect, app: &App) {
+    let theme = crate::theme::current();
     let members = app.team_visible_tickets_by_member();
     let (key_w, status_w, summary_w, epic_w, labels_w) = team_column_widths(area);
     let heading_style = Style::default()
-        .fg(Color::Gray)
+        .fg(theme.subtle)
         .add_modifier(Modifier::BOLD);
 
     let mut lines: Vec<Line> = Vec::new();
     let mut item_idx: usize = 0;
     let mut selected_visual_line: Option<usize> = None;
+    let mut item_rows: Vec<(usize, usize)> = Vec::new();
 
     for (member, active, done) in members {
         let collapsed = app.is_collapsed(Tab::Team, &member.email);
@@ -89,6 +80,7 @@ pub fn render(f: &mut ratatui::Frame, area: Rect, app: &App) {
         let marker = group_marker(app.group_selection_state(&member.email));
 
         // Member header
+        item_rows.push((lines.len(), item_idx));
         let is_header_selected = item_idx == app.selected_index;
         if is_header_selected {
             selected_visual_line = Some(lines.len());
@@ -96,16 +88,16 @@ pub fn render(f: &mut ratatui::Frame, area: Rect, app: &App) {
         let header_style = if is_header_selected {
             Style::default()
                 .add_modifier(Modifier::BOLD)
-                .bg(Color::DarkGray)
+                .patch(theme.selection())
         } else {
             Style::default().add_modifier(Modifier::BOLD)
         };
 
         if collapsed {
             let summary_style = if is_header_selected {
-                Style::default().fg(Color::Gray).bg(Color::DarkGray)
+                Style::default().fg(theme.subtle).patch(theme.selection())
             } else {
-                Style::default().fg(Color::DarkGray)
+                Style::default().fg(theme.muted)
             };
             lines.push(Line::from(vec![
                 Span::styled(
@@ -131,36 +123,37 @@ pub fn render(f: &mut ratatui::Frame, area: Rect, app: &App) {
         if active.is_empty() && done.is_empty() {
             lines.push(Line::from(Span::styled(
                 "  (no tickets)",
-                Style::default().fg(Color::DarkGray),
+                Style::default().fg(theme.muted),
             )));
         } else {
             lines.push(Line::from(vec![
                 Span::styled(format!("  {:<key_w$}", "SEL KEY"), heading_style),
-                Span::styled(" | ", Style::default().fg(Color::DarkGray)),
+                Span::styled(" | ", Style::default().fg(theme.muted)),
                 Span::styled(format!("{:<status_w$}", "STATUS"), heading_style),
-                Span::styled(" | ", Style::default().fg(Color::DarkGray)),
+                Span::styled(" | ", Style::default().fg(theme.muted)),
                 Span::styled(format!("{:<summary_w$}", "SUMMARY"), heading_style),
-                Span::styled(" | ", Style::default().fg(Color::DarkGray)),
+                Span::styled(" | ", Style::default().fg(theme.muted)),
                 Span::styled(format!("{:<epic_w$}", "EPIC"), heading_style),
-                Span::styled(" | ", Style::default().fg(Color::DarkGray)),
+                Span::styled(" | ", Style::default().fg(theme.muted)),
                 Span::styled(format!("{:<labels_w$}", "LABELS"), heading_style),
             ]));
 
             for ticket in &active {
+                item_rows.push((lines.len(), item_idx));
                 let is_selected = item_idx == app.selected_index;
                 if is_selected {
                     selected_visual_line = Some(lines.len());
                 }
 
                 let base = if is_selected {
-                    Style::default().bg(Color::DarkGray)
+                    theme.selection()
                 } else {
                     Style::default()
                 };
 
-                let status_fg = status_color(&ticket.status);
+                let status_fg = theme.status(&ticket.status);
                 let colored = if is_selected {
-                    Style::default().fg(status_fg).bg(Color::DarkGray)
+                    Style::default().fg(status_fg).patch(theme.selection())
                 } else {
                     Style::default().fg(status_fg)
                 };
@@ -175,6 +168,15 @@ pub fn render(f: &mut ratatui::Frame, area: Rect, app: &App) {
                 } else {
                     "[ ]"
                 };
+                // Committed work on a ticket nobody has started
+                let (status_str, status_style) = if app.has_commits_but_not_started(ticket) {
+                    (
+                        format!("{} {}", ticket.status.as_str(), COMMITTED_MARK),
+                        colored.fg(theme.special).add_modifier(Modifier::BOLD),
+                    )
+                } else {
+                    (ticket.status.as_str().to_string(), colored)
+                };
 
                 lines.push(Line::from(vec![
                     Span::styled(
@@ -182,7 +184,7 @@ pub fn render(f: &mut ratatui::Frame, area: Rect, app: &App) {
                         base,
                     ),
                     Span::styled(" | ", base),
-                    Span::styled(format!("{:<status_w$}", ticket.status.as_str()), colored),
+                    Span::styled(format!("{:<status_w$}", status_str), status_style),
                     Span::styled(" | ", base),
                     Span::styled(
                         format!("{:<summary_w$}", truncate(&ticket.summary, summary_w)),
@@ -192,18 +194,20 @@ pub fn render(f: &mut ratatui::Frame, area: Rect, app: &App) {
                     Span::styled(
                         format!("{:<epic_w$}", truncate(epic_str, epic_w)),
                         if is_selected {
-                            Style::default().fg(Color::Gray).bg(Color::DarkGray)
+                            Style::default().fg(theme.subtle).patch(theme.selection())
                         } else {
-                            Style::default().fg(Color::DarkGray)
+                            Style::default().fg(theme.muted)
                         },
                     ),
                     Span::styled(" | ", base),
                     Span::styled(
                         format!("{:<labels_w$}", truncate(&labels_str, labels_w)),
                         if is_selected {
-                            Style::default().fg(Color::Yellow).bg(Color::DarkGray)
+                            Style::default()
+                                .fg(theme.highlight)
+                                .patch(theme.selection())
                         } else {
-                            Style::default().fg(Color::DarkGray)
+                            Style::default().fg(theme.muted)
                         },
                     ),
                 ]));
@@ -214,27 +218,26 @@ pub fn render(f: &mut ratatui::Frame, area: Rect, app: &App) {
             if !done.is_empty() {
                 lines.push(Line::from(Span::styled(
                     format!("    done ({})", done.len()),
-                    Style::default()
-                        .fg(Color::DarkGray)
-                        .add_modifier(Modifier::DIM),
+                    Style::default().fg(theme.muted).add_modifier(Modifier::DIM),
                 )));
             }
 
             for ticket in &done {
+                item_rows.push((lines.len(), item_idx));
                 let is_selected = item_idx == app.selected_index;
                 if is_selected {
                     selected_visual_line = Some(lines.len());
                 }
 
                 let base = if is_selected {
-                    Style::default().bg(Color::DarkGray)
+                    theme.selection()
                 } else {
                     Style::default().add_modifier(Modifier::DIM)
                 };
 
-                let status_fg = status_color(&ticket.status);
+                let status_fg = theme.status(&ticket.status);
                 let colored = if is_selected {
-                    Style::default().fg(status_fg).bg(Color::DarkGray)
+                    Style::default().fg(status_fg).patch(theme.selection())
                 } else {
                     Style::default().fg(status_fg).add_modifier(Modifier::DIM)
                 };
@@ -266,22 +269,20 @@ pub fn render(f: &mut ratatui::Frame, area: Rect, app: &App) {
                     Span::styled(
                         format!("{:<epic_w$}", truncate(epic_str, epic_w)),
                         if is_selected {
-                            Style::default().fg(Color::Gray).bg(Color::DarkGray)
+                            Style::default().fg(theme.subtle).patch(theme.selection())
                         } else {
-                            Style::default()
-                                .fg(Color::DarkGray)
-                                .add_modifier(Modifier::DIM)
+                            Style::default().fg(theme.muted).add_modifier(Modifier::DIM)
                         },
                     ),
                     Span::styled(" | ", base),
                     Span::styled(
                         format!("{:<labels_w$}", truncate(&labels_str, labels_w)),
                         if is_selected {
-                            Style::default().fg(Color::Yellow).bg(Color::DarkGray)
-                        } else {
                             Style::default()
-                                .fg(Color::DarkGray)
-                                .add_modifier(Modifier::DIM)
+                                .fg(theme.highlight)
+                                .patch(theme.selection())
+                        } else {
+                            Style::default().fg(theme.muted).add_modifier(Modifier::DIM)
                         },
                     ),
                 ]));
@@ -293,7 +294,7 @@ pub fn render(f: &mut ratatui::Frame, area: Rect, app: &App) {
                 Span::raw("  "),
                 Span::styled(
                     format!("active: {}  done: {}", active.len(), done.len()),
-                    Style::default().fg(Color::DarkGray),
+                    Style::default().fg(theme.muted),
                 ),
             ]));
         }
@@ -305,7 +306,7 @@ pub fn render(f: &mut ratatui::Frame, area: Rect, app: &App) {
     if lines.is_empty() {
         lines.push(Line::from(Span::styled(
             "  No team members",
-            Style::default().fg(Color::DarkGray),
+            Style::default().fg(theme.muted),
         )));
     }
 
@@ -316,6 +317,8 @@ pub fn render(f: &mut ratatui::Frame, area: Rect, app: &App) {
         _ => 0,
     };
 
+    super::record_item_rows(app, area, scroll_y, &item_rows);
+
     let widget = Paragraph::new(lines)
         .block(Block::default().borders(Borders::ALL))
         .scroll((scroll_y, 0));
@@ -0,0 +1,13 @@
+use ratatui::layout::Rect;
+
+use crate::app::{App, Tab};
+
+pub fn render(f: &mut ratatui::Frame, area: Rect, app: &App) {
+    super::my_work::render_status_groups(
+        f,
+        area,
+        app,
+        Tab::Watching,
+        &app.watching_visible_by_status(),
+    );
+}
@@ -1,5 +1,5 @@
 use ratatui::layout::Rect;
-use ratatui::style::{Color, Modifier, Style};
+use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{Paragraph, Wrap};
 
@@ -15,12 +15,13 @@ fn format_timestamp(ts: &str) -> String {
 }
 
 pub fn render(f: &mut ratatui::Frame, area: Rect, entries: &[ActivityEntry], scroll: u16) {
+    let theme = crate::theme::current();
     let mut lines = Vec::new();
 
     lines.push(Line::from(Span::styled(
         "Activity History",
         Style::default()
-            .fg(Color::Cyan)
+            .fg(theme.accent)
             .add_modifier(Modifier::BOLD),
     )));
     lines.push(Line::from(""));
@@ -28,7 +29,7 @@ pub fn render(f: &mut ratatui::Frame, area: Rect, entries: &[ActivityEntry], scr
     if entries.is_empty() {
         lines.push(Line::from(Span::styled(
             "(no activity found -- open the ticket once to load history)",
-            Style::default().fg(Color::DarkGray),
+            Style::default().fg(theme.muted),
         )));
     }
 
@@ -52,19 +53,19 @@ pub fn render(f: &mut ratatui::Frame, area: Rect, entries: &[ActivityEntry], scr
         };
 
         lines.push(Line::from(vec![
-            Span::styled(format!("{:<17}", ts), Style::default().fg(Color::DarkGray)),
+            Span::styled(format!("{:<17}", ts), Style::default().fg(theme.muted)),
             Span::styled(
                 format!("{:<20}", entry.author),
-                Style::default().fg(Color::White),
+                Style::default().fg(theme.text),
             ),
-            Span::styled(detail, Style::default().fg(Color::Gray)),
+            Span::styled(detail, Style::default().fg(theme.subtle)),
         ]));
     }
 
     lines.push(Line::from(""));
     lines.push(Line::from(Span::styled(
         "[Up/Down] scroll  [Esc] back to detail",
-        Style::default().fg(Color::DarkGray),
+        Style::default().fg(theme.muted),
     )));
 
     let widget = Paragraph::new(lines)
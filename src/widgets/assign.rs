@@ -1,4 +1,4 @@
-use ratatui::style::{Color, Modifier, Style};
+use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::Paragraph;
 
@@ -6,6 +6,7 @@ use super::form;
 use crate::app::App;
 
 pub fn render(f: &mut ratatui::Frame, app: &App) {
+    let theme = crate::theme::current();
     let state = match &app.assign_state {
         Some(s) => s,
         None => return,
@@ -21,10 +22,10 @@ pub fn render(f: &mut ratatui::Frame, app: &App) {
         let prefix = if i == state.selected { "> " } else { "  " };
         let style = if i == state.selected {
             Style::default()
-                .fg(Color::Yellow)
+                .fg(theme.highlight)
                 .add_modifier(Modifier::BOLD)
         } else {
-            Style::default().fg(Color::Gray)
+            Style::default().fg(theme.subtle)
         };
         lines.push(Line::from(Span::styled(
             format!("  {}{} ({})", prefix, member.name, member.email),
@@ -37,8 +38,11 @@ pub fn render(f: &mut ratatui::Frame, app: &App) {
 
     // Footer hints
     lines.push(Line::from(Span::styled(
-        "[j/k] navigate  [Enter] assign  [Esc] cancel",
-        Style::default().fg(Color::DarkGray),
+        format!(
+            "[{}] navigate  [Enter] assign  [Esc] cancel",
+            app.keymap.picker_hint()
+        ),
+        Style::default().fg(theme.muted),
     )));
 
     let body = Paragraph::new(lines);
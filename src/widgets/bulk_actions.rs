@@ -1,20 +1,22 @@
-use ratatui::style::{Color, Modifier, Style};
+use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::Paragraph;
 
-use crate::app::{App, BulkAction, BulkState, BulkSummary, BulkTarget};
+use crate::app::{App, BulkAction, BulkPreviewRow, BulkState, BulkSummary, PRIORITIES};
 use crate::cache::Status;
+use crate::config::AppConfig;
 
 use super::form;
 
 fn render_option(lines: &mut Vec<Line>, label: &str, selected: bool) {
+    let theme = crate::theme::current();
     let prefix = if selected { "> " } else { "  " };
     let style = if selected {
         Style::default()
-            .fg(Color::Yellow)
+            .fg(theme.highlight)
             .add_modifier(Modifier::BOLD)
     } else {
-        Style::default().fg(Color::Gray)
+        Style::default().fg(theme.subtle)
     };
     lines.push(Line::from(Span::styled(
         format!("{}{}", prefix, label),
@@ -22,19 +24,6 @@ fn render_option(lines: &mut Vec<Line>, label: &str, selected: bool) {
     )));
 }
 
-fn target_label(target: &BulkTarget) -> String {
-    match target {
-        BulkTarget::Move { status, resolution } => match resolution {
-            Some(resolution) => format!("Move to {} (resolution: {})", status.as_str(), resolution),
-            None => format!("Move to {}", status.as_str()),
-        },
-        BulkTarget::Assign {
-            member_name,
-            member_email,
-        } => format!("Assign to {} ({})", member_name, member_email),
-    }
-}
-
 fn sample_keys(targets: &[String]) -> String {
     if targets.is_empty() {
         return "(none)".to_string();
@@ -54,21 +43,15 @@ fn sample_keys(targets: &[String]) -> String {
 }
 
 fn render_result(lines: &mut Vec<Line>, summary: &BulkSummary) {
-    let action = match summary.action {
-        BulkAction::Move => "Bulk Move",
-        BulkAction::Assign => "Bulk Assign",
-    };
+    let theme = crate::theme::current();
     lines.push(Line::from(Span::styled(
-        format!("{} complete", action),
+        format!("Bulk action complete: {}", summary.action.label()),
         Style::default()
-            .fg(Color::Green)
+            .fg(theme.success)
             .add_modifier(Modifier::BOLD),
     )));
     lines.push(Line::from(""));
-    lines.push(Line::from(format!(
-        "Target: {}",
-        target_label(&summary.target)
-    )));
+    lines.push(Line::from(format!("Target: {}", summary.target.describe())));
     lines.push(Line::from(format!("Total: {}", summary.total)));
     lines.push(Line::from(format!("Attempted: {}", summary.attempted)));
     lines.push(Line::from(format!("Succeeded: {}", summary.succeeded)));
@@ -79,12 +62,14 @@ fn render_result(lines: &mut Vec<Line>, summary: &BulkSummary) {
         lines.push(Line::from(""));
         lines.push(Line::from(Span::styled(
             "Failures:",
-            Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+            Style::default()
+                .fg(theme.error)
+                .add_modifier(Modifier::BOLD),
         )));
         for (key, err) in summary.failed_details.iter().take(4) {
             lines.push(Line::from(Span::styled(
                 format!("  {}: {}", key, err),
-                Style::default().fg(Color::Red),
+                Style::default().fg(theme.error),
             )));
         }
         if summary.failed_details.len() > 4 {
@@ -93,19 +78,74 @@ fn render_result(lines: &mut Vec<Line>, summary: &BulkSummary) {
                     "  ... and {} more",
                     summary.failed_details.len().saturating_sub(4)
                 ),
-                Style::default().fg(Color::Red),
+                Style::default().fg(theme.error),
             )));
         }
     }
 
+    lines.push(Line::from(""));
+    let hint = if summary.failed_details.is_empty() {
+        "[c] export CSV  [J] export JSON  [Enter/Esc] close"
+    } else {
+        "[f] retry failed  [c] export CSV  [J] export JSON  [Enter/Esc] close"
+    };
+    lines.push(Line::from(Span::styled(
+        hint,
+        Style::default().fg(theme.muted),
+    )));
+}
+
+fn render_dry_run(
+    lines: &mut Vec<Line>,
+    rows: &[BulkPreviewRow],
+    selected: usize,
+    height: u16,
+    nav: &str,
+) {
+    let theme = crate::theme::current();
+    let skips = rows.iter().filter(|r| r.skip.is_some()).count();
+    lines.push(Line::from(format!(
+        "Tickets: {}  Will change: {}  Skipped: {}",
+        rows.len(),
+        rows.len() - skips,
+        skips
+    )));
+    lines.push(Line::from(""));
+
+    // Keep the selected row visible; header and footer take six lines.
+    let visible = (height as usize).saturating_sub(6).max(1);
+    let start = selected.saturating_sub(visible - 1);
+    for (i, row) in rows.iter().enumerate().skip(start).take(visible) {
+        let prefix = if i == selected { "> " } else { "  " };
+        let (change, style) = match &row.skip {
+            Some(reason) => (
+                format!("skip ({})", reason),
+                Style::default().fg(theme.muted),
+            ),
+            None => (row.planned.clone(), Style::default().fg(theme.subtle)),
+        };
+        let style = if i == selected {
+            style.add_modifier(Modifier::BOLD)
+        } else {
+            style
+        };
+        lines.push(Line::from(Span::styled(
+            format!("{}{:<12} {} -> {}", prefix, row.key, row.current, change),
+            style,
+        )));
+    }
+
     lines.push(Line::from(""));
     lines.push(Line::from(Span::styled(
-        "[Enter/Esc] close",
-        Style::default().fg(Color::DarkGray),
+        format!("[{}] scroll  [Enter/y] run  [Esc] back", nav),
+        Style::default().fg(theme.muted),
     )));
 }
 
-pub fn render(f: &mut ratatui::Frame, app: &App, resolutions: &[String]) {
+pub fn render(f: &mut ratatui::Frame, app: &App, config: &AppConfig) {
+    let theme = crate::theme::current();
+    let nav = app.keymap.picker_hint();
+    let resolutions = &config.resolutions;
     let Some(state) = app.bulk_state.as_ref() else {
         return;
     };
@@ -113,6 +153,7 @@ pub fn render(f: &mut ratatui::Frame, app: &App, resolutions: &[String]) {
     let (title, percent_x, percent_y) = match state {
         BulkState::Result { .. } => ("Bulk Results", 72, 62),
         BulkState::Confirm { .. } => ("Confirm Bulk Action", 72, 58),
+        BulkState::DryRun { .. } => ("Bulk Dry Run", 80, 70),
         BulkState::Running { .. } => ("Bulk Action Running", 60, 34),
         _ => ("Bulk Actions", 58, 54),
     };
@@ -126,12 +167,13 @@ pub fn render(f: &mut ratatui::Frame, app: &App, resolutions: &[String]) {
             lines.push(Line::from(format!("Selected tickets: {}", targets.len())));
             lines.push(Line::from(format!("Keys: {}", sample_keys(targets))));
             lines.push(Line::from(""));
-            render_option(&mut lines, "Move tickets", *selected == 0);
-            render_option(&mut lines, "Assign tickets", *selected == 1);
+            for (i, action) in BulkAction::MENU.iter().enumerate() {
+                render_option(&mut lines, action.label(), i == *selected);
+            }
             lines.push(Line::from(""));
             lines.push(Line::from(Span::styled(
-                "[j/k] choose  [Enter] next  [Esc] cancel",
-                Style::default().fg(Color::DarkGray),
+                format!("[{}] choose  [Enter] next  [Esc] cancel", nav),
+                Style::default().fg(theme.muted),
             )));
         }
         BulkState::MoveStatusPicker { targets, selected } => {
@@ -142,8 +184,8 @@ pub fn render(f: &mut ratatui::Frame, app: &App, resolutions: &[String]) {
             }
             lines.push(Line::from(""));
             lines.push(Line::from(Span::styled(
-                "[j/k] choose status  [Enter] next  [Esc] cancel",
-                Style::default().fg(Color::DarkGray),
+                format!("[{}] choose status  [Enter] next  [Esc] cancel", nav),
+                Style::default().fg(theme.muted),
             )));
         }
         BulkState::MoveResolutionPicker {
@@ -157,7 +199,7 @@ pub fn render(f: &mut ratatui::Frame, app: &App, resolutions: &[String]) {
             if resolutions.is_empty() {
                 lines.push(Line::from(Span::styled(
                     "No configured resolutions. Press Enter to continue.",
-                    Style::default().fg(Color::DarkGray),
+                    Style::default().fg(theme.muted),
                 )));
             } else {
                 for (i, resolution) in resolutions.iter().enumerate() {
@@ -166,8 +208,8 @@ pub fn render(f: &mut ratatui::Frame, app: &App, resolutions: &[String]) {
             }
             lines.push(Line::from(""));
             lines.push(Line::from(Span::styled(
-                "[j/k] choose resolution  [Enter] next  [Esc] cancel",
-                Style::default().fg(Color::DarkGray),
+                format!("[{}] choose resolution  [Enter] next  [Esc] cancel", nav),
+                Style::default().fg(theme.muted),
             )));
         }
         BulkState::AssignPicker { targets, selected } => {
@@ -183,48 +225,167 @@ pub fn render(f: &mut ratatui::Frame, app: &App, resolutions: &[String]) {
             if app.cache.team_members.is_empty() {
                 lines.push(Line::from(Span::styled(
                     "No team members configured",
-                    Style::default().fg(Color::DarkGray),
+                    Style::default().fg(theme.muted),
                 )));
             }
             lines.push(Line::from(""));
             lines.push(Line::from(Span::styled(
-                "[j/k] choose assignee  [Enter] next  [Esc] cancel",
-                Style::default().fg(Color::DarkGray),
+                format!("[{}] choose assignee  [Enter] next  [Esc] cancel", nav),
+                Style::default().fg(theme.muted),
+            )));
+        }
+        BulkState::EpicPicker { targets, selected } => {
+            lines.push(Line::from(format!("Tickets: {}", targets.len())));
+            lines.push(Line::from(""));
+            render_option(&mut lines, "None (remove from epic)", *selected == 0);
+            for (i, epic) in app.cache.epics.iter().enumerate() {
+                render_option(
+                    &mut lines,
+                    &format!("{} {}", epic.key, epic.summary),
+                    i + 1 == *selected,
+                );
+            }
+            lines.push(Line::from(""));
+            lines.push(Line::from(Span::styled(
+                format!("[{}] choose epic  [Enter] next  [Esc] cancel", nav),
+                Style::default().fg(theme.muted),
+            )));
+        }
+        BulkState::LabelsInput {
+            targets,
+            remove,
+            input,
+        } => {
+            lines.push(Line::from(format!("Tickets: {}", targets.len())));
+            lines.push(Line::from(""));
+            let label = if *remove {
+                "Labels to remove"
+            } else {
+                "Labels to add"
+            };
+            form::render_text_input(&mut lines, label, input, true);
+            lines.push(Line::from(""));
+            lines.push(Line::from(Span::styled(
+                "Separate labels with commas or spaces. Tickets already matching are skipped.",
+                Style::default().fg(theme.muted),
+            )));
+            lines.push(Line::from(Span::styled(
+                "[Enter] next  [Esc] cancel",
+                Style::default().fg(theme.muted),
+            )));
+        }
+        BulkState::PriorityPicker { targets, selected } => {
+            lines.push(Line::from(format!("Tickets: {}", targets.len())));
+            lines.push(Line::from(""));
+            for (i, priority) in PRIORITIES.iter().enumerate() {
+                render_option(&mut lines, priority, i == *selected);
+            }
+            lines.push(Line::from(""));
+            lines.push(Line::from(Span::styled(
+                format!("[{}] choose priority  [Enter] next  [Esc] cancel", nav),
+                Style::default().fg(theme.muted),
+            )));
+        }
+        BulkState::CommentTemplatePicker { targets, selected } => {
+            lines.push(Line::from(format!("Tickets: {}", targets.len())));
+            lines.push(Line::from(""));
+            render_option(&mut lines, "Blank comment", *selected == 0);
+            for (i, template) in config.comment_templates.iter().enumerate() {
+                render_option(&mut lines, &template.name, i + 1 == *selected);
+            }
+            lines.push(Line::from(""));
+            lines.push(Line::from(Span::styled(
+                format!("[{}] choose template  [Enter] next  [Esc] cancel", nav),
+                Style::default().fg(theme.muted),
+            )));
+        }
+        BulkState::CommentInput { targets, body } => {
+            lines.push(Line::from(format!("Tickets: {}", targets.len())));
+            lines.push(Line::from(""));
+            form::render_multiline_input(&mut lines, "Comment", body, true);
+            lines.push(Line::from(""));
+            lines.push(Line::from(Span::styled(
+                "Placeholders: {key} {summary} {status} {assignee}",
+                Style::default().fg(theme.muted),
+            )));
+            lines.push(Line::from(Span::styled(
+                "[Enter] next  [Esc] cancel",
+                Style::default().fg(theme.muted),
+            )));
+        }
+        BulkState::CustomFieldPicker { targets, selected } => {
+            lines.push(Line::from(format!("Tickets: {}", targets.len())));
+            lines.push(Line::from(""));
+            for (i, (name, field_id)) in config.custom_fields.iter().enumerate() {
+                render_option(
+                    &mut lines,
+                    &format!("{} ({})", name, field_id),
+                    i == *selected,
+                );
+            }
+            lines.push(Line::from(""));
+            lines.push(Line::from(Span::styled(
+                format!("[{}] choose field  [Enter] next  [Esc] cancel", nav),
+                Style::default().fg(theme.muted),
+            )));
+        }
+        BulkState::CustomFieldInput {
+            targets,
+            name,
+            value,
+            ..
+        } => {
+            lines.push(Line::from(format!("Tickets: {}", targets.len())));
+            lines.push(Line::from(""));
+            form::render_text_input(&mut lines, name, value, true);
+            lines.push(Line::from(""));
+            lines.push(Line::from(Span::styled(
+                "[Enter] next  [Esc] cancel",
+                Style::default().fg(theme.muted),
             )));
         }
         BulkState::Confirm { targets, target } => {
             lines.push(Line::from(Span::styled(
                 "Please confirm",
                 Style::default()
-                    .fg(Color::Yellow)
+                    .fg(theme.highlight)
                     .add_modifier(Modifier::BOLD),
             )));
             lines.push(Line::from(""));
-            lines.push(Line::from(format!("Action: {}", target_label(target))));
+            lines.push(Line::from(format!("Action: {}", target.describe())));
             lines.push(Line::from(format!("Tickets: {}", targets.len())));
             lines.push(Line::from(format!("Keys: {}", sample_keys(targets))));
             lines.push(Line::from(""));
             lines.push(Line::from(Span::styled(
-                "[Enter/y] run  [Esc] cancel",
-                Style::default().fg(Color::DarkGray),
+                "[Enter/y] run  [d] dry run  [Esc] cancel",
+                Style::default().fg(theme.muted),
             )));
         }
         BulkState::Running { targets, target } => {
             lines.push(Line::from(Span::styled(
                 "Executing bulk action...",
                 Style::default()
-                    .fg(Color::Yellow)
+                    .fg(theme.highlight)
                     .add_modifier(Modifier::BOLD),
             )));
             lines.push(Line::from(""));
-            lines.push(Line::from(format!("Action: {}", target_label(target))));
+            lines.push(Line::from(format!("Action: {}", target.describe())));
             lines.push(Line::from(format!("Tickets: {}", targets.len())));
             lines.push(Line::from(""));
             lines.push(Line::from(Span::styled(
                 "Processing in background. Press Esc to close this modal.",
-                Style::default().fg(Color::DarkGray),
+                Style::default().fg(theme.muted),
             )));
         }
+        BulkState::DryRun {
+            target,
+            rows,
+            selected,
+            ..
+        } => {
+            lines.push(Line::from(format!("Action: {}", target.describe())));
+            render_dry_run(&mut lines, rows, *selected, inner.height, &nav);
+        }
         BulkState::Result { summary } => {
             render_result(&mut lines, summary);
         }
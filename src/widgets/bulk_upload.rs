@@ -1,4 +1,4 @@
-use ratatui::style::{Color, Modifier, Style};
+use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::Paragraph;
 
@@ -32,13 +32,14 @@ fn preview_window(total: usize, selected: usize, max_rows: usize) -> (usize, usi
 }
 
 pub fn render(f: &mut ratatui::Frame, app: &App) {
+    let theme = crate::theme::current();
     let Some(state) = app.bulk_upload_state.as_ref() else {
         return;
     };
 
     let (title, percent_x, percent_y) = match state {
-        BulkUploadState::PathInput { .. } => ("Bulk CSV Upload", 72, 34),
-        BulkUploadState::Preview { .. } => ("Bulk CSV Preview", 90, 80),
+        BulkUploadState::PathInput { .. } => ("Bulk Upload", 72, 40),
+        BulkUploadState::Preview { .. } => ("Bulk Upload Preview", 90, 80),
         BulkUploadState::Running { .. } => ("Bulk Upload Running", 68, 36),
         BulkUploadState::Result { .. } => ("Bulk Upload Results", 72, 54),
     };
@@ -49,29 +50,41 @@ pub fn render(f: &mut ratatui::Frame, app: &App) {
 
     match state {
         BulkUploadState::PathInput { path, loading } => {
-            form::render_text_input(&mut lines, "CSV Path", path, true);
+            form::render_text_input(&mut lines, "File Path", path, true);
             lines.push(Line::from(""));
             lines.push(Line::from(Span::styled(
-                "Headers: summary (required), type, assignee_email, epic_key, labels, description",
-                Style::default().fg(Color::DarkGray),
+                "Headers: summary (required), type, assignee_email, epic_key, labels, description,",
+                Style::default().fg(theme.muted),
             )));
             lines.push(Line::from(Span::styled(
-                "Labels use '|' separators (example: frontend|urgent). Max rows: 500.",
-                Style::default().fg(Color::DarkGray),
+                "  priority, components, story_points, due_date, parent, field:<name>",
+                Style::default().fg(theme.muted),
+            )));
+            lines.push(Line::from(Span::styled(
+                "A 'key' column updates that ticket instead of creating one.",
+                Style::default().fg(theme.muted),
+            )));
+            lines.push(Line::from(Span::styled(
+                "Labels and components use '|' separators (example: frontend|urgent). Max rows: 500.",
+                Style::default().fg(theme.muted),
+            )));
+            lines.push(Line::from(Span::styled(
+                ".json/.yaml: a list of objects with the same keys. .md: '- [ ] Summary #label @assignee', optionally a 'Labels: a, b' line below",
+                Style::default().fg(theme.muted),
             )));
             lines.push(Line::from(""));
             if *loading {
                 lines.push(Line::from(Span::styled(
                     "Loading preview...",
                     Style::default()
-                        .fg(Color::Yellow)
+                        .fg(theme.highlight)
                         .add_modifier(Modifier::BOLD),
                 )));
             }
             lines.push(Line::from(""));
             lines.push(Line::from(Span::styled(
                 "[Enter] preview  [Esc] cancel",
-                Style::default().fg(Color::DarkGray),
+                Style::default().fg(theme.muted),
             )));
         }
         BulkUploadState::Preview { preview, selected } => {
@@ -83,9 +96,9 @@ pub fn render(f: &mut ratatui::Frame, app: &App) {
             lines.push(Line::from(""));
 
             let gate_style = if preview.can_submit() {
-                Style::default().fg(Color::Green)
+                Style::default().fg(theme.success)
             } else {
-                Style::default().fg(Color::Red)
+                Style::default().fg(theme.error)
             };
             let gate_message = if preview.can_submit() {
                 "Ready to submit."
@@ -97,8 +110,8 @@ pub fn render(f: &mut ratatui::Frame, app: &App) {
 
             if preview.rows.is_empty() {
                 lines.push(Line::from(Span::styled(
-                    "No rows found in CSV.",
-                    Style::default().fg(Color::DarkGray),
+                    "No rows found in file.",
+                    Style::default().fg(theme.muted),
                 )));
             } else {
                 let (start, end) = preview_window(preview.rows.len(), *selected, 14);
@@ -113,20 +126,24 @@ pub fn render(f: &mut ratatui::Frame, app: &App) {
                         "OK"
                     };
                     let color = if !row.errors.is_empty() {
-                        Color::Red
+                        theme.error
                     } else if !row.warnings.is_empty() {
-                        Color::Yellow
+                        theme.highlight
                     } else {
-                        Color::Green
+                        theme.success
+                    };
+                    let (kind, text) = match row.key.as_deref() {
+                        Some(key) => (key.to_string(), format!("{} change(s)", row.diffs.len())),
+                        None => (row.issue_type.clone(), row.summary.clone()),
                     };
                     lines.push(Line::from(Span::styled(
                         format!(
-                            "{} {:<4} row {:<4} {:<6} {}",
+                            "{} {:<4} row {:<4} {:<8} {}",
                             marker,
                             status,
                             row.row_number,
-                            row.issue_type,
-                            truncate(&row.summary, 68)
+                            kind,
+                            truncate(&text, 68)
                         ),
                         Style::default().fg(color),
                     )));
@@ -134,7 +151,7 @@ pub fn render(f: &mut ratatui::Frame, app: &App) {
                 if end < preview.rows.len() {
                     lines.push(Line::from(Span::styled(
                         format!("... {} more rows", preview.rows.len().saturating_sub(end)),
-                        Style::default().fg(Color::DarkGray),
+                        Style::default().fg(theme.muted),
                     )));
                 }
             }
@@ -144,7 +161,7 @@ pub fn render(f: &mut ratatui::Frame, app: &App) {
                 if !row.errors.is_empty() {
                     lines.push(Line::from(Span::styled(
                         format!("Row {} errors: {}", row.row_number, row.errors.join("; ")),
-                        Style::default().fg(Color::Red),
+                        Style::default().fg(theme.error),
                     )));
                 } else if !row.warnings.is_empty() {
                     lines.push(Line::from(Span::styled(
@@ -153,21 +170,38 @@ pub fn render(f: &mut ratatui::Frame, app: &App) {
                             row.row_number,
                             row.warnings.join("; ")
                         ),
-                        Style::default().fg(Color::Yellow),
+                        Style::default().fg(theme.highlight),
                     )));
                 }
+                for diff in &row.diffs {
+                    lines.push(Line::from(vec![
+                        Span::styled(
+                            format!("  {}: ", diff.field),
+                            Style::default().fg(theme.accent),
+                        ),
+                        Span::styled(
+                            truncate(&diff.current, 40),
+                            Style::default().fg(theme.muted),
+                        ),
+                        Span::raw(" -> "),
+                        Span::styled(truncate(&diff.new, 40), Style::default().fg(theme.text)),
+                    ]));
+                }
             }
             lines.push(Line::from(""));
             lines.push(Line::from(Span::styled(
-                "[j/k] inspect rows  [r] reload CSV  [Enter/y] submit  [Esc] close",
-                Style::default().fg(Color::DarkGray),
+                format!(
+                    "[{}] inspect rows  [r] reload file  [Enter/y] submit  [Esc] close",
+                    app.keymap.picker_hint()
+                ),
+                Style::default().fg(theme.muted),
             )));
         }
         BulkUploadState::Running { preview } => {
             lines.push(Line::from(Span::styled(
-                "Creating tickets from CSV...",
+                "Creating and updating tickets from file...",
                 Style::default()
-                    .fg(Color::Yellow)
+                    .fg(theme.highlight)
                     .add_modifier(Modifier::BOLD),
             )));
             lines.push(Line::from(""));
@@ -179,14 +213,14 @@ pub fn render(f: &mut ratatui::Frame, app: &App) {
             lines.push(Line::from(""));
             lines.push(Line::from(Span::styled(
                 "Upload is running in background. Press Esc to close this modal.",
-                Style::default().fg(Color::DarkGray),
+                Style::default().fg(theme.muted),
             )));
         }
-        BulkUploadState::Result { summary } => {
+        BulkUploadState::Result { summary, .. } => {
             lines.push(Line::from(Span::styled(
                 "Bulk upload complete",
                 Style::default()
-                    .fg(Color::Green)
+                    .fg(theme.success)
                     .add_modifier(Modifier::BOLD),
             )));
             lines.push(Line::from(""));
@@ -205,11 +239,23 @@ pub fn render(f: &mut ratatui::Frame, app: &App) {
                     .join(", ");
                 lines.push(Line::from(format!("Created keys: {}", sample)));
             }
+            if !summary.updated_keys.is_empty() {
+                let sample = summary
+                    .updated_keys
+                    .iter()
+                    .take(5)
+                    .cloned()
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                lines.push(Line::from(format!("Updated keys: {}", sample)));
+            }
             if !summary.failed_details.is_empty() {
                 lines.push(Line::from(""));
                 lines.push(Line::from(Span::styled(
                     "Failures:",
-                    Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+                    Style::default()
+                        .fg(theme.error)
+                        .add_modifier(Modifier::BOLD),
                 )));
                 for (row_number, row_summary, err) in summary.failed_details.iter().take(5) {
                     lines.push(Line::from(Span::styled(
@@ -219,7 +265,7 @@ pub fn render(f: &mut ratatui::Frame, app: &App) {
                             truncate(row_summary, 30),
                             truncate(err, 80)
                         ),
-                        Style::default().fg(Color::Red),
+                        Style::default().fg(theme.error),
                     )));
                 }
                 if summary.failed_details.len() > 5 {
@@ -228,14 +274,19 @@ pub fn render(f: &mut ratatui::Frame, app: &App) {
                             "  ... and {} more",
                             summary.failed_details.len().saturating_sub(5)
                         ),
-                        Style::default().fg(Color::Red),
+                        Style::default().fg(theme.error),
                     )));
                 }
             }
             lines.push(Line::from(""));
+            let hint = if summary.failed_details.is_empty() {
+                "[r] rerun from same file  [Enter/Esc] close"
+            } else {
+                "[f] retry failed  [r] rerun from same file  [Enter/Esc] close"
+            };
             lines.push(Line::from(Span::styled(
-                "[r] rerun from same CSV  [Enter/Esc] close",
-                Style::default().fg(Color::DarkGray),
+                hint,
+                Style::default().fg(theme.muted),
             )));
         }
     }
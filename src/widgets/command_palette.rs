@@ -0,0 +1,69 @@
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::Paragraph;
+
+use super::form;
+use crate::app::App;
+
+pub fn render(f: &mut ratatui::Frame, app: &App) {
+    let theme = crate::theme::current();
+    let Some(palette) = app.command_palette.as_ref() else {
+        return;
+    };
+
+    let inner = form::render_modal_frame(f, "Commands", 60, 60);
+    let mut lines: Vec<Line> = Vec::new();
+    form::render_text_input(&mut lines, "Run", &palette.query, true);
+    lines.push(Line::from(""));
+
+    let matches = palette.matches();
+    // Input, blank line, blank line and footer take four rows.
+    let rows = (inner.height as usize).saturating_sub(4).max(1);
+    let start = palette.selected.saturating_sub(rows - 1);
+    let width = inner.width as usize;
+    if matches.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "  No matching commands",
+            Style::default().fg(theme.muted),
+        )));
+    }
+    for (i, entry) in matches.iter().enumerate().skip(start).take(rows) {
+        let selected = i == palette.selected;
+        let key = entry.key.as_deref().unwrap_or("");
+        let label_width = width.saturating_sub(key.chars().count() + 5);
+        let label: String = entry.label.chars().take(label_width).collect();
+        let pad = width.saturating_sub(label.chars().count() + key.chars().count() + 4);
+        let (label_style, key_style) = if selected {
+            (
+                Style::default()
+                    .fg(theme.highlight)
+                    .add_modifier(Modifier::BOLD)
+                    .patch(theme.selection()),
+                Style::default().fg(theme.accent).patch(theme.selection()),
+            )
+        } else {
+            (
+                Style::default().fg(theme.text),
+                Style::default().fg(theme.muted),
+            )
+        };
+        lines.push(Line::from(vec![
+            Span::styled(if selected { "> " } else { "  " }, label_style),
+            Span::styled(format!("{}{}", label, " ".repeat(pad)), label_style),
+            Span::styled(format!(" {} ", key), key_style),
+        ]));
+    }
+
+    while lines.len() < rows + 3 {
+        lines.push(Line::from(""));
+    }
+    lines.push(Line::from(Span::styled(
+        format!(
+            "type to filter  [{}] navigate  [Enter] run  [Esc] cancel",
+            app.keymap.input_hint()
+        ),
+        Style::default().fg(theme.muted),
+    )));
+
+    f.render_widget(Paragraph::new(lines), inner);
+}
@@ -1,5 +1,5 @@
 use ratatui::layout::{Constraint, Direction, Layout};
-use ratatui::style::{Color, Style};
+use ratatui::style::Style;
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, Paragraph, Wrap};
 
@@ -7,6 +7,7 @@ use super::form;
 use crate::app::App;
 
 pub fn render(f: &mut ratatui::Frame, app: &App) {
+    let theme = crate::theme::current();
     let state = match &app.comment_state {
         Some(s) => s,
         None => return,
@@ -26,7 +27,7 @@ pub fn render(f: &mut ratatui::Frame, app: &App) {
 
     let label = Paragraph::new(Line::from(Span::styled(
         "Comment:",
-        Style::default().fg(Color::Cyan),
+        Style::default().fg(theme.accent),
     )));
     f.render_widget(label, sections[0]);
 
@@ -39,7 +40,7 @@ pub fn render(f: &mut ratatui::Frame, app: &App) {
 
     let footer = Paragraph::new(Line::from(Span::styled(
         "[Shift+Enter] newline  [Enter] submit  [Esc] cancel",
-        Style::default().fg(Color::DarkGray),
+        Style::default().fg(theme.muted),
     )));
     f.render_widget(footer, sections[2]);
 }
@@ -1,78 +1,167 @@
-use ratatui::style::{Color, Style};
+use ratatui::style::Style;
 use ratatui::text::Line;
-use ratatui::widgets::Paragraph;
+use ratatui::widgets::{Paragraph, Wrap};
 
 use super::form;
-use crate::app::{App, ISSUE_TYPES};
+use crate::app::{App, CreateTicketState, DeriveKind, ISSUE_TYPES, PRIORITIES, SUBTASK_ISSUE_TYPE};
+use crate::config::TicketTemplate;
 
-pub fn render(f: &mut ratatui::Frame, app: &App) {
+pub fn render(f: &mut ratatui::Frame, app: &App, templates: &[TicketTemplate]) {
+    let theme = crate::theme::current();
     let state = match &app.create_ticket {
         Some(s) => s,
         None => return,
     };
-
-    let inner = form::render_modal_frame(f, "Create Ticket", 50, 60);
+    let focused = |field: usize| state.focused_field == field;
+
+    let title = match &state.derived_from {
+        Some(d) => match d.kind {
+            DeriveKind::Clone => format!("Clone {}", d.source_key),
+            DeriveKind::SubTask => format!("Sub-task of {}", d.source_key),
+            DeriveKind::FollowUp => format!("Follow-up to {}", d.source_key),
+        },
+        None if state.is_epic() => "Create Epic".to_string(),
+        None => "Create Ticket".to_string(),
+    };
+    let subtask_parent = state
+        .derived_from
+        .as_ref()
+        .filter(|d| d.kind == DeriveKind::SubTask)
+        .map(|d| d.source_key.as_str());
+    let inner = form::render_modal_frame(f, &title, 60, 80);
 
     let mut lines: Vec<Line> = Vec::new();
     lines.push(Line::from(""));
 
-    // Type picker (field 0)
-    let type_options: Vec<String> = ISSUE_TYPES.iter().map(|s| s.to_string()).collect();
-    form::render_picker(
+    form::render_inline_picker(
         &mut lines,
-        "Type",
-        &type_options,
-        state.issue_type_idx,
-        state.focused_field == 0,
+        "Template",
+        &build_template_options(templates),
+        state.template_idx,
+        focused(CreateTicketState::TEMPLATE),
     );
-
     lines.push(Line::from(""));
 
-    // Summary text input (field 1)
+    if subtask_parent.is_some() {
+        form::render_text_input(
+            &mut lines,
+            "Type",
+            SUBTASK_ISSUE_TYPE,
+            focused(CreateTicketState::TYPE),
+        );
+    } else {
+        let type_options: Vec<String> = ISSUE_TYPES.iter().map(|s| s.to_string()).collect();
+        form::render_inline_picker(
+            &mut lines,
+            "Type",
+            &type_options,
+            state.issue_type_idx,
+            focused(CreateTicketState::TYPE),
+        );
+    }
     form::render_text_input(
         &mut lines,
         "Summary",
         &state.summary,
-        state.focused_field == 1,
+        focused(CreateTicketState::SUMMARY),
     );
-
-    lines.push(Line::from(""));
-
-    // Assignee picker (field 2)
-    let assignee_options = build_assignee_options(app);
-    form::render_picker(
+    form::render_multiline_input(
         &mut lines,
-        "Assignee",
-        &assignee_options,
-        state.assignee_idx,
-        state.focused_field == 2,
+        "Description",
+        &state.description,
+        focused(CreateTicketState::DESCRIPTION),
     );
-
-    lines.push(Line::from(""));
-
-    // Epic picker (field 3)
-    let epic_options = build_epic_options(app);
-    form::render_picker(
+    form::render_text_input(
+        &mut lines,
+        "Labels (comma-separated)",
+        &state.labels,
+        focused(CreateTicketState::LABELS),
+    );
+    form::render_inline_picker(
         &mut lines,
-        "Epic",
-        &epic_options,
-        state.epic_idx,
-        state.focused_field == 3,
+        "Priority",
+        &build_priority_options(),
+        state.priority_idx,
+        focused(CreateTicketState::PRIORITY),
     );
+    form::render_text_input(
+        &mut lines,
+        "Components (comma-separated)",
+        &state.components,
+        focused(CreateTicketState::COMPONENTS),
+    );
+    form::render_text_input(
+        &mut lines,
+        "Story points",
+        &state.story_points,
+        focused(CreateTicketState::STORY_POINTS),
+    );
+    form::render_inline_picker(
+        &mut lines,
+        "Assignee",
+        &build_assignee_options(app),
+        state.assignee_idx,
+        focused(CreateTicketState::ASSIGNEE),
+    );
+    if let Some(parent) = subtask_parent {
+        form::render_text_input(
+            &mut lines,
+            "Parent",
+            parent,
+            focused(CreateTicketState::EPIC),
+        );
+    } else if state.is_epic() {
+        form::render_text_input(
+            &mut lines,
+            "Epic",
+            "- (epics cannot be nested)",
+            focused(CreateTicketState::EPIC),
+        );
+    } else {
+        form::render_inline_picker(
+            &mut lines,
+            "Epic",
+            &build_epic_options(app),
+            state.epic_idx,
+            focused(CreateTicketState::EPIC),
+        );
+    }
 
-    lines.push(Line::from(""));
     lines.push(Line::from(""));
 
     // Footer hints
+    let hint = if focused(CreateTicketState::DESCRIPTION) {
+        "[Tab] next field  [Shift+Enter] newline  [Enter] submit  [Esc] cancel"
+    } else if state.is_text_field() {
+        "[Tab] next field  [Enter] submit  [Esc] cancel"
+    } else {
+        "[Tab] next field  [j/k] choose  [Enter] submit  [Esc] cancel"
+    };
     lines.push(Line::from(ratatui::text::Span::styled(
-        "[Tab] next field  [Enter] submit  [Esc] cancel",
-        Style::default().fg(Color::DarkGray),
+        hint,
+        Style::default().fg(theme.muted),
     )));
 
-    let body = Paragraph::new(lines);
+    let body = Paragraph::new(lines).wrap(Wrap { trim: false });
     f.render_widget(body, inner);
 }
 
+pub fn build_template_options(templates: &[TicketTemplate]) -> Vec<String> {
+    let mut options = vec!["None".to_string()];
+    for template in templates {
+        options.push(template.name.clone());
+    }
+    options
+}
+
+pub fn build_priority_options() -> Vec<String> {
+    let mut options = vec!["Default".to_string()];
+    for priority in PRIORITIES {
+        options.push(priority.to_string());
+    }
+    options
+}
+
 pub fn build_assignee_options(app: &App) -> Vec<String> {
     let mut options = vec!["None".to_string()];
     for member in &app.cache.team_members {
@@ -1,4 +1,4 @@
-use ratatui::style::{Color, Style};
+use ratatui::style::Style;
 use ratatui::text::{Line, Span};
 use ratatui::widgets::Paragraph;
 
@@ -6,6 +6,7 @@ use super::form;
 use crate::app::App;
 
 pub fn render(f: &mut ratatui::Frame, app: &App) {
+    let theme = crate::theme::current();
     let state = match &app.edit_state {
         Some(s) => s,
         None => return,
@@ -41,7 +42,7 @@ pub fn render(f: &mut ratatui::Frame, app: &App) {
     // Footer hints
     lines.push(Line::from(Span::styled(
         "[Tab] next field  [Enter] save  [Esc] cancel",
-        Style::default().fg(Color::DarkGray),
+        Style::default().fg(theme.muted),
     )));
 
     let body = Paragraph::new(lines);
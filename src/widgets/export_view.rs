@@ -0,0 +1,55 @@
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::Paragraph;
+
+use super::form;
+use crate::app::App;
+use crate::view_export::ExportFormat;
+
+pub fn render(f: &mut ratatui::Frame, app: &App) {
+    let theme = crate::theme::current();
+    let selected = match app.export_picker {
+        Some(s) => s,
+        None => return,
+    };
+
+    let title = format!("Export {}", app.active_tab.title());
+    let inner = form::render_modal_frame(f, &title, 44, 34);
+
+    let mut lines: Vec<Line> = Vec::new();
+    lines.push(Line::from(format!(
+        "{} visible tickets",
+        app.visible_tickets_for_export().len()
+    )));
+    lines.push(Line::from(""));
+
+    for (i, format) in ExportFormat::ALL.iter().enumerate() {
+        let prefix = if i == selected { "> " } else { "  " };
+        let style = if i == selected {
+            Style::default()
+                .fg(theme.highlight)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(theme.subtle)
+        };
+        lines.push(Line::from(Span::styled(
+            format!("  {}{}", prefix, format.label()),
+            style,
+        )));
+    }
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(""));
+
+    // Footer hints
+    lines.push(Line::from(Span::styled(
+        format!(
+            "[{}] navigate  [Enter] export  [Esc] cancel",
+            app.keymap.picker_hint()
+        ),
+        Style::default().fg(theme.muted),
+    )));
+
+    let body = Paragraph::new(lines);
+    f.render_widget(body, inner);
+}
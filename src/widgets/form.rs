@@ -1,5 +1,5 @@
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
-use ratatui::style::{Color, Modifier, Style};
+use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, Clear};
 
@@ -41,56 +41,69 @@ pub fn render_modal_frame(
 
 /// Render a single-line text input with label.
 pub fn render_text_input(lines: &mut Vec<Line>, label: &str, value: &str, focused: bool) {
+    let theme = crate::theme::current();
     let cursor = if focused { "_" } else { "" };
     lines.push(Line::from(vec![
         Span::styled(
             format!("{}: ", label),
-            Style::default().fg(if focused {
-                Color::Cyan
-            } else {
-                Color::DarkGray
-            }),
+            Style::default().fg(if focused { theme.accent } else { theme.muted }),
         ),
         Span::styled(
             value.to_string(),
-            Style::default().fg(Color::White).add_modifier(if focused {
+            Style::default().fg(theme.text).add_modifier(if focused {
                 Modifier::BOLD
             } else {
                 Modifier::empty()
             }),
         ),
-        Span::styled(cursor, Style::default().fg(Color::DarkGray)),
+        Span::styled(cursor, Style::default().fg(theme.muted)),
     ]));
 }
 
-/// Render a picker list with selected highlight.
-pub fn render_picker(
+/// Render a one-line picker (`Label: < option >`) for forms with many fields.
+pub fn render_inline_picker(
     lines: &mut Vec<Line>,
     label: &str,
     options: &[String],
     selected: usize,
     focused: bool,
 ) {
+    let theme = crate::theme::current();
+    let value = options.get(selected).map(|s| s.as_str()).unwrap_or("-");
+    let (open, close) = if focused { ("< ", " >") } else { ("", "") };
+    lines.push(Line::from(vec![
+        Span::styled(
+            format!("{}: ", label),
+            Style::default().fg(if focused { theme.accent } else { theme.muted }),
+        ),
+        Span::styled(
+            format!("{}{}{}", open, value, close),
+            if focused {
+                Style::default()
+                    .fg(theme.highlight)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(theme.text)
+            },
+        ),
+    ]));
+}
+
+/// Render a multi-line text input: the label, then each line of the value indented.
+pub fn render_multiline_input(lines: &mut Vec<Line>, label: &str, value: &str, focused: bool) {
+    let theme = crate::theme::current();
     lines.push(Line::from(Span::styled(
         format!("{}:", label),
-        Style::default().fg(if focused {
-            Color::Cyan
-        } else {
-            Color::DarkGray
-        }),
+        Style::default().fg(if focused { theme.accent } else { theme.muted }),
     )));
-    for (i, option) in options.iter().enumerate() {
-        let prefix = if i == selected { "> " } else { "  " };
-        let style = if i == selected && focused {
-            Style::default()
-                .fg(Color::Yellow)
-                .add_modifier(Modifier::BOLD)
-        } else {
-            Style::default().fg(Color::Gray)
-        };
-        lines.push(Line::from(Span::styled(
-            format!("  {}{}", prefix, option),
-            style,
-        )));
+    let value_style = Style::default().fg(if focused { theme.text } else { theme.subtle });
+    let body: Vec<&str> = value.split('\n').collect();
+    let last = body.len() - 1;
+    for (i, line) in body.into_iter().enumerate() {
+        let cursor = if focused && i == last { "_" } else { "" };
+        lines.push(Line::from(vec![
+            Span::styled(format!("  {}", line), value_style),
+            Span::styled(cursor, Style::default().fg(theme.muted)),
+        ]));
     }
 }
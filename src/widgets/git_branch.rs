@@ -0,0 +1,36 @@
+use ratatui::style::Style;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::Paragraph;
+
+use super::form;
+use crate::app::App;
+
+pub fn render(f: &mut ratatui::Frame, app: &App) {
+    let theme = crate::theme::current();
+    let state = match &app.git_branch {
+        Some(s) => s,
+        None => return,
+    };
+
+    let title = format!("New branch for {}", state.ticket_key);
+    let inner = form::render_modal_frame(f, &title, 60, 20);
+
+    let mut lines: Vec<Line> = Vec::new();
+    lines.push(Line::from(""));
+    form::render_text_input(&mut lines, "Branch", &state.name, true);
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        "Creates the branch from HEAD and switches to it.",
+        Style::default().fg(theme.subtle),
+    )));
+    lines.push(Line::from(""));
+
+    // Footer hints
+    lines.push(Line::from(Span::styled(
+        "[Enter] create  [Esc] cancel",
+        Style::default().fg(theme.muted),
+    )));
+
+    let body = Paragraph::new(lines);
+    f.render_widget(body, inner);
+}
@@ -0,0 +1,95 @@
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::Paragraph;
+
+use super::form;
+use crate::app::App;
+use crate::cache::Viewed;
+
+pub fn render(f: &mut ratatui::Frame, app: &App) {
+    let theme = crate::theme::current();
+    let Some(state) = app.goto_key.as_ref() else {
+        return;
+    };
+
+    let inner = form::render_modal_frame(f, "Go to Key", 60, 50);
+    let mut lines: Vec<Line> = Vec::new();
+    form::render_text_input(&mut lines, "Key", &state.input, state.selected.is_none());
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        "Recently viewed",
+        Style::default()
+            .fg(theme.accent)
+            .add_modifier(Modifier::BOLD),
+    )));
+
+    // Input, blank, heading, blank and footer take five rows.
+    let rows = (inner.height as usize).saturating_sub(5).max(1);
+    let selected = state.selected.unwrap_or(0);
+    let start = selected.saturating_sub(rows - 1);
+    let width = inner.width as usize;
+    if app.recently_viewed.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "  Nothing viewed yet",
+            Style::default().fg(theme.muted),
+        )));
+    }
+    for (i, viewed) in app
+        .recently_viewed
+        .iter()
+        .enumerate()
+        .skip(start)
+        .take(rows)
+    {
+        let highlighted = state.selected == Some(i);
+        let summary = match viewed {
+            Viewed::Ticket(key) => app.find_ticket(key).map(|t| t.summary.clone()),
+            Viewed::Epic(key) => app
+                .cache
+                .epics
+                .iter()
+                .find(|epic| epic.key == *key)
+                .map(|epic| format!("{} (epic)", epic.summary)),
+        }
+        .unwrap_or_default();
+        let key = viewed.key();
+        let summary: String = summary
+            .chars()
+            .take(width.saturating_sub(key.chars().count() + 4))
+            .collect();
+        let (key_style, summary_style) = if highlighted {
+            (
+                Style::default()
+                    .fg(theme.accent)
+                    .add_modifier(Modifier::BOLD)
+                    .patch(theme.selection()),
+                Style::default()
+                    .fg(theme.highlight)
+                    .patch(theme.selection()),
+            )
+        } else {
+            (
+                Style::default().fg(theme.accent),
+                Style::default().fg(theme.text),
+            )
+        };
+        lines.push(Line::from(vec![
+            Span::styled(if highlighted { "> " } else { "  " }, key_style),
+            Span::styled(key.to_string(), key_style),
+            Span::styled(format!(" {}", summary), summary_style),
+        ]));
+    }
+
+    while lines.len() < rows + 4 {
+        lines.push(Line::from(""));
+    }
+    lines.push(Line::from(Span::styled(
+        format!(
+            "type a key or number  [{}] recent  [Enter] open  [Esc] cancel",
+            app.keymap.input_hint()
+        ),
+        Style::default().fg(theme.muted),
+    )));
+
+    f.render_widget(Paragraph::new(lines), inner);
+}
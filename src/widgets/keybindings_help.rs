@@ -1,8 +1,11 @@
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
-use ratatui::style::{Color, Modifier, Style};
+use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, Clear, Paragraph};
 
+use crate::cache::Status;
+use crate::keymap::{Keymap, Section};
+
 fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
     let popup_layout = Layout::default()
         .direction(Direction::Vertical)
@@ -23,7 +26,34 @@ fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
         .split(popup_layout[1])[1]
 }
 
-pub fn render(f: &mut ratatui::Frame) {
+/// Fixed keys and notes shown under a section, after the rebindable actions.
+fn section_notes(section: Section) -> Vec<String> {
+    match section {
+        Section::Filtering => vec![
+            "Team tab: [git] marks To Do tickets that already have commits".to_string(),
+            "Unassigned tab: tickets are grouped by epic".to_string(),
+            "Watching tab: tickets you watch, grouped by status".to_string(),
+        ],
+        Section::Detail => {
+            let shortcuts: Vec<String> = Status::all()
+                .iter()
+                .map(|status| status.move_shortcut().to_string())
+                .collect();
+            vec![
+                format!(
+                    "(in move picker) {}: choose + confirm prompt",
+                    shortcuts.join("/")
+                ),
+                "(in move picker) Shift+key: move immediately".to_string(),
+                "(in move picker) Enter or y: confirm pending move".to_string(),
+            ]
+        }
+        _ => Vec::new(),
+    }
+}
+
+pub fn render(f: &mut ratatui::Frame, keymap: &Keymap, scroll: u16) {
+    let theme = crate::theme::current();
     let area = centered_rect(64, 66, f.area());
     f.render_widget(Clear, area);
 
@@ -33,90 +63,33 @@ pub fn render(f: &mut ratatui::Frame) {
     let inner = block.inner(area);
     f.render_widget(block, area);
 
-    let lines = vec![
-        Line::from(Span::styled(
-            "Navigation",
-            Style::default()
-                .fg(Color::Yellow)
-                .add_modifier(Modifier::BOLD),
-        )),
-        Line::from("  Tab: switch tab"),
-        Line::from("  j / k or Up / Down: move selection"),
-        Line::from("  Space: toggle ticket or group selection"),
-        Line::from("  A: select all visible tickets"),
-        Line::from("  u: clear selected tickets"),
-        Line::from("  B: open bulk actions (move/assign)"),
-        Line::from("  U: open bulk CSV upload"),
-        Line::from("  Enter: open detail (ticket or epic)"),
-        Line::from("  z: fold/unfold group"),
-        Line::from("  Z: fold/unfold all groups"),
-        Line::from(""),
-        Line::from(Span::styled(
-            "Filtering (My Work + Team)",
-            Style::default()
-                .fg(Color::Yellow)
-                .add_modifier(Modifier::BOLD),
-        )),
-        Line::from("  d: toggle Done tickets"),
-        Line::from("  p: focus In Progress"),
-        Line::from("  w: focus Ready for Work"),
-        Line::from("  n: focus Needs Triage"),
-        Line::from("  v: focus In Review"),
-        Line::from("  /: search (tickets, labels, and team member names)"),
-        Line::from("  (while searching) Up/Down or Ctrl+j/Ctrl+k: navigate"),
-        Line::from("  Unassigned tab: tickets are grouped by epic"),
-        Line::from(""),
-        Line::from(Span::styled(
-            "Actions",
-            Style::default()
-                .fg(Color::Yellow)
-                .add_modifier(Modifier::BOLD),
-        )),
-        Line::from("  c: create ticket"),
-        Line::from("  U: bulk upload tickets from CSV"),
-        Line::from("  r: refresh tickets"),
-        Line::from(""),
-        Line::from(Span::styled(
-            "Detail View",
-            Style::default()
-                .fg(Color::Yellow)
-                .add_modifier(Modifier::BOLD),
-        )),
-        Line::from("  Esc: close detail"),
-        Line::from("  Up / Down: scroll detail"),
-        Line::from("  o: open ticket in browser"),
-        Line::from("  m: move ticket"),
-        Line::from("  C: add comment"),
-        Line::from("  a: assign/reassign ticket"),
-        Line::from("  e: edit summary and labels"),
-        Line::from("  h: view activity history"),
-        Line::from("  (in move picker) j/k or Up/Down: choose status"),
-        Line::from("  (in move picker) p/w/n/t/v/b/c: choose + confirm prompt"),
-        Line::from("  (in move picker) Shift+key: move immediately"),
-        Line::from("  (in move picker) Enter or y: confirm pending move"),
-        Line::from(""),
-        Line::from(Span::styled(
-            "Filters Tab",
+    let mut lines: Vec<Line> = Vec::new();
+    for (section, rows) in keymap.help_sections() {
+        if !lines.is_empty() {
+            lines.push(Line::from(""));
+        }
+        lines.push(Line::from(Span::styled(
+            section.title(),
             Style::default()
-                .fg(Color::Yellow)
+                .fg(theme.highlight)
                 .add_modifier(Modifier::BOLD),
-        )),
-        Line::from("  j / k: navigate filters or results"),
-        Line::from("  Tab: switch to results / next tab"),
-        Line::from("  Shift+Tab: switch back to sidebar"),
-        Line::from("  Enter: run filter (sidebar) / open ticket (results)"),
-        Line::from("  Space/A/u/B: select + bulk actions (results pane)"),
-        Line::from("  U: open bulk CSV upload"),
-        Line::from("  n: new filter"),
-        Line::from("  e: edit selected filter"),
-        Line::from("  x: delete selected filter"),
-        Line::from(""),
-        Line::from(Span::styled(
-            "Press ? or Esc to close",
-            Style::default().fg(Color::DarkGray),
-        )),
-    ];
+        )));
+        for (keys, description) in rows {
+            lines.push(Line::from(format!("  {}: {}", keys, description)));
+        }
+        for note in section_notes(section) {
+            lines.push(Line::from(format!("  {}", note)));
+        }
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        format!("{} to scroll, ? or Esc to close", keymap.picker_hint()),
+        Style::default().fg(theme.muted),
+    )));
 
-    let body = Paragraph::new(lines).block(Block::default());
+    let max_scroll = (lines.len() as u16).saturating_sub(inner.height);
+    let body = Paragraph::new(lines)
+        .block(Block::default())
+        .scroll((scroll.min(max_scroll), 0));
     f.render_widget(body, inner);
 }
@@ -2,9 +2,17 @@ pub mod activity;
 pub mod assign;
 pub mod bulk_actions;
 pub mod bulk_upload;
+pub mod command_palette;
 pub mod comment;
 pub mod create_ticket;
 pub mod edit_fields;
+pub mod export_view;
 pub mod form;
+pub mod git_branch;
+pub mod goto_key;
 pub mod keybindings_help;
+pub mod review_prompt;
+pub mod standup;
 pub mod ticket_detail;
+pub mod undo_history;
+pub mod worklog;
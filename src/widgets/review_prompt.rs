@@ -0,0 +1,48 @@
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::Paragraph;
+
+use super::form;
+use crate::app::App;
+
+pub fn render(f: &mut ratatui::Frame, app: &App) {
+    let theme = crate::theme::current();
+    let Some(key) = app.review_prompts.first() else {
+        return;
+    };
+
+    let inner = form::render_modal_frame(f, "Branch pushed", 60, 20);
+    let summary = app
+        .find_ticket(key)
+        .map(|t| t.summary.clone())
+        .unwrap_or_default();
+
+    let mut lines: Vec<Line> = Vec::new();
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![
+        Span::styled(
+            format!("Branch for {} pushed", key),
+            Style::default()
+                .fg(theme.accent)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(format!("  {}", summary), Style::default().fg(theme.subtle)),
+    ]));
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        "Move it to In Review?",
+        Style::default().fg(theme.text),
+    )));
+    lines.push(Line::from(""));
+    let waiting = app.review_prompts.len() - 1;
+    let mut footer = "[y/Enter] move  [n/Esc] leave as is".to_string();
+    if waiting > 0 {
+        footer.push_str(&format!("  ({} more waiting)", waiting));
+    }
+    lines.push(Line::from(Span::styled(
+        footer,
+        Style::default().fg(theme.muted),
+    )));
+
+    f.render_widget(Paragraph::new(lines), inner);
+}
@@ -0,0 +1,46 @@
+use ratatui::layout::{Constraint, Direction, Layout};
+use ratatui::style::Style;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Paragraph, Wrap};
+
+use super::form;
+use crate::app::App;
+
+pub fn render(f: &mut ratatui::Frame, app: &App) {
+    let theme = crate::theme::current();
+    let state = match &app.standup {
+        Some(s) => s,
+        None => return,
+    };
+
+    let title = format!(
+        "Standup: {} ({}, last {}h)",
+        state.scope.label(),
+        state.format.label(),
+        state.window_hours
+    );
+    let inner = form::render_modal_frame(f, &title, 70, 80);
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(1), Constraint::Length(2)])
+        .split(inner);
+
+    let lines: Vec<Line> = state.text.lines().map(Line::from).collect();
+    let body = Paragraph::new(lines)
+        .wrap(Wrap { trim: false })
+        .scroll((state.scroll, 0));
+    f.render_widget(body, chunks[0]);
+
+    // Footer hints
+    let footer = Paragraph::new(vec![
+        Line::from(""),
+        Line::from(Span::styled(
+            format!(
+                "[{}] scroll  [t] me/team  [f] format  [+/-] window  [y] copy  [Esc] close",
+                app.keymap.picker_hint()
+            ),
+            Style::default().fg(theme.muted),
+        )),
+    ]);
+    f.render_widget(footer, chunks[1]);
+}
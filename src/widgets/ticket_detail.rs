@@ -1,23 +1,12 @@
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
-use ratatui::style::{Color, Modifier, Style};
+use ratatui::style::{Modifier, Style};
+use ratatui::symbols;
 use ratatui::text::{Line, Span};
-use ratatui::widgets::{Block, Borders, Clear, Paragraph, Wrap};
+use ratatui::widgets::{Axis, Block, Borders, Chart, Clear, Dataset, GraphType, Paragraph, Wrap};
 
-use crate::app::{App, DetailMode};
+use crate::app::{App, DeriveKind, DetailMode, Tab};
 use crate::cache::Status;
-
-fn status_color(status: &Status) -> Color {
-    match status {
-        Status::NeedsTriage => Color::White,
-        Status::ReadyForWork => Color::Blue,
-        Status::InProgress => Color::Yellow,
-        Status::ToDo => Color::White,
-        Status::InReview => Color::Cyan,
-        Status::Blocked => Color::Red,
-        Status::Closed => Color::Green,
-        Status::Other(_) => Color::Magenta,
-    }
-}
+use crate::keymap::{Action, Keymap};
 
 fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
     let popup_layout = Layout::default()
@@ -147,6 +136,7 @@ fn progress_bar(done: usize, total: usize, width: usize) -> String {
 }
 
 fn push_description_lines(lines: &mut Vec<Line>, desc: &str) {
+    let theme = crate::theme::current();
     let mut in_code = false;
 
     for raw in desc.lines() {
@@ -156,7 +146,7 @@ fn push_description_lines(lines: &mut Vec<Line>, desc: &str) {
             in_code = !in_code;
             lines.push(Line::from(Span::styled(
                 "---- code ----",
-                Style::default().fg(Color::DarkGray),
+                Style::default().fg(theme.muted),
             )));
             continue;
         }
@@ -164,7 +154,7 @@ fn push_description_lines(lines: &mut Vec<Line>, desc: &str) {
         if in_code {
             lines.push(Line::from(Span::styled(
                 raw.to_string(),
-                Style::default().fg(Color::White),
+                Style::default().fg(theme.text),
             )));
             continue;
         }
@@ -181,7 +171,7 @@ fn push_description_lines(lines: &mut Vec<Line>, desc: &str) {
             lines.push(Line::from(Span::styled(
                 heading_text,
                 Style::default()
-                    .fg(Color::Cyan)
+                    .fg(theme.accent)
                     .add_modifier(Modifier::BOLD),
             )));
             continue;
@@ -191,22 +181,23 @@ fn push_description_lines(lines: &mut Vec<Line>, desc: &str) {
             let prefix = " ".repeat(indent);
             lines.push(Line::from(Span::styled(
                 format!("{}• {}", prefix, normalize_inline(&item)),
-                Style::default().fg(Color::Gray),
+                Style::default().fg(theme.subtle),
             )));
             continue;
         }
 
         lines.push(Line::from(Span::styled(
             normalize_inline(raw),
-            Style::default().fg(Color::Gray),
+            Style::default().fg(theme.subtle),
         )));
     }
 }
 
-pub fn render(f: &mut ratatui::Frame, app: &App, resolutions: &[String]) {
+/// Draw the open ticket or epic over the list, or into `pane` in split view.
+pub fn render(f: &mut ratatui::Frame, app: &App, resolutions: &[String], pane: Option<Rect>) {
     if let Some(ticket_key) = app.detail_ticket_key.as_ref() {
         if let Some(ticket) = app.find_ticket(ticket_key) {
-            let area = centered_rect(60, 60, f.area());
+            let area = pane.unwrap_or_else(|| centered_rect(60, 60, f.area()));
             f.render_widget(Clear, area);
 
             let block = Block::default()
@@ -217,18 +208,52 @@ pub fn render(f: &mut ratatui::Frame, app: &App, resolutions: &[String]) {
             f.render_widget(block, area);
 
             match &app.detail_mode {
-                DetailMode::View => render_view(f, inner, ticket, app.detail_scroll),
+                DetailMode::View => {
+                    let timer_elapsed = app
+                        .work_timer
+                        .as_ref()
+                        .filter(|t| &t.ticket_key == ticket_key)
+                        .map(|t| t.elapsed_secs(crate::jira_client::now_unix_secs()));
+                    let commits = app.commits_for(ticket_key);
+                    let footer = footer_hints(&app.keymap, TICKET_FOOTER);
+                    render_view(
+                        f,
+                        inner,
+                        ticket,
+                        app.detail_scroll,
+                        timer_elapsed,
+                        commits,
+                        &footer,
+                    )
+                }
                 DetailMode::MovePicker {
                     selected,
                     confirm_target,
-                } => render_move_picker(f, inner, ticket, *selected, confirm_target.as_ref()),
+                } => render_move_picker(
+                    f,
+                    inner,
+                    ticket,
+                    *selected,
+                    confirm_target.as_ref(),
+                    &app.keymap.picker_hint(),
+                ),
                 DetailMode::ResolutionPicker {
                     target_status,
                     selected,
-                } => render_resolution_picker(f, inner, target_status, *selected, resolutions),
+                } => render_resolution_picker(
+                    f,
+                    inner,
+                    target_status,
+                    *selected,
+                    resolutions,
+                    &app.keymap.picker_hint(),
+                ),
                 DetailMode::History { scroll } => {
                     crate::widgets::activity::render(f, inner, &ticket.activity, *scroll);
                 }
+                DetailMode::DerivePicker { selected } => {
+                    render_derive_picker(f, inner, ticket, *selected, &app.keymap.picker_hint())
+                }
             }
             return;
         }
@@ -243,7 +268,7 @@ pub fn render(f: &mut ratatui::Frame, app: &App, resolutions: &[String]) {
         None => return,
     };
 
-    let area = centered_rect(70, 70, f.area());
+    let area = pane.unwrap_or_else(|| centered_rect(70, 70, f.area()));
     f.render_widget(Clear, area);
 
     let block = Block::default()
@@ -252,10 +277,257 @@ pub fn render(f: &mut ratatui::Frame, app: &App, resolutions: &[String]) {
 
     let inner = block.inner(area);
     f.render_widget(block, area);
-    render_epic_view(f, inner, epic, app.detail_scroll);
+    match &app.detail_mode {
+        DetailMode::ResolutionPicker {
+            target_status,
+            selected,
+        } => render_resolution_picker(
+            f,
+            inner,
+            target_status,
+            *selected,
+            resolutions,
+            &app.keymap.picker_hint(),
+        ),
+        _ => render_epic_view(
+            f,
+            inner,
+            epic,
+            app.detail_scroll,
+            &footer_hints(&app.keymap, EPIC_FOOTER),
+        ),
+    }
+}
+
+/// Read-only view of the selected row for the split layout: its ticket, or on the Epics
+/// tab the epic whose header is selected.
+pub fn render_preview(f: &mut ratatui::Frame, app: &App, area: Rect) {
+    let theme = crate::theme::current();
+    let block = |title: String| {
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(theme.muted))
+            .title(title)
+    };
+
+    if let Some(ticket) = app
+        .selected_ticket_key()
+        .and_then(|key| app.find_ticket(&key))
+    {
+        let block = block(format!(" {} ", ticket.key));
+        let inner = block.inner(area);
+        f.render_widget(block, area);
+        let footer = if ticket.detail_loaded {
+            action_hints(&app.keymap, PREVIEW_FOOTER)
+        } else {
+            "Loading details...".to_string()
+        };
+        let timer_elapsed = app
+            .work_timer
+            .as_ref()
+            .filter(|t| t.ticket_key == ticket.key)
+            .map(|t| t.elapsed_secs(crate::jira_client::now_unix_secs()));
+        let commits = app.commits_for(&ticket.key);
+        render_view(f, inner, ticket, 0, timer_elapsed, commits, &footer);
+        return;
+    }
+
+    let epic = (app.active_tab == Tab::Epics)
+        .then(|| app.selected_header_group_id())
+        .flatten()
+        .and_then(|key| app.cache.epics.iter().find(|e| e.key == key));
+    if let Some(epic) = epic {
+        let block = block(format!(" {} ", epic.key));
+        let inner = block.inner(area);
+        f.render_widget(block, area);
+        render_epic_view(
+            f,
+            inner,
+            epic,
+            0,
+            &action_hints(&app.keymap, PREVIEW_FOOTER),
+        );
+        return;
+    }
+
+    let empty = Paragraph::new(Span::styled(
+        "Nothing selected",
+        Style::default().fg(theme.muted),
+    ))
+    .block(block(" Preview ".to_string()));
+    f.render_widget(empty, area);
+}
+
+const TICKET_FOOTER: &[(Action, &str)] = &[
+    (Action::CloseDetail, "close"),
+    (Action::OpenInBrowser, "browser"),
+    (Action::MoveStatus, "move"),
+    (Action::Comment, "comment"),
+    (Action::Assign, "assign"),
+    (Action::EditFields, "edit"),
+    (Action::ActivityHistory, "history"),
+    (Action::LogWork, "log work"),
+    (Action::WorkTimer, "timer"),
+    (Action::Watch, "watch"),
+    (Action::NewFromTicket, "new from"),
+    (Action::CommandPalette, "commands"),
+];
+
+const PREVIEW_FOOTER: &[(Action, &str)] =
+    &[(Action::Open, "open"), (Action::TogglePreview, "overlay")];
+
+const EPIC_FOOTER: &[(Action, &str)] = &[
+    (Action::CloseDetail, "close"),
+    (Action::OpenInBrowser, "browser"),
+    (Action::CloseEpic, "close epic"),
+    (Action::CommandPalette, "commands"),
+];
+
+/// `[key] label` hints from the active keymap, led by the scroll keys.
+fn footer_hints(keymap: &Keymap, actions: &[(Action, &str)]) -> String {
+    let mut hints: Vec<String> = Vec::new();
+    if let (Some(up), Some(down)) = (
+        keymap.hint(Action::ScrollUp),
+        keymap.hint(Action::ScrollDown),
+    ) {
+        hints.push(format!("[{}/{}] scroll", up, down));
+    }
+    hints.push(action_hints(keymap, actions));
+    hints.join("  ")
+}
+
+fn action_hints(keymap: &Keymap, actions: &[(Action, &str)]) -> String {
+    actions
+        .iter()
+        .filter_map(|(action, label)| keymap.hint(*action).map(|k| format!("[{}] {}", k, label)))
+        .collect::<Vec<_>>()
+        .join("  ")
+}
+
+fn push_time_tracking_lines(
+    lines: &mut Vec<Line>,
+    tracking: &crate::cache::TimeTracking,
+    timer_elapsed: Option<u64>,
+) {
+    let theme = crate::theme::current();
+    if tracking.is_empty() && timer_elapsed.is_none() {
+        return;
+    }
+
+    lines.push(Line::from(vec![
+        Span::raw("Estimate: "),
+        Span::styled(
+            tracking
+                .original_estimate
+                .as_deref()
+                .unwrap_or("-")
+                .to_string(),
+            Style::default().fg(theme.text),
+        ),
+        Span::raw("    Remaining: "),
+        Span::styled(
+            tracking
+                .remaining_estimate
+                .as_deref()
+                .unwrap_or("-")
+                .to_string(),
+            Style::default().fg(theme.text),
+        ),
+        Span::raw("    Logged: "),
+        Span::styled(
+            tracking.time_spent.as_deref().unwrap_or("-").to_string(),
+            Style::default().fg(theme.text),
+        ),
+    ]));
+
+    if let Some(elapsed) = timer_elapsed {
+        lines.push(Line::from(vec![
+            Span::raw("Timer: "),
+            Span::styled(
+                format!("running {}", crate::worklog::format_elapsed(elapsed)),
+                Style::default().fg(theme.highlight),
+            ),
+        ]));
+    }
+
+    if !tracking.worklogs.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "Worklogs",
+            Style::default()
+                .fg(theme.accent)
+                .add_modifier(Modifier::BOLD),
+        )));
+        for worklog in &tracking.worklogs {
+            let date: String = worklog.started.chars().take(10).collect();
+            let mut spans = vec![
+                Span::styled(format!("  {:<12}", date), Style::default().fg(theme.muted)),
+                Span::styled(
+                    format!("{:<20}", truncate(&worklog.author, 19)),
+                    Style::default().fg(theme.text),
+                ),
+                Span::styled(
+                    format!("{:<10}", worklog.time_spent),
+                    Style::default().fg(theme.success),
+                ),
+            ];
+            if let Some(comment) = worklog.comment.as_deref() {
+                spans.push(Span::styled(
+                    truncate(comment, 60),
+                    Style::default().fg(theme.subtle),
+                ));
+            }
+            lines.push(Line::from(spans));
+        }
+    }
+
+    lines.push(Line::from(""));
+}
+
+fn push_commit_lines(lines: &mut Vec<Line>, commits: &[crate::git::CommitRef]) {
+    let theme = crate::theme::current();
+    if commits.is_empty() {
+        return;
+    }
+
+    lines.push(Line::from(Span::styled(
+        format!("Commits referencing this ticket ({})", commits.len()),
+        Style::default()
+            .fg(theme.accent)
+            .add_modifier(Modifier::BOLD),
+    )));
+    for commit in commits {
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!("  {:<9}", commit.hash),
+                Style::default().fg(theme.highlight),
+            ),
+            Span::styled(
+                format!("{:<12}", commit.date),
+                Style::default().fg(theme.muted),
+            ),
+            Span::styled(
+                format!("{:<20}", truncate(&commit.author, 19)),
+                Style::default().fg(theme.text),
+            ),
+            Span::styled(
+                truncate(&commit.subject, 72),
+                Style::default().fg(theme.subtle),
+            ),
+        ]));
+    }
+    lines.push(Line::from(""));
 }
 
-fn render_view(f: &mut ratatui::Frame, area: Rect, ticket: &crate::cache::Ticket, scroll: u16) {
+fn render_view(
+    f: &mut ratatui::Frame,
+    area: Rect,
+    ticket: &crate::cache::Ticket,
+    scroll: u16,
+    timer_elapsed: Option<u64>,
+    commits: &[crate::git::CommitRef],
+    footer: &str,
+) {
+    let theme = crate::theme::current();
     // Split into body and footer
     let chunks = Layout::default()
         .direction(Direction::Vertical)
@@ -271,9 +543,7 @@ fn render_view(f: &mut ratatui::Frame, area: Rect, ticket: &crate::cache::Ticket
     // Line 1: Summary (bold, white)
     lines.push(Line::from(Span::styled(
         ticket.summary.clone(),
-        Style::default()
-            .fg(Color::White)
-            .add_modifier(Modifier::BOLD),
+        Style::default().fg(theme.text).add_modifier(Modifier::BOLD),
     )));
 
     // Line 2: empty
@@ -285,17 +555,17 @@ fn render_view(f: &mut ratatui::Frame, area: Rect, ticket: &crate::cache::Ticket
         Span::raw("Status: "),
         Span::styled(
             ticket.status.as_str(),
-            Style::default().fg(status_color(&ticket.status)),
+            Style::default().fg(theme.status(&ticket.status)),
         ),
         Span::raw("    Assignee: "),
-        Span::styled(assignee_str, Style::default().fg(Color::White)),
+        Span::styled(assignee_str, Style::default().fg(theme.text)),
     ]));
 
     // Line 4: Reporter (if loaded)
     if let Some(ref reporter) = ticket.reporter {
         lines.push(Line::from(vec![
             Span::raw("Reporter: "),
-            Span::styled(reporter.as_str(), Style::default().fg(Color::White)),
+            Span::styled(reporter.as_str(), Style::default().fg(theme.text)),
         ]));
     }
 
@@ -305,16 +575,38 @@ fn render_view(f: &mut ratatui::Frame, area: Rect, ticket: &crate::cache::Ticket
             Span::raw("Epic: "),
             Span::styled(
                 format!("{} ({})", epic_key, epic_name),
-                Style::default().fg(Color::Magenta),
+                Style::default().fg(theme.special),
             ),
         ]));
     } else if let Some(ref epic_key) = ticket.epic_key {
         lines.push(Line::from(vec![
             Span::raw("Epic: "),
-            Span::styled(epic_key.clone(), Style::default().fg(Color::Magenta)),
+            Span::styled(epic_key.clone(), Style::default().fg(theme.special)),
         ]));
     }
 
+    // Watchers (once detail has reported any)
+    let watchers = &ticket.watchers;
+    if watchers.count > 0 || watchers.is_watching {
+        let mut spans = vec![
+            Span::raw("Watchers: "),
+            Span::styled(watchers.count.to_string(), Style::default().fg(theme.text)),
+        ];
+        if watchers.is_watching {
+            spans.push(Span::styled(
+                " (watching)",
+                Style::default().fg(theme.accent),
+            ));
+        }
+        if !watchers.names.is_empty() {
+            spans.push(Span::styled(
+                format!("  {}", truncate(&watchers.names.join(", "), 80)),
+                Style::default().fg(theme.subtle),
+            ));
+        }
+        lines.push(Line::from(spans));
+    }
+
     // Line 5: empty
     lines.push(Line::from(""));
 
@@ -322,11 +614,20 @@ fn render_view(f: &mut ratatui::Frame, area: Rect, ticket: &crate::cache::Ticket
     if !ticket.labels.is_empty() {
         lines.push(Line::from(vec![
             Span::raw("Labels: "),
-            Span::styled(ticket.labels.join(", "), Style::default().fg(Color::Yellow)),
+            Span::styled(
+                ticket.labels.join(", "),
+                Style::default().fg(theme.highlight),
+            ),
         ]));
         lines.push(Line::from(""));
     }
 
+    // Estimates, logged work, and the running timer
+    push_time_tracking_lines(&mut lines, &ticket.time_tracking, timer_elapsed);
+
+    // Local git commits that mention the key
+    push_commit_lines(&mut lines, commits);
+
     // Line 6+: Description
     let desc = ticket.description.as_deref().unwrap_or("(no description)");
     push_description_lines(&mut lines, desc);
@@ -338,28 +639,40 @@ fn render_view(f: &mut ratatui::Frame, area: Rect, ticket: &crate::cache::Ticket
 
     // Footer
     let footer = Paragraph::new(Line::from(Span::styled(
-        "[↑/↓] scroll  [Esc] close  [o] browser  [m] move  [C] comment  [a] assign  [e] edit  [h] history",
-        Style::default().fg(Color::DarkGray),
+        footer.to_string(),
+        Style::default().fg(theme.muted),
     )));
     f.render_widget(footer, footer_area);
 }
 
-fn render_epic_view(f: &mut ratatui::Frame, area: Rect, epic: &crate::cache::Epic, scroll: u16) {
+fn render_epic_view(
+    f: &mut ratatui::Frame,
+    area: Rect,
+    epic: &crate::cache::Epic,
+    scroll: u16,
+    footer: &str,
+) {
+    let theme = crate::theme::current();
     let chunks = Layout::default()
         .direction(Direction::Vertical)
-        .constraints([Constraint::Min(0), Constraint::Length(1)])
+        .constraints([
+            Constraint::Min(0),
+            Constraint::Length(12),
+            Constraint::Length(1),
+        ])
         .split(area);
 
     let body_area = chunks[0];
-    let footer_area = chunks[1];
+    let chart_area = chunks[1];
+    let footer_area = chunks[2];
+    let now = crate::jira_client::now_unix_secs();
+    let forecast = crate::burndown::forecast(epic, now);
 
     let mut lines: Vec<Line> = Vec::new();
 
     lines.push(Line::from(Span::styled(
         epic.summary.clone(),
-        Style::default()
-            .fg(Color::White)
-            .add_modifier(Modifier::BOLD),
+        Style::default().fg(theme.text).add_modifier(Modifier::BOLD),
     )));
     lines.push(Line::from(""));
 
@@ -376,7 +689,7 @@ fn render_epic_view(f: &mut ratatui::Frame, area: Rect, epic: &crate::cache::Epi
                 total,
                 pct
             ),
-            Style::default().fg(Color::Green),
+            Style::default().fg(theme.success),
         ),
     ]));
 
@@ -393,13 +706,29 @@ fn render_epic_view(f: &mut ratatui::Frame, area: Rect, epic: &crate::cache::Epi
     }
     lines.push(Line::from(vec![
         Span::raw("Status: "),
-        Span::styled(parts.join("  "), Style::default().fg(Color::Gray)),
+        Span::styled(parts.join("  "), Style::default().fg(theme.subtle)),
+    ]));
+    let forecast_text = match (forecast.days_left, forecast.eta(now)) {
+        (Some(0), _) => "all related tickets are closed".to_string(),
+        (Some(days), Some(eta)) => format!(
+            "{} remaining at {:.1} closed/week -> {} (~{} days)",
+            forecast.remaining, forecast.per_week, eta, days
+        ),
+        _ => format!(
+            "{} remaining, nothing closed in the last {} days",
+            forecast.remaining,
+            crate::burndown::THROUGHPUT_WINDOW_DAYS
+        ),
+    };
+    lines.push(Line::from(vec![
+        Span::raw("Forecast: "),
+        Span::styled(forecast_text, Style::default().fg(theme.accent)),
     ]));
     lines.push(Line::from(""));
     lines.push(Line::from(Span::styled(
         "Related Tickets",
         Style::default()
-            .fg(Color::Cyan)
+            .fg(theme.accent)
             .add_modifier(Modifier::BOLD),
     )));
     lines.push(Line::from(""));
@@ -413,23 +742,23 @@ fn render_epic_view(f: &mut ratatui::Frame, area: Rect, epic: &crate::cache::Epi
     if children.is_empty() {
         lines.push(Line::from(Span::styled(
             "(no related tickets)",
-            Style::default().fg(Color::DarkGray),
+            Style::default().fg(theme.muted),
         )));
     } else {
         for ticket in children {
             lines.push(Line::from(vec![
                 Span::styled(
                     format!("{:<12}", ticket.key),
-                    Style::default().fg(Color::White),
+                    Style::default().fg(theme.text),
                 ),
                 Span::styled(
                     format!("{:<15}", ticket.status.as_str()),
-                    Style::default().fg(status_color(&ticket.status)),
+                    Style::default().fg(theme.status(&ticket.status)),
                 ),
                 Span::raw("  "),
                 Span::styled(
                     truncate(&ticket.summary, 78),
-                    Style::default().fg(Color::Gray),
+                    Style::default().fg(theme.subtle),
                 ),
             ]));
         }
@@ -440,20 +769,92 @@ fn render_epic_view(f: &mut ratatui::Frame, area: Rect, epic: &crate::cache::Epi
         .wrap(Wrap { trim: false });
     f.render_widget(body, body_area);
 
+    render_burndown_chart(f, chart_area, epic, &forecast, now);
+
     let footer = Paragraph::new(Line::from(Span::styled(
-        "[↑/↓] scroll  [Esc] close  [o] browser",
-        Style::default().fg(Color::DarkGray),
+        footer.to_string(),
+        Style::default().fg(theme.muted),
     )));
     f.render_widget(footer, footer_area);
 }
 
+/// Remaining (burndown) and done (burnup) per day, plus the forecast line to zero.
+fn render_burndown_chart(
+    f: &mut ratatui::Frame,
+    area: Rect,
+    epic: &crate::cache::Epic,
+    forecast: &crate::burndown::Forecast,
+    now: u64,
+) {
+    let theme = crate::theme::current();
+    let series = crate::burndown::burn_series(epic, now);
+    let remaining: Vec<(f64, f64)> = series
+        .iter()
+        .map(|p| (p.day as f64, p.remaining as f64))
+        .collect();
+    let done: Vec<(f64, f64)> = series
+        .iter()
+        .map(|p| (p.day as f64, p.done as f64))
+        .collect();
+    let projection: Vec<(f64, f64)> = match forecast.days_left {
+        Some(days) if days > 0 => vec![(0.0, forecast.remaining as f64), (days as f64, 0.0)],
+        _ => Vec::new(),
+    };
+
+    let first_day = series.first().map(|p| p.day).unwrap_or(0) as f64;
+    let last_day = projection.last().map(|(x, _)| *x).unwrap_or(0.0).max(1.0);
+    let total = epic.total().max(1) as f64;
+
+    let datasets = vec![
+        Dataset::default()
+            .name("remaining")
+            .marker(symbols::Marker::Braille)
+            .graph_type(GraphType::Line)
+            .style(Style::default().fg(theme.highlight))
+            .data(&remaining),
+        Dataset::default()
+            .name("done")
+            .marker(symbols::Marker::Braille)
+            .graph_type(GraphType::Line)
+            .style(Style::default().fg(theme.success))
+            .data(&done),
+        Dataset::default()
+            .name("forecast")
+            .marker(symbols::Marker::Dot)
+            .graph_type(GraphType::Line)
+            .style(Style::default().fg(theme.accent))
+            .data(&projection),
+    ];
+    let chart = Chart::new(datasets)
+        .block(
+            Block::default()
+                .borders(Borders::TOP)
+                .title(" Burndown (days from today) "),
+        )
+        .x_axis(
+            Axis::default()
+                .style(Style::default().fg(theme.muted))
+                .bounds([first_day, last_day])
+                .labels([format!("{}", first_day), format!("+{}", last_day)]),
+        )
+        .y_axis(
+            Axis::default()
+                .style(Style::default().fg(theme.muted))
+                .bounds([0.0, total])
+                .labels(["0".to_string(), format!("{}", total)]),
+        );
+    f.render_widget(chart, area);
+}
+
 fn render_move_picker(
     f: &mut ratatui::Frame,
     area: Rect,
     ticket: &crate::cache::Ticket,
     selected: usize,
     confirm_target: Option<&Status>,
+    nav: &str,
 ) {
+    let theme = crate::theme::current();
     // Split into body and footer
     let chunks = Layout::default()
         .direction(Direction::Vertical)
@@ -468,9 +869,7 @@ fn render_move_picker(
     // Line 1: "Move to:" (bold)
     lines.push(Line::from(Span::styled(
         "Move to:",
-        Style::default()
-            .fg(Color::White)
-            .add_modifier(Modifier::BOLD),
+        Style::default().fg(theme.text).add_modifier(Modifier::BOLD),
     )));
 
     // Line 2: empty
@@ -480,9 +879,9 @@ fn render_move_picker(
     let options = ticket.status.others();
     for (i, status) in options.iter().enumerate() {
         let prefix = if i == selected { "> " } else { "  " };
-        let mut style = Style::default().fg(status_color(status));
+        let mut style = Style::default().fg(theme.status(status));
         if i == selected {
-            style = style.add_modifier(Modifier::BOLD).bg(Color::DarkGray);
+            style = style.add_modifier(Modifier::BOLD).patch(theme.selection());
         }
         lines.push(Line::from(Span::styled(
             format!("{}[{}] {}", prefix, status.move_shortcut(), status.as_str()),
@@ -497,7 +896,7 @@ fn render_move_picker(
                 "Confirm move to {}? Press Enter or y. Esc cancels.",
                 status.as_str()
             ),
-            Style::default().fg(Color::Yellow),
+            Style::default().fg(theme.highlight),
         )));
     }
 
@@ -506,19 +905,65 @@ fn render_move_picker(
 
     // Footer
     let footer = Paragraph::new(Line::from(Span::styled(
-        "[j/k/↑/↓] choose   [p/w/n/t/v/b/c] confirm   [Shift+key] move now   [Esc] cancel",
-        Style::default().fg(Color::DarkGray),
+        format!(
+            "[{}] choose   [p/w/n/t/v/b/c] confirm   [Shift+key] move now   [Esc] cancel",
+            nav
+        ),
+        Style::default().fg(theme.muted),
     )));
     f.render_widget(footer, footer_area);
 }
 
+fn render_derive_picker(
+    f: &mut ratatui::Frame,
+    area: Rect,
+    ticket: &crate::cache::Ticket,
+    selected: usize,
+    nav: &str,
+) {
+    let theme = crate::theme::current();
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(1)])
+        .split(area);
+
+    let mut lines: Vec<Line> = Vec::new();
+    lines.push(Line::from(Span::styled(
+        format!("New ticket from {}:", ticket.key),
+        Style::default().fg(theme.text).add_modifier(Modifier::BOLD),
+    )));
+    lines.push(Line::from(""));
+
+    for (i, kind) in DeriveKind::all().iter().enumerate() {
+        let prefix = if i == selected { "> " } else { "  " };
+        let mut style = Style::default().fg(theme.subtle);
+        if i == selected {
+            style = style.add_modifier(Modifier::BOLD).patch(theme.selection());
+        }
+        lines.push(Line::from(Span::styled(
+            format!("{}[{}] {}", prefix, kind.shortcut(), kind.label()),
+            style,
+        )));
+    }
+
+    f.render_widget(Paragraph::new(lines), chunks[0]);
+
+    let footer = Paragraph::new(Line::from(Span::styled(
+        format!("[{}] choose   [Enter/c/s/f] open form   [Esc] cancel", nav),
+        Style::default().fg(theme.muted),
+    )));
+    f.render_widget(footer, chunks[1]);
+}
+
 fn render_resolution_picker(
     f: &mut ratatui::Frame,
     area: Rect,
     target_status: &Status,
     selected: usize,
     resolutions: &[String],
+    nav: &str,
 ) {
+    let theme = crate::theme::current();
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([Constraint::Min(0), Constraint::Length(1)])
@@ -531,17 +976,15 @@ fn render_resolution_picker(
 
     lines.push(Line::from(Span::styled(
         format!("Moving to {} — select resolution:", target_status.as_str()),
-        Style::default()
-            .fg(Color::White)
-            .add_modifier(Modifier::BOLD),
+        Style::default().fg(theme.text).add_modifier(Modifier::BOLD),
     )));
     lines.push(Line::from(""));
 
     for (i, resolution) in resolutions.iter().enumerate() {
         let prefix = if i == selected { "> " } else { "  " };
-        let mut style = Style::default().fg(Color::White);
+        let mut style = Style::default().fg(theme.text);
         if i == selected {
-            style = style.add_modifier(Modifier::BOLD).bg(Color::DarkGray);
+            style = style.add_modifier(Modifier::BOLD).patch(theme.selection());
         }
         lines.push(Line::from(Span::styled(
             format!("{}{}", prefix, resolution),
@@ -553,8 +996,8 @@ fn render_resolution_picker(
     f.render_widget(body, body_area);
 
     let footer = Paragraph::new(Line::from(Span::styled(
-        "[j/k/↑/↓] choose   [Enter] confirm   [Esc] back",
-        Style::default().fg(Color::DarkGray),
+        format!("[{}] choose   [Enter] confirm   [Esc] back", nav),
+        Style::default().fg(theme.muted),
     )));
     f.render_widget(footer, footer_area);
 }
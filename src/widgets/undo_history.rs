@@ -0,0 +1,77 @@
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::Paragraph;
+
+use super::form;
+use crate::app::App;
+
+pub fn render(f: &mut ratatui::Frame, app: &App) {
+    let theme = crate::theme::current();
+    let selected = match app.undo_history {
+        Some(s) => s,
+        None => return,
+    };
+
+    let inner = form::render_modal_frame(f, "Undo History", 60, 60);
+    let now = crate::jira_client::now_unix_secs();
+
+    let mut lines: Vec<Line> = Vec::new();
+    lines.push(Line::from(""));
+
+    if app.journal.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "  Nothing to undo",
+            Style::default().fg(theme.muted),
+        )));
+    }
+
+    // Newest first, so the top row is what Ctrl+Z would undo.
+    for (i, entry) in app.journal.iter().rev().enumerate() {
+        let prefix = if i == selected { "> " } else { "  " };
+        let style = if i == selected {
+            Style::default()
+                .fg(theme.highlight)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(theme.subtle)
+        };
+        let count = entry.changes.len();
+        lines.push(Line::from(vec![
+            Span::styled(format!("  {}{}", prefix, entry.label), style),
+            Span::styled(
+                format!(
+                    "  {} ticket{}, {} ago",
+                    count,
+                    if count == 1 { "" } else { "s" },
+                    format_age(now.saturating_sub(entry.recorded_at))
+                ),
+                Style::default().fg(theme.muted),
+            ),
+        ]));
+    }
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(""));
+
+    // Footer hints
+    lines.push(Line::from(Span::styled(
+        format!(
+            "[{}] navigate  [Enter] undo  [Esc] close",
+            app.keymap.picker_hint()
+        ),
+        Style::default().fg(theme.muted),
+    )));
+
+    let body = Paragraph::new(lines);
+    f.render_widget(body, inner);
+}
+
+fn format_age(secs: u64) -> String {
+    if secs < 60 {
+        format!("{}s", secs)
+    } else if secs < 3600 {
+        format!("{}m", secs / 60)
+    } else {
+        format!("{}h", secs / 3600)
+    }
+}
@@ -0,0 +1,74 @@
+use ratatui::style::Style;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::Paragraph;
+
+use super::form;
+use crate::app::App;
+
+pub fn render(f: &mut ratatui::Frame, app: &App) {
+    let theme = crate::theme::current();
+    let state = match &app.worklog_state {
+        Some(s) => s,
+        None => return,
+    };
+
+    let title = format!("Log Work on {}", state.ticket_key);
+    let inner = form::render_modal_frame(f, &title, 50, 40);
+
+    let mut lines: Vec<Line> = Vec::new();
+    lines.push(Line::from(""));
+
+    if let Some(ticket) = app.find_ticket(&state.ticket_key) {
+        let tracking = &ticket.time_tracking;
+        lines.push(Line::from(Span::styled(
+            format!(
+                "Estimate: {}  Remaining: {}  Logged: {}",
+                tracking.original_estimate.as_deref().unwrap_or("-"),
+                tracking.remaining_estimate.as_deref().unwrap_or("-"),
+                tracking.time_spent.as_deref().unwrap_or("-"),
+            ),
+            Style::default().fg(theme.muted),
+        )));
+        lines.push(Line::from(""));
+    }
+
+    // Duration text input (field 0)
+    form::render_text_input(
+        &mut lines,
+        "Time spent (e.g. 1h 30m)",
+        &state.duration,
+        state.focused_field == 0,
+    );
+
+    lines.push(Line::from(""));
+
+    // Date text input (field 1)
+    form::render_text_input(
+        &mut lines,
+        "Date (YYYY-MM-DD)",
+        &state.date,
+        state.focused_field == 1,
+    );
+
+    lines.push(Line::from(""));
+
+    // Comment text input (field 2)
+    form::render_text_input(
+        &mut lines,
+        "Comment",
+        &state.comment,
+        state.focused_field == 2,
+    );
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(""));
+
+    // Footer hints
+    lines.push(Line::from(Span::styled(
+        "[Tab] next field  [Enter] log  [Esc] cancel",
+        Style::default().fg(theme.muted),
+    )));
+
+    let body = Paragraph::new(lines);
+    f.render_widget(body, inner);
+}
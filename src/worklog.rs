@@ -0,0 +1,215 @@
+use anyhow::{bail, Context, Result};
+use chrono::{Local, NaiveDate};
+
+use crate::app::WorklogState;
+
+const SECS_PER_MINUTE: u64 = 60;
+const SECS_PER_HOUR: u64 = 60 * SECS_PER_MINUTE;
+/// Jira's default working day and week lengths.
+const SECS_PER_DAY: u64 = 8 * SECS_PER_HOUR;
+const SECS_PER_WEEK: u64 = 5 * SECS_PER_DAY;
+
+/// Start time used when logging work against a past date.
+const BACKDATED_START_TIME: &str = "09:00:00";
+
+/// A validated worklog ready to send to Jira.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorklogRequest {
+    pub ticket_key: String,
+    pub time_spent: String,
+    pub started: Option<String>,
+    pub comment: Option<String>,
+}
+
+/// Parse a Jira-style duration (`1h 30m`, `2d`, `1.5h`, `45m`) into seconds.
+pub fn parse_duration_secs(input: &str) -> Result<u64> {
+    let compact: String = input.chars().filter(|c| !c.is_whitespace()).collect();
+    if compact.is_empty() {
+        bail!("duration is required");
+    }
+
+    let mut total = 0f64;
+    let mut number = String::new();
+    for c in compact.chars() {
+        if c.is_ascii_digit() || c == '.' {
+            number.push(c);
+            continue;
+        }
+        let unit_secs = match c.to_ascii_lowercase() {
+            'w' => SECS_PER_WEEK,
+            'd' => SECS_PER_DAY,
+            'h' => SECS_PER_HOUR,
+            'm' => SECS_PER_MINUTE,
+            _ => bail!("invalid duration '{}': unknown unit '{}'", input.trim(), c),
+        };
+        let value: f64 = number
+            .parse()
+            .with_context(|| format!("invalid duration '{}'", input.trim()))?;
+        total += value * unit_secs as f64;
+        number.clear();
+    }
+
+    if !number.is_empty() {
+        bail!(
+            "invalid duration '{}': missing unit (w, d, h or m)",
+            input.trim()
+        );
+    }
+
+    let secs = total.round() as u64;
+    if secs < SECS_PER_MINUTE {
+        bail!("duration must be at least 1m");
+    }
+    Ok(secs)
+}
+
+/// Format seconds the way Jira displays time spent (`1d 2h 30m`).
+pub fn format_duration_secs(secs: u64) -> String {
+    let mut remaining = secs;
+    let mut parts = Vec::new();
+    for (unit, size) in [
+        ("w", SECS_PER_WEEK),
+        ("d", SECS_PER_DAY),
+        ("h", SECS_PER_HOUR),
+        ("m", SECS_PER_MINUTE),
+    ] {
+        let count = remaining / size;
+        if count > 0 {
+            parts.push(format!("{}{}", count, unit));
+            remaining %= size;
+        }
+    }
+    if parts.is_empty() {
+        "0m".to_string()
+    } else {
+        parts.join(" ")
+    }
+}
+
+/// Round a running timer up to whole minutes so short sessions still log 1m.
+pub fn timer_duration(elapsed_secs: u64) -> String {
+    let minutes = elapsed_secs.div_ceil(SECS_PER_MINUTE).max(1);
+    format_duration_secs(minutes * SECS_PER_MINUTE)
+}
+
+/// Compact elapsed-time label for the running timer (`1:05` or `12m`).
+pub fn format_elapsed(elapsed_secs: u64) -> String {
+    let hours = elapsed_secs / SECS_PER_HOUR;
+    let minutes = (elapsed_secs % SECS_PER_HOUR) / SECS_PER_MINUTE;
+    if hours > 0 {
+        format!("{}:{:02}", hours, minutes)
+    } else {
+        format!("{}m", minutes)
+    }
+}
+
+pub fn today() -> NaiveDate {
+    Local::now().date_naive()
+}
+
+pub fn format_date(date: NaiveDate) -> String {
+    date.format("%Y-%m-%d").to_string()
+}
+
+/// Validate the modal input and turn it into a Jira worklog request.
+pub fn prepare_request(state: &WorklogState, today: NaiveDate) -> Result<WorklogRequest> {
+    let secs = parse_duration_secs(&state.duration)?;
+
+    let date_input = state.date.trim();
+    let started = if date_input.is_empty() {
+        None
+    } else {
+        let date = NaiveDate::parse_from_str(date_input, "%Y-%m-%d")
+            .with_context(|| format!("invalid date '{}'; expected YYYY-MM-DD", date_input))?;
+        if date > today {
+            bail!("cannot log work in the future");
+        }
+        if date == today {
+            None
+        } else {
+            Some(format!("{} {}", format_date(date), BACKDATED_START_TIME))
+        }
+    };
+
+    let comment = Some(state.comment.trim().to_string()).filter(|c| !c.is_empty());
+
+    Ok(WorklogRequest {
+        ticket_key: state.ticket_key.clone(),
+        time_spent: format_duration_secs(secs),
+        started,
+        comment,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn state(duration: &str, date: &str) -> WorklogState {
+        WorklogState {
+            ticket_key: "AMP-1".to_string(),
+            focused_field: 0,
+            duration: duration.to_string(),
+            date: date.to_string(),
+            comment: "  pairing  ".to_string(),
+        }
+    }
+
+    fn day(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").expect("date")
+    }
+
+    #[test]
+    fn parse_duration_accepts_jira_units() {
+        assert_eq!(parse_duration_secs("1h 30m").unwrap(), 5400);
+        assert_eq!(parse_duration_secs("1h30m").unwrap(), 5400);
+        assert_eq!(parse_duration_secs("1.5h").unwrap(), 5400);
+        assert_eq!(parse_duration_secs("2d").unwrap(), 2 * 8 * 3600);
+        assert_eq!(parse_duration_secs("1w").unwrap(), 5 * 8 * 3600);
+    }
+
+    #[test]
+    fn parse_duration_rejects_bad_input() {
+        assert!(parse_duration_secs("").is_err());
+        assert!(parse_duration_secs("90").is_err());
+        assert!(parse_duration_secs("3x").is_err());
+        assert!(parse_duration_secs("0m").is_err());
+    }
+
+    #[test]
+    fn format_duration_round_trips_through_parse() {
+        assert_eq!(format_duration_secs(5400), "1h 30m");
+        assert_eq!(format_duration_secs(9 * 3600), "1d 1h");
+        assert_eq!(
+            parse_duration_secs(&format_duration_secs(123 * 60)).unwrap(),
+            123 * 60
+        );
+    }
+
+    #[test]
+    fn timer_duration_rounds_up_to_a_minute() {
+        assert_eq!(timer_duration(0), "1m");
+        assert_eq!(timer_duration(61), "2m");
+        assert_eq!(timer_duration(3600), "1h");
+    }
+
+    #[test]
+    fn prepare_request_omits_started_for_today() {
+        let req = prepare_request(&state("45m", "2026-03-02"), day("2026-03-02")).unwrap();
+        assert_eq!(req.time_spent, "45m");
+        assert_eq!(req.started, None);
+        assert_eq!(req.comment.as_deref(), Some("pairing"));
+    }
+
+    #[test]
+    fn prepare_request_backdates_past_days() {
+        let req = prepare_request(&state("2h", "2026-02-27"), day("2026-03-02")).unwrap();
+        assert_eq!(req.started.as_deref(), Some("2026-02-27 09:00:00"));
+    }
+
+    #[test]
+    fn prepare_request_rejects_future_and_malformed_dates() {
+        assert!(prepare_request(&state("1h", "2026-03-03"), day("2026-03-02")).is_err());
+        assert!(prepare_request(&state("1h", "03/02/2026"), day("2026-03-02")).is_err());
+    }
+}